@@ -1,16 +1,31 @@
+# Without the Wii toolchain, build the host simulation to run apps as Linux processes.
+if(CMAKE_SYSTEM_NAME STREQUAL "Linux")
+  option(POWERBLOCKS_HOST "Build PowerBlocks for the host simulation" ON)
+else()
+  option(POWERBLOCKS_HOST "Build PowerBlocks for the host simulation" OFF)
+endif()
+
 add_library(PowerBlocksCommon INTERFACE)
 target_include_directories(PowerBlocksCommon INTERFACE
   ${CMAKE_CURRENT_LIST_DIR}
 )
 
-# Add picolibc to PowerBlocks::Common
-target_include_directories(PowerBlocksCommon INTERFACE
-  ${CMAKE_CURRENT_LIST_DIR}/picolibc/include
-)
-target_link_libraries(
-  PowerBlocksCommon INTERFACE
-  ${CMAKE_CURRENT_LIST_DIR}/picolibc/lib/libc.a
-)
+if(POWERBLOCKS_HOST)
+  # The host uses its own libc, and FreeRTOS runs on pthreads
+  find_package(Threads REQUIRED)
+
+  target_compile_definitions(PowerBlocksCommon INTERFACE POWERBLOCKS_HOST)
+  target_link_libraries(PowerBlocksCommon INTERFACE Threads::Threads m)
+else()
+  # Add picolibc to PowerBlocks::Common
+  target_include_directories(PowerBlocksCommon INTERFACE
+    ${CMAKE_CURRENT_LIST_DIR}/picolibc/include
+  )
+  target_link_libraries(
+    PowerBlocksCommon INTERFACE
+    ${CMAKE_CURRENT_LIST_DIR}/picolibc/lib/libc.a
+  )
+endif()
 
 add_library(PowerBlocks::Common ALIAS PowerBlocksCommon)
 
@@ -24,4 +39,10 @@ add_subdirectory(
     ${CMAKE_CURRENT_BINARY_DIR}/tools/dspasm_build # Here to make cmake not complain about out of
 )
 
+# Include ROM File System Tools
+add_subdirectory(
+    ${CMAKE_CURRENT_LIST_DIR}/tools/romfs
+    ${CMAKE_CURRENT_BINARY_DIR}/tools/romfs_build
+)
+
 add_subdirectory("${CMAKE_CURRENT_LIST_DIR}/powerblocks" "${CMAKE_BINARY_DIR}/powerblocks")
\ No newline at end of file
@@ -0,0 +1,20 @@
+# Host tests, built apart from the SDK with the host's own compiler:
+#   cmake -S <tests dir> -B build-tests && cmake --build build-tests && ctest --test-dir build-tests
+
+# Shared test header and the FreeRTOS stand ins.
+set(POWERBLOCKS_TESTS_DIR ${CMAKE_CURRENT_LIST_DIR}/../powerblocks/tests)
+
+enable_testing()
+
+#
+# add_host_test(<name> <source>...)
+#
+# Builds <name> from the sources and runs it as a CTest test. Tests
+# include "test.h" for CHECK and test_summary. FreeRTOS.h and semphr.h
+# resolve to stubs that do nothing, as the tests run on one thread.
+#
+function(add_host_test name)
+    add_executable(${name} ${ARGN})
+    target_include_directories(${name} PRIVATE ${POWERBLOCKS_TESTS_DIR} ${POWERBLOCKS_TESTS_DIR}/stubs)
+    add_test(NAME ${name} COMMAND ${name})
+endfunction()
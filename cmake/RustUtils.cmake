@@ -1,27 +1,153 @@
 include(ExternalProject)
 
+# Directory of the SDK's Rust crates and target spec.
+set(POWERBLOCKS_RUST_DIR ${CMAKE_CURRENT_LIST_DIR}/../rust)
+set(POWERBLOCKS_RUST_TARGET ${POWERBLOCKS_RUST_DIR}/powerpc750.json CACHE FILEPATH
+    "Rust target spec for the Wii's PPC750")
+
+#
+# add_rust_library(<name> <crate_dir>
+#                  [CRATES <dir>...]
+#                  [FEATURES <feature>...]
+#                  [ALLOC]
+#                  [NO_HEADER])
+#
+# Builds a Rust static library with Cargo and imports it as <name>.
+#
+# Without CRATES, <crate_dir> is a crate with crate-type "staticlib" whose
+# package is called <name>. With CRATES, each <dir> (relative to
+# <crate_dir>) is an rlib crate, and they are linked into a single static
+# library generated in the build folder. Only one copy of core then ends
+# up in the ELF, which linking several Rust static libraries would not give.
+#
+# The Cargo profile follows CMAKE_BUILD_TYPE: Debug builds use "dev", and
+# everything else "release". FEATURES are passed to Cargo as they are, so
+# with CRATES they are written "<crate>/<feature>". ALLOC builds the alloc
+# crate along with core.
+#
+# Unless NO_HEADER is given, cbindgen writes <name>.h declaring the
+# crate's exported functions, and it is added to <name>'s include
+# directories. A cbindgen.toml in <crate_dir> is used if there is one.
+#
 function(add_rust_library NAME CRATE_DIR)
+    cmake_parse_arguments(RUST "ALLOC;NO_HEADER" "" "CRATES;FEATURES" ${ARGN})
+
     set(RUST_TARGET_DIR ${CMAKE_BINARY_DIR}/rust-target)
-    set(RUST_JSON ${CRATE_DIR}/powerpc750.json)
-    set(RUST_LIB_PATH ${RUST_TARGET_DIR}/powerpc750/release/lib${NAME}.a)
+    get_filename_component(RUST_TARGET_NAME ${POWERBLOCKS_RUST_TARGET} NAME_WE)
+
+    if(CMAKE_BUILD_TYPE STREQUAL "Debug")
+        set(RUST_PROFILE dev)
+        set(RUST_PROFILE_DIR debug)
+    else()
+        set(RUST_PROFILE release)
+        set(RUST_PROFILE_DIR release)
+    endif()
+
+    if(RUST_ALLOC)
+        set(RUST_BUILD_STD core,alloc)
+    else()
+        set(RUST_BUILD_STD core)
+    endif()
+
+    set(RUST_CARGO_ARGS
+        --profile ${RUST_PROFILE}
+        --target ${POWERBLOCKS_RUST_TARGET}
+        --target-dir ${RUST_TARGET_DIR}
+        -Z build-std=${RUST_BUILD_STD}
+        -Z json-target-spec
+    )
+    if(RUST_FEATURES)
+        string(REPLACE ";" "," RUST_FEATURE_LIST "${RUST_FEATURES}")
+        list(APPEND RUST_CARGO_ARGS --features ${RUST_FEATURE_LIST})
+    endif()
+
+    set(RUST_CBINDGEN_CONFIG "language = \"C\"\npragma_once = true\n")
+    if(RUST_CRATES)
+        # Generate a crate that pulls every listed crate into one staticlib.
+        set(RUST_BUILD_DIR ${CMAKE_CURRENT_BINARY_DIR}/${NAME}-rust)
+        set(RUST_DEPENDENCIES "")
+        set(RUST_EXTERNS "")
+        set(RUST_CRATE_NAMES "")
+        foreach(CRATE ${RUST_CRATES})
+            get_filename_component(CRATE_PATH ${CRATE} ABSOLUTE BASE_DIR ${CRATE_DIR})
+            file(STRINGS ${CRATE_PATH}/Cargo.toml CRATE_NAME_LINE REGEX "^name *= *\"[^\"]+\"" LIMIT_COUNT 1)
+            string(REGEX REPLACE "^name *= *\"([^\"]+)\".*" "\\1" CRATE_NAME "${CRATE_NAME_LINE}")
+            string(REPLACE "-" "_" CRATE_IDENT ${CRATE_NAME})
+
+            string(APPEND RUST_DEPENDENCIES "${CRATE_NAME} = { path = \"${CRATE_PATH}\" }\n")
+            string(APPEND RUST_EXTERNS "extern crate ${CRATE_IDENT};\n")
+            list(APPEND RUST_CRATE_NAMES "\"${CRATE_NAME}\"")
+        endforeach()
+        string(REPLACE ";" ", " RUST_CRATE_NAMES "${RUST_CRATE_NAMES}")
+
+        file(WRITE ${RUST_BUILD_DIR}/Cargo.toml
+            "[package]\n"
+            "name = \"${NAME}\"\n"
+            "version = \"0.1.0\"\n"
+            "edition = \"2021\"\n\n"
+            "[lib]\n"
+            "path = \"lib.rs\"\n"
+            "crate-type = [\"staticlib\"]\n\n"
+            "[dependencies]\n"
+            "${RUST_DEPENDENCIES}\n"
+            "[profile.release]\n"
+            "panic = \"abort\"\n"
+            "lto = true\n\n"
+            "[profile.dev]\n"
+            "panic = \"abort\"\n\n"
+            "[workspace]\n"
+        )
+        file(WRITE ${RUST_BUILD_DIR}/lib.rs "#![no_std]\n\n${RUST_EXTERNS}")
+        string(APPEND RUST_CBINDGEN_CONFIG "\n[parse]\nparse_deps = true\ninclude = [${RUST_CRATE_NAMES}]\n")
+    else()
+        set(RUST_BUILD_DIR ${CRATE_DIR})
+    endif()
+
+    string(REPLACE "-" "_" RUST_LIB_NAME ${NAME})
+    set(RUST_LIB_PATH ${RUST_TARGET_DIR}/${RUST_TARGET_NAME}/${RUST_PROFILE_DIR}/lib${RUST_LIB_NAME}.a)
+
+    set(RUST_HEADER_COMMAND "")
+    set(RUST_HEADER_DIR ${CMAKE_CURRENT_BINARY_DIR}/${NAME}-include)
+    set(RUST_HEADER ${RUST_HEADER_DIR}/${NAME}.h)
+    if(NOT RUST_NO_HEADER)
+        find_program(CBINDGEN cbindgen HINTS $ENV{HOME}/.cargo/bin)
+        if(NOT CBINDGEN)
+            message(FATAL_ERROR "add_rust_library needs cbindgen to generate ${NAME}.h. "
+                                "Install it with `cargo install cbindgen`, or pass NO_HEADER.")
+        endif()
+
+        if(EXISTS ${CRATE_DIR}/cbindgen.toml)
+            set(RUST_CBINDGEN_TOML ${CRATE_DIR}/cbindgen.toml)
+        else()
+            set(RUST_CBINDGEN_TOML ${CMAKE_CURRENT_BINARY_DIR}/${NAME}-cbindgen.toml)
+            file(WRITE ${RUST_CBINDGEN_TOML} ${RUST_CBINDGEN_CONFIG})
+        endif()
+
+        file(MAKE_DIRECTORY ${RUST_HEADER_DIR})
+        set(RUST_HEADER_COMMAND
+            COMMAND ${CBINDGEN} --quiet --config ${RUST_CBINDGEN_TOML} --output ${RUST_HEADER} ${RUST_BUILD_DIR}
+        )
+    endif()
 
     ExternalProject_Add(
         ${NAME}_ext
         DOWNLOAD_COMMAND ""
         CONFIGURE_COMMAND ""
-        BUILD_COMMAND cargo +nightly build --release
-                      --target ${RUST_JSON}
-                      --target-dir ${RUST_TARGET_DIR}
-                      -Z build-std=core
-        BINARY_DIR ${CRATE_DIR}
+        BUILD_COMMAND cargo +nightly build ${RUST_CARGO_ARGS}
+                      ${RUST_HEADER_COMMAND}
+        BINARY_DIR ${RUST_BUILD_DIR}
         INSTALL_COMMAND ""
-        BUILD_BYPRODUCTS ${RUST_LIB_PATH}
+        BUILD_ALWAYS ON
+        BUILD_BYPRODUCTS ${RUST_LIB_PATH} ${RUST_HEADER}
         LOG_BUILD 1
         LOG_OUTPUT_ON_FAILURE 1
     )
 
     add_library(${NAME} STATIC IMPORTED)
     set_target_properties(${NAME} PROPERTIES IMPORTED_LOCATION ${RUST_LIB_PATH})
+    if(NOT RUST_NO_HEADER)
+        set_target_properties(${NAME} PROPERTIES INTERFACE_INCLUDE_DIRECTORIES ${RUST_HEADER_DIR})
+    endif()
 
     add_dependencies(${NAME} ${NAME}_ext)
 endfunction()
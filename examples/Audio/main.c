@@ -0,0 +1,101 @@
+#include "powerblocks/core/system/system.h"
+#include "powerblocks/core/ios/ios.h"
+#include "powerblocks/core/ios/ios_settings.h"
+
+#include "powerblocks/core/graphics/video.h"
+#include "powerblocks/core/audio/audio.h"
+
+#include "powerblocks/core/utils/fonts.h"
+#include "powerblocks/core/utils/console.h"
+
+#include <stdio.h>
+#include <math.h>
+
+#define AUDIO_FRAMES 1024
+#define SINE_TABLE_SIZE 256
+
+framebuffer_t frame_buffer ALIGN(512);
+
+// Played one after another. While one plays, the other is refilled.
+int16_t audio_buffer_a[AUDIO_FRAMES * AUDIO_CHANNELS] ALIGN(AUDIO_BUFFER_ALIGNMENT);
+int16_t audio_buffer_b[AUDIO_FRAMES * AUDIO_CHANNELS] ALIGN(AUDIO_BUFFER_ALIGNMENT);
+
+int16_t sine_table[SINE_TABLE_SIZE];
+
+// Position in the sine table, in 16.16 fixed point
+uint32_t phase;
+volatile uint32_t phase_step;
+
+void retrace_callback() {
+    // Make it so we can see the framebuffer changes
+    system_flush_dcache(&frame_buffer, sizeof(frame_buffer));
+}
+
+void set_tone(float frequency) {
+    phase_step = (uint32_t)(frequency * SINE_TABLE_SIZE * 65536.0f / audio_get_sample_rate_hz());
+}
+
+void fill_tone(int16_t* buffer, uint32_t frames) {
+    for(uint32_t i = 0; i < frames; i++) {
+        int16_t sample = sine_table[(phase >> 16) % SINE_TABLE_SIZE] / 4;
+        buffer[i * 2 + 0] = sample;
+        buffer[i * 2 + 1] = sample;
+        phase += phase_step;
+    }
+
+    audio_commit_buffer(buffer, frames);
+}
+
+int main() {
+    // Initialize IOS. Must be done first as many thing use it
+    ios_initialize();
+
+    // Get default video mode from IOS and use it to initialize the video interface.
+    video_mode_t tv_mode = video_system_default_video_mode();
+    video_initialize(tv_mode);
+
+    // Fill background with black
+    console_initialize(&frame_buffer, &fonts_ibm_iso_8x16);
+    video_set_framebuffer(&frame_buffer);
+
+    // Set the retrace callback to flush the framebuffer before drawing.
+    video_set_retrace_callback(retrace_callback);
+
+    // Create Black Background
+    framebuffer_fill_rgba(&frame_buffer, 0x000000FF, vec2i_new(0,0), vec2i_new(VIDEO_WIDTH, VIDEO_HEIGHT));
+
+    // Back Text To White with a Black Background
+    console_set_text_color(0xFFFFFFFF, 0x000000FF);
+
+    printf("\n\n\n");
+    printf("  PowerBlocks SDK Audio Example\n");
+
+    for(int i = 0; i < SINE_TABLE_SIZE; i++) {
+        sine_table[i] = (int16_t)(sinf(i * 2.0f * (float)M_PI / SINE_TABLE_SIZE) * 32767.0f);
+    }
+
+    audio_initialize(AUDIO_SAMPLE_RATE_48KHZ);
+
+    // Fill both buffers before playing, the callback refills them after.
+    set_tone(440.0f);
+    fill_tone(audio_buffer_a, AUDIO_FRAMES);
+    fill_tone(audio_buffer_b, AUDIO_FRAMES);
+
+    audio_start(audio_buffer_a, audio_buffer_b, sizeof(audio_buffer_a), fill_tone);
+
+    // Step up a semitone each second.
+    int note = 0;
+    while(true) {
+        float frequency = 440.0f * powf(2.0f, note / 12.0f);
+        set_tone(frequency);
+
+        printf("  Playing %.1f Hz\n", frequency);
+
+        for(int i = 0; i < 60; i++)
+            video_wait_vsync();
+
+        note = (note + 1) % 12;
+    }
+
+    return 0;
+}
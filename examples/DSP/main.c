@@ -0,0 +1,65 @@
+#include "powerblocks/core/system/system.h"
+#include "powerblocks/core/ios/ios.h"
+#include "powerblocks/core/ios/ios_settings.h"
+
+#include "powerblocks/core/graphics/video.h"
+#include "powerblocks/core/dsp/dsp.h"
+
+#include "powerblocks/core/utils/fonts.h"
+#include "powerblocks/core/utils/console.h"
+
+#include "dsp_microcode.h"
+
+#include <stdio.h>
+
+framebuffer_t frame_buffer ALIGN(512);
+
+void retrace_callback() {
+    // Make it so we can see the framebuffer changes
+    system_flush_dcache(&frame_buffer, sizeof(frame_buffer));
+}
+
+int main() {
+    // Initialize IOS. Must be done first as many thing use it
+    ios_initialize();
+
+    // Get default video mode from IOS and use it to initialize the video interface.
+    video_mode_t tv_mode = video_system_default_video_mode();
+    video_initialize(tv_mode);
+
+    // Fill background with black
+    console_initialize(&frame_buffer, &fonts_ibm_iso_8x16);
+    video_set_framebuffer(&frame_buffer);
+
+    // Set the retrace callback to flush the framebuffer before drawing.
+    video_set_retrace_callback(retrace_callback);
+
+    // Create Black Background
+    framebuffer_fill_rgba(&frame_buffer, 0x000000FF, vec2i_new(0,0), vec2i_new(VIDEO_WIDTH, VIDEO_HEIGHT));
+
+    // Back Text To White with a Black Background
+    console_set_text_color(0xFFFFFFFF, 0x000000FF);
+
+    printf("\n\n\n");
+    printf("  PowerBlocks SDK DSP Example\n");
+
+    dsp_initialize();
+
+    if(dsp_load(dsp_microcode, dsp_microcode_size) < 0) {
+        printf("  Failed to load the microcode.\n");
+    } else {
+        // The microcode sends one mail as soon as it starts.
+        uint32_t mail;
+        if(dsp_receive_mail(&mail, pdMS_TO_TICKS(1000)) < 0) {
+            printf("  No mail from the DSP.\n");
+        } else {
+            printf("  DSP sent 0x%08X\n", (unsigned int)mail);
+        }
+    }
+
+    while(true) {
+        video_wait_vsync();
+    }
+
+    return 0;
+}
@@ -0,0 +1,22 @@
+// Sends one mail to the CPU, then halts.
+//
+// dspasm does not know the instructions yet, so they are written out by hand.
+
+#define DSP_DIRQ 0xFFFB // Raise the DSP interrupt on the CPU
+#define DSP_DMBH 0xFFFC // DSP to CPU mailbox
+#define DSP_DMBL 0xFFFD
+
+#define MAIL_HELLO 0xDCD10000
+
+start:
+    // SI @DMBH, #(MAIL_HELLO >> 16)
+    .half 0x1600 | (DSP_DMBH & 0xFF), MAIL_HELLO >> 16
+
+    // SI @DMBL, #(MAIL_HELLO & 0xFFFF), writing the low half sends it.
+    .half 0x1600 | (DSP_DMBL & 0xFF), MAIL_HELLO & 0xFFFF
+
+    // SI @DIRQ, #1
+    .half 0x1600 | (DSP_DIRQ & 0xFF), 1
+
+    // HALT
+    .half 0x0021
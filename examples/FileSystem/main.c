@@ -1,6 +1,7 @@
 #include "powerblocks/core/system/system.h"
 #include "powerblocks/core/ios/ios.h"
 #include "powerblocks/core/ios/ios_settings.h"
+#include "powerblocks/core/ios/isfs.h"
 
 #include "powerblocks/core/graphics/video.h"
 
@@ -8,12 +9,14 @@
 #include "powerblocks/core/utils/console.h"
 
 #include "powerblocks/filesystem/sd.h"
+#include "powerblocks/filesystem/usb_storage.h"
+#include "powerblocks/filesystem/vfs_fatfs.h"
+#include "powerblocks/filesystem/vfs_isfs.h"
 
 #include <stdio.h>
 #include <math.h>
 #include <unistd.h>
-
-#include "ff.h"
+#include <dirent.h>
 
 framebuffer_t frame_buffer ALIGN(512);
 
@@ -22,8 +25,6 @@ void retrace_callback() {
     system_flush_dcache(&frame_buffer, sizeof(frame_buffer));
 }
 
-FATFS fs;
-
 static void write_time_announcement(FILE* fp) {
     fprintf(fp, "Hello World From PowerBlocks!\n");
 
@@ -43,33 +44,34 @@ static void write_gossip(FILE* fp) {
     fprintf(fp, "look for boyfriends\n");
 }
 
-static void file_system_example() {
-    FRESULT fr;
-    // Lets list the directories
-    DIR dir;
-    fr = f_opendir(&dir, ".");
-    if(fr != FR_OK) {
-        printf("Failed to open directory. Error: %d!\n", fr);
+static void list_directory(const char* path) {
+    DIR* dir = opendir(path);
+    if(dir == NULL) {
+        printf("Failed to open directory.\n");
         return;
     }
 
-    FILINFO fno;
-    while(true) {
-        fr = f_readdir(&dir, &fno);
-
-        // Break on error or last file
-        if(fr != FR_OK || fno.fname[0] == 0)
-            break;
-        
-        if(fno.fattrib & AM_DIR) {
-            printf("<DIR>  %s\n", fno.fname);
+    struct dirent* entry;
+    while((entry = readdir(dir)) != NULL) {
+        if(entry->d_type == DT_DIR) {
+            printf("<DIR>  %s\n", entry->d_name);
         } else {
-            printf("<FILE> %s (%d bytes)\n", fno.fname, fno.fsize);
+            printf("<FILE> %s\n", entry->d_name);
         }
     }
 
+    closedir(dir);
+}
+
+static void file_system_example() {
+    // Lets list the directories
+    list_directory(".");
+
     // Check for mask of truth
-    bool truth_exist = f_stat("truth.txt", &fno) == FR_OK;
+    FILE* truth = fopen("truth.txt", "r");
+    bool truth_exist = truth != NULL;
+    if(truth_exist)
+        fclose(truth);
 
 
     // Lets create a new file using libc
@@ -137,10 +139,9 @@ int main() {
     // Initialize the SD card interface
     sd_initialize();
 
-    // Mount the file system, in this case "" defaults to SD.
-    FRESULT fr = f_mount(&fs, "0:", 1);
-    if(fr != FR_OK) {
-        printf("Failed to mount. Error: %d!\n", fr);
+    // Mount the SD card as "sd:/"
+    if(vfs_fatfs_mount("sd", DISK_DEV_SD) < 0) {
+        printf("Failed to mount the SD card.\n");
         goto ERROR;
     }
 
@@ -149,9 +150,25 @@ int main() {
     system_get_boot_path("sd", game_dir, sizeof(game_dir));
 
     // Change dir to it, and run the example.
-    printf("Game launched from: %s\n", game_dir);
+    // Paths without a device, like this one, stay on the current device.
+    printf("Game launched from: sd:%s\n", game_dir);
     chdir(game_dir);
     file_system_example();
+
+    // A USB drive is optional, list it if one is plugged in
+    usb_storage_initialize();
+    if(vfs_fatfs_mount("usb", DISK_DEV_USB) == 0) {
+        printf("USB drive:\n");
+        list_directory("usb:/");
+    }
+
+    // The NAND can be browsed too, as far as IOS lets this title.
+    // /shared2 is open to everyone.
+    isfs_initialize();
+    if(vfs_isfs_mount("nand") == 0) {
+        printf("NAND /shared2:\n");
+        list_directory("nand:/shared2");
+    }
 ERROR:
 
     while(true) {
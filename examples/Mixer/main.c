@@ -0,0 +1,112 @@
+#include "powerblocks/core/system/system.h"
+#include "powerblocks/core/ios/ios.h"
+#include "powerblocks/core/ios/ios_settings.h"
+
+#include "powerblocks/core/graphics/video.h"
+
+#include "powerblocks/core/utils/fonts.h"
+#include "powerblocks/core/utils/console.h"
+
+#include "powerblocks/mixer/mixer.h"
+
+#include <stdio.h>
+#include <math.h>
+
+#define MIXER_FRAMES 512
+
+// One cycle of each wave, played at a pitch to get the note.
+#define WAVE_LENGTH 64
+#define WAVE_RATE   (440 * WAVE_LENGTH)
+
+framebuffer_t frame_buffer ALIGN(512);
+
+int16_t sine_wave[WAVE_LENGTH];
+int8_t square_wave[WAVE_LENGTH];
+
+mixer_sound_t tone;
+mixer_sound_t note;
+
+void retrace_callback() {
+    // Make it so we can see the framebuffer changes
+    system_flush_dcache(&frame_buffer, sizeof(frame_buffer));
+}
+
+int main() {
+    // Initialize IOS. Must be done first as many thing use it
+    ios_initialize();
+
+    // Get default video mode from IOS and use it to initialize the video interface.
+    video_mode_t tv_mode = video_system_default_video_mode();
+    video_initialize(tv_mode);
+
+    // Fill background with black
+    console_initialize(&frame_buffer, &fonts_ibm_iso_8x16);
+    video_set_framebuffer(&frame_buffer);
+
+    // Set the retrace callback to flush the framebuffer before drawing.
+    video_set_retrace_callback(retrace_callback);
+
+    // Create Black Background
+    framebuffer_fill_rgba(&frame_buffer, 0x000000FF, vec2i_new(0,0), vec2i_new(VIDEO_WIDTH, VIDEO_HEIGHT));
+
+    // Back Text To White with a Black Background
+    console_set_text_color(0xFFFFFFFF, 0x000000FF);
+
+    printf("\n\n\n");
+    printf("  PowerBlocks SDK Mixer Example\n");
+
+    for(int i = 0; i < WAVE_LENGTH; i++) {
+        sine_wave[i] = (int16_t)(sinf(i * 2.0f * (float)M_PI / WAVE_LENGTH) * 32767.0f);
+        square_wave[i] = i < WAVE_LENGTH / 2 ? 127 : -127;
+    }
+
+    // Both loop, the note is stopped when the next one plays.
+    tone = (mixer_sound_t){
+        .format = MIXER_FORMAT_PCM16,
+        .data = sine_wave,
+        .length = WAVE_LENGTH,
+        .sample_rate = WAVE_RATE,
+        .loop = true,
+    };
+
+    note = (mixer_sound_t){
+        .format = MIXER_FORMAT_PCM8,
+        .data = square_wave,
+        .length = WAVE_LENGTH,
+        .sample_rate = WAVE_RATE,
+        .loop = true,
+    };
+
+    mixer_initialize(AUDIO_SAMPLE_RATE_48KHZ, MIXER_FRAMES);
+
+    int tone_voice = mixer_find_free_voice();
+    mixer_set_volume(tone_voice, MIXER_VOLUME_MAX / 4);
+    mixer_set_pitch(tone_voice, 0.5f);
+    mixer_play(tone_voice, &tone);
+
+    // Pan the tone side to side, and play a note of a major scale every half second.
+    static const int scale[] = { 0, 2, 4, 5, 7, 9, 11, 12 };
+    int step = 0;
+    int note_voice = -1;
+    while(true) {
+        if(note_voice >= 0)
+            mixer_stop(note_voice);
+
+        note_voice = mixer_find_free_voice();
+        mixer_set_volume(note_voice, MIXER_VOLUME_MAX / 8);
+        mixer_set_pitch(note_voice, powf(2.0f, scale[step] / 12.0f));
+        mixer_play(note_voice, &note);
+
+        printf("  Playing note %d\n", step + 1);
+
+        for(int i = 0; i < 30; i++) {
+            float angle = (step * 30 + i) * 2.0f * (float)M_PI / 240.0f;
+            mixer_set_pan(tone_voice, (int8_t)(sinf(angle) * MIXER_PAN_RIGHT));
+            video_wait_vsync();
+        }
+
+        step = (step + 1) % (sizeof(scale) / sizeof(scale[0]));
+    }
+
+    return 0;
+}
@@ -7,6 +7,9 @@
 #include "powerblocks/core/utils/fonts.h"
 #include "powerblocks/core/utils/console.h"
 
+// Generated from rust-lib by cbindgen.
+#include "rust_lib.h"
+
 #include <stdio.h>
 #include <math.h>
 #include <stdlib.h>
@@ -18,8 +21,6 @@ void retrace_callback() {
     system_flush_dcache(&frame_buffer, sizeof(frame_buffer));
 }
 
-extern int rust_add(int a, int b);
-
 static uint32_t rng_state = 0x12345678; // default seed
 
 uint32_t rand32() {
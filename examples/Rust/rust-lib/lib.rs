@@ -1,14 +1,10 @@
 #![no_std]
 #![no_main]
 
-use core::panic::PanicInfo;
+// Panics are reported on the crash screen by powerblocks' panic handler.
+extern crate powerblocks;
 
 #[no_mangle]
 pub extern "C" fn rust_add(a: i32, b: i32) -> i32 {
     a + b
 }
-
-#[panic_handler]
-fn panic(_info: &PanicInfo) -> ! {
-    loop {}
-}
@@ -9,6 +9,7 @@
  * @license MIT (see LICENSE file)
  */
 #include <math.h>
+#include <stdio.h>
 
 #include "powerblocks/core/system/system.h"
 #include "powerblocks/core/ios/ios.h"
@@ -20,10 +21,14 @@
 #include "powerblocks/core/utils/math/matrix4.h"
 #include "powerblocks/core/utils/math/matrix34.h"
 
+#include "powerblocks/filesystem/vfs_romfs.h"
+
 #include "utah_teapot.h"
+#include "teapot_romfs.h"
 
 framebuffer_t frame_buffer ALIGN(512);
 uint8_t fifo_buffer[GX_FIFO_MINIMUM_SIZE] ALIGN(32);
+uint8_t teapot_texture[UTAH_TEAPOT_TEXTURE_WIDTH * UTAH_TEAPOT_TEXTURE_HEIGHT] ALIGN(32);
 
 // Set to true so that on the next vblank
 // period we copy the new frame buffer
@@ -198,11 +203,26 @@ static void setup_cool_tev_lighting() {
     gx_flash_tev_stage(GX_TEV_STAGE_1, &lighting);
 }
 
+static void load_texture() {
+    // The texture is packed into the romfs, read it like any other file.
+    FILE* fp = fopen("romfs:/teapot_texture.i8", "rb");
+    if(fp == NULL)
+        return;
+
+    fread(teapot_texture, 1, sizeof(teapot_texture), fp);
+    fclose(fp);
+
+    // GX reads it from memory, not the cache
+    system_flush_dcache(teapot_texture, sizeof(teapot_texture));
+}
+
 static void setup_texturing() {
+    load_texture();
+
     // Generate texture data
     gx_texture_t cool_texture;
-    gx_initialize_texture(&cool_texture, utah_teapot_texture_data, GX_TEXTURE_FORMAT_I8,
-        utah_teapot_texture_width, utah_teapot_texture_height, GX_WRAP_REPEAT, GX_WRAP_REPEAT, false);
+    gx_initialize_texture(&cool_texture, teapot_texture, GX_TEXTURE_FORMAT_I8,
+        UTAH_TEAPOT_TEXTURE_WIDTH, UTAH_TEAPOT_TEXTURE_HEIGHT, GX_WRAP_REPEAT, GX_WRAP_REPEAT, false);
     
     gx_flash_texture(GX_TEXTURE_MAP_0, &cool_texture);
 
@@ -285,6 +305,9 @@ int main() {
     video_set_retrace_callback(copy_framebuffer);
     video_set_framebuffer(&frame_buffer);
 
+    // Assets are built into the app, under "romfs:/"
+    vfs_romfs_mount("romfs", teapot_romfs, teapot_romfs_size);
+
     // Initialize the graphics API
     const video_profile_t* profile = video_get_profile(tv_mode);
     gx_fifo_t fifo;
@@ -27,9 +27,8 @@ extern const uint16_t utah_teapot_model_indices[];
 extern const uint16_t utah_teapot_model_indices_count;
 extern const float utah_teapot_model_texcoords_scale;
 
-// Texture Data
-extern const uint16_t utah_teapot_texture_width;
-extern const uint16_t utah_teapot_texture_height;
-extern const uint8_t utah_teapot_texture_data[];
+// Texture, loaded from romfs:/teapot_texture.i8
+#define UTAH_TEAPOT_TEXTURE_WIDTH  128
+#define UTAH_TEAPOT_TEXTURE_HEIGHT 128
 
 extern void utah_teapot_draw();
\ No newline at end of file
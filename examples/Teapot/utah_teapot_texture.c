@@ -1,1033 +0,0 @@
-#include "utah_teapot.h"
-
-#include "powerblocks/core/system/system.h"
-
-const uint16_t utah_teapot_texture_width = 128;
-const uint16_t utah_teapot_texture_height = 128;
-
-const uint8_t utah_teapot_texture_data[] ALIGN(32) = {
-	10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 11, 10, 
-10, 10, 25, 229, 255, 255, 255, 255, 10, 19, 165, 255, 255, 255, 255, 255, 
-10, 131, 255, 255, 255, 255, 255, 108, 10, 47, 240, 255, 255, 255, 255, 34, 
-255, 255, 222, 221, 237, 253, 255, 255, 154, 29, 14, 34, 79, 78, 76, 198, 
-17, 13, 29, 226, 255, 220, 67, 50, 11, 16, 13, 203, 255, 255, 152, 38, 
-229, 41, 26, 10, 10, 10, 10, 10, 255, 214, 58, 45, 10, 10, 10, 10, 
-112, 255, 255, 141, 20, 10, 10, 10, 86, 255, 255, 255, 210, 95, 13, 10, 
-46, 255, 255, 255, 255, 255, 255, 255, 16, 255, 255, 255, 255, 255, 255, 255, 
-10, 184, 255, 255, 255, 255, 255, 100, 10, 15, 228, 255, 255, 255, 255, 157, 
-255, 255, 194, 194, 220, 252, 255, 255, 114, 29, 10, 11, 29, 29, 28, 157, 
-81, 85, 35, 201, 255, 191, 23, 16, 154, 65, 36, 163, 255, 255, 94, 13, 
-255, 97, 72, 16, 10, 10, 10, 10, 255, 233, 117, 102, 13, 10, 10, 10, 
-51, 255, 255, 189, 61, 19, 10, 10, 34, 255, 255, 255, 231, 154, 38, 10, 
-106, 255, 255, 255, 255, 255, 255, 255, 50, 255, 255, 255, 255, 255, 255, 255, 
-16, 216, 255, 255, 255, 255, 255, 108, 10, 47, 240, 255, 255, 255, 255, 34, 
-255, 255, 222, 221, 214, 25, 10, 10, 154, 29, 14, 34, 79, 52, 13, 10, 
-17, 13, 29, 226, 255, 220, 44, 11, 11, 16, 13, 203, 255, 255, 152, 27, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 14, 22, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 13, 16, 13, 19, 
-10, 10, 10, 10, 10, 29, 50, 102, 10, 10, 10, 29, 20, 109, 250, 255, 
-13, 36, 21, 100, 232, 255, 255, 255, 84, 178, 246, 239, 179, 121, 214, 255, 
-44, 13, 88, 193, 255, 255, 255, 165, 255, 64, 11, 14, 131, 213, 241, 255, 
-255, 245, 67, 13, 11, 20, 56, 154, 255, 255, 251, 172, 82, 27, 19, 21, 
-22, 17, 60, 247, 255, 255, 131, 22, 247, 225, 255, 255, 255, 255, 56, 16, 
-240, 255, 255, 255, 245, 83, 13, 84, 34, 70, 85, 89, 64, 60, 147, 251, 
-108, 255, 255, 255, 255, 255, 226, 56, 209, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 225, 128, 60, 181, 255, 
-14, 10, 34, 146, 255, 255, 255, 144, 255, 22, 10, 10, 69, 178, 229, 255, 
-255, 237, 23, 10, 10, 10, 17, 95, 255, 255, 247, 117, 30, 10, 10, 10, 
-191, 215, 36, 243, 255, 255, 69, 10, 243, 226, 255, 255, 255, 255, 17, 10, 
-228, 255, 255, 255, 237, 32, 10, 33, 11, 25, 33, 35, 22, 20, 86, 247, 
-50, 255, 255, 255, 255, 255, 240, 116, 172, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 239, 179, 121, 214, 255, 
-44, 13, 88, 193, 255, 255, 255, 165, 255, 64, 11, 14, 131, 213, 241, 255, 
-255, 245, 67, 13, 11, 20, 56, 154, 255, 255, 251, 172, 82, 27, 19, 21, 
-22, 17, 60, 247, 255, 255, 131, 22, 247, 225, 255, 255, 255, 255, 56, 16, 
-240, 255, 255, 255, 245, 83, 13, 84, 34, 70, 85, 89, 64, 60, 147, 251, 
-95, 52, 29, 10, 10, 10, 10, 10, 209, 250, 109, 20, 29, 10, 10, 10, 
-255, 255, 255, 232, 100, 21, 36, 21, 255, 255, 255, 239, 179, 116, 150, 85, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 26, 21, 41, 17, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 21, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 28, 27, 23, 44, 
-10, 20, 15, 10, 40, 213, 253, 255, 23, 72, 193, 54, 16, 209, 255, 255, 
-11, 11, 10, 13, 35, 121, 218, 255, 114, 144, 117, 214, 255, 255, 251, 172, 
-255, 255, 255, 255, 255, 245, 67, 13, 255, 255, 255, 255, 255, 64, 11, 14, 
-244, 177, 70, 27, 25, 32, 48, 89, 82, 27, 19, 21, 34, 70, 85, 89, 
-11, 20, 56, 154, 240, 255, 255, 255, 131, 213, 241, 255, 247, 225, 255, 255, 
-183, 245, 255, 255, 244, 177, 70, 27, 64, 60, 147, 251, 255, 255, 255, 239, 
-245, 83, 13, 84, 255, 255, 255, 255, 255, 255, 56, 16, 209, 255, 255, 255, 
-25, 32, 48, 89, 183, 245, 255, 255, 179, 121, 214, 255, 255, 255, 247, 117, 
-255, 255, 255, 255, 255, 237, 23, 10, 255, 255, 255, 255, 255, 22, 10, 10, 
-234, 123, 25, 10, 10, 11, 16, 35, 30, 10, 10, 10, 11, 25, 33, 35, 
-10, 10, 17, 95, 228, 255, 255, 255, 69, 178, 229, 255, 243, 226, 255, 255, 
-132, 237, 255, 255, 234, 123, 25, 10, 22, 20, 86, 247, 255, 255, 255, 225, 
-237, 32, 10, 33, 255, 255, 255, 255, 255, 255, 17, 10, 172, 255, 255, 255, 
-10, 11, 16, 35, 132, 237, 255, 255, 128, 60, 181, 255, 255, 255, 251, 172, 
-255, 255, 255, 255, 255, 245, 67, 13, 255, 255, 255, 255, 255, 64, 11, 14, 
-244, 177, 70, 27, 25, 32, 48, 89, 82, 27, 19, 21, 34, 70, 85, 89, 
-11, 20, 56, 154, 240, 255, 255, 255, 131, 213, 241, 255, 247, 225, 255, 255, 
-183, 245, 255, 255, 244, 177, 70, 27, 64, 60, 147, 251, 255, 255, 255, 239, 
-245, 83, 13, 84, 255, 255, 255, 255, 255, 255, 56, 16, 209, 255, 255, 255, 
-25, 32, 48, 89, 183, 245, 255, 255, 179, 121, 214, 255, 255, 255, 251, 172, 
-255, 255, 255, 255, 255, 245, 67, 13, 255, 255, 255, 255, 255, 64, 11, 14, 
-244, 177, 70, 27, 25, 32, 48, 89, 82, 27, 19, 21, 34, 70, 85, 89, 
-11, 20, 56, 154, 240, 255, 255, 255, 131, 213, 241, 255, 247, 240, 255, 255, 
-183, 218, 168, 91, 33, 13, 15, 11, 64, 60, 147, 251, 255, 247, 204, 114, 
-245, 83, 13, 84, 255, 255, 255, 255, 255, 255, 56, 16, 209, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 33, 16, 23, 29, 10, 10, 10, 10, 
-255, 253, 213, 108, 28, 14, 10, 10, 255, 255, 255, 255, 63, 10, 11, 16, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 19, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 21, 10, 10, 17, 10, 11, 10, 13, 13, 
-10, 10, 14, 21, 50, 16, 13, 29, 10, 10, 10, 220, 255, 154, 29, 14, 
-200, 255, 255, 131, 22, 108, 255, 255, 203, 255, 255, 152, 38, 86, 255, 255, 
-226, 255, 220, 67, 50, 112, 255, 189, 34, 79, 78, 76, 198, 233, 117, 102, 
-255, 255, 240, 116, 44, 13, 88, 193, 231, 154, 38, 10, 10, 47, 240, 255, 
-61, 19, 10, 10, 16, 216, 255, 255, 13, 10, 10, 10, 50, 255, 255, 255, 
-255, 255, 255, 165, 22, 17, 60, 247, 255, 255, 255, 34, 11, 16, 13, 203, 
-255, 255, 255, 108, 17, 13, 29, 226, 255, 255, 255, 255, 154, 29, 14, 34, 
-255, 255, 131, 22, 108, 255, 255, 255, 255, 255, 152, 38, 86, 255, 255, 255, 
-255, 220, 67, 50, 112, 255, 255, 141, 79, 78, 76, 198, 255, 214, 58, 45, 
-255, 255, 226, 56, 14, 10, 34, 146, 210, 95, 13, 10, 10, 15, 228, 255, 
-20, 10, 10, 10, 10, 184, 255, 255, 10, 10, 10, 10, 16, 255, 255, 255, 
-255, 255, 255, 144, 191, 215, 36, 243, 255, 255, 255, 157, 154, 65, 36, 163, 
-255, 255, 255, 100, 81, 85, 35, 201, 255, 255, 255, 255, 114, 29, 10, 11, 
-255, 255, 69, 10, 50, 255, 255, 255, 255, 255, 94, 13, 34, 255, 255, 255, 
-255, 191, 23, 16, 51, 255, 255, 189, 29, 29, 28, 157, 255, 233, 117, 102, 
-255, 255, 240, 116, 44, 13, 88, 193, 231, 154, 38, 10, 10, 47, 240, 255, 
-61, 19, 10, 10, 16, 216, 255, 255, 13, 10, 10, 10, 50, 255, 255, 255, 
-255, 255, 255, 165, 22, 17, 60, 247, 255, 255, 255, 34, 11, 16, 13, 203, 
-255, 255, 255, 108, 17, 13, 29, 226, 255, 255, 255, 255, 154, 29, 14, 34, 
-255, 255, 131, 22, 108, 255, 255, 255, 255, 255, 152, 38, 86, 255, 255, 255, 
-255, 220, 67, 50, 112, 255, 255, 189, 79, 78, 76, 198, 255, 233, 117, 102, 
-255, 255, 240, 116, 44, 13, 88, 193, 231, 154, 38, 10, 10, 47, 240, 255, 
-61, 19, 10, 10, 16, 216, 255, 255, 13, 10, 10, 10, 50, 255, 255, 255, 
-255, 255, 255, 191, 220, 233, 90, 247, 255, 255, 255, 198, 197, 127, 90, 203, 
-255, 255, 255, 158, 141, 145, 89, 226, 255, 255, 255, 255, 170, 81, 23, 34, 
-255, 255, 131, 22, 108, 255, 255, 255, 255, 255, 152, 38, 86, 255, 255, 231, 
-255, 220, 67, 50, 112, 255, 189, 61, 79, 78, 76, 198, 233, 117, 102, 13, 
-255, 240, 116, 44, 13, 88, 193, 206, 154, 38, 10, 10, 47, 240, 255, 255, 
-19, 10, 10, 16, 216, 255, 255, 255, 10, 10, 10, 50, 255, 255, 255, 255, 
-67, 21, 10, 10, 10, 10, 10, 10, 255, 153, 11, 10, 10, 10, 10, 10, 
-255, 255, 106, 13, 10, 10, 10, 10, 255, 255, 255, 154, 26, 10, 10, 10, 
-10, 10, 10, 235, 255, 255, 255, 222, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-221, 237, 253, 255, 255, 97, 72, 16, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 106, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 222, 221, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-237, 253, 255, 255, 229, 41, 26, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 46, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 194, 194, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-220, 252, 255, 255, 255, 97, 72, 16, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 106, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 222, 221, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-237, 253, 255, 255, 229, 97, 72, 16, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 106, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 222, 221, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-237, 253, 255, 255, 97, 72, 16, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 106, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 235, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 131, 255, 255, 255, 255, 
-10, 10, 10, 83, 255, 255, 255, 255, 10, 10, 10, 38, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 160, 169, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 131, 10, 10, 10, 
-255, 255, 255, 255, 83, 10, 10, 10, 255, 255, 255, 255, 38, 10, 10, 10, 
-10, 10, 10, 30, 255, 255, 255, 255, 10, 10, 10, 23, 255, 255, 255, 255, 
-10, 10, 10, 16, 255, 255, 255, 255, 10, 10, 10, 10, 250, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 216, 
-255, 255, 255, 255, 255, 255, 252, 75, 255, 255, 255, 255, 255, 255, 188, 10, 
-184, 65, 11, 175, 255, 255, 255, 255, 27, 10, 106, 255, 255, 255, 255, 255, 
-10, 10, 190, 255, 255, 255, 255, 255, 10, 10, 145, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 252, 
-255, 255, 255, 255, 255, 240, 162, 56, 239, 243, 225, 172, 108, 38, 10, 88, 
-255, 255, 255, 255, 255, 255, 255, 255, 206, 229, 255, 255, 255, 255, 255, 255, 
-56, 228, 255, 255, 255, 255, 255, 255, 232, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 30, 10, 10, 10, 255, 255, 255, 255, 23, 10, 10, 10, 
-255, 255, 255, 255, 16, 10, 10, 10, 255, 255, 255, 250, 10, 10, 10, 10, 
-10, 10, 10, 10, 237, 255, 255, 255, 10, 10, 10, 10, 222, 255, 255, 255, 
-10, 10, 10, 10, 208, 255, 255, 255, 10, 10, 10, 10, 194, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 222, 255, 255, 255, 255, 255, 255, 243, 51, 
-255, 255, 255, 255, 255, 255, 221, 191, 255, 207, 156, 108, 94, 92, 23, 10, 
-95, 13, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-201, 240, 255, 255, 255, 255, 255, 255, 10, 38, 164, 255, 255, 255, 255, 255, 
-10, 10, 10, 148, 255, 255, 255, 255, 10, 10, 10, 10, 153, 255, 255, 194, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 253, 
-214, 191, 193, 156, 123, 95, 82, 63, 19, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 88, 10, 255, 255, 255, 251, 170, 106, 10, 10, 
-73, 108, 122, 78, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 13, 97, 178, 188, 151, 97, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 61, 
-38, 44, 23, 10, 10, 21, 153, 253, 10, 10, 10, 28, 125, 224, 255, 255, 
-14, 56, 145, 229, 255, 255, 255, 255, 213, 251, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 237, 10, 10, 10, 10, 255, 255, 255, 222, 10, 10, 10, 10, 
-255, 255, 255, 208, 10, 10, 10, 10, 255, 255, 255, 194, 10, 10, 10, 10, 
-10, 10, 10, 10, 179, 255, 255, 255, 10, 10, 10, 10, 165, 255, 255, 255, 
-10, 10, 10, 10, 150, 255, 255, 255, 10, 10, 10, 10, 135, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 163, 10, 255, 255, 255, 255, 255, 255, 156, 10, 
-255, 255, 255, 255, 255, 255, 239, 44, 255, 255, 255, 255, 255, 255, 255, 185, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 11, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 145, 164, 30, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 176, 10, 10, 10, 10, 10, 10, 10, 50, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 98, 90, 63, 63, 189, 251, 232, 215, 
-10, 10, 10, 10, 22, 46, 22, 10, 10, 10, 10, 10, 10, 10, 51, 132, 
-255, 255, 255, 255, 255, 255, 255, 255, 194, 215, 255, 255, 255, 255, 255, 255, 
-40, 191, 255, 255, 255, 255, 255, 255, 235, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 179, 10, 10, 10, 10, 255, 255, 255, 165, 10, 10, 10, 10, 
-255, 255, 255, 150, 10, 10, 10, 10, 255, 255, 255, 135, 10, 10, 10, 10, 
-10, 10, 10, 10, 97, 255, 255, 255, 10, 10, 10, 10, 42, 255, 255, 255, 
-10, 10, 10, 10, 38, 255, 255, 255, 10, 10, 10, 10, 35, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-157, 11, 10, 10, 10, 10, 10, 10, 255, 187, 63, 10, 10, 10, 10, 10, 
-255, 255, 252, 188, 88, 15, 10, 10, 255, 255, 255, 255, 255, 204, 29, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 21, 191, 255, 255, 10, 10, 10, 10, 15, 142, 215, 245, 
-10, 10, 10, 10, 10, 10, 14, 46, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-86, 164, 249, 255, 255, 255, 255, 255, 10, 44, 229, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 253, 255, 240, 229, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 250, 240, 249, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 97, 10, 10, 10, 10, 255, 255, 255, 42, 10, 10, 10, 10, 
-255, 255, 255, 38, 10, 10, 10, 10, 255, 255, 255, 35, 10, 10, 10, 10, 
-10, 10, 10, 10, 33, 255, 255, 255, 10, 10, 10, 10, 29, 255, 255, 255, 
-10, 10, 10, 10, 27, 255, 255, 255, 10, 10, 10, 10, 23, 255, 255, 255, 
-255, 255, 225, 188, 195, 216, 241, 255, 255, 255, 203, 97, 42, 10, 33, 83, 
-255, 255, 255, 255, 240, 165, 58, 10, 255, 255, 255, 255, 255, 255, 251, 126, 
-255, 255, 255, 255, 255, 255, 173, 106, 138, 204, 247, 255, 255, 255, 255, 255, 
-10, 13, 52, 133, 215, 255, 255, 255, 10, 10, 10, 10, 17, 86, 190, 249, 
-137, 71, 16, 10, 10, 10, 10, 10, 255, 253, 215, 159, 109, 76, 36, 29, 
-255, 255, 255, 255, 255, 255, 239, 233, 255, 255, 255, 255, 255, 243, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 32, 25, 25, 29, 48, 88, 71, 10, 
-235, 227, 228, 240, 255, 255, 206, 20, 255, 255, 245, 212, 134, 57, 29, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 35, 92, 10, 10, 10, 10, 10, 85, 247, 255, 
-10, 10, 10, 10, 10, 60, 231, 255, 10, 10, 10, 10, 10, 10, 34, 158, 
-175, 240, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 226, 
-255, 255, 255, 255, 235, 152, 206, 64, 255, 255, 255, 255, 108, 10, 13, 10, 
-255, 255, 224, 90, 78, 119, 40, 36, 88, 79, 36, 10, 10, 10, 10, 11, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-115, 54, 34, 165, 184, 135, 109, 238, 25, 10, 10, 66, 119, 28, 0, 201, 
-10, 10, 10, 10, 10, 10, 10, 3, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 253, 255, 255, 255, 255, 255, 255, 164, 52, 229, 255, 255, 255, 255, 255, 
-0, 35, 221, 141, 172, 255, 255, 255, 10, 14, 36, 10, 65, 233, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 33, 10, 10, 10, 10, 255, 255, 255, 29, 10, 10, 10, 10, 
-255, 255, 255, 27, 10, 10, 10, 10, 255, 255, 255, 23, 10, 10, 10, 10, 
-10, 10, 10, 10, 21, 255, 255, 255, 10, 10, 10, 10, 17, 255, 255, 255, 
-10, 10, 10, 10, 16, 255, 255, 255, 10, 10, 10, 10, 13, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 201, 255, 255, 255, 255, 255, 255, 210, 52, 
-255, 255, 255, 255, 255, 160, 19, 10, 255, 255, 255, 255, 160, 10, 10, 10, 
-11, 10, 10, 10, 10, 10, 10, 58, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-101, 150, 172, 175, 141, 50, 154, 150, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-127, 82, 38, 13, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 23, 90, 94, 142, 
-10, 10, 59, 113, 194, 255, 255, 255, 10, 34, 221, 255, 255, 255, 255, 204, 
-177, 255, 154, 54, 10, 10, 10, 10, 231, 198, 10, 10, 10, 10, 10, 10, 
-255, 72, 10, 10, 10, 10, 10, 10, 101, 11, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 25, 91, 255, 10, 10, 10, 10, 10, 10, 13, 170, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 201, 
-169, 255, 255, 255, 255, 255, 202, 13, 52, 206, 255, 255, 255, 255, 98, 10, 
-255, 255, 249, 166, 221, 255, 255, 255, 250, 247, 89, 10, 125, 255, 255, 243, 
-116, 106, 10, 10, 38, 140, 90, 40, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 195, 158, 240, 255, 255, 255, 255, 255, 
-10, 144, 255, 255, 255, 255, 255, 255, 133, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 21, 10, 10, 10, 10, 255, 255, 255, 17, 10, 10, 10, 10, 
-255, 255, 255, 16, 10, 10, 10, 10, 255, 255, 255, 13, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 
-10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 
-255, 255, 255, 255, 100, 10, 10, 10, 255, 255, 255, 255, 229, 102, 15, 10, 
-255, 255, 255, 255, 255, 255, 204, 72, 255, 255, 255, 255, 255, 255, 255, 250, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 46, 86, 114, 117, 117, 119, 185, 170, 238, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 16, 14, 
-101, 82, 70, 39, 22, 169, 219, 212, 255, 255, 255, 240, 214, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 15, 34, 38, 36, 59, 114, 115, 132, 
-213, 235, 241, 240, 251, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 177, 60, 10, 10, 10, 10, 10, 10, 
-252, 51, 10, 10, 10, 10, 10, 10, 116, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 70, 255, 255, 255, 255, 255, 67, 10, 38, 238, 255, 255, 255, 209, 17, 
-10, 25, 226, 255, 255, 218, 50, 10, 10, 110, 255, 255, 255, 72, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 96, 255, 255, 255, 229, 29, 10, 10, 28, 176, 255, 171, 50, 10, 10, 
-10, 10, 17, 100, 19, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 127, 10, 10, 10, 10, 10, 10, 10, 94, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 170, 
-255, 255, 255, 255, 255, 255, 255, 255, 193, 241, 255, 255, 255, 255, 255, 255, 
-45, 182, 255, 255, 255, 255, 255, 255, 253, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 
-255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 
-10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 157, 255, 255, 255, 255, 255, 255, 181, 25, 
-255, 255, 255, 255, 255, 184, 13, 10, 255, 255, 255, 255, 170, 26, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 14, 
-10, 184, 255, 255, 191, 13, 10, 10, 40, 229, 255, 214, 34, 10, 10, 10, 
-141, 255, 255, 175, 10, 10, 10, 10, 207, 255, 255, 128, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 16, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 41, 34, 13, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 85, 10, 10, 10, 10, 107, 126, 112, 159, 
-13, 29, 13, 27, 229, 255, 255, 255, 108, 241, 212, 219, 255, 255, 255, 255, 
-243, 255, 255, 255, 255, 255, 255, 255, 243, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 
-255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 
-10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 126, 10, 10, 10, 10, 255, 247, 132, 10, 10, 10, 10, 10, 
-246, 79, 10, 10, 10, 10, 10, 10, 119, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 142, 10, 10, 10, 10, 10, 10, 25, 229, 
-10, 10, 10, 10, 10, 17, 162, 255, 10, 10, 10, 10, 10, 128, 255, 255, 
-255, 255, 243, 84, 10, 10, 10, 10, 255, 255, 90, 27, 10, 10, 10, 10, 
-255, 191, 11, 10, 10, 10, 10, 10, 253, 63, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 60, 212, 10, 10, 10, 10, 10, 54, 229, 255, 
-10, 10, 10, 10, 22, 212, 255, 255, 10, 10, 10, 10, 125, 255, 255, 255, 
-245, 237, 61, 10, 10, 10, 10, 10, 255, 255, 71, 10, 10, 10, 10, 10, 
-255, 249, 54, 10, 10, 10, 10, 10, 255, 200, 11, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-71, 255, 255, 255, 255, 255, 255, 255, 10, 133, 255, 255, 255, 255, 255, 255, 
-10, 13, 184, 255, 255, 255, 255, 255, 10, 10, 27, 204, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 
-255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 
-10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 239, 79, 255, 255, 255, 255, 255, 255, 101, 10, 
-255, 255, 255, 255, 255, 190, 17, 10, 255, 255, 255, 255, 193, 26, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 75, 229, 255, 255, 10, 10, 10, 83, 255, 255, 255, 255, 
-10, 10, 10, 132, 255, 255, 255, 249, 10, 10, 57, 233, 255, 255, 255, 232, 
-224, 22, 10, 10, 10, 10, 10, 10, 132, 10, 10, 10, 10, 10, 10, 10, 
-51, 10, 10, 10, 10, 10, 10, 10, 29, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 34, 229, 255, 255, 255, 10, 10, 10, 116, 255, 255, 255, 255, 
-10, 10, 13, 204, 255, 255, 255, 243, 10, 10, 46, 246, 255, 255, 255, 112, 
-255, 88, 10, 10, 10, 10, 10, 10, 185, 10, 10, 10, 10, 10, 10, 10, 
-61, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 27, 25, 10, 10, 10, 10, 10, 28, 214, 224, 47, 10, 10, 10, 
-10, 10, 10, 39, 216, 255, 255, 255, 10, 10, 10, 10, 52, 238, 255, 255, 
-10, 10, 10, 10, 10, 90, 253, 255, 10, 10, 10, 10, 10, 10, 142, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 
-255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 
-10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 240, 44, 10, 10, 10, 255, 255, 255, 158, 10, 10, 10, 10, 
-255, 255, 207, 23, 10, 10, 10, 10, 255, 255, 86, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 198, 255, 255, 255, 255, 178, 10, 82, 255, 255, 255, 255, 246, 134, 
-10, 165, 255, 255, 255, 255, 106, 14, 10, 177, 255, 255, 255, 163, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 112, 255, 255, 255, 162, 10, 10, 10, 182, 255, 255, 212, 28, 10, 
-10, 22, 222, 255, 243, 59, 10, 10, 10, 76, 255, 255, 106, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 19, 194, 255, 152, 10, 10, 10, 10, 10, 34, 218, 241, 34, 10, 10, 
-10, 10, 10, 40, 226, 100, 10, 10, 10, 10, 10, 10, 42, 141, 21, 10, 
-10, 10, 10, 10, 10, 10, 17, 206, 10, 10, 10, 10, 10, 10, 10, 83, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-194, 255, 255, 255, 255, 255, 255, 255, 97, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 
-255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 
-10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 238, 35, 10, 10, 10, 10, 10, 255, 122, 10, 10, 10, 10, 10, 10, 
-233, 34, 10, 10, 10, 10, 10, 10, 187, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 170, 255, 255, 237, 34, 10, 10, 10, 30, 144, 120, 164, 52, 10, 10, 
-10, 10, 10, 9, 3, 15, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 147, 255, 189, 10, 10, 10, 10, 23, 226, 253, 67, 10, 10, 10, 10, 
-72, 255, 206, 10, 10, 10, 10, 10, 128, 255, 140, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 46, 38, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-20, 214, 255, 255, 255, 255, 255, 255, 10, 142, 255, 255, 255, 255, 255, 255, 
-10, 75, 255, 255, 255, 255, 255, 255, 10, 32, 228, 255, 255, 255, 255, 255, 
-255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 
-255, 255, 255, 10, 10, 10, 10, 10, 255, 255, 255, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 13, 255, 255, 255, 10, 10, 10, 10, 16, 255, 255, 255, 
-10, 10, 10, 10, 17, 255, 255, 255, 10, 10, 10, 10, 21, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 233, 255, 255, 255, 255, 255, 255, 255, 231, 
-154, 10, 10, 10, 10, 10, 10, 10, 77, 10, 10, 10, 10, 10, 10, 10, 
-29, 10, 10, 10, 10, 10, 10, 10, 28, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 25, 10, 10, 10, 10, 10, 10, 10, 101, 
-10, 10, 10, 10, 10, 10, 10, 190, 10, 10, 10, 10, 10, 10, 53, 247, 
-219, 255, 94, 10, 10, 10, 10, 10, 255, 253, 59, 10, 10, 10, 10, 10, 
-255, 210, 20, 10, 10, 10, 10, 10, 255, 115, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 188, 255, 255, 255, 255, 255, 10, 10, 145, 255, 255, 255, 255, 255, 
-10, 10, 150, 255, 255, 255, 255, 255, 10, 10, 123, 255, 255, 255, 255, 255, 
-255, 255, 255, 13, 10, 10, 10, 10, 255, 255, 255, 16, 10, 10, 10, 10, 
-255, 255, 255, 17, 10, 10, 10, 10, 255, 255, 255, 21, 10, 10, 10, 10, 
-10, 10, 10, 10, 23, 255, 255, 255, 10, 10, 10, 10, 27, 255, 255, 255, 
-10, 10, 10, 10, 29, 255, 255, 255, 10, 10, 10, 10, 33, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 227, 255, 255, 255, 255, 255, 255, 255, 202, 
-255, 255, 255, 255, 255, 255, 255, 231, 255, 255, 255, 255, 255, 255, 255, 255, 
-26, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-32, 10, 10, 10, 10, 10, 10, 10, 89, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 121, 255, 10, 10, 10, 10, 10, 10, 197, 255, 
-10, 10, 10, 10, 10, 79, 255, 255, 10, 10, 10, 10, 10, 170, 255, 255, 
-255, 224, 25, 10, 10, 10, 10, 10, 255, 152, 10, 10, 10, 10, 10, 10, 
-255, 208, 73, 10, 10, 10, 10, 10, 255, 255, 177, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 154, 255, 255, 255, 255, 255, 10, 10, 184, 255, 255, 255, 255, 255, 
-10, 39, 240, 255, 255, 255, 255, 255, 10, 100, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 23, 10, 10, 10, 10, 255, 255, 255, 27, 10, 10, 10, 10, 
-255, 255, 255, 29, 10, 10, 10, 10, 255, 255, 255, 33, 10, 10, 10, 10, 
-10, 10, 10, 10, 35, 255, 255, 255, 10, 10, 10, 10, 38, 255, 255, 255, 
-10, 10, 10, 10, 42, 255, 255, 255, 10, 10, 10, 10, 97, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 178, 255, 255, 255, 255, 255, 255, 247, 46, 
-97, 10, 10, 10, 10, 10, 10, 10, 94, 10, 10, 10, 10, 10, 10, 10, 
-19, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 201, 255, 255, 10, 10, 10, 10, 10, 120, 255, 255, 
-10, 10, 10, 10, 10, 25, 219, 255, 10, 10, 10, 10, 10, 10, 83, 255, 
-255, 255, 255, 77, 10, 10, 10, 10, 255, 255, 255, 201, 16, 10, 10, 10, 
-255, 255, 255, 255, 159, 10, 10, 10, 255, 255, 255, 255, 255, 108, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 47, 10, 10, 10, 10, 10, 10, 21, 200, 
-17, 213, 255, 255, 255, 255, 255, 255, 116, 255, 255, 255, 255, 255, 255, 255, 
-241, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 35, 10, 10, 10, 10, 255, 255, 255, 38, 10, 10, 10, 10, 
-255, 255, 255, 42, 10, 10, 10, 10, 255, 255, 255, 97, 10, 10, 10, 10, 
-10, 10, 10, 10, 135, 255, 255, 255, 10, 10, 10, 10, 150, 255, 255, 255, 
-10, 10, 10, 10, 165, 255, 255, 255, 10, 10, 10, 10, 179, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 142, 10, 255, 255, 255, 255, 255, 171, 10, 10, 
-255, 255, 255, 255, 251, 85, 72, 10, 255, 255, 255, 255, 253, 246, 234, 102, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 150, 170, 190, 177, 36, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 198, 10, 10, 10, 10, 10, 10, 10, 100, 
-10, 10, 10, 10, 10, 10, 10, 38, 10, 10, 10, 10, 10, 10, 10, 29, 
-255, 255, 255, 255, 255, 119, 10, 10, 255, 255, 255, 255, 181, 10, 10, 10, 
-240, 255, 255, 255, 145, 21, 10, 10, 229, 255, 255, 255, 251, 63, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 23, 50, 
-10, 10, 10, 10, 10, 45, 183, 255, 10, 10, 10, 19, 132, 241, 255, 255, 
-10, 10, 83, 209, 255, 255, 255, 255, 98, 189, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 135, 10, 10, 10, 10, 255, 255, 255, 150, 10, 10, 10, 10, 
-255, 255, 255, 165, 10, 10, 10, 10, 255, 255, 255, 179, 10, 10, 10, 10, 
-10, 10, 10, 10, 194, 255, 255, 255, 10, 10, 10, 10, 208, 255, 255, 255, 
-10, 10, 10, 10, 222, 255, 255, 255, 10, 10, 10, 10, 237, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 203, 61, 10, 10, 255, 255, 255, 255, 255, 251, 150, 19, 
-255, 255, 255, 255, 255, 255, 255, 214, 255, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-109, 20, 10, 10, 10, 10, 10, 10, 255, 218, 145, 77, 25, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-22, 83, 53, 10, 10, 10, 10, 10, 142, 255, 247, 148, 23, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 48, 10, 10, 10, 10, 10, 10, 10, 92, 
-10, 10, 10, 10, 10, 10, 10, 191, 10, 10, 10, 10, 10, 10, 137, 255, 
-247, 255, 255, 255, 220, 52, 101, 172, 255, 255, 255, 255, 253, 246, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 178, 255, 255, 255, 255, 255, 255, 255, 246, 
-91, 10, 10, 10, 10, 17, 21, 17, 57, 10, 27, 38, 119, 214, 224, 219, 
-91, 197, 233, 240, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-17, 22, 38, 29, 17, 17, 10, 10, 218, 224, 241, 231, 218, 219, 191, 123, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-132, 208, 184, 203, 203, 202, 224, 249, 129, 255, 255, 255, 255, 255, 255, 255, 
-252, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 194, 10, 10, 10, 10, 255, 255, 255, 208, 10, 10, 10, 10, 
-255, 255, 255, 222, 10, 10, 10, 10, 255, 255, 255, 237, 10, 10, 10, 10, 
-10, 10, 10, 10, 250, 255, 255, 255, 10, 10, 10, 16, 255, 255, 255, 255, 
-10, 10, 10, 23, 255, 255, 255, 255, 10, 10, 10, 30, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 225, 202, 181, 142, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-13, 10, 10, 10, 10, 10, 10, 10, 58, 10, 10, 10, 10, 10, 10, 10, 
-150, 10, 10, 10, 10, 10, 10, 10, 243, 45, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 13, 23, 38, 81, 145, 
-56, 243, 255, 255, 218, 109, 35, 10, 10, 206, 255, 255, 255, 255, 238, 194, 
-67, 247, 255, 255, 255, 255, 255, 255, 232, 255, 255, 255, 255, 255, 255, 255, 
-10, 10, 10, 10, 38, 170, 255, 255, 162, 160, 158, 179, 234, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 250, 10, 10, 10, 10, 255, 255, 255, 255, 16, 10, 10, 10, 
-255, 255, 255, 255, 23, 10, 10, 10, 255, 255, 255, 255, 30, 10, 10, 10, 
-10, 10, 10, 38, 255, 255, 255, 255, 10, 10, 10, 83, 255, 255, 255, 255, 
-10, 10, 10, 131, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 189, 41, 20, 23, 23, 25, 47, 255, 255, 240, 225, 226, 226, 228, 246, 
-255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-76, 122, 169, 213, 226, 241, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-255, 255, 255, 255, 255, 255, 255, 255, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 38, 10, 10, 10, 255, 255, 255, 255, 83, 10, 10, 10, 
-255, 255, 255, 255, 131, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 235, 255, 255, 255, 222, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 221, 237, 253, 255, 255, 97, 72, 16, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 106, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 222, 221, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 237, 253, 255, 255, 229, 41, 26, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 46, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 194, 194, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 220, 252, 255, 255, 255, 97, 72, 16, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 106, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 222, 221, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 237, 253, 255, 255, 229, 97, 72, 16, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 106, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 255, 255, 222, 221, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 237, 253, 255, 255, 97, 72, 16, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 106, 255, 255, 255, 255, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 255, 255, 255, 255, 235, 10, 10, 10, 
-10, 10, 10, 220, 255, 154, 29, 14, 10, 10, 14, 21, 50, 16, 13, 29, 
-10, 10, 17, 10, 11, 10, 13, 13, 10, 10, 10, 10, 10, 10, 10, 21, 
-34, 79, 78, 76, 198, 233, 117, 102, 226, 255, 220, 67, 50, 112, 255, 189, 
-203, 255, 255, 152, 38, 86, 255, 255, 200, 255, 255, 131, 22, 108, 255, 255, 
-13, 10, 10, 10, 50, 255, 255, 255, 61, 19, 10, 10, 16, 216, 255, 255, 
-231, 154, 38, 10, 10, 47, 240, 255, 255, 255, 240, 116, 44, 13, 88, 193, 
-255, 255, 255, 255, 154, 29, 14, 34, 255, 255, 255, 108, 17, 13, 29, 226, 
-255, 255, 255, 34, 11, 16, 13, 203, 255, 255, 255, 165, 22, 17, 60, 247, 
-79, 78, 76, 198, 255, 214, 58, 45, 255, 220, 67, 50, 112, 255, 255, 141, 
-255, 255, 152, 38, 86, 255, 255, 255, 255, 255, 131, 22, 108, 255, 255, 255, 
-10, 10, 10, 10, 16, 255, 255, 255, 20, 10, 10, 10, 10, 184, 255, 255, 
-210, 95, 13, 10, 10, 15, 228, 255, 255, 255, 226, 56, 14, 10, 34, 146, 
-255, 255, 255, 255, 114, 29, 10, 11, 255, 255, 255, 100, 81, 85, 35, 201, 
-255, 255, 255, 157, 154, 65, 36, 163, 255, 255, 255, 144, 191, 215, 36, 243, 
-29, 29, 28, 157, 255, 233, 117, 102, 255, 191, 23, 16, 51, 255, 255, 189, 
-255, 255, 94, 13, 34, 255, 255, 255, 255, 255, 69, 10, 50, 255, 255, 255, 
-13, 10, 10, 10, 50, 255, 255, 255, 61, 19, 10, 10, 16, 216, 255, 255, 
-231, 154, 38, 10, 10, 47, 240, 255, 255, 255, 240, 116, 44, 13, 88, 193, 
-255, 255, 255, 255, 154, 29, 14, 34, 255, 255, 255, 108, 17, 13, 29, 226, 
-255, 255, 255, 34, 11, 16, 13, 203, 255, 255, 255, 165, 22, 17, 60, 247, 
-79, 78, 76, 198, 255, 233, 117, 102, 255, 220, 67, 50, 112, 255, 255, 189, 
-255, 255, 152, 38, 86, 255, 255, 255, 255, 255, 131, 22, 108, 255, 255, 255, 
-13, 10, 10, 10, 50, 255, 255, 255, 61, 19, 10, 10, 16, 216, 255, 255, 
-231, 154, 38, 10, 10, 47, 240, 255, 255, 255, 240, 116, 44, 13, 88, 193, 
-255, 255, 255, 255, 170, 81, 23, 34, 255, 255, 255, 158, 141, 145, 89, 226, 
-255, 255, 255, 198, 197, 127, 90, 203, 255, 255, 255, 191, 220, 233, 90, 247, 
-79, 78, 76, 198, 233, 117, 102, 13, 255, 220, 67, 50, 112, 255, 189, 61, 
-255, 255, 152, 38, 86, 255, 255, 231, 255, 255, 131, 22, 108, 255, 255, 255, 
-10, 10, 10, 50, 255, 255, 255, 255, 19, 10, 10, 16, 216, 255, 255, 255, 
-154, 38, 10, 10, 47, 240, 255, 255, 255, 240, 116, 44, 13, 88, 193, 206, 
-255, 255, 255, 154, 26, 10, 10, 10, 255, 255, 106, 13, 10, 10, 10, 10, 
-255, 153, 11, 10, 10, 10, 10, 10, 67, 21, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 21, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-23, 72, 193, 54, 16, 209, 255, 255, 10, 20, 15, 10, 40, 213, 253, 255, 
-10, 10, 10, 10, 28, 27, 23, 44, 10, 10, 10, 10, 10, 10, 10, 10, 
-255, 255, 255, 255, 255, 64, 11, 14, 255, 255, 255, 255, 255, 245, 67, 13, 
-114, 144, 117, 214, 255, 255, 251, 172, 11, 11, 10, 13, 35, 121, 218, 255, 
-131, 213, 241, 255, 247, 225, 255, 255, 11, 20, 56, 154, 240, 255, 255, 255, 
-82, 27, 19, 21, 34, 70, 85, 89, 244, 177, 70, 27, 25, 32, 48, 89, 
-255, 255, 56, 16, 209, 255, 255, 255, 245, 83, 13, 84, 255, 255, 255, 255, 
-64, 60, 147, 251, 255, 255, 255, 239, 183, 245, 255, 255, 244, 177, 70, 27, 
-255, 255, 255, 255, 255, 22, 10, 10, 255, 255, 255, 255, 255, 237, 23, 10, 
-179, 121, 214, 255, 255, 255, 247, 117, 25, 32, 48, 89, 183, 245, 255, 255, 
-69, 178, 229, 255, 243, 226, 255, 255, 10, 10, 17, 95, 228, 255, 255, 255, 
-30, 10, 10, 10, 11, 25, 33, 35, 234, 123, 25, 10, 10, 11, 16, 35, 
-255, 255, 17, 10, 172, 255, 255, 255, 237, 32, 10, 33, 255, 255, 255, 255, 
-22, 20, 86, 247, 255, 255, 255, 225, 132, 237, 255, 255, 234, 123, 25, 10, 
-255, 255, 255, 255, 255, 64, 11, 14, 255, 255, 255, 255, 255, 245, 67, 13, 
-128, 60, 181, 255, 255, 255, 251, 172, 10, 11, 16, 35, 132, 237, 255, 255, 
-131, 213, 241, 255, 247, 225, 255, 255, 11, 20, 56, 154, 240, 255, 255, 255, 
-82, 27, 19, 21, 34, 70, 85, 89, 244, 177, 70, 27, 25, 32, 48, 89, 
-255, 255, 56, 16, 209, 255, 255, 255, 245, 83, 13, 84, 255, 255, 255, 255, 
-64, 60, 147, 251, 255, 255, 255, 239, 183, 245, 255, 255, 244, 177, 70, 27, 
-255, 255, 255, 255, 255, 64, 11, 14, 255, 255, 255, 255, 255, 245, 67, 13, 
-179, 121, 214, 255, 255, 255, 251, 172, 25, 32, 48, 89, 183, 245, 255, 255, 
-131, 213, 241, 255, 247, 240, 255, 255, 11, 20, 56, 154, 240, 255, 255, 255, 
-82, 27, 19, 21, 34, 70, 85, 89, 244, 177, 70, 27, 25, 32, 48, 89, 
-255, 255, 56, 16, 209, 255, 255, 255, 245, 83, 13, 84, 255, 255, 255, 255, 
-64, 60, 147, 251, 255, 247, 204, 114, 183, 218, 168, 91, 33, 13, 15, 11, 
-255, 255, 255, 255, 63, 10, 11, 16, 255, 253, 213, 108, 28, 14, 10, 10, 
-33, 16, 23, 29, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-19, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 13, 16, 13, 19, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-84, 178, 246, 239, 179, 121, 214, 255, 13, 36, 21, 100, 232, 255, 255, 255, 
-10, 10, 10, 29, 20, 109, 250, 255, 10, 10, 10, 10, 10, 29, 50, 102, 
-255, 255, 251, 172, 82, 27, 19, 21, 255, 245, 67, 13, 11, 20, 56, 154, 
-255, 64, 11, 14, 131, 213, 241, 255, 44, 13, 88, 193, 255, 255, 255, 165, 
-34, 70, 85, 89, 64, 60, 147, 251, 240, 255, 255, 255, 245, 83, 13, 84, 
-247, 225, 255, 255, 255, 255, 56, 16, 22, 17, 60, 247, 255, 255, 131, 22, 
-255, 255, 255, 225, 128, 60, 181, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-209, 255, 255, 255, 255, 255, 255, 255, 108, 255, 255, 255, 255, 255, 226, 56, 
-255, 255, 247, 117, 30, 10, 10, 10, 255, 237, 23, 10, 10, 10, 17, 95, 
-255, 22, 10, 10, 69, 178, 229, 255, 14, 10, 34, 146, 255, 255, 255, 144, 
-11, 25, 33, 35, 22, 20, 86, 247, 228, 255, 255, 255, 237, 32, 10, 33, 
-243, 226, 255, 255, 255, 255, 17, 10, 191, 215, 36, 243, 255, 255, 69, 10, 
-255, 255, 255, 239, 179, 121, 214, 255, 255, 255, 255, 255, 255, 255, 255, 255, 
-172, 255, 255, 255, 255, 255, 255, 255, 50, 255, 255, 255, 255, 255, 240, 116, 
-255, 255, 251, 172, 82, 27, 19, 21, 255, 245, 67, 13, 11, 20, 56, 154, 
-255, 64, 11, 14, 131, 213, 241, 255, 44, 13, 88, 193, 255, 255, 255, 165, 
-34, 70, 85, 89, 64, 60, 147, 251, 240, 255, 255, 255, 245, 83, 13, 84, 
-247, 225, 255, 255, 255, 255, 56, 16, 22, 17, 60, 247, 255, 255, 131, 22, 
-255, 255, 255, 239, 179, 116, 150, 85, 255, 255, 255, 232, 100, 21, 36, 21, 
-209, 250, 109, 20, 29, 10, 10, 10, 95, 52, 29, 10, 10, 10, 10, 10, 
-26, 21, 41, 17, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 11, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 47, 240, 255, 255, 255, 255, 34, 10, 131, 255, 255, 255, 255, 255, 108, 
-10, 19, 165, 255, 255, 255, 255, 255, 10, 10, 25, 229, 255, 255, 255, 255, 
-11, 16, 13, 203, 255, 255, 152, 38, 17, 13, 29, 226, 255, 220, 67, 50, 
-154, 29, 14, 34, 79, 78, 76, 198, 255, 255, 222, 221, 237, 253, 255, 255, 
-86, 255, 255, 255, 210, 95, 13, 10, 112, 255, 255, 141, 20, 10, 10, 10, 
-255, 214, 58, 45, 10, 10, 10, 10, 229, 41, 26, 10, 10, 10, 10, 10, 
-10, 15, 228, 255, 255, 255, 255, 157, 10, 184, 255, 255, 255, 255, 255, 100, 
-16, 255, 255, 255, 255, 255, 255, 255, 46, 255, 255, 255, 255, 255, 255, 255, 
-154, 65, 36, 163, 255, 255, 94, 13, 81, 85, 35, 201, 255, 191, 23, 16, 
-114, 29, 10, 11, 29, 29, 28, 157, 255, 255, 194, 194, 220, 252, 255, 255, 
-34, 255, 255, 255, 231, 154, 38, 10, 51, 255, 255, 189, 61, 19, 10, 10, 
-255, 233, 117, 102, 13, 10, 10, 10, 255, 97, 72, 16, 10, 10, 10, 10, 
-10, 47, 240, 255, 255, 255, 255, 34, 16, 216, 255, 255, 255, 255, 255, 108, 
-50, 255, 255, 255, 255, 255, 255, 255, 106, 255, 255, 255, 255, 255, 255, 255, 
-11, 16, 13, 203, 255, 255, 152, 27, 17, 13, 29, 226, 255, 220, 44, 11, 
-154, 29, 14, 34, 79, 52, 13, 10, 255, 255, 222, 221, 214, 25, 10, 10, 
-14, 22, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 
-};
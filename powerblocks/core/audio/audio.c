@@ -0,0 +1,193 @@
+/**
+ * @file audio.c
+ * @brief Plays audio through the audio interface.
+ *
+ * Streams 16-bit stereo PCM to the audio interface using DMA.
+ *
+ * The DSP interface raises a interrupt each time the audio DMA
+ * starts a buffer. At that point the next buffer can be set, and the
+ * one played before it is free to refill.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "audio.h"
+
+#include "system/system.h"
+#include "system/exceptions.h"
+
+#include "dsp/dsp.h"
+
+#include "utils/log.h"
+
+#include <stdbool.h>
+#include <stddef.h>
+
+static const char* TAB = "AUDIO";
+
+// DSP interface, the audio DMA lives here
+#define DSP_CSR                (*(volatile uint16_t*)0xCC00500A)
+#define DSP_AI_DMA_START_H     (*(volatile uint16_t*)0xCC005030)
+#define DSP_AI_DMA_START_L     (*(volatile uint16_t*)0xCC005032)
+#define DSP_AI_DMA_CONTROL     (*(volatile uint16_t*)0xCC005036)
+#define DSP_AI_DMA_BLOCKS_LEFT (*(volatile uint16_t*)0xCC00503A)
+
+// Audio interface
+#define AI_CR   (*(volatile uint32_t*)0xCD006C00)
+#define AI_VR   (*(volatile uint32_t*)0xCD006C04)
+#define AI_SCNT (*(volatile uint32_t*)0xCD006C08)
+#define AI_IT   (*(volatile uint32_t*)0xCD006C0C)
+
+#define DSP_CSR_AIDINT    (1<<3) // Audio DMA interrupt, write 1 to clear
+#define DSP_CSR_AIDINTMSK (1<<4)
+#define DSP_CSR_ARINT     (1<<5) // ARAM DMA interrupt, write 1 to clear
+#define DSP_CSR_DSPINT    (1<<7) // DSP interrupt, write 1 to clear
+
+// Interrupt flags that are cleared by writing them back
+#define DSP_CSR_INTERRUPTS (DSP_CSR_AIDINT | DSP_CSR_ARINT | DSP_CSR_DSPINT)
+
+#define DSP_AI_DMA_CONTROL_ENABLE    (1<<15)
+#define DSP_AI_DMA_CONTROL_LENGTH(x) (((x) >> 5) & 0x7FFF)
+
+#define AI_CR_PSTAT    (1<<0) // Play disc streaming audio
+#define AI_CR_AIINTMSK (1<<2)
+#define AI_CR_AIINTVLD (1<<4)
+#define AI_CR_SCRESET  (1<<5) // Reset sample counter
+#define AI_CR_DMAFR    (1<<6) // Set for 32 kHz DMA, clear for 48 kHz
+
+static int16_t* audio_buffers[2];
+static uint32_t audio_buffer_size;
+static audio_buffer_callback_t audio_callback;
+
+// Buffer last given to the DMA, it starts playing on the next interrupt.
+static int audio_queued_buffer;
+
+// The first interrupt has no buffer before it to free.
+static bool audio_first_block;
+
+static void audio_queue_buffer(int index) {
+    uint32_t address = SYSTEM_MEM_PHYSICAL(audio_buffers[index]);
+
+    DSP_AI_DMA_START_H = (DSP_AI_DMA_START_H & ~0x1FFF) | ((address >> 16) & 0x1FFF);
+    DSP_AI_DMA_START_L = (DSP_AI_DMA_START_L & ~0xFFE0) | (address & 0xFFE0);
+    DSP_AI_DMA_CONTROL = (DSP_AI_DMA_CONTROL & ~0x7FFF) | DSP_AI_DMA_CONTROL_LENGTH(audio_buffer_size);
+
+    audio_queued_buffer = index;
+}
+
+static void audio_irq_handler(exception_irq_type_t irq) {
+    uint16_t csr = DSP_CSR;
+
+    if(!(csr & DSP_CSR_AIDINT))
+        return;
+
+    // Acknowledge only the audio DMA interrupt
+    DSP_CSR = (csr & ~DSP_CSR_INTERRUPTS) | DSP_CSR_AIDINT;
+
+    if(!(DSP_AI_DMA_CONTROL & DSP_AI_DMA_CONTROL_ENABLE))
+        return;
+
+    // The queued buffer is now playing, queue the other one after it.
+    int free_buffer = audio_queued_buffer ^ 1;
+    audio_queue_buffer(free_buffer);
+
+    if(audio_first_block) {
+        audio_first_block = false;
+        return;
+    }
+
+    if(audio_callback != NULL)
+        audio_callback(audio_buffers[free_buffer], audio_buffer_size / AUDIO_FRAME_SIZE);
+}
+
+void audio_initialize(audio_sample_rate_t rate) {
+    // Enter safe mode when initializing hardware.
+    uint32_t irq_enabled;
+    SYSTEM_DISABLE_ISR(irq_enabled);
+
+    audio_callback = NULL;
+
+    // Stop the DMA and disc streaming
+    DSP_AI_DMA_CONTROL &= ~DSP_AI_DMA_CONTROL_ENABLE;
+    AI_CR &= ~(AI_CR_AIINTVLD | AI_CR_AIINTMSK | AI_CR_PSTAT);
+    AI_VR = 0;
+    AI_IT = 0;
+    AI_CR |= AI_CR_SCRESET;
+
+    audio_set_sample_rate(rate);
+
+    // Clear any pending audio DMA interrupt and enable it
+    DSP_CSR = (DSP_CSR & ~DSP_CSR_INTERRUPTS) | DSP_CSR_AIDINT | DSP_CSR_AIDINTMSK;
+
+    // The DSP driver owns the IRQ, and passes the audio DMA interrupt on.
+    dsp_install_audio_irq(audio_irq_handler);
+
+    LOG_INFO(TAB, "Audio interface initialized.");
+
+    SYSTEM_ENABLE_ISR(irq_enabled);
+}
+
+void audio_set_sample_rate(audio_sample_rate_t rate) {
+    if(rate == AUDIO_SAMPLE_RATE_32KHZ) {
+        AI_CR |= AI_CR_DMAFR;
+    } else {
+        AI_CR &= ~AI_CR_DMAFR;
+    }
+}
+
+audio_sample_rate_t audio_get_sample_rate() {
+    return (AI_CR & AI_CR_DMAFR) ? AUDIO_SAMPLE_RATE_32KHZ : AUDIO_SAMPLE_RATE_48KHZ;
+}
+
+uint32_t audio_get_sample_rate_hz() {
+    return audio_get_sample_rate() == AUDIO_SAMPLE_RATE_32KHZ ? 32000 : 48000;
+}
+
+int audio_start(int16_t* buffer_a, int16_t* buffer_b, uint32_t size, audio_buffer_callback_t callback) {
+    if(((uintptr_t)buffer_a % AUDIO_BUFFER_ALIGNMENT) != 0 || ((uintptr_t)buffer_b % AUDIO_BUFFER_ALIGNMENT) != 0) {
+        LOG_ERROR(TAB, "Audio buffers must be aligned to %d bytes.", AUDIO_BUFFER_ALIGNMENT);
+        return -1;
+    }
+
+    if(size == 0 || (size % AUDIO_BUFFER_ALIGNMENT) != 0 || DSP_AI_DMA_CONTROL_LENGTH(size) != size >> 5) {
+        LOG_ERROR(TAB, "Invalid audio buffer size %u.", (unsigned int)size);
+        return -1;
+    }
+
+    uint32_t irq_enabled;
+    SYSTEM_DISABLE_ISR(irq_enabled);
+
+    audio_buffers[0] = buffer_a;
+    audio_buffers[1] = buffer_b;
+    audio_buffer_size = size;
+    audio_callback = callback;
+    audio_first_block = true;
+
+    audio_queue_buffer(0);
+    DSP_AI_DMA_CONTROL |= DSP_AI_DMA_CONTROL_ENABLE;
+
+    SYSTEM_ENABLE_ISR(irq_enabled);
+
+    return 0;
+}
+
+void audio_stop() {
+    uint32_t irq_enabled;
+    SYSTEM_DISABLE_ISR(irq_enabled);
+
+    DSP_AI_DMA_CONTROL &= ~DSP_AI_DMA_CONTROL_ENABLE;
+    audio_callback = NULL;
+
+    SYSTEM_ENABLE_ISR(irq_enabled);
+
+    // The DMA finishes the buffer it is on, so wait out the length of one.
+    uint64_t frames = audio_buffer_size / AUDIO_FRAME_SIZE;
+    system_delay_int(SYSTEM_TB_CLOCK_HZ * frames / audio_get_sample_rate_hz());
+}
+
+void audio_commit_buffer(int16_t* buffer, uint32_t frames) {
+    audio_apply_volume(buffer, frames);
+    system_flush_dcache(buffer, frames * AUDIO_FRAME_SIZE);
+}
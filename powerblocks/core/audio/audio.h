@@ -0,0 +1,164 @@
+/**
+ * @file audio.h
+ * @brief Plays audio through the audio interface.
+ *
+ * Streams 16-bit stereo PCM to the audio interface using DMA.
+ * Two buffers are played one after another. While one is playing
+ * the other can be filled with the next samples.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include <stdint.h>
+
+/** @def AUDIO_BUFFER_ALIGNMENT
+ *  @brief Alignment of audio buffers in bytes.
+ *
+ *  The DMA works in 32 byte blocks, so buffers must be aligned
+ *  to them and be a multiple of them in size.
+ */
+#define AUDIO_BUFFER_ALIGNMENT 32
+
+/** @def AUDIO_CHANNELS
+ *  @brief Number of channels in the audio buffers.
+ *
+ *  Samples are interleaved, left then right.
+ */
+#define AUDIO_CHANNELS 2
+
+/** @def AUDIO_FRAME_SIZE
+ *  @brief Size of a stereo sample in bytes.
+ */
+#define AUDIO_FRAME_SIZE (AUDIO_CHANNELS * sizeof(int16_t))
+
+/** @def AUDIO_VOLUME_MAX
+ *  @brief Volume that leaves samples unchanged.
+ */
+#define AUDIO_VOLUME_MAX 255
+
+/**
+ * @enum audio_sample_rate_t
+ * @brief Sample rates of the audio DMA.
+ */
+typedef enum {
+    AUDIO_SAMPLE_RATE_32KHZ,
+    AUDIO_SAMPLE_RATE_48KHZ
+} audio_sample_rate_t;
+
+/**
+ * @typedef audio_buffer_callback_t
+ * @brief Called when a buffer has been consumed.
+ *
+ * Called from the interrupt handler when the DMA has moved on from a buffer.
+ * The buffer will be played again after the current one, so it should be
+ * refilled before then and given to audio_commit_buffer with frames.
+ *
+ * Refilling can be done here, or by waking a task to do it.
+ *
+ * @param buffer The buffer that can be refilled.
+ * @param frames Number of stereo samples in the buffer.
+ */
+typedef void (*audio_buffer_callback_t)(int16_t* buffer, uint32_t frames);
+
+/**
+ * @brief Initializes the audio interface.
+ *
+ * Resets the audio interface and sets the sample rate.
+ * Nothing is played until audio_start is called.
+ *
+ * @param rate Sample rate of the audio DMA.
+ */
+extern void audio_initialize(audio_sample_rate_t rate);
+
+/**
+ * @brief Sets the sample rate of the audio DMA.
+ *
+ * @param rate Sample rate to play at.
+ */
+extern void audio_set_sample_rate(audio_sample_rate_t rate);
+
+/**
+ * @brief Gets the sample rate of the audio DMA.
+ *
+ * @return The current sample rate.
+ */
+extern audio_sample_rate_t audio_get_sample_rate();
+
+/**
+ * @brief Gets the sample rate of the audio DMA in hertz.
+ *
+ * @return 32000 or 48000.
+ */
+extern uint32_t audio_get_sample_rate_hz();
+
+/**
+ * @brief Starts playing from two buffers.
+ *
+ * Plays buffer_a, then buffer_b, then buffer_a again and so on.
+ * Both buffers should be filled and committed with audio_commit_buffer before this,
+ * as the first two are played before the callback is called.
+ *
+ * If a buffer is not refilled in time, it is played again.
+ *
+ * @param buffer_a First buffer, aligned to AUDIO_BUFFER_ALIGNMENT.
+ * @param buffer_b Second buffer, aligned to AUDIO_BUFFER_ALIGNMENT.
+ * @param size Size of each buffer in bytes. Must be a multiple of AUDIO_BUFFER_ALIGNMENT.
+ * @param callback Called when a buffer has been consumed. May be NULL.
+ * @return 0 on success, negative if the buffers are not aligned.
+ */
+extern int audio_start(int16_t* buffer_a, int16_t* buffer_b, uint32_t size, audio_buffer_callback_t callback);
+
+/**
+ * @brief Stops playing.
+ *
+ * Stops the DMA. The buffers can be freed after this returns.
+ */
+extern void audio_stop();
+
+/**
+ * @brief Finishes writing to a buffer.
+ *
+ * Applies the volume to the samples, and flushes them from
+ * the data cache so the DMA can see them.
+ *
+ * Call this after each time a buffer is filled,
+ * including before audio_start.
+ *
+ * @param buffer Buffer given to audio_start.
+ * @param frames Number of stereo samples in the buffer.
+ */
+extern void audio_commit_buffer(int16_t* buffer, uint32_t frames);
+
+/**
+ * @brief Sets the output volume.
+ *
+ * The audio interface has no volume for DMA audio, so
+ * the volume is applied to the samples in audio_commit_buffer.
+ *
+ * @param left Volume of the left channel, 0 to AUDIO_VOLUME_MAX.
+ * @param right Volume of the right channel, 0 to AUDIO_VOLUME_MAX.
+ */
+extern void audio_set_volume(uint8_t left, uint8_t right);
+
+/**
+ * @brief Gets the output volume.
+ *
+ * @param left Set to the volume of the left channel.
+ * @param right Set to the volume of the right channel.
+ */
+extern void audio_get_volume(uint8_t* left, uint8_t* right);
+
+/**
+ * @brief Applies the output volume to samples.
+ *
+ * Done by audio_commit_buffer, only needed for samples
+ * played some other way.
+ *
+ * @param samples Interleaved stereo samples.
+ * @param frames Number of stereo samples.
+ */
+extern void audio_apply_volume(int16_t* samples, uint32_t frames);
@@ -0,0 +1,39 @@
+/**
+ * @file audio_volume.c
+ * @brief Output volume of the audio interface.
+ *
+ * The audio interface only has volume for disc streaming,
+ * so DMA audio is scaled in software.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "audio.h"
+
+static uint8_t audio_volume_left = AUDIO_VOLUME_MAX;
+static uint8_t audio_volume_right = AUDIO_VOLUME_MAX;
+
+void audio_set_volume(uint8_t left, uint8_t right) {
+    audio_volume_left = left;
+    audio_volume_right = right;
+}
+
+void audio_get_volume(uint8_t* left, uint8_t* right) {
+    *left = audio_volume_left;
+    *right = audio_volume_right;
+}
+
+void audio_apply_volume(int16_t* samples, uint32_t frames) {
+    int32_t left = audio_volume_left;
+    int32_t right = audio_volume_right;
+
+    if(left == AUDIO_VOLUME_MAX && right == AUDIO_VOLUME_MAX)
+        return;
+
+    for(uint32_t i = 0; i < frames; i++) {
+        samples[i * 2 + 0] = (samples[i * 2 + 0] * left) / AUDIO_VOLUME_MAX;
+        samples[i * 2 + 1] = (samples[i * 2 + 1] * right) / AUDIO_VOLUME_MAX;
+    }
+}
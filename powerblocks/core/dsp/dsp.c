@@ -0,0 +1,201 @@
+/**
+ * @file dsp.c
+ * @brief Boots and talks to the audio DSP.
+ *
+ * Microcode is uploaded with the ARAM DMA. While the DSP is held
+ * in reset with the boot bit set, the DMA writes to its instruction
+ * memory instead of ARAM.
+ *
+ * The DSP raises the DSP interrupt after sending mail,
+ * the handler moves it from the mailbox into a queue.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "dsp.h"
+
+#include "system/system.h"
+#include "system/exceptions.h"
+
+#include "utils/log.h"
+
+#include "queue.h"
+
+static const char* TAB = "DSP";
+
+// DSP interface
+#define DSP_MAILBOX_IN_H  (*(volatile uint16_t*)0xCC005000) // CPU to DSP
+#define DSP_MAILBOX_IN_L  (*(volatile uint16_t*)0xCC005002)
+#define DSP_MAILBOX_OUT_H (*(volatile uint16_t*)0xCC005004) // DSP to CPU
+#define DSP_MAILBOX_OUT_L (*(volatile uint16_t*)0xCC005006)
+#define DSP_CSR           (*(volatile uint16_t*)0xCC00500A)
+#define AR_DMA_MMADDR     (*(volatile uint32_t*)0xCC005020)
+#define AR_DMA_ARADDR     (*(volatile uint32_t*)0xCC005024)
+#define AR_DMA_CNT        (*(volatile uint32_t*)0xCC005028)
+
+#define DSP_MAILBOX_STATUS (1<<15) // Set while the mail is unread
+
+#define DSP_CSR_RES        (1<<0)  // Reset, clears when done
+#define DSP_CSR_HALT       (1<<2)
+#define DSP_CSR_AIDINT     (1<<3)  // Audio DMA interrupt, write 1 to clear
+#define DSP_CSR_ARINT      (1<<5)  // ARAM DMA interrupt, write 1 to clear
+#define DSP_CSR_DSPINT     (1<<7)  // DSP interrupt, write 1 to clear
+#define DSP_CSR_DSPINTMSK  (1<<8)
+#define DSP_CSR_BOOT_BUSY  (1<<10) // Set while leaving boot mode
+#define DSP_CSR_BOOT       (1<<11) // ARAM DMA goes to IRAM while in reset
+
+// Interrupt flags that are cleared by writing them back
+#define DSP_CSR_INTERRUPTS (DSP_CSR_AIDINT | DSP_CSR_ARINT | DSP_CSR_DSPINT)
+
+static struct {
+    exception_irq_handler_t audio_irq_handler;
+
+    // Mail received from the DSP
+    QueueHandle_t mail;
+    StaticQueue_t mail_data;
+    uint32_t mail_storage[DSP_MAIL_QUEUE_LENGTH];
+} dsp_state;
+
+// Sets and clears bits of the CSR, without clearing interrupts that are not set.
+static void dsp_modify_csr(uint16_t set, uint16_t clear) {
+    uint32_t irq_enabled;
+    SYSTEM_DISABLE_ISR(irq_enabled);
+
+    DSP_CSR = (DSP_CSR & ~(DSP_CSR_INTERRUPTS | clear)) | set;
+
+    SYSTEM_ENABLE_ISR(irq_enabled);
+}
+
+static void dsp_reset() {
+    dsp_modify_csr(DSP_CSR_RES | DSP_CSR_HALT, 0);
+    while(DSP_CSR & DSP_CSR_RES);
+}
+
+static void dsp_irq_handler(exception_irq_type_t irq) {
+    uint16_t csr = DSP_CSR;
+
+    if((csr & DSP_CSR_AIDINT) && dsp_state.audio_irq_handler != NULL)
+        dsp_state.audio_irq_handler(irq);
+
+    if(!(csr & DSP_CSR_DSPINT))
+        return;
+
+    // Acknowledge only the DSP interrupt
+    DSP_CSR = (DSP_CSR & ~DSP_CSR_INTERRUPTS) | DSP_CSR_DSPINT;
+
+    // Reading the low half marks the mail as read
+    while(DSP_MAILBOX_OUT_H & DSP_MAILBOX_STATUS) {
+        uint32_t mail = (uint32_t)DSP_MAILBOX_OUT_H << 16;
+        mail |= DSP_MAILBOX_OUT_L;
+
+        if(dsp_state.mail != NULL)
+            xQueueSendFromISR(dsp_state.mail, &mail, &exception_isr_context_switch_needed);
+    }
+}
+
+void dsp_initialize() {
+    if(dsp_state.mail == NULL) {
+        dsp_state.mail = xQueueCreateStatic(DSP_MAIL_QUEUE_LENGTH, sizeof(uint32_t),
+            (uint8_t*)dsp_state.mail_storage, &dsp_state.mail_data);
+    }
+
+    dsp_reset();
+
+    // Clear any pending DSP interrupt and enable it
+    dsp_modify_csr(DSP_CSR_DSPINT | DSP_CSR_DSPINTMSK, 0);
+
+    exceptions_install_irq(dsp_irq_handler, EXCEPTION_IRQ_TYPE_DSP);
+
+    LOG_INFO(TAB, "DSP initialized.");
+}
+
+int dsp_load(const void* microcode, size_t size) {
+    if(((uintptr_t)microcode % DSP_MICROCODE_ALIGNMENT) != 0) {
+        LOG_ERROR(TAB, "Microcode must be aligned to %d bytes.", DSP_MICROCODE_ALIGNMENT);
+        return -1;
+    }
+
+    if(size == 0 || (size % DSP_MICROCODE_ALIGNMENT) != 0 || size > DSP_IRAM_SIZE) {
+        LOG_ERROR(TAB, "Invalid microcode size %u.", (unsigned int)size);
+        return -1;
+    }
+
+    system_flush_dcache(microcode, size);
+
+    // Reset into boot mode, so the DMA goes to IRAM
+    dsp_modify_csr(DSP_CSR_BOOT | DSP_CSR_RES | DSP_CSR_HALT, 0);
+    while(DSP_CSR & DSP_CSR_RES);
+
+    dsp_modify_csr(DSP_CSR_ARINT, 0);
+
+    // Main memory to IRAM address 0
+    AR_DMA_MMADDR = SYSTEM_MEM_PHYSICAL(microcode);
+    AR_DMA_ARADDR = 0;
+    AR_DMA_CNT = size;
+
+    while(!(DSP_CSR & DSP_CSR_ARINT));
+    dsp_modify_csr(DSP_CSR_ARINT, 0);
+
+    if(dsp_state.mail != NULL)
+        xQueueReset(dsp_state.mail);
+
+    // Leave boot mode and run from address 0
+    dsp_modify_csr(0, DSP_CSR_BOOT);
+    while(DSP_CSR & DSP_CSR_BOOT_BUSY);
+    dsp_modify_csr(0, DSP_CSR_HALT);
+
+    LOG_INFO(TAB, "Started %u bytes of microcode.", (unsigned int)size);
+
+    return 0;
+}
+
+void dsp_stop() {
+    dsp_reset();
+}
+
+static void dsp_write_mail(uint32_t mail) {
+    // Writing the low half sends it
+    DSP_MAILBOX_IN_H = mail >> 16;
+    DSP_MAILBOX_IN_L = mail & 0xFFFF;
+}
+
+void dsp_send_mail(uint32_t mail) {
+    while(!dsp_mail_read());
+
+    dsp_write_mail(mail);
+}
+
+int dsp_send_mail_timeout(uint32_t mail, uint32_t timeout_us) {
+    // Polled on the time base, so it works before the scheduler starts
+    uint64_t stop = system_get_time_base_int() + SYSTEM_US_TO_TICKS((uint64_t)timeout_us);
+
+    while(!dsp_mail_read()) {
+        if(system_get_time_base_int() >= stop)
+            return -1;
+    }
+
+    dsp_write_mail(mail);
+    return 0;
+}
+
+bool dsp_mail_read() {
+    return !(DSP_MAILBOX_IN_H & DSP_MAILBOX_STATUS);
+}
+
+int dsp_receive_mail(uint32_t* mail, TickType_t timeout) {
+    if(dsp_state.mail == NULL)
+        return -1;
+
+    if(xQueueReceive(dsp_state.mail, mail, timeout) != pdPASS)
+        return -1;
+
+    return 0;
+}
+
+void dsp_install_audio_irq(exception_irq_handler_t handler) {
+    dsp_state.audio_irq_handler = handler;
+
+    exceptions_install_irq(dsp_irq_handler, EXCEPTION_IRQ_TYPE_DSP);
+}
@@ -0,0 +1,118 @@
+/**
+ * @file dsp.h
+ * @brief Boots and talks to the audio DSP.
+ *
+ * Uploads microcode into the DSP's instruction memory and starts it.
+ * After that, the CPU and DSP talk by 32 bit mail through a mailbox
+ * in each direction. Mail from the DSP is received by a interrupt
+ * and queued until read.
+ *
+ * Microcode is assembled with dspasm, see dsp_embed_microcode
+ * in tools/dspasm for building it into a app.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include "powerblocks/core/system/exceptions.h"
+
+#include "FreeRTOS.h"
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+/** @def DSP_MICROCODE_ALIGNMENT
+ *  @brief Alignment of microcode in bytes.
+ *
+ *  It is uploaded by DMA, so it must be aligned to 32 bytes
+ *  and be a multiple of 32 bytes in size.
+ */
+#define DSP_MICROCODE_ALIGNMENT 32
+
+/** @def DSP_IRAM_SIZE
+ *  @brief Size of the DSP's instruction memory in bytes.
+ */
+#define DSP_IRAM_SIZE 0x2000
+
+/** @def DSP_MAIL_QUEUE_LENGTH
+ *  @brief Mail from the DSP that can wait to be read.
+ *
+ *  Mail that arrives while the queue is full is dropped.
+ */
+#define DSP_MAIL_QUEUE_LENGTH 16
+
+/**
+ * @brief Initializes the DSP driver.
+ *
+ * Holds the DSP in reset, and enables the interrupt
+ * it uses to send mail.
+ */
+extern void dsp_initialize();
+
+/**
+ * @brief Uploads microcode and starts running it.
+ *
+ * The DSP is reset and halted while the microcode is copied into its
+ * instruction memory. It then starts running from address 0.
+ * Mail waiting from the previous microcode is thrown away.
+ *
+ * @param microcode Assembled microcode. Must be aligned to DSP_MICROCODE_ALIGNMENT.
+ * @param size Size in bytes. A multiple of DSP_MICROCODE_ALIGNMENT, up to DSP_IRAM_SIZE.
+ * @return Negative if error.
+ */
+extern int dsp_load(const void* microcode, size_t size);
+
+/**
+ * @brief Stops the running microcode.
+ *
+ * Leaves the DSP halted in reset until dsp_load is called.
+ */
+extern void dsp_stop();
+
+/**
+ * @brief Sends mail to the DSP.
+ *
+ * Waits for the DSP to read the last mail sent, before sending this one.
+ * This blocks forever if the DSP is stopped or the microcode never reads
+ * its mail, use dsp_send_mail_timeout when that can happen.
+ *
+ * @param mail Mail to send.
+ */
+extern void dsp_send_mail(uint32_t mail);
+
+/**
+ * @brief Sends mail to the DSP, giving up if the last mail is not read in time.
+ *
+ * @param mail Mail to send.
+ * @param timeout_us Microseconds to wait for the DSP to read the last mail.
+ * @return Negative if the last mail was not read in time, nothing is sent.
+ */
+extern int dsp_send_mail_timeout(uint32_t mail, uint32_t timeout_us);
+
+/**
+ * @brief Checks if the DSP has read the last mail sent.
+ */
+extern bool dsp_mail_read();
+
+/**
+ * @brief Receives mail from the DSP.
+ *
+ * @param mail Where to put the mail.
+ * @param timeout Ticks to wait for mail. portMAX_DELAY to wait forever.
+ * @return Negative if no mail arrived in time.
+ */
+extern int dsp_receive_mail(uint32_t* mail, TickType_t timeout);
+
+/**
+ * @brief Sets the handler of the audio DMA interrupt.
+ *
+ * The audio DMA interrupt comes from the DSP interface, so it shares
+ * a IRQ with mail from the DSP. Used by the audio driver.
+ *
+ * @param handler Called when the DSP IRQ has the audio DMA interrupt set.
+ */
+extern void dsp_install_audio_irq(exception_irq_handler_t handler);
@@ -120,70 +120,6 @@ static const uint16_t VIDEO_Mode640X480NtsciYUV16[64] = {
     0x1313, 0x0F08, 0x0008, 0x0C0F, 0x00FF, 0x0000, 0x0001, 0x0001,
     0x0280, 0x807A, 0x019C, 0x00FF, 0x00FF, 0x00FF, 0x00FF, 0x00FF};
 
-static const video_profile_t VIDEO_Profile640X480Ntsci = {
-    .width = 640,
-    .efb_height = 480,
-    .xfb_height = 480,
-
-    .copy_pattern = { // 3x MSAA pattern
-        {6, 6}, {6, 6}, {6, 6},
-        {6, 6}, {6, 6}, {6, 6},
-        {6, 6}, {6, 6}, {6, 6},
-        {6, 6}, {6, 6}, {6, 6},
-    },
-    .copy_filer = {   0, 0,    // Top Row
-                   21, 22, 21, // Middle Row (Slandered Deflikering Pattern)
-                      0, 0}    // Bottom Row  
-};
-
-static const video_profile_t VIDEO_Profile640X480Pal50 = {
-    .width = 640,
-    .efb_height = 480,
-    .xfb_height = 576,
-
-    .copy_pattern = { // 3x MSAA pattern
-        {6, 6}, {6, 6}, {6, 6},
-        {6, 6}, {6, 6}, {6, 6},
-        {6, 6}, {6, 6}, {6, 6},
-        {6, 6}, {6, 6}, {6, 6},
-    },
-    .copy_filer = {   0, 0,    // Top Row
-                   21, 22, 21, // Middle Row (Slandered Deflikering Pattern)
-                      0, 0}    // Bottom Row
-};
-
-static const video_profile_t VIDEO_Profile640X480Pal60 = {
-    .width = 640,
-    .efb_height = 480,
-    .xfb_height = 576,
-
-    .copy_pattern = { // 3x MSAA pattern
-        {6, 6}, {6, 6}, {6, 6},
-        {6, 6}, {6, 6}, {6, 6},
-        {6, 6}, {6, 6}, {6, 6},
-        {6, 6}, {6, 6}, {6, 6},
-    },
-    .copy_filer = {   0, 0,    // Top Row
-                   21, 22, 21, // Middle Row (Slandered Deflikering Pattern)
-                      0, 0}    // Bottom Row
-};
-
-static const video_profile_t VIDEO_Profile640X480Ntscp = {
-    .width = 640,
-    .efb_height = 480,
-    .xfb_height = 480,
-
-    .copy_pattern = { // 3x MSAA pattern
-        {6, 6}, {6, 6}, {6, 6},
-        {6, 6}, {6, 6}, {6, 6},
-        {6, 6}, {6, 6}, {6, 6},
-        {6, 6}, {6, 6}, {6, 6},
-    },
-    .copy_filer = {   0, 0,    // Top Row
-                   21, 22, 21, // Middle Row (Slandered Deflikering Pattern)
-                      0, 0}    // Bottom Row
-};
-
 static void video_irq_handler(exception_irq_type_t irq) {
     uint32_t display;
 
@@ -262,22 +198,6 @@ video_mode_t video_system_default_video_mode() {
     }
 }
 
-const video_profile_t* video_get_profile(video_mode_t mode) {
-    switch(mode) {
-        case VIDEO_MODE_640X480_NTSC_INTERLACED:
-            return &VIDEO_Profile640X480Ntsci;
-        case VIDEO_MODE_640X480_NTSC_PROGRESSIVE:
-            return &VIDEO_Profile640X480Ntscp;
-        case VIDEO_MODE_640X480_PAL50:
-            return &VIDEO_Profile640X480Pal50;
-        case VIDEO_MODE_640X480_PAL60:
-            return &VIDEO_Profile640X480Pal60;
-        default:
-            LOG_ERROR(TAB, "Invalid video mode %d", mode);
-            return NULL;
-    }
-}
-
 void video_initialize(video_mode_t mode) {
     const uint16_t* vi_state;
 
@@ -385,4 +305,8 @@ void video_wait_vsync_int() {
 
 void video_set_retrace_callback(video_retrace_callback_t callback) {
     video_retrace_callback = callback;
+}
+
+video_retrace_callback_t video_get_retrace_callback() {
+    return video_retrace_callback;
 }
\ No newline at end of file
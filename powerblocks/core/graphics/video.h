@@ -130,4 +130,14 @@ extern void video_wait_vsync_int();
  * 
  * @param callback Function pointer to call on retrace.
  */
-extern void video_set_retrace_callback(video_retrace_callback_t callback);
\ No newline at end of file
+extern void video_set_retrace_callback(video_retrace_callback_t callback);
+
+/**
+ * @brief Gets the retrace callback.
+ *
+ * Gets the callback set with video_set_retrace_callback, so that
+ * it can be chained when installing another one.
+ * 
+ * @return The current retrace callback, or NULL.
+ */
+extern video_retrace_callback_t video_get_retrace_callback();
\ No newline at end of file
@@ -0,0 +1,97 @@
+/**
+ * @file video_profile.c
+ * @brief Profiles of the video modes.
+ *
+ * Holds the framebuffer sizes and copy settings of each video mode.
+ * Kept apart from video.c as they do not touch the video interface.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "video.h"
+
+#include "utils/log.h"
+
+static const char* TAB = "VIDEO";
+
+static const video_profile_t VIDEO_Profile640X480Ntsci = {
+    .width = 640,
+    .efb_height = 480,
+    .xfb_height = 480,
+
+    .copy_pattern = { // 3x MSAA pattern
+        {6, 6}, {6, 6}, {6, 6},
+        {6, 6}, {6, 6}, {6, 6},
+        {6, 6}, {6, 6}, {6, 6},
+        {6, 6}, {6, 6}, {6, 6},
+    },
+    .copy_filer = {   0, 0,    // Top Row
+                   21, 22, 21, // Middle Row (Slandered Deflikering Pattern)
+                      0, 0}    // Bottom Row  
+};
+
+static const video_profile_t VIDEO_Profile640X480Pal50 = {
+    .width = 640,
+    .efb_height = 480,
+    .xfb_height = 576,
+
+    .copy_pattern = { // 3x MSAA pattern
+        {6, 6}, {6, 6}, {6, 6},
+        {6, 6}, {6, 6}, {6, 6},
+        {6, 6}, {6, 6}, {6, 6},
+        {6, 6}, {6, 6}, {6, 6},
+    },
+    .copy_filer = {   0, 0,    // Top Row
+                   21, 22, 21, // Middle Row (Slandered Deflikering Pattern)
+                      0, 0}    // Bottom Row
+};
+
+static const video_profile_t VIDEO_Profile640X480Pal60 = {
+    .width = 640,
+    .efb_height = 480,
+    .xfb_height = 576,
+
+    .copy_pattern = { // 3x MSAA pattern
+        {6, 6}, {6, 6}, {6, 6},
+        {6, 6}, {6, 6}, {6, 6},
+        {6, 6}, {6, 6}, {6, 6},
+        {6, 6}, {6, 6}, {6, 6},
+    },
+    .copy_filer = {   0, 0,    // Top Row
+                   21, 22, 21, // Middle Row (Slandered Deflikering Pattern)
+                      0, 0}    // Bottom Row
+};
+
+static const video_profile_t VIDEO_Profile640X480Ntscp = {
+    .width = 640,
+    .efb_height = 480,
+    .xfb_height = 480,
+
+    .copy_pattern = { // 3x MSAA pattern
+        {6, 6}, {6, 6}, {6, 6},
+        {6, 6}, {6, 6}, {6, 6},
+        {6, 6}, {6, 6}, {6, 6},
+        {6, 6}, {6, 6}, {6, 6},
+    },
+    .copy_filer = {   0, 0,    // Top Row
+                   21, 22, 21, // Middle Row (Slandered Deflikering Pattern)
+                      0, 0}    // Bottom Row
+};
+
+const video_profile_t* video_get_profile(video_mode_t mode) {
+    switch(mode) {
+        case VIDEO_MODE_640X480_NTSC_INTERLACED:
+            return &VIDEO_Profile640X480Ntsci;
+        case VIDEO_MODE_640X480_NTSC_PROGRESSIVE:
+            return &VIDEO_Profile640X480Ntscp;
+        case VIDEO_MODE_640X480_PAL50:
+            return &VIDEO_Profile640X480Pal50;
+        case VIDEO_MODE_640X480_PAL60:
+            return &VIDEO_Profile640X480Pal60;
+        default:
+            LOG_ERROR(TAB, "Invalid video mode %d", mode);
+            return NULL;
+    }
+}
\ No newline at end of file
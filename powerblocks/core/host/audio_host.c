@@ -0,0 +1,228 @@
+/**
+ * @file audio_host.c
+ * @brief Plays audio on the host.
+ *
+ * A task stands in for the audio DMA. It starts each buffer
+ * with the DSP IRQ at the sample rate, and records the samples
+ * to the WAV file set with POWERBLOCKS_HOST_AUDIO.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "host.h"
+
+#include "audio/audio.h"
+#include "dsp/dsp.h"
+#include "system/system.h"
+
+#include "FreeRTOS.h"
+#include "task.h"
+
+#include "utils/log.h"
+
+#include <fcntl.h>
+#include <stdbool.h>
+#include <stdlib.h>
+#include <string.h>
+#include <unistd.h>
+
+static const char* TAB = "AUDIO";
+
+#define AUDIO_HOST_WAV_HEADER_SIZE 44
+
+static audio_sample_rate_t audio_sample_rate = AUDIO_SAMPLE_RATE_48KHZ;
+
+static int16_t* audio_buffers[2];
+static uint32_t audio_buffer_size;
+static audio_buffer_callback_t audio_callback;
+static bool audio_playing;
+
+// Buffer last given to the DMA, it starts playing on the next interrupt.
+static int audio_queued_buffer;
+
+// The first interrupt has no buffer before it to free.
+static bool audio_first_block;
+
+static TaskHandle_t audio_task_handle;
+
+static int audio_wav = -1;
+static uint32_t audio_wav_data_size;
+
+static void audio_write_u16(uint8_t* data, uint16_t value) {
+    data[0] = value & 0xFF;
+    data[1] = value >> 8;
+}
+
+static void audio_write_u32(uint8_t* data, uint32_t value) {
+    audio_write_u16(data, value & 0xFFFF);
+    audio_write_u16(data + 2, value >> 16);
+}
+
+// Rewritten after each buffer, so the file is whole if the app exits at any point.
+static void audio_wav_write_header() {
+    uint8_t header[AUDIO_HOST_WAV_HEADER_SIZE];
+    uint32_t rate = audio_get_sample_rate_hz();
+
+    memcpy(header + 0, "RIFF", 4);
+    audio_write_u32(header + 4, 36 + audio_wav_data_size);
+    memcpy(header + 8, "WAVE", 4);
+
+    memcpy(header + 12, "fmt ", 4);
+    audio_write_u32(header + 16, 16);
+    audio_write_u16(header + 20, 1); // PCM
+    audio_write_u16(header + 22, AUDIO_CHANNELS);
+    audio_write_u32(header + 24, rate);
+    audio_write_u32(header + 28, rate * AUDIO_FRAME_SIZE);
+    audio_write_u16(header + 32, AUDIO_FRAME_SIZE);
+    audio_write_u16(header + 34, 16);
+
+    memcpy(header + 36, "data", 4);
+    audio_write_u32(header + 40, audio_wav_data_size);
+
+    pwrite(audio_wav, header, sizeof(header), 0);
+}
+
+static void audio_wav_record(const int16_t* samples, uint32_t size) {
+    if(audio_wav < 0)
+        return;
+
+    // Samples are in host order, which is the little endian WAV wants.
+    if(host_write_all(audio_wav, samples, size) < 0) {
+        LOG_ERROR(TAB, "Failed to record audio.");
+        close(audio_wav);
+        audio_wav = -1;
+        return;
+    }
+
+    audio_wav_data_size += size;
+    audio_wav_write_header();
+}
+
+static void audio_irq_handler(exception_irq_type_t irq) {
+    if(!audio_playing)
+        return;
+
+    // The queued buffer is now playing, queue the other one after it.
+    int free_buffer = audio_queued_buffer ^ 1;
+    audio_queued_buffer = free_buffer;
+
+    if(audio_first_block) {
+        audio_first_block = false;
+        return;
+    }
+
+    if(audio_callback != NULL)
+        audio_callback(audio_buffers[free_buffer], audio_buffer_size / AUDIO_FRAME_SIZE);
+}
+
+static void audio_task(void* params) {
+    TickType_t last_wake = 0;
+    uint64_t played_frames = 0;
+
+    while(true) {
+        if(!audio_playing) {
+            ulTaskNotifyTake(pdTRUE, portMAX_DELAY);
+            last_wake = xTaskGetTickCount();
+            played_frames = 0;
+            continue;
+        }
+
+        int playing_buffer = audio_queued_buffer;
+        host_raise_irq(EXCEPTION_IRQ_TYPE_DSP);
+
+        audio_wav_record(audio_buffers[playing_buffer], audio_buffer_size);
+
+        // Buffers do not line up with ticks, so wait to where the next one is due.
+        uint32_t rate = audio_get_sample_rate_hz();
+        uint64_t frames = audio_buffer_size / AUDIO_FRAME_SIZE;
+        TickType_t increment = (played_frames + frames) * configTICK_RATE_HZ / rate - played_frames * configTICK_RATE_HZ / rate;
+        played_frames += frames;
+
+        vTaskDelayUntil(&last_wake, increment);
+    }
+}
+
+void audio_initialize(audio_sample_rate_t rate) {
+    audio_playing = false;
+    audio_callback = NULL;
+
+    audio_set_sample_rate(rate);
+
+    const char* path = getenv(HOST_ENV_AUDIO);
+    if(path != NULL && audio_wav < 0) {
+        audio_wav = open(path, O_WRONLY | O_CREAT | O_TRUNC, 0644);
+        if(audio_wav < 0) {
+            LOG_ERROR(TAB, "Failed to open %s", path);
+        } else {
+            audio_wav_data_size = 0;
+            audio_wav_write_header();
+            lseek(audio_wav, AUDIO_HOST_WAV_HEADER_SIZE, SEEK_SET);
+        }
+    }
+
+    dsp_install_audio_irq(audio_irq_handler);
+
+    // Like a interrupt, it should run over everything else.
+    if(audio_task_handle == NULL)
+        xTaskCreate(audio_task, "AI", configMINIMAL_STACK_SIZE * 4, NULL, configMAX_PRIORITIES - 1, &audio_task_handle);
+
+    LOG_INFO(TAB, "Audio interface initialized.");
+}
+
+void audio_set_sample_rate(audio_sample_rate_t rate) {
+    audio_sample_rate = rate;
+}
+
+audio_sample_rate_t audio_get_sample_rate() {
+    return audio_sample_rate;
+}
+
+uint32_t audio_get_sample_rate_hz() {
+    return audio_sample_rate == AUDIO_SAMPLE_RATE_32KHZ ? 32000 : 48000;
+}
+
+int audio_start(int16_t* buffer_a, int16_t* buffer_b, uint32_t size, audio_buffer_callback_t callback) {
+    if(((uintptr_t)buffer_a % AUDIO_BUFFER_ALIGNMENT) != 0 || ((uintptr_t)buffer_b % AUDIO_BUFFER_ALIGNMENT) != 0) {
+        LOG_ERROR(TAB, "Audio buffers must be aligned to %d bytes.", AUDIO_BUFFER_ALIGNMENT);
+        return -1;
+    }
+
+    if(size == 0 || (size % AUDIO_BUFFER_ALIGNMENT) != 0) {
+        LOG_ERROR(TAB, "Invalid audio buffer size %u.", (unsigned int)size);
+        return -1;
+    }
+
+    uint32_t irq_enabled;
+    SYSTEM_DISABLE_ISR(irq_enabled);
+
+    audio_buffers[0] = buffer_a;
+    audio_buffers[1] = buffer_b;
+    audio_buffer_size = size;
+    audio_callback = callback;
+    audio_first_block = true;
+    audio_queued_buffer = 0;
+    audio_playing = true;
+
+    SYSTEM_ENABLE_ISR(irq_enabled);
+
+    xTaskNotifyGive(audio_task_handle);
+
+    return 0;
+}
+
+void audio_stop() {
+    uint32_t irq_enabled;
+    SYSTEM_DISABLE_ISR(irq_enabled);
+
+    // The task only touches the buffers while playing, and runs over this one.
+    audio_playing = false;
+    audio_callback = NULL;
+
+    SYSTEM_ENABLE_ISR(irq_enabled);
+}
+
+void audio_commit_buffer(int16_t* buffer, uint32_t frames) {
+    audio_apply_volume(buffer, frames);
+}
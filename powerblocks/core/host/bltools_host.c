@@ -0,0 +1,44 @@
+/**
+ * @file bltools_host.c
+ * @brief Bluetooth Tools on the host.
+ *
+ * There is no Bluetooth controller on the host, so no devices
+ * are ever found. Apps can still start Bluetooth as they would on
+ * a Wii. Scripted remotes come from wiimote_host.c instead.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "bluetooth/bltootls.h"
+#include "bluetooth/blerror.h"
+
+#include <stddef.h>
+
+int bltools_initialize() {
+    return 0;
+}
+
+void bltools_register_driver(bluetooth_driver_t driver) {
+}
+
+bluetooth_driver_t* bltoots_find_driver_by_id(uint16_t driver_id) {
+    return NULL;
+}
+
+bluetooth_driver_t* bltools_find_compatable_driver(const hci_discovered_device_info_t* device, const char* device_name) {
+    return NULL;
+}
+
+int bltools_load_driver(const bluetooth_driver_t* driver, const hci_discovered_device_info_t* device) {
+    return BLERROR_DRIVER_INITIALIZE_FAIL;
+}
+
+int bltools_load_compatable_driver(const hci_discovered_device_info_t* device, const char* device_name) {
+    return BLERROR_NO_DRIVER_FOUND;
+}
+
+int bltools_begin_discovery(uint32_t lap, TickType_t duration, uint8_t responses) {
+    return 0;
+}
@@ -0,0 +1,40 @@
+/**
+ * @file crash_handler_host.c
+ * @brief Reports crashes on the host.
+ *
+ * Prints the crash to stderr and ends the process,
+ * so a crash fails the run instead of freezing it.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "utils/crash_handler.h"
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <unistd.h>
+
+static crash_handler_t system_crash_handler = NULL;
+
+void crash_handler_bug_check(const char* cause, exception_context_t* ctx) {
+    if(system_crash_handler) {
+        system_crash_handler(cause, ctx);
+        return;
+    }
+
+    fprintf(stderr, "PowerBlocks crashed: %s\n", cause);
+
+    // Only set by code building contexts itself, the host exceptions have none.
+    if(ctx) {
+        fprintf(stderr, "PC: %08XH LR: %08XH\n", ctx->srr0, ctx->lr);
+    }
+
+    fflush(stderr);
+    _exit(EXIT_FAILURE);
+}
+
+void crash_handler_set(crash_handler_t handler) {
+    system_crash_handler = handler;
+}
@@ -0,0 +1,52 @@
+/**
+ * @file dsp_host.c
+ * @brief The audio DSP on the host.
+ *
+ * There is no DSP on the host to run microcode, so loading it fails
+ * and no mail ever arrives. The audio DMA interrupt is still raised
+ * by audio_host.c through the DSP IRQ.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "dsp/dsp.h"
+
+#include "task.h"
+
+#include "utils/log.h"
+
+static const char* TAB = "DSP";
+
+void dsp_initialize() {
+    LOG_INFO(TAB, "DSP initialized.");
+}
+
+int dsp_load(const void* microcode, size_t size) {
+    LOG_ERROR(TAB, "DSP microcode can not run on the host.");
+    return -1;
+}
+
+void dsp_stop() {
+}
+
+void dsp_send_mail(uint32_t mail) {
+}
+
+int dsp_send_mail_timeout(uint32_t mail, uint32_t timeout_us) {
+    return 0;
+}
+
+bool dsp_mail_read() {
+    return true;
+}
+
+int dsp_receive_mail(uint32_t* mail, TickType_t timeout) {
+    vTaskDelay(timeout);
+    return -1;
+}
+
+void dsp_install_audio_irq(exception_irq_handler_t handler) {
+    exceptions_install_irq(handler, EXCEPTION_IRQ_TYPE_DSP);
+}
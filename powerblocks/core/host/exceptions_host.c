@@ -0,0 +1,93 @@
+/**
+ * @file exceptions_host.c
+ * @brief Handles System Exceptions on the host.
+ *
+ * There is no processor interface on the host. IRQs are raised by the
+ * simulated hardware with host_raise_irq, and faults come in as signals.
+ *
+ * Fault handlers are kept but never called, as there
+ * is no PowerPC context to give them.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "host.h"
+
+#include "system/system.h"
+#include "utils/crash_handler.h"
+
+#include "FreeRTOS.h"
+#include "task.h"
+
+#include <signal.h>
+#include <string.h>
+
+long exception_isr_context_switch_needed;
+
+// The IRQ handlers used with the simulated hardware
+static exception_irq_handler_t irq_handlers[EXCEPTION_IRQ_COUNT];
+
+// The handlers hooking faults before the crash handler
+static exception_fault_handler_t fault_handlers[EXCEPTION_FAULT_COUNT];
+
+static void exception_signal(int signal) {
+    switch(signal) {
+        case SIGSEGV:
+            crash_handler_bug_check("DSI", NULL);
+            break;
+        case SIGBUS:
+            crash_handler_bug_check("ALIGNMENT", NULL);
+            break;
+        default:
+            crash_handler_bug_check("PROGRAM", NULL);
+            break;
+    }
+}
+
+void exceptions_install_vector() {
+    struct sigaction action;
+    memset(&action, 0, sizeof(action));
+
+    // Go back to the default if the crash handler itself faults
+    action.sa_handler = exception_signal;
+    action.sa_flags = SA_RESETHAND;
+    sigemptyset(&action.sa_mask);
+
+    sigaction(SIGSEGV, &action, NULL);
+    sigaction(SIGBUS, &action, NULL);
+    sigaction(SIGILL, &action, NULL);
+    sigaction(SIGFPE, &action, NULL);
+}
+
+void exceptions_install_irq(exception_irq_handler_t handler, exception_irq_type_t type) {
+    irq_handlers[type] = handler;
+}
+
+int exceptions_install_fault_handler(exception_fault_handler_t handler, exception_fault_type_t type) {
+    if((unsigned int)type >= EXCEPTION_FAULT_COUNT)
+        return -1;
+
+    fault_handlers[type] = handler;
+    return 0;
+}
+
+void host_raise_irq(exception_irq_type_t type) {
+    exception_irq_handler_t handler = irq_handlers[type];
+    if(handler == NULL)
+        return;
+
+    uint32_t irq_enabled;
+    SYSTEM_DISABLE_ISR(irq_enabled);
+
+    exception_isr_context_switch_needed = 0;
+    handler(type);
+    long switch_needed = exception_isr_context_switch_needed;
+
+    SYSTEM_ENABLE_ISR(irq_enabled);
+
+    if(switch_needed != 0) {
+        taskYIELD();
+    }
+}
@@ -0,0 +1,63 @@
+/**
+ * @file framebuffer_host.c
+ * @brief Saves framebuffers as images on the host.
+ *
+ * Converts the YUYV framebuffer to RGB and writes it out as a
+ * binary PPM, which most image viewers and tools can open.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "host.h"
+
+#include <fcntl.h>
+#include <stdio.h>
+#include <string.h>
+#include <unistd.h>
+
+static inline uint8_t clamp(int val) {
+    return (uint8_t)((val < 0) ? 0 : (val > 255 ? 255 : val));
+}
+
+// Same conversion framebuffer.c blends with
+static void framebuffer_yuv_to_rgb(uint8_t* rgb, int y, int u, int v) {
+    int c = y - 16;
+    int d = u - 128;
+    int e = v - 128;
+
+    rgb[0] = clamp((298 * c + 409 * e + 128) >> 8);
+    rgb[1] = clamp((298 * c - 100 * d - 208 * e + 128) >> 8);
+    rgb[2] = clamp((298 * c + 516 * d + 128) >> 8);
+}
+
+int host_framebuffer_dump(const char* path, const framebuffer_t* framebuffer) {
+    static uint8_t row[VIDEO_WIDTH * 3];
+
+    int fd = open(path, O_WRONLY | O_CREAT | O_TRUNC, 0644);
+    if(fd < 0)
+        return -1;
+
+    char header[32];
+    int header_length = snprintf(header, sizeof(header), "P6\n%d %d\n255\n", VIDEO_WIDTH, VIDEO_HEIGHT);
+    int ret = host_write_all(fd, header, header_length);
+
+    for(int y = 0; y < VIDEO_HEIGHT && ret >= 0; y++) {
+        // Even pixels hold U, odd pixels hold V, shared by the pair.
+        for(int x = 0; x < VIDEO_WIDTH; x += 2) {
+            uint16_t even = framebuffer->pixels[y][x];
+            uint16_t odd = framebuffer->pixels[y][x + 1];
+            int u = even & 0xFF;
+            int v = odd & 0xFF;
+
+            framebuffer_yuv_to_rgb(row + x * 3, even >> 8, u, v);
+            framebuffer_yuv_to_rgb(row + (x + 1) * 3, odd >> 8, u, v);
+        }
+
+        ret = host_write_all(fd, row, sizeof(row));
+    }
+
+    close(fd);
+    return ret;
+}
@@ -0,0 +1,14 @@
+/**
+ * @file FreeRTOSConfig.h
+ * @brief FreeRTOS Configuration on the host
+ *
+ * The host uses the POSIX port, but the same configuration.
+ * Kept here so the Wii port's portmacro.h is not on the include path.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ */
+
+#pragma once
+
+#include "powerblocks/core/freertos_port/FreeRTOSConfig.h"
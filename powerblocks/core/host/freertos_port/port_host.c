@@ -0,0 +1,49 @@
+/**
+ * @file port_host.c
+ * @brief Application hooks for the FreeRTOS POSIX port.
+ *
+ * The POSIX port does the scheduling itself,
+ * this only provides what the configuration asks the application for.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ */
+
+#include "FreeRTOS.h"
+#include "task.h"
+
+#include "utils/crash_handler.h"
+
+#include <stdio.h>
+
+// The idle thread is statically allocated. So we will need something to provide that memory.
+static StaticTask_t xIdleTaskTCB;
+static StackType_t uxIdleTaskStack[configMINIMAL_STACK_SIZE];
+
+void vApplicationGetIdleTaskMemory(StaticTask_t **ppxIdleTaskTCBBuffer,
+                                   StackType_t **ppxIdleTaskStackBuffer,
+                                   configSTACK_DEPTH_TYPE *puxIdleTaskStackSize)
+{
+    *ppxIdleTaskTCBBuffer = &xIdleTaskTCB;
+    *ppxIdleTaskStackBuffer = uxIdleTaskStack;
+    *puxIdleTaskStackSize = configMINIMAL_STACK_SIZE;
+}
+
+// Same static allocation situation
+static StaticTask_t timerTaskTCB;
+static StackType_t timerTaskStack[ configTIMER_TASK_STACK_DEPTH ];
+
+void vApplicationGetTimerTaskMemory( StaticTask_t **ppxTimerTaskTCBBuffer,
+                                     StackType_t **ppxTimerTaskStackBuffer,
+                                     configSTACK_DEPTH_TYPE *puxTimerTaskStackSize )
+{
+    *ppxTimerTaskTCBBuffer   = &timerTaskTCB;
+    *ppxTimerTaskStackBuffer = timerTaskStack;
+    *puxTimerTaskStackSize   = configTIMER_TASK_STACK_DEPTH;
+}
+
+void vApplicationStackOverflowHook(TaskHandle_t xTask, char * pcTaskName ) {
+    char msg[64];
+    snprintf(msg, sizeof(msg), "STACK OVERFLOW: %s", pcTaskName);
+    crash_handler_bug_check(msg, NULL);
+}
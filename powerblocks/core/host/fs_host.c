@@ -0,0 +1,526 @@
+/**
+ * @file fs_host.c
+ * @brief IOS NAND File System on the host.
+ *
+ * Simulates /dev/fs on top of the NAND directory,
+ * set with POWERBLOCKS_HOST_NAND.
+ *
+ * Everything is owned by the running title, and
+ * permissions come from the host's mode bits.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#define _GNU_SOURCE
+
+#include "host.h"
+
+#include "ios/isfs.h"
+
+#include <dirent.h>
+#include <errno.h>
+#include <fcntl.h>
+#include <stdbool.h>
+#include <stdio.h>
+#include <string.h>
+#include <sys/stat.h>
+#include <unistd.h>
+
+#define ISFS_IOCTL_GET_STATS      0x02
+#define ISFS_IOCTL_CREATE_DIR     0x03
+#define ISFS_IOCTL_READ_DIR       0x04
+#define ISFS_IOCTL_GET_ATTRIBUTES 0x06
+#define ISFS_IOCTL_DELETE         0x07
+#define ISFS_IOCTL_RENAME         0x08
+#define ISFS_IOCTL_CREATE_FILE    0x09
+#define ISFS_IOCTL_GET_FILE_STATS 0x0B
+#define ISFS_IOCTL_GET_USAGE      0x0C
+
+// Made up owner of everything, and size of the NAND
+#define FS_HOST_OWNER_ID       0x00001000
+#define FS_HOST_GROUP_ID       0x0001
+#define FS_HOST_CLUSTER_SIZE   0x4000
+#define FS_HOST_CLUSTER_COUNT  0x8000
+#define FS_HOST_INODE_COUNT    6143
+
+static int fs_host_error(int error) {
+    switch(error) {
+        case ENOENT:
+        case ENOTDIR:
+            return ISFS_ERROR_NOT_FOUND;
+        case EACCES:
+        case EPERM:
+        case EROFS:
+            return ISFS_ERROR_ACCESS;
+        case EEXIST:
+            return ISFS_ERROR_EXISTS;
+        case ENOTEMPTY:
+            return ISFS_ERROR_NOT_EMPTY;
+        case ENOSPC:
+            return ISFS_ERROR_FULL;
+        default:
+            return ISFS_ERROR_INVALID;
+    }
+}
+
+// Checks a path like ISFS does, then finds it in the NAND directory.
+static int fs_host_path(char* host_path, size_t size, const char* path) {
+    size_t length = strnlen(path, ISFS_MAX_PATH);
+    if(length >= ISFS_MAX_PATH)
+        return ISFS_ERROR_PATH_TOO_LONG;
+
+    if(path[0] != '/')
+        return ISFS_ERROR_INVALID;
+
+    // Each name must fit in a directory entry
+    const char* name = path + 1;
+    while(*name) {
+        const char* end = strchr(name, '/');
+        size_t name_length = end ? (size_t)(end - name) : strlen(name);
+
+        // No empty names, ".", or ".."
+        if(name_length == 0 || name_length > ISFS_MAX_NAME || strncmp(name, "..", name_length) == 0)
+            return ISFS_ERROR_INVALID;
+
+        if(end == NULL)
+            break;
+
+        name = end + 1;
+    }
+
+    const char* root = host_get_env(HOST_ENV_NAND, HOST_DEFAULT_NAND);
+
+    // The real NAND always has /tmp
+    if(strncmp(path, "/tmp", 4) == 0 && (path[4] == '/' || path[4] == '\0')) {
+        snprintf(host_path, size, "%s/tmp", root);
+        mkdir(host_path, 0777);
+    }
+
+    snprintf(host_path, size, "%s%s", root, path);
+    return 0;
+}
+
+// The directory holding a path must exist for anything to be made in it
+static int fs_host_check_parent(const char* host_path) {
+    char parent[512];
+    snprintf(parent, sizeof(parent), "%s", host_path);
+
+    char* slash = strrchr(parent, '/');
+    if(slash)
+        *slash = '\0';
+
+    struct stat st;
+    if(stat(parent, &st) < 0 || !S_ISDIR(st.st_mode))
+        return ISFS_ERROR_NOT_FOUND;
+
+    return 0;
+}
+
+static mode_t fs_host_permissions_to_mode(uint8_t owner, uint8_t group, uint8_t other) {
+    mode_t mode = 0;
+    uint8_t permissions[3] = { owner, group, other };
+
+    for(int i = 0; i < 3; i++) {
+        mode <<= 3;
+        if(permissions[i] & ISFS_PERMISSION_READ)
+            mode |= 4;
+        if(permissions[i] & ISFS_PERMISSION_WRITE)
+            mode |= 2;
+    }
+
+    return mode;
+}
+
+static uint8_t fs_host_mode_to_permissions(mode_t mode) {
+    return ((mode & 4) ? ISFS_PERMISSION_READ : 0) | ((mode & 2) ? ISFS_PERMISSION_WRITE : 0);
+}
+
+static int fs_host_create(int ioctl, const isfs_attributes_t* attributes) {
+    char host_path[512];
+    char path[ISFS_MAX_PATH];
+
+    memcpy(path, attributes->path, ISFS_MAX_PATH);
+    path[ISFS_MAX_PATH - 1] = '\0';
+
+    int ret = fs_host_path(host_path, sizeof(host_path), path);
+    if(ret < 0)
+        return ret;
+
+    ret = fs_host_check_parent(host_path);
+    if(ret < 0)
+        return ret;
+
+    // The owner always keeps write access on the host, so the NAND stays cleanable
+    mode_t mode = fs_host_permissions_to_mode(attributes->owner_permissions, attributes->group_permissions, attributes->other_permissions) | S_IWUSR;
+
+    if(ioctl == ISFS_IOCTL_CREATE_DIR) {
+        mode |= S_IXUSR | S_IXGRP | S_IXOTH;
+
+        if(mkdir(host_path, mode) < 0)
+            return fs_host_error(errno);
+    } else {
+        int fd = open(host_path, O_WRONLY | O_CREAT | O_EXCL, mode);
+        if(fd < 0)
+            return fs_host_error(errno);
+
+        close(fd);
+    }
+
+    // Not masked by the umask, so the permissions read back as given
+    chmod(host_path, mode);
+    return 0;
+}
+
+static int fs_host_get_attributes(const char* path, isfs_attributes_t* attributes) {
+    char host_path[512];
+
+    int ret = fs_host_path(host_path, sizeof(host_path), path);
+    if(ret < 0)
+        return ret;
+
+    struct stat st;
+    if(stat(host_path, &st) < 0)
+        return fs_host_error(errno);
+
+    memset(attributes, 0, sizeof(*attributes));
+    attributes->owner_id = FS_HOST_OWNER_ID;
+    attributes->group_id = FS_HOST_GROUP_ID;
+    snprintf(attributes->path, sizeof(attributes->path), "%s", path);
+    attributes->owner_permissions = fs_host_mode_to_permissions(st.st_mode >> 6);
+    attributes->group_permissions = fs_host_mode_to_permissions(st.st_mode >> 3);
+    attributes->other_permissions = fs_host_mode_to_permissions(st.st_mode);
+
+    return 0;
+}
+
+// opendir and readdir are replaced by the VFS's when fs_syscall_host.c
+// is linked in, so directories are read with getdents64 instead.
+typedef int (*fs_host_entry_handler_t)(const char* host_path, const char* name, void* params);
+
+// Calls the handler for each entry in a directory, until it returns non zero.
+// Returns that, or -1 with errno set.
+static int fs_host_for_each(const char* host_path, fs_host_entry_handler_t handler, void* params) {
+    int fd = open(host_path, O_RDONLY | O_DIRECTORY);
+    if(fd < 0)
+        return -1;
+
+    char buffer[4096] __attribute__((aligned(8)));
+    int ret = 0;
+
+    while(ret == 0) {
+        ssize_t size = getdents64(fd, buffer, sizeof(buffer));
+        if(size < 0) {
+            ret = -1;
+            break;
+        }
+
+        if(size == 0)
+            break;
+
+        for(ssize_t offset = 0; offset < size && ret == 0;) {
+            struct dirent64* entry = (struct dirent64*)(buffer + offset);
+            offset += entry->d_reclen;
+
+            if(strcmp(entry->d_name, ".") == 0 || strcmp(entry->d_name, "..") == 0)
+                continue;
+
+            ret = handler(host_path, entry->d_name, params);
+        }
+    }
+
+    int error = errno;
+    close(fd);
+    errno = error;
+
+    return ret;
+}
+
+typedef struct {
+    char* names;
+    uint32_t max_count;
+    uint32_t found;
+} fs_host_read_dir_t;
+
+static int fs_host_read_dir_entry(const char* host_path, const char* name, void* params) {
+    fs_host_read_dir_t* read_dir = (fs_host_read_dir_t*)params;
+
+    // Names ISFS could not hold are not part of it
+    size_t length = strlen(name);
+    if(length > ISFS_MAX_NAME)
+        return 0;
+
+    if(read_dir->names) {
+        if(read_dir->found >= read_dir->max_count)
+            return 1;
+
+        memcpy(read_dir->names, name, length + 1);
+        read_dir->names += length + 1;
+    }
+
+    read_dir->found++;
+    return 0;
+}
+
+static int fs_host_read_dir(const char* path, char* names, uint32_t max_count, uint32_t* count) {
+    char host_path[512];
+
+    int ret = fs_host_path(host_path, sizeof(host_path), path);
+    if(ret < 0)
+        return ret;
+
+    fs_host_read_dir_t read_dir = { .names = names, .max_count = max_count };
+
+    if(fs_host_for_each(host_path, fs_host_read_dir_entry, &read_dir) < 0)
+        return errno == ENOTDIR ? ISFS_ERROR_INVALID : fs_host_error(errno);
+
+    *count = read_dir.found;
+    return 0;
+}
+
+static int fs_host_delete_path(const char* host_path);
+
+static int fs_host_delete_entry(const char* host_path, const char* name, void* params) {
+    char child[512];
+    snprintf(child, sizeof(child), "%s/%s", host_path, name);
+
+    return fs_host_delete_path(child);
+}
+
+static int fs_host_delete_path(const char* host_path) {
+    struct stat st;
+    if(lstat(host_path, &st) < 0)
+        return fs_host_error(errno);
+
+    if(!S_ISDIR(st.st_mode)) {
+        if(unlink(host_path) < 0)
+            return fs_host_error(errno);
+
+        return 0;
+    }
+
+    // Directories go with everything in them
+    int ret = fs_host_for_each(host_path, fs_host_delete_entry, NULL);
+    if(ret == -1)
+        return fs_host_error(errno);
+    if(ret < 0)
+        return ret;
+
+    if(rmdir(host_path) < 0)
+        return fs_host_error(errno);
+
+    return 0;
+}
+
+static int fs_host_delete(const char* path) {
+    char host_path[512];
+
+    int ret = fs_host_path(host_path, sizeof(host_path), path);
+    if(ret < 0)
+        return ret;
+
+    if(strcmp(path, "/") == 0)
+        return ISFS_ERROR_ACCESS;
+
+    return fs_host_delete_path(host_path);
+}
+
+static int fs_host_rename(const char* old_path, const char* new_path) {
+    char old_host_path[512];
+    char new_host_path[512];
+
+    int ret = fs_host_path(old_host_path, sizeof(old_host_path), old_path);
+    if(ret < 0)
+        return ret;
+
+    ret = fs_host_path(new_host_path, sizeof(new_host_path), new_path);
+    if(ret < 0)
+        return ret;
+
+    struct stat st;
+    if(stat(old_host_path, &st) < 0)
+        return fs_host_error(errno);
+
+    if(stat(new_host_path, &st) == 0)
+        return ISFS_ERROR_EXISTS;
+
+    ret = fs_host_check_parent(new_host_path);
+    if(ret < 0)
+        return ret;
+
+    if(rename(old_host_path, new_host_path) < 0)
+        return fs_host_error(errno);
+
+    return 0;
+}
+
+typedef struct {
+    uint32_t clusters;
+    uint32_t inodes;
+} fs_host_usage_t;
+
+// Counts the clusters and inodes used in a directory
+static int fs_host_count_usage(const char* host_path, const char* name, void* params) {
+    fs_host_usage_t* usage = (fs_host_usage_t*)params;
+
+    char child[512];
+    snprintf(child, sizeof(child), "%s/%s", host_path, name);
+
+    struct stat st;
+    if(lstat(child, &st) < 0)
+        return 0;
+
+    usage->inodes++;
+
+    if(S_ISDIR(st.st_mode))
+        fs_host_for_each(child, fs_host_count_usage, usage);
+    else
+        usage->clusters += (st.st_size + FS_HOST_CLUSTER_SIZE - 1) / FS_HOST_CLUSTER_SIZE;
+
+    return 0;
+}
+
+static int fs_host_get_usage(const char* path, uint32_t* clusters, uint32_t* inodes) {
+    char host_path[512];
+
+    int ret = fs_host_path(host_path, sizeof(host_path), path);
+    if(ret < 0)
+        return ret;
+
+    struct stat st;
+    if(stat(host_path, &st) < 0)
+        return fs_host_error(errno);
+
+    if(!S_ISDIR(st.st_mode))
+        return ISFS_ERROR_INVALID;
+
+    fs_host_usage_t usage = { 0 };
+    fs_host_for_each(host_path, fs_host_count_usage, &usage);
+
+    *clusters = usage.clusters;
+    *inodes = usage.inodes;
+
+    return 0;
+}
+
+static int fs_host_get_stats(isfs_stats_t* stats) {
+    fs_host_usage_t usage = { 0 };
+    fs_host_for_each(host_get_env(HOST_ENV_NAND, HOST_DEFAULT_NAND), fs_host_count_usage, &usage);
+
+    memset(stats, 0, sizeof(*stats));
+    stats->cluster_size = FS_HOST_CLUSTER_SIZE;
+    stats->used_clusters = usage.clusters;
+    stats->free_clusters = usage.clusters < FS_HOST_CLUSTER_COUNT ? FS_HOST_CLUSTER_COUNT - usage.clusters : 0;
+    stats->used_inodes = usage.inodes;
+    stats->free_inodes = usage.inodes < FS_HOST_INODE_COUNT ? FS_HOST_INODE_COUNT - usage.inodes : 0;
+
+    return 0;
+}
+
+// Paths are always 64 bytes, but may not be terminated
+static bool fs_host_get_path(char* path, const void* buffer, int size) {
+    if(buffer == NULL || size < ISFS_MAX_PATH)
+        return false;
+
+    memcpy(path, buffer, ISFS_MAX_PATH);
+    path[ISFS_MAX_PATH - 1] = '\0';
+    return true;
+}
+
+static int fs_host_ioctl(int ioctl, void* buffer_in, int size_in, void* buffer_io, int size_io) {
+    char path[ISFS_MAX_PATH];
+
+    switch(ioctl) {
+        case ISFS_IOCTL_GET_STATS:
+            if(buffer_io == NULL || size_io < sizeof(isfs_stats_t))
+                return ISFS_ERROR_INVALID;
+
+            return fs_host_get_stats((isfs_stats_t*)buffer_io);
+        case ISFS_IOCTL_CREATE_DIR:
+        case ISFS_IOCTL_CREATE_FILE:
+            if(buffer_in == NULL || size_in < sizeof(isfs_attributes_t))
+                return ISFS_ERROR_INVALID;
+
+            return fs_host_create(ioctl, (const isfs_attributes_t*)buffer_in);
+        case ISFS_IOCTL_GET_ATTRIBUTES:
+            if(!fs_host_get_path(path, buffer_in, size_in) || buffer_io == NULL || size_io < sizeof(isfs_attributes_t))
+                return ISFS_ERROR_INVALID;
+
+            return fs_host_get_attributes(path, (isfs_attributes_t*)buffer_io);
+        case ISFS_IOCTL_DELETE:
+            if(!fs_host_get_path(path, buffer_in, size_in))
+                return ISFS_ERROR_INVALID;
+
+            return fs_host_delete(path);
+        case ISFS_IOCTL_RENAME: {
+            char new_path[ISFS_MAX_PATH];
+            if(!fs_host_get_path(path, buffer_in, size_in) || !fs_host_get_path(new_path, (char*)buffer_in + ISFS_MAX_PATH, size_in - ISFS_MAX_PATH))
+                return ISFS_ERROR_INVALID;
+
+            return fs_host_rename(path, new_path);
+        }
+        default:
+            return ISFS_ERROR_INVALID;
+    }
+}
+
+static int fs_host_ioctlv(int ioctl, int argcin, int argcio, ios_ioctlv_t* argv) {
+    char path[ISFS_MAX_PATH];
+
+    switch(ioctl) {
+        case ISFS_IOCTL_READ_DIR:
+            if(argcin < 1 || !fs_host_get_path(path, argv[0].data, argv[0].size))
+                return ISFS_ERROR_INVALID;
+
+            // Count only
+            if(argcin == 1 && argcio == 1) {
+                if(argv[1].size < sizeof(uint32_t))
+                    return ISFS_ERROR_INVALID;
+
+                return fs_host_read_dir(path, NULL, 0, (uint32_t*)argv[1].data);
+            }
+
+            if(argcin != 2 || argcio != 2 || argv[1].size < sizeof(uint32_t) || argv[3].size < sizeof(uint32_t))
+                return ISFS_ERROR_INVALID;
+
+            uint32_t max_count = *(uint32_t*)argv[1].data;
+            if(argv[2].size < max_count * (ISFS_MAX_NAME + 1))
+                return ISFS_ERROR_INVALID;
+
+            return fs_host_read_dir(path, argv[2].data, max_count, (uint32_t*)argv[3].data);
+        case ISFS_IOCTL_GET_USAGE:
+            if(argcin != 1 || argcio != 2 || !fs_host_get_path(path, argv[0].data, argv[0].size)
+                    || argv[1].size < sizeof(uint32_t) || argv[2].size < sizeof(uint32_t))
+                return ISFS_ERROR_INVALID;
+
+            return fs_host_get_usage(path, (uint32_t*)argv[1].data, (uint32_t*)argv[2].data);
+        default:
+            return ISFS_ERROR_INVALID;
+    }
+}
+
+int host_fs_file_ioctl(int fd, int ioctl, void* buffer_in, int size_in, void* buffer_io, int size_io) {
+    if(ioctl != ISFS_IOCTL_GET_FILE_STATS || buffer_io == NULL || size_io < sizeof(isfs_file_stats_t))
+        return ISFS_ERROR_INVALID;
+
+    struct stat st;
+    if(fstat(fd, &st) < 0)
+        return fs_host_error(errno);
+
+    off_t position = lseek(fd, 0, SEEK_CUR);
+    if(position < 0)
+        return fs_host_error(errno);
+
+    isfs_file_stats_t* stats = (isfs_file_stats_t*)buffer_io;
+    stats->length = st.st_size;
+    stats->position = position;
+
+    return 0;
+}
+
+const host_ios_device_t host_fs_device = {
+    .path = "/dev/fs",
+    .ioctl = fs_host_ioctl,
+    .ioctlv = fs_host_ioctlv,
+};
@@ -0,0 +1,170 @@
+/**
+ * @file host.h
+ * @brief Host simulation backend.
+ *
+ * Used when PowerBlocks is built for Linux instead of the Wii.
+ * The hardware facing parts of the core are replaced by host
+ * implementations, so apps can run as plain Linux processes.
+ *
+ * Where the simulated hardware keeps its data is set
+ * with the environment variables below.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include <stdint.h>
+#include <stddef.h>
+
+#include "powerblocks/core/system/exceptions.h"
+#include "powerblocks/core/ios/ios.h"
+#include "powerblocks/core/graphics/framebuffer.h"
+
+/** @def HOST_ENV_NAND
+ *  @brief Directory IOS files are mapped into.
+ *
+ *  The IOS path "/shared2/sys/SYSCONF" is read from "<dir>/shared2/sys/SYSCONF".
+ */
+#define HOST_ENV_NAND         "POWERBLOCKS_HOST_NAND"
+#define HOST_DEFAULT_NAND     "nand"
+
+/** @def HOST_ENV_SD
+ *  @brief Disk image used as the SD card.
+ *
+ *  A raw image holding a FAT file system. No card is inserted if it does not exist.
+ */
+#define HOST_ENV_SD           "POWERBLOCKS_HOST_SD"
+#define HOST_DEFAULT_SD       "sd.img"
+
+/** @def HOST_ENV_USB
+ *  @brief Disk image used as the USB drive.
+ *
+ *  A raw image holding a FAT file system. No drive is plugged in if it does not exist.
+ */
+#define HOST_ENV_USB          "POWERBLOCKS_HOST_USB"
+#define HOST_DEFAULT_USB      "usb.img"
+
+/** @def HOST_ENV_FRAMES
+ *  @brief Directory the framebuffer is dumped into on each vsync.
+ *
+ *  Frames are not dumped if it is set to an empty string.
+ */
+#define HOST_ENV_FRAMES       "POWERBLOCKS_HOST_FRAMES"
+#define HOST_DEFAULT_FRAMES   "frames"
+
+/** @def HOST_ENV_FRAME_LIMIT
+ *  @brief Exits the process after this many vsyncs.
+ *
+ *  Lets apps that never return from main run headlessly.
+ *  Runs forever if not set.
+ */
+#define HOST_ENV_FRAME_LIMIT  "POWERBLOCKS_HOST_FRAME_LIMIT"
+
+/** @def HOST_ENV_BOOT_PATH
+ *  @brief Command line the app was launched with, like "sd:/apps/MyApp/boot.elf".
+ *
+ *  Used by system_get_boot_path.
+ */
+#define HOST_ENV_BOOT_PATH    "POWERBLOCKS_HOST_BOOT_PATH"
+
+/** @def HOST_ENV_WIIMOTE_SCRIPT
+ *  @brief Script of the wiimote input.
+ *
+ *  No wiimotes are connected if it is not set.
+ */
+#define HOST_ENV_WIIMOTE_SCRIPT "POWERBLOCKS_HOST_WIIMOTE_SCRIPT"
+
+/** @def HOST_ENV_AUDIO
+ *  @brief WAV file the audio output is recorded to.
+ *
+ *  Audio is thrown away if it is not set.
+ */
+#define HOST_ENV_AUDIO        "POWERBLOCKS_HOST_AUDIO"
+
+/**
+ * @struct host_ios_device_t
+ * @brief A simulated IOS device, like /dev/sdio/slot0.
+ *
+ * Handlers return IOS error codes. Missing handlers
+ * return IOS_HOST_ERROR_INVALID.
+ */
+typedef struct {
+    const char* path;
+
+    int (*open)(void);
+    int (*close)(void);
+    int (*ioctl)(int ioctl, void* buffer_in, int size_in, void* buffer_io, int size_io);
+    int (*ioctlv)(int ioctl, int argcin, int argcio, ios_ioctlv_t* argv);
+} host_ios_device_t;
+
+// Error codes returned by the simulated IOS
+#define IOS_HOST_ERROR_ACCESS      -102
+#define IOS_HOST_ERROR_NOT_FOUND   -106
+#define IOS_HOST_ERROR_NO_DEVICE   -6
+#define IOS_HOST_ERROR_INVALID     -4
+
+extern const host_ios_device_t host_sdio_device;
+extern const host_ios_device_t host_usb_device;
+extern const host_ios_device_t host_usb_drive_device;
+extern const host_ios_device_t host_fs_device;
+
+/**
+ * @brief Runs an IOCTL on a file opened from the NAND.
+ *
+ * ISFS gets the length and position of opened files this way.
+ *
+ * @return The IOS return value of the request.
+ */
+extern int host_fs_file_ioctl(int fd, int ioctl, void* buffer_in, int size_in, void* buffer_io, int size_io);
+
+/**
+ * @brief Gets an environment variable, or a default.
+ */
+extern const char* host_get_env(const char* name, const char* fallback);
+
+/**
+ * @brief Writes all of a buffer to a host file descriptor.
+ *
+ * Retries writes interrupted by the tick.
+ *
+ * @return Negative if error.
+ */
+extern int host_write_all(int fd, const void* data, size_t size);
+
+/**
+ * @brief Runs an IPC message against the simulated IOS.
+ *
+ * Called from ipc_request.
+ *
+ * @return The IOS return value of the request.
+ */
+extern int host_ios_execute(ipc_message* message);
+
+/**
+ * @brief Calls the handler of an IRQ as if it was raised.
+ *
+ * Runs the handler with interrupts disabled, then yields
+ * if it woke a task, like the exception handler on the Wii.
+ *
+ * Call from a FreeRTOS task.
+ */
+extern void host_raise_irq(exception_irq_type_t type);
+
+/**
+ * @brief Sets up stdout to print to the console and the terminal.
+ *
+ * Called before system_initialize.
+ */
+extern void host_libcio_initialize();
+
+/**
+ * @brief Writes a framebuffer out as a PPM image.
+ *
+ * @param path File to write.
+ * @param framebuffer Framebuffer to convert.
+ * @return Negative if error.
+ */
+extern int host_framebuffer_dump(const char* path, const framebuffer_t* framebuffer);
@@ -0,0 +1,340 @@
+/**
+ * @file ios_host.c
+ * @brief Input Output System on the host.
+ *
+ * Runs IPC messages against a simulated IOS.
+ *
+ * Paths under /dev/ open simulated devices, /dev/fs browsing the NAND directory.
+ * Every other path is a file in the NAND directory, set with
+ * POWERBLOCKS_HOST_NAND, so "/shared2/sys/SYSCONF" is "nand/shared2/sys/SYSCONF".
+ *
+ * The system settings files are made up when they are
+ * not in the NAND directory, so apps start without a NAND dump.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#define _GNU_SOURCE
+
+#include "host.h"
+
+#include <errno.h>
+#include <fcntl.h>
+#include <stdbool.h>
+#include <stdio.h>
+#include <string.h>
+#include <sys/mman.h>
+#include <sys/stat.h>
+#include <unistd.h>
+
+#define IOS_COMMAND_OPEN   1
+#define IOS_COMMAND_CLOSE  2
+#define IOS_COMMAND_READ   3
+#define IOS_COMMAND_WRITE  4
+#define IOS_COMMAND_SEEK   5
+#define IOS_COMMAND_IOCTL  6
+#define IOS_COMMAND_IOCTLV 7
+
+// Handles open at once
+#define IOS_HOST_MAX_FILES 32
+
+#define IOS_HOST_SETTINGS_PATH "/title/00000001/00000002/data/setting.txt"
+#define IOS_HOST_CONFIG_PATH   "/shared2/sys/SYSCONF"
+
+#define IOS_HOST_SETTINGS_LENGTH 256
+#define IOS_HOST_CONFIG_LENGTH   0x4000
+
+// Made up settings of an NTSC console. Missing lines read as NULL.
+static const char* IOS_HOST_SETTINGS =
+    "AREA=USA\r\n"
+    "MODEL=RVL-001(USA)\r\n"
+    "DVD=0\r\n"
+    "MPCH=0x7FFE\r\n"
+    "CODE=LU\r\n"
+    "SERNO=000000000\r\n"
+    "VIDEO=NTSC\r\n"
+    "GAME=US\r\n";
+
+// BOOL entries of the made up config, all false.
+static const char* IOS_HOST_CONFIG_BOOLS[] = {
+    "IPL.PGS", // Progressive scan
+    "IPL.E60", // EuRGB60
+};
+
+typedef struct {
+    bool used;
+
+    // Host file, or -1 for a device
+    int fd;
+    const host_ios_device_t* device;
+} host_ios_file_t;
+
+static host_ios_file_t host_ios_files[IOS_HOST_MAX_FILES];
+
+static const host_ios_device_t* const host_ios_devices[] = {
+    &host_sdio_device,
+    &host_usb_device,
+    &host_usb_drive_device,
+    &host_fs_device,
+};
+
+static int host_ios_error(int error) {
+    switch(error) {
+        case ENOENT:
+        case ENOTDIR:
+            return IOS_HOST_ERROR_NOT_FOUND;
+        case EACCES:
+        case EPERM:
+        case EROFS:
+            return IOS_HOST_ERROR_ACCESS;
+        default:
+            return IOS_HOST_ERROR_INVALID;
+    }
+}
+
+static host_ios_file_t* host_ios_get_file(int file_handle) {
+    if(file_handle < 0 || file_handle >= IOS_HOST_MAX_FILES || !host_ios_files[file_handle].used)
+        return NULL;
+
+    return &host_ios_files[file_handle];
+}
+
+static int host_ios_allocate_file(int fd, const host_ios_device_t* device) {
+    for(int i = 0; i < IOS_HOST_MAX_FILES; i++) {
+        if(!host_ios_files[i].used) {
+            host_ios_files[i].used = true;
+            host_ios_files[i].fd = fd;
+            host_ios_files[i].device = device;
+            return i;
+        }
+    }
+
+    return IOS_HOST_ERROR_INVALID;
+}
+
+// Puts data in a file that only lives in memory.
+static int host_ios_memory_file(const char* name, const void* data, size_t size) {
+    int fd = memfd_create(name, 0);
+    if(fd < 0)
+        return -1;
+
+    if(host_write_all(fd, data, size) < 0) {
+        close(fd);
+        return -1;
+    }
+
+    lseek(fd, 0, SEEK_SET);
+    return fd;
+}
+
+static int host_ios_default_settings() {
+    uint8_t data[IOS_HOST_SETTINGS_LENGTH];
+    memset(data, 0, sizeof(data));
+    memcpy(data, IOS_HOST_SETTINGS, strlen(IOS_HOST_SETTINGS));
+
+    // Encrypt the whole file, the same way ios_settings.c decrypts it.
+    uint32_t key = 0x73B5DBFA;
+    for(int i = 0; i < IOS_HOST_SETTINGS_LENGTH; i++) {
+        data[i] = data[i] ^ key;
+        key = (key << 1) | (key >> 31);
+    }
+
+    return host_ios_memory_file("setting.txt", data, sizeof(data));
+}
+
+static int host_ios_default_config() {
+    static uint8_t data[IOS_HOST_CONFIG_LENGTH];
+    memset(data, 0, sizeof(data));
+
+    const int count = sizeof(IOS_HOST_CONFIG_BOOLS) / sizeof(IOS_HOST_CONFIG_BOOLS[0]);
+
+    // Header, then the big endian entry count and offsets.
+    memcpy(data, "SCv0", 4);
+    data[4] = count >> 8;
+    data[5] = count & 0xFF;
+
+    int offset = 6 + (count + 1) * 2;
+    for(int i = 0; i < count; i++) {
+        const char* key = IOS_HOST_CONFIG_BOOLS[i];
+        int key_length = strlen(key);
+
+        data[6 + i * 2] = offset >> 8;
+        data[7 + i * 2] = offset & 0xFF;
+
+        data[offset] = (7 << 5) | (key_length - 1); // BOOL
+        memcpy(data + offset + 1, key, key_length);
+        data[offset + 1 + key_length] = 0;
+
+        offset += 1 + key_length + 1;
+    }
+
+    // The last offset points at the end of the entries
+    data[6 + count * 2] = offset >> 8;
+    data[7 + count * 2] = offset & 0xFF;
+
+    return host_ios_memory_file("SYSCONF", data, sizeof(data));
+}
+
+static int host_ios_open(const char* path, int mode) {
+    if(path == NULL)
+        return IOS_HOST_ERROR_INVALID;
+
+    // Devices
+    if(strncmp(path, "/dev/", 5) == 0) {
+        for(size_t i = 0; i < sizeof(host_ios_devices) / sizeof(host_ios_devices[0]); i++) {
+            const host_ios_device_t* device = host_ios_devices[i];
+            if(strcmp(device->path, path) != 0)
+                continue;
+
+            if(device->open) {
+                int ret = device->open();
+                if(ret < 0)
+                    return ret;
+            }
+
+            return host_ios_allocate_file(-1, device);
+        }
+
+        return IOS_HOST_ERROR_NO_DEVICE;
+    }
+
+    // Files in the NAND
+    int flags;
+    switch(mode & (IOS_MODE_READ | IOS_MODE_WRITE)) {
+        case IOS_MODE_READ:
+            flags = O_RDONLY;
+            break;
+        case IOS_MODE_WRITE:
+            flags = O_WRONLY;
+            break;
+        case IOS_MODE_READ | IOS_MODE_WRITE:
+            flags = O_RDWR;
+            break;
+        default:
+            return IOS_HOST_ERROR_INVALID;
+    }
+
+    char host_path[512];
+    snprintf(host_path, sizeof(host_path), "%s%s", host_get_env(HOST_ENV_NAND, HOST_DEFAULT_NAND), path);
+
+    int fd = open(host_path, flags);
+    if(fd < 0 && errno == ENOENT && flags == O_RDONLY) {
+        if(strcmp(path, IOS_HOST_SETTINGS_PATH) == 0) {
+            fd = host_ios_default_settings();
+        } else if(strcmp(path, IOS_HOST_CONFIG_PATH) == 0) {
+            fd = host_ios_default_config();
+        } else {
+            errno = ENOENT;
+        }
+    }
+
+    if(fd < 0)
+        return host_ios_error(errno);
+
+    // IOS only opens files
+    struct stat st;
+    if(fstat(fd, &st) == 0 && S_ISDIR(st.st_mode)) {
+        close(fd);
+        return IOS_HOST_ERROR_ACCESS;
+    }
+
+    int file_handle = host_ios_allocate_file(fd, NULL);
+    if(file_handle < 0)
+        close(fd);
+
+    return file_handle;
+}
+
+static int host_ios_close(host_ios_file_t* file) {
+    int ret = 0;
+
+    if(file->device) {
+        if(file->device->close)
+            ret = file->device->close();
+    } else {
+        close(file->fd);
+    }
+
+    file->used = false;
+    return ret;
+}
+
+static int host_ios_seek(host_ios_file_t* file, int where, int whence) {
+    int host_whence;
+    switch(whence) {
+        case 0:
+            host_whence = SEEK_SET;
+            break;
+        case 1:
+            host_whence = SEEK_CUR;
+            break;
+        case 2:
+            host_whence = SEEK_END;
+            break;
+        default:
+            return IOS_HOST_ERROR_INVALID;
+    }
+
+    off_t position = lseek(file->fd, where, host_whence);
+    if(position < 0)
+        return host_ios_error(errno);
+
+    return position;
+}
+
+int host_ios_execute(ipc_message* message) {
+    if(message->command == IOS_COMMAND_OPEN)
+        return host_ios_open(message->open.path, message->open.mode);
+
+    host_ios_file_t* file = host_ios_get_file(message->file_handle);
+    if(file == NULL)
+        return IOS_HOST_ERROR_INVALID;
+
+    const host_ios_device_t* device = file->device;
+    ssize_t ret;
+
+    switch(message->command) {
+        case IOS_COMMAND_CLOSE:
+            return host_ios_close(file);
+        case IOS_COMMAND_READ:
+            if(device)
+                return IOS_HOST_ERROR_INVALID;
+
+            ret = read(file->fd, message->read.address, message->read.size);
+            return ret < 0 ? host_ios_error(errno) : ret;
+        case IOS_COMMAND_WRITE:
+            if(device)
+                return IOS_HOST_ERROR_INVALID;
+
+            ret = write(file->fd, message->write.address, message->write.size);
+            return ret < 0 ? host_ios_error(errno) : ret;
+        case IOS_COMMAND_SEEK:
+            if(device)
+                return IOS_HOST_ERROR_INVALID;
+
+            return host_ios_seek(file, message->seek.where, message->seek.whence);
+        case IOS_COMMAND_IOCTL:
+            if(device == NULL)
+                return host_fs_file_ioctl(file->fd, message->ioctl.ioctl,
+                    message->ioctl.address_in, message->ioctl.size_in,
+                    message->ioctl.address_io, message->ioctl.size_io);
+
+            if(device->ioctl == NULL)
+                return IOS_HOST_ERROR_INVALID;
+
+            return device->ioctl(message->ioctl.ioctl,
+                message->ioctl.address_in, message->ioctl.size_in,
+                message->ioctl.address_io, message->ioctl.size_io);
+        case IOS_COMMAND_IOCTLV:
+            if(device == NULL || device->ioctlv == NULL)
+                return IOS_HOST_ERROR_INVALID;
+
+            return device->ioctlv(message->ioctlv.ioctl,
+                message->ioctlv.argcin, message->ioctlv.argcio,
+                (ios_ioctlv_t*)message->ioctlv.pairs);
+        default:
+            return IOS_HOST_ERROR_INVALID;
+    }
+}
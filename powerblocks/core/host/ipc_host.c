@@ -0,0 +1,61 @@
+/**
+ * @file ipc_host.c
+ * @brief Inter Processor Communications on the host.
+ *
+ * There is no Starlet on the host, so requests are
+ * run right away against the simulated IOS in ios_host.c.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "host.h"
+
+#include "system/system.h"
+#include "system/ipc.h"
+
+#include "FreeRTOS.h"
+#include "semphr.h"
+#include "task.h"
+
+#include "utils/log.h"
+
+static const char* TAB = "IPC";
+
+static StaticSemaphore_t ipc_semaphore_static;
+
+// Taken while a request runs, as only one runs at a time on the Wii too.
+static SemaphoreHandle_t ipc_semaphore_busy;
+
+void ipc_initialize() {
+    ipc_semaphore_busy = xSemaphoreCreateMutexStatic(&ipc_semaphore_static);
+
+    LOG_INFO(TAB, "IPC initialized.");
+}
+
+int ipc_request(ipc_message* message, ipc_async_handler_t handler, void* params) {
+    message->response_handler = handler;
+    message->params = params;
+
+    xSemaphoreTake(ipc_semaphore_busy, portMAX_DELAY);
+
+    // The tick would interrupt the host's system calls, so run it like an ISR.
+    uint32_t irq_enabled;
+    SYSTEM_DISABLE_ISR(irq_enabled);
+
+    exception_isr_context_switch_needed = 0;
+    message->returned = host_ios_execute(message);
+    message->response_handler(message->params, message->returned);
+    long switch_needed = exception_isr_context_switch_needed;
+
+    SYSTEM_ENABLE_ISR(irq_enabled);
+
+    xSemaphoreGive(ipc_semaphore_busy);
+
+    if(switch_needed != 0) {
+        taskYIELD();
+    }
+
+    return 0;
+}
@@ -0,0 +1,56 @@
+/**
+ * @file libcio_host.c
+ * @brief Adds standard IO to libc on the host.
+ *
+ * Replaces glibc's stdout with one that prints to the console,
+ * like libcio.c does on the Wii, and to the terminal.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#define _GNU_SOURCE
+
+#include "host.h"
+
+#include "utils/console.h"
+
+#include <stdio.h>
+#include <string.h>
+#include <unistd.h>
+
+static ssize_t libc_write(void* cookie, const char* data, size_t size) {
+    // The console wants zero terminated strings
+    char buffer[128];
+    size_t done = 0;
+    while(done < size) {
+        size_t chunk = size - done;
+        if(chunk > sizeof(buffer) - 1)
+            chunk = sizeof(buffer) - 1;
+
+        memcpy(buffer, data + done, chunk);
+        buffer[chunk] = 0;
+        console_put(buffer);
+
+        done += chunk;
+    }
+
+    host_write_all(STDOUT_FILENO, data, size);
+
+    return size;
+}
+
+void host_libcio_initialize() {
+    cookie_io_functions_t functions = {
+        .write = libc_write,
+    };
+
+    FILE* file = fopencookie(NULL, "w", functions);
+    if(file == NULL)
+        return;
+
+    // Unbuffered, so the console is drawn as it is printed, like on the Wii.
+    setvbuf(file, NULL, _IONBF, 0);
+    stdout = file;
+}
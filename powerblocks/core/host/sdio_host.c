@@ -0,0 +1,168 @@
+/**
+ * @file sdio_host.c
+ * @brief IOS SDIO Interface on the host.
+ *
+ * Simulates /dev/sdio/slot0 with a SDHC card backed
+ * by a disk image, set with POWERBLOCKS_HOST_SD.
+ *
+ * Only what sd.c uses is simulated. Commands without data succeed,
+ * and CMD18 and CMD25 read and write the image.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "host.h"
+
+#include "ios/sdio.h"
+
+#include <errno.h>
+#include <stdbool.h>
+#include <fcntl.h>
+#include <string.h>
+#include <unistd.h>
+
+#define SDIO_IOCTL_SET_HC_REGISTER   0x01
+#define SDIO_IOCTL_READ_HC_REGISTER  0x02
+#define SDIO_IOCTL_RESET_DEVICE      0x04
+#define SDIO_IOCTL_SET_CLOCK         0x06
+#define SDIO_IOCTL_SEND_CMD          0x07
+#define SDIO_IOCTL_GET_DEVICE_STATUS 0x0B
+#define SDIO_IOCTL_READ_OC_REGISTER  0x0C
+
+#define SDIO_HOST_SECTOR_SIZE 512
+
+// Relative card address given on reset, in the upper half like on hardware.
+#define SDIO_HOST_RCA 0x00010000
+
+// Card powered up, and is a SDHC card.
+#define SDIO_HOST_OCR ((1u<<31) | (1u<<30))
+
+static struct {
+    int image;
+    bool write_protect;
+} sdio_host_state = { .image = -1 };
+
+static int sdio_host_open() {
+    const char* path = host_get_env(HOST_ENV_SD, HOST_DEFAULT_SD);
+
+    sdio_host_state.write_protect = false;
+    sdio_host_state.image = open(path, O_RDWR);
+
+    // Read only images act like the write protect switch is on.
+    if(sdio_host_state.image < 0 && (errno == EACCES || errno == EROFS)) {
+        sdio_host_state.write_protect = true;
+        sdio_host_state.image = open(path, O_RDONLY);
+    }
+
+    // A missing image is a empty slot, the device still opens.
+    return 0;
+}
+
+static int sdio_host_close() {
+    if(sdio_host_state.image >= 0) {
+        close(sdio_host_state.image);
+        sdio_host_state.image = -1;
+    }
+
+    return 0;
+}
+
+static void sdio_host_set_io(void* buffer_io, int size_io, uint32_t value) {
+    if(buffer_io != NULL && size_io >= (int)sizeof(value))
+        memcpy(buffer_io, &value, sizeof(value));
+}
+
+static int sdio_host_ioctl(int ioctl, void* buffer_in, int size_in, void* buffer_io, int size_io) {
+    switch(ioctl) {
+        case SDIO_IOCTL_GET_DEVICE_STATUS: {
+            uint32_t status = SDIO_DEVICE_STATUS_NOT_INSERTED;
+            if(sdio_host_state.image >= 0) {
+                status = SDIO_DEVICE_STATUS_CARD_INSERTED | SDIO_DEVICE_STATUS_SD_INITIALIZED | SDIO_DEVICE_STATUS_IS_SDHC;
+                if(sdio_host_state.write_protect)
+                    status |= SDIO_DEVICE_STATUS_WRITE_PROTECT_SWITCH;
+            }
+
+            sdio_host_set_io(buffer_io, size_io, status);
+            return 0;
+        }
+        case SDIO_IOCTL_RESET_DEVICE:
+            if(sdio_host_state.image < 0)
+                return IOS_HOST_ERROR_INVALID;
+
+            sdio_host_set_io(buffer_io, size_io, SDIO_HOST_RCA);
+            return 0;
+        case SDIO_IOCTL_READ_OC_REGISTER:
+            sdio_host_set_io(buffer_io, size_io, sdio_host_state.image >= 0 ? SDIO_HOST_OCR : 0);
+            return 0;
+        case SDIO_IOCTL_READ_HC_REGISTER:
+            sdio_host_set_io(buffer_io, size_io, 0);
+            return 0;
+        case SDIO_IOCTL_SET_HC_REGISTER:
+        case SDIO_IOCTL_SET_CLOCK:
+            return 0;
+        case SDIO_IOCTL_SEND_CMD:
+            // Commands without data only move the card between states
+            if(buffer_io != NULL)
+                memset(buffer_io, 0, size_io);
+
+            return sdio_host_state.image >= 0 ? 0 : IOS_HOST_ERROR_INVALID;
+        default:
+            return IOS_HOST_ERROR_INVALID;
+    }
+}
+
+static int sdio_host_ioctlv(int ioctl, int argcin, int argcio, ios_ioctlv_t* argv) {
+    if(ioctl != SDIO_IOCTL_SEND_CMD || argcin != 2 || argcio != 1)
+        return IOS_HOST_ERROR_INVALID;
+
+    if(sdio_host_state.image < 0)
+        return IOS_HOST_ERROR_INVALID;
+
+    // Same layout sdio_send_cmd fills in
+    const uint32_t* input = (const uint32_t*)argv[0].data;
+    uint32_t cmd = input[0];
+    uint32_t arg = input[3];
+    uint32_t block_count = input[4];
+    uint32_t sector_size = input[5];
+
+    uint8_t* data = (uint8_t*)argv[1].data;
+    size_t size = (size_t)block_count * sector_size;
+    if(size > argv[1].size)
+        return IOS_HOST_ERROR_INVALID;
+
+    // SDHC cards are addressed by sector
+    off_t offset = (off_t)arg * SDIO_HOST_SECTOR_SIZE;
+
+    memset(argv[2].data, 0, argv[2].size);
+
+    ssize_t done;
+    switch(cmd) {
+        case SD_CMD18_READ_MULTIPLE_BLOCK:
+            done = pread(sdio_host_state.image, data, size, offset);
+            break;
+        case SD_CMD25_WRITE_MULTIPLE_BLOCK:
+            if(sdio_host_state.write_protect)
+                return IOS_HOST_ERROR_ACCESS;
+
+            done = pwrite(sdio_host_state.image, data, size, offset);
+            break;
+        default:
+            return IOS_HOST_ERROR_INVALID;
+    }
+
+    if(done != (ssize_t)size)
+        return IOS_HOST_ERROR_INVALID;
+
+    return 0;
+}
+
+const host_ios_device_t host_sdio_device = {
+    .path = "/dev/sdio/slot0",
+
+    .open = sdio_host_open,
+    .close = sdio_host_close,
+    .ioctl = sdio_host_ioctl,
+    .ioctlv = sdio_host_ioctlv,
+};
@@ -0,0 +1,53 @@
+/**
+ * @file start_host.c
+ * @brief Starts the system on the host.
+ *
+ * Stands in for start.S. Fills in the arguments
+ * and starts the system before the app's main is called.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "host.h"
+
+#include "system/system.h"
+
+#include <stdlib.h>
+#include <string.h>
+
+system_argv_t system_argv;
+
+const char* host_get_env(const char* name, const char* fallback) {
+    const char* value = getenv(name);
+    if(value == NULL)
+        return fallback;
+
+    return value;
+}
+
+// On the Wii start.S calls system_initialize, which runs main as a task
+// and never returns. Here the C runtime calls main itself, so start
+// the system from a constructor instead, before main is reached.
+//
+// glibc runs constructors in link order, so this runs after the constructors
+// of the app, as the core library is linked after it.
+__attribute__((constructor))
+static void host_start(int argc, char** argv, char** envp) {
+    const char* command_line = getenv(HOST_ENV_BOOT_PATH);
+
+    system_argv.magic = 0x5f617267;
+    system_argv.command_line = command_line;
+    system_argv.command_line_length = command_line ? strlen(command_line) : 0;
+    system_argv.argc = argc;
+    system_argv.argv = argv;
+    system_argv.end_argv = argv + argc;
+
+    host_libcio_initialize();
+
+    system_initialize();
+
+    // We do not expect execution to return here.
+    exit(EXIT_FAILURE);
+}
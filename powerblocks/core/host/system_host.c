@@ -0,0 +1,103 @@
+/**
+ * @file system_host.c
+ * @brief Access parts of the base system on the host.
+ *
+ * Host versions of the parts of the system written
+ * in assembly on the Wii, and of the syscalls.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "host.h"
+
+#include "system/system.h"
+#include "system/syscall.h"
+#include "utils/crash_handler.h"
+
+#include "FreeRTOS.h"
+#include "task.h"
+
+#include <errno.h>
+#include <pthread.h>
+#include <signal.h>
+#include <stdio.h>
+#include <time.h>
+#include <unistd.h>
+
+uint64_t system_get_time_base_int() {
+    // Like the time base, the monotonic clock counts from when the machine started.
+    struct timespec now;
+    clock_gettime(CLOCK_MONOTONIC, &now);
+
+    return (uint64_t)now.tv_sec * SYSTEM_TB_CLOCK_HZ + (uint64_t)now.tv_nsec * SYSTEM_TB_CLOCK_HZ / 1000000000;
+}
+
+// There is no cache to keep in sync with hardware on the host.
+void system_flush_dcache(const void* data, uint32_t size) {
+}
+
+void system_invalidate_dcache(void* data, uint32_t size) {
+}
+
+void system_invalidate_icache(void* data, uint32_t size) {
+}
+
+uint32_t system_host_disable_isr() {
+    sigset_t tick, old;
+    sigemptyset(&tick);
+    sigaddset(&tick, SIGALRM);
+
+    pthread_sigmask(SIG_BLOCK, &tick, &old);
+
+    return !sigismember(&old, SIGALRM);
+}
+
+void system_host_enable_isr() {
+    sigset_t tick;
+    sigemptyset(&tick);
+    sigaddset(&tick, SIGALRM);
+
+    pthread_sigmask(SIG_UNBLOCK, &tick, NULL);
+}
+
+int host_write_all(int fd, const void* data, size_t size) {
+    const uint8_t* bytes = (const uint8_t*)data;
+
+    while(size > 0) {
+        ssize_t written = write(fd, bytes, size);
+        if(written < 0) {
+            // The tick interrupts system calls, so just try again.
+            if(errno == EINTR)
+                continue;
+
+            return -1;
+        }
+
+        bytes += written;
+        size -= written;
+    }
+
+    return 0;
+}
+
+uintptr_t syscall_host(uint32_t num, uintptr_t arg1, uintptr_t arg2, uintptr_t arg3) {
+    switch(num) {
+        case SYSCALL_ID_YIELD:
+            vPortYield();
+            return 0;
+        case SYSCALL_ID_ASSERT:
+        case SYSCALL_ID_OUT_OF_MEMORY: {
+            char msg[256];
+            snprintf(msg, sizeof(msg), "%s: %s:%d", (const char*)arg1, (const char*)arg3, (int)arg2);
+            crash_handler_bug_check(msg, NULL);
+            return 0;
+        }
+        case SYSCALL_ID_BUG_CHECK:
+            crash_handler_bug_check((const char*)arg1, NULL);
+            return 0;
+        default:
+            return 0;
+    }
+}
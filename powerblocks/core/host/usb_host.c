@@ -0,0 +1,357 @@
+/**
+ * @file usb_host.c
+ * @brief IOS USB Interface on the host.
+ *
+ * Simulates /dev/usb/oh0 with one USB drive plugged in,
+ * backed by a disk image set with POWERBLOCKS_HOST_USB.
+ *
+ * Only what usb_storage.c uses is simulated. The drive speaks the
+ * bulk only transport, with each phase done in one bulk message.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "host.h"
+
+#include "ios/usb.h"
+
+#include <errno.h>
+#include <stdbool.h>
+#include <fcntl.h>
+#include <string.h>
+#include <sys/stat.h>
+#include <unistd.h>
+
+#define USB_IOCTL_CTRLMSG    0x00
+#define USB_IOCTL_BLKMSG     0x01
+#define USB_IOCTL_GETDEVLIST 0x0C
+
+#define USB_HOST_SECTOR_SIZE 512
+
+// Made up IDs of the drive, from the pid.codes test range
+#define USB_HOST_VENDOR_ID  0x1209
+#define USB_HOST_PRODUCT_ID 0x0001
+#define USB_HOST_DEVICE_ID  1
+
+#define USB_HOST_ENDPOINT_IN  0x81
+#define USB_HOST_ENDPOINT_OUT 0x02
+
+// Sense keys
+#define USB_HOST_SENSE_NONE            0x00
+#define USB_HOST_SENSE_NOT_READY       0x02
+#define USB_HOST_SENSE_ILLEGAL_REQUEST 0x05
+#define USB_HOST_SENSE_DATA_PROTECT    0x07
+
+// Configuration, interface, then the two bulk endpoints
+static const uint8_t USB_HOST_CONFIGURATION[] = {
+    9, USB_DESCRIPTOR_CONFIGURATION, 32, 0, 1, 1, 0, 0x80, 50,
+    9, USB_DESCRIPTOR_INTERFACE, 0, 0, 2, USB_CLASS_MASS_STORAGE, 0x06, 0x50, 0,
+    7, USB_DESCRIPTOR_ENDPOINT, USB_HOST_ENDPOINT_IN, USB_ENDPOINT_TYPE_BULK, 0x00, 0x02, 0,
+    7, USB_DESCRIPTOR_ENDPOINT, USB_HOST_ENDPOINT_OUT, USB_ENDPOINT_TYPE_BULK, 0x00, 0x02, 0,
+};
+
+typedef enum {
+    USB_HOST_PHASE_COMMAND,
+    USB_HOST_PHASE_DATA,
+    USB_HOST_PHASE_STATUS,
+} usb_host_phase_t;
+
+static struct {
+    int image;
+    bool write_protect;
+    uint32_t sector_count;
+
+    // Bulk only transport
+    usb_host_phase_t phase;
+    uint32_t tag;
+    uint32_t length;
+    uint32_t residue;
+    bool in;
+    uint8_t command[16];
+    uint8_t status;
+    uint8_t sense;
+} usb_host_state = { .image = -1 };
+
+static uint32_t usb_host_read_le32(const uint8_t* data) {
+    return data[0] | (data[1] << 8) | (data[2] << 16) | ((uint32_t)data[3] << 24);
+}
+
+static void usb_host_write_le32(uint8_t* data, uint32_t value) {
+    data[0] = value;
+    data[1] = value >> 8;
+    data[2] = value >> 16;
+    data[3] = value >> 24;
+}
+
+static void usb_host_write_be32(uint8_t* data, uint32_t value) {
+    data[0] = value >> 24;
+    data[1] = value >> 16;
+    data[2] = value >> 8;
+    data[3] = value;
+}
+
+static void usb_host_open_image() {
+    if(usb_host_state.image >= 0)
+        return;
+
+    const char* path = host_get_env(HOST_ENV_USB, HOST_DEFAULT_USB);
+
+    usb_host_state.write_protect = false;
+    usb_host_state.image = open(path, O_RDWR);
+
+    // Read only images act like a locked drive.
+    if(usb_host_state.image < 0 && (errno == EACCES || errno == EROFS)) {
+        usb_host_state.write_protect = true;
+        usb_host_state.image = open(path, O_RDONLY);
+    }
+
+    struct stat st;
+    if(usb_host_state.image >= 0 && fstat(usb_host_state.image, &st) == 0)
+        usb_host_state.sector_count = st.st_size / USB_HOST_SECTOR_SIZE;
+
+    // Too small to hold anything, not plugged in
+    if(usb_host_state.image >= 0 && usb_host_state.sector_count == 0) {
+        close(usb_host_state.image);
+        usb_host_state.image = -1;
+    }
+
+    usb_host_state.phase = USB_HOST_PHASE_COMMAND;
+}
+
+static int usb_host_ioctlv(int ioctl, int argcin, int argcio, ios_ioctlv_t* argv) {
+    if(ioctl != USB_IOCTL_GETDEVLIST || argcin != 2 || argcio != 2)
+        return IOS_HOST_ERROR_INVALID;
+
+    usb_host_open_image();
+
+    uint8_t max_entries = *(uint8_t*)argv[0].data;
+    uint8_t device_class = *(uint8_t*)argv[1].data;
+    uint8_t* count = (uint8_t*)argv[2].data;
+    usb_device_entry_t* entries = (usb_device_entry_t*)argv[3].data;
+
+    // Drives give their class in the interface, so they only match 0
+    *count = 0;
+    if(usb_host_state.image >= 0 && device_class == 0 && max_entries > 0 && argv[3].size >= sizeof(usb_device_entry_t)) {
+        entries[0].device_id = USB_HOST_DEVICE_ID;
+        entries[0].vendor_id = USB_HOST_VENDOR_ID;
+        entries[0].product_id = USB_HOST_PRODUCT_ID;
+        *count = 1;
+    }
+
+    return 0;
+}
+
+static int usb_host_drive_open() {
+    usb_host_open_image();
+    return usb_host_state.image >= 0 ? 0 : IOS_HOST_ERROR_NO_DEVICE;
+}
+
+static int usb_host_drive_close() {
+    if(usb_host_state.image >= 0) {
+        close(usb_host_state.image);
+        usb_host_state.image = -1;
+    }
+
+    return 0;
+}
+
+static int usb_host_control(ios_ioctlv_t* argv) {
+    uint8_t request_type = *(uint8_t*)argv[0].data;
+    uint8_t request = *(uint8_t*)argv[1].data;
+    const uint8_t* value = (const uint8_t*)argv[2].data;
+    const uint8_t* length = (const uint8_t*)argv[4].data;
+
+    // Little endian, like on the bus
+    uint16_t descriptor = value[0] | (value[1] << 8);
+    uint16_t size = length[0] | (length[1] << 8);
+    if(size > argv[6].size)
+        return IOS_HOST_ERROR_INVALID;
+
+    switch(request) {
+        case USB_REQUEST_GET_DESCRIPTOR:
+            if(request_type != USB_REQTYPE_DEVICE_IN || descriptor != (USB_DESCRIPTOR_CONFIGURATION << 8))
+                return IOS_HOST_ERROR_INVALID;
+
+            if(size > sizeof(USB_HOST_CONFIGURATION))
+                size = sizeof(USB_HOST_CONFIGURATION);
+
+            memcpy(argv[6].data, USB_HOST_CONFIGURATION, size);
+            return size;
+        case USB_REQUEST_SET_CONFIGURATION:
+        case USB_REQUEST_CLEAR_FEATURE:
+            return 0;
+        case 0xFF:
+            // Bulk only mass storage reset
+            usb_host_state.phase = USB_HOST_PHASE_COMMAND;
+            return 0;
+        default:
+            return IOS_HOST_ERROR_INVALID;
+    }
+}
+
+// Runs the command, moving its data. Returns the bytes moved.
+static uint32_t usb_host_run_command(uint8_t* data, uint32_t length) {
+    const uint8_t* command = usb_host_state.command;
+    uint8_t sense = USB_HOST_SENSE_NONE;
+    uint32_t done = 0;
+
+    switch(command[0]) {
+        case 0x00: // TEST UNIT READY
+        case 0x35: // SYNCHRONIZE CACHE
+            break;
+        case 0x03: // REQUEST SENSE
+            done = length < 18 ? length : 18;
+            memset(data, 0, done);
+            if(done >= 3) {
+                data[0] = 0x70;
+                data[2] = usb_host_state.sense;
+            }
+
+            usb_host_state.sense = USB_HOST_SENSE_NONE;
+            usb_host_state.status = 0;
+            return done;
+        case 0x1A: // MODE SENSE(6), just the header
+            done = length < 4 ? length : 4;
+            memset(data, 0, done);
+            if(done >= 3)
+                data[2] = usb_host_state.write_protect ? 0x80 : 0;
+            break;
+        case 0x25: // READ CAPACITY(10)
+            if(length < 8) {
+                sense = USB_HOST_SENSE_ILLEGAL_REQUEST;
+                break;
+            }
+
+            usb_host_write_be32(data + 0, usb_host_state.sector_count - 1);
+            usb_host_write_be32(data + 4, USB_HOST_SECTOR_SIZE);
+            done = 8;
+            break;
+        case 0x28: // READ(10)
+        case 0x2A: { // WRITE(10)
+            uint32_t sector = ((uint32_t)command[2] << 24) | (command[3] << 16) | (command[4] << 8) | command[5];
+            uint32_t count = (command[7] << 8) | command[8];
+            uint32_t size = count * USB_HOST_SECTOR_SIZE;
+
+            if((uint64_t)sector + count > usb_host_state.sector_count || size > length) {
+                sense = USB_HOST_SENSE_ILLEGAL_REQUEST;
+                break;
+            }
+
+            if(command[0] == 0x2A && usb_host_state.write_protect) {
+                sense = USB_HOST_SENSE_DATA_PROTECT;
+                break;
+            }
+
+            off_t offset = (off_t)sector * USB_HOST_SECTOR_SIZE;
+            ssize_t moved;
+            if(command[0] == 0x28)
+                moved = pread(usb_host_state.image, data, size, offset);
+            else
+                moved = pwrite(usb_host_state.image, data, size, offset);
+
+            if(moved != (ssize_t)size) {
+                sense = USB_HOST_SENSE_NOT_READY;
+                break;
+            }
+
+            done = size;
+            break;
+        }
+        default:
+            sense = USB_HOST_SENSE_ILLEGAL_REQUEST;
+            break;
+    }
+
+    usb_host_state.sense = sense;
+    usb_host_state.status = sense == USB_HOST_SENSE_NONE ? 0 : 1;
+    return done;
+}
+
+static int usb_host_bulk(ios_ioctlv_t* argv) {
+    uint8_t endpoint = *(uint8_t*)argv[0].data;
+    uint16_t length = *(uint16_t*)argv[1].data;
+    uint8_t* data = (uint8_t*)argv[2].data;
+
+    if(length > argv[2].size || (endpoint != USB_HOST_ENDPOINT_IN && endpoint != USB_HOST_ENDPOINT_OUT))
+        return IOS_HOST_ERROR_INVALID;
+
+    bool in = endpoint == USB_HOST_ENDPOINT_IN;
+
+    switch(usb_host_state.phase) {
+        case USB_HOST_PHASE_COMMAND:
+            if(in || length != 31 || usb_host_read_le32(data) != 0x43425355 || data[14] > 16)
+                return IOS_HOST_ERROR_INVALID;
+
+            usb_host_state.tag = usb_host_read_le32(data + 4);
+            usb_host_state.length = usb_host_read_le32(data + 8);
+            usb_host_state.in = (data[12] & USB_ENDPOINT_IN) != 0;
+            memset(usb_host_state.command, 0, sizeof(usb_host_state.command));
+            memcpy(usb_host_state.command, data + 15, data[14]);
+
+            if(usb_host_state.length == 0) {
+                usb_host_run_command(NULL, 0);
+                usb_host_state.residue = 0;
+                usb_host_state.phase = USB_HOST_PHASE_STATUS;
+            } else {
+                usb_host_state.phase = USB_HOST_PHASE_DATA;
+            }
+
+            return length;
+        case USB_HOST_PHASE_DATA: {
+            if(in != usb_host_state.in || length != usb_host_state.length)
+                return IOS_HOST_ERROR_INVALID;
+
+            uint32_t done = usb_host_run_command(data, length);
+            usb_host_state.residue = length - done;
+            usb_host_state.phase = USB_HOST_PHASE_STATUS;
+
+            // Short of what was asked for, the rest is padding
+            if(in && done < length)
+                memset(data + done, 0, length - done);
+
+            return length;
+        }
+        case USB_HOST_PHASE_STATUS:
+            if(!in || length != 13)
+                return IOS_HOST_ERROR_INVALID;
+
+            usb_host_write_le32(data + 0, 0x53425355);
+            usb_host_write_le32(data + 4, usb_host_state.tag);
+            usb_host_write_le32(data + 8, usb_host_state.residue);
+            data[12] = usb_host_state.status;
+
+            usb_host_state.phase = USB_HOST_PHASE_COMMAND;
+            return length;
+        default:
+            return IOS_HOST_ERROR_INVALID;
+    }
+}
+
+static int usb_host_drive_ioctlv(int ioctl, int argcin, int argcio, ios_ioctlv_t* argv) {
+    if(usb_host_state.image < 0)
+        return IOS_HOST_ERROR_NO_DEVICE;
+
+    if(ioctl == USB_IOCTL_CTRLMSG && argcin == 6 && argcio == 1)
+        return usb_host_control(argv);
+
+    if(ioctl == USB_IOCTL_BLKMSG && argcin == 2 && argcio == 1)
+        return usb_host_bulk(argv);
+
+    return IOS_HOST_ERROR_INVALID;
+}
+
+const host_ios_device_t host_usb_device = {
+    .path = "/dev/usb/oh0",
+
+    .ioctlv = usb_host_ioctlv,
+};
+
+const host_ios_device_t host_usb_drive_device = {
+    .path = "/dev/usb/oh0/1209/1",
+
+    .open = usb_host_drive_open,
+    .close = usb_host_drive_close,
+    .ioctlv = usb_host_drive_ioctlv,
+};
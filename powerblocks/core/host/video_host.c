@@ -0,0 +1,172 @@
+/**
+ * @file video_host.c
+ * @brief Manages the video output of the system on the host.
+ *
+ * A task stands in for the video interface. It raises the
+ * video IRQ at the refresh rate of the mode, and dumps the
+ * framebuffer to POWERBLOCKS_HOST_FRAMES on each vsync.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "host.h"
+
+#include "graphics/video.h"
+#include "system/system.h"
+#include "ios/ios_settings.h"
+
+#include "FreeRTOS.h"
+#include "semphr.h"
+#include "task.h"
+
+#include "utils/log.h"
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <sys/stat.h>
+
+static const char* TAB = "VIDEO";
+
+static video_mode_t video_mode = VIDEO_MODE_UNINITIALIZED;
+static const framebuffer_t* video_framebuffer;
+
+static StaticSemaphore_t video_retrace_semaphore_static;
+static SemaphoreHandle_t video_retrace_semaphore;
+static video_retrace_callback_t video_retrace_callback;
+
+static TaskHandle_t video_task_handle;
+
+static uint32_t video_refresh_rate() {
+    return video_mode == VIDEO_MODE_640X480_PAL50 ? 50 : 60;
+}
+
+static void video_irq_handler(exception_irq_type_t irq) {
+    // Alert waiting task of vsync
+    xSemaphoreGiveFromISR(video_retrace_semaphore, &exception_isr_context_switch_needed);
+
+    if(video_retrace_callback != NULL) {
+        video_retrace_callback();
+    }
+}
+
+static void video_dump_frame(const char* directory, uint32_t frame) {
+    if(video_framebuffer == NULL)
+        return;
+
+    char path[512];
+    snprintf(path, sizeof(path), "%s/frame_%06u.ppm", directory, frame);
+
+    if(host_framebuffer_dump(path, video_framebuffer) < 0) {
+        LOG_ERROR(TAB, "Failed to write %s", path);
+    }
+}
+
+static void video_task(void* params) {
+    const char* frames = host_get_env(HOST_ENV_FRAMES, HOST_DEFAULT_FRAMES);
+    const char* limit_env = getenv(HOST_ENV_FRAME_LIMIT);
+    uint32_t limit = limit_env ? strtoul(limit_env, NULL, 0) : 0;
+
+    bool dump = frames[0] != 0;
+    if(dump)
+        mkdir(frames, 0755);
+
+    TickType_t last_wake = xTaskGetTickCount();
+    uint32_t frame = 0;
+    while(true) {
+        // Frames do not line up with ticks, so wait to where the next one is due.
+        uint32_t rate = video_refresh_rate();
+        TickType_t increment = (TickType_t)(frame + 1) * configTICK_RATE_HZ / rate - (TickType_t)frame * configTICK_RATE_HZ / rate;
+        vTaskDelayUntil(&last_wake, increment);
+
+        host_raise_irq(EXCEPTION_IRQ_TYPE_VIDEO);
+
+        frame++;
+        if(dump)
+            video_dump_frame(frames, frame);
+
+        if(limit != 0 && frame >= limit) {
+            LOG_INFO(TAB, "Frame limit of %u reached.", limit);
+            exit(EXIT_SUCCESS);
+        }
+    }
+}
+
+video_mode_t video_system_default_video_mode() {
+    const char* tv_type = ios_settings_get("VIDEO");
+
+    if(tv_type == NULL) {
+        LOG_ERROR(TAB, "Failed to get TV type setting.\n");
+        return VIDEO_MODE_UNINITIALIZED;
+    }
+
+    // There is no component cable on the host, so it is never progressive.
+    if(strcmp(tv_type, "NTSC") == 0) {
+        return VIDEO_MODE_640X480_NTSC_INTERLACED;
+    } else if(strcmp(tv_type, "PAL") == 0) {
+        if(ios_config_is_eurgb60()) {
+            return VIDEO_MODE_640X480_PAL60;
+        } else {
+            return VIDEO_MODE_640X480_PAL50;
+        }
+    } else if(strcmp(tv_type, "MPAL") == 0) {
+        return VIDEO_MODE_640X480_PAL50;
+    } else {
+        LOG_ERROR(TAB, "Unknown TV type %s.", tv_type);
+        return VIDEO_MODE_UNINITIALIZED;
+    }
+}
+
+void video_initialize(video_mode_t mode) {
+    if(video_get_profile(mode) == NULL)
+        return;
+
+    // Create semaphore for retrace (vsync)
+    if(video_retrace_semaphore == NULL)
+        video_retrace_semaphore = xSemaphoreCreateBinaryStatic(&video_retrace_semaphore_static);
+
+    // Clear callback
+    video_retrace_callback = NULL;
+
+    video_mode = mode;
+
+    // Register interrupt
+    exceptions_install_irq(video_irq_handler, EXCEPTION_IRQ_TYPE_VIDEO);
+
+    // Like a interrupt, it should run over everything else.
+    if(video_task_handle == NULL)
+        xTaskCreate(video_task, "VI", configMINIMAL_STACK_SIZE * 4, NULL, configMAX_PRIORITIES - 1, &video_task_handle);
+
+    LOG_INFO(TAB, "Video interface initialized.");
+}
+
+void video_set_framebuffer(const framebuffer_t* framebuffer) {
+    video_framebuffer = framebuffer;
+}
+
+framebuffer_t* video_get_framebuffer() {
+    return (framebuffer_t*)video_framebuffer;
+}
+
+void video_wait_vsync() {
+    // Wait till vsync is alerted
+    xSemaphoreTake(video_retrace_semaphore, portMAX_DELAY);
+}
+
+void video_wait_vsync_int() {
+    // Go by the clock, as interrupts may be off and the video task can not run.
+    uint64_t period = SYSTEM_TB_CLOCK_HZ / video_refresh_rate();
+    uint64_t next = (system_get_time_base_int() / period + 1) * period;
+
+    while(system_get_time_base_int() < next);
+}
+
+void video_set_retrace_callback(video_retrace_callback_t callback) {
+    video_retrace_callback = callback;
+}
+
+video_retrace_callback_t video_get_retrace_callback() {
+    return video_retrace_callback;
+}
@@ -121,10 +121,14 @@ const char* ios_settings_get(const char* key) {
     return NULL;
 }
 
+// The config file is big endian, read it that way so it also works off the Wii.
+static uint16_t ios_config_read_u16(const uint8_t* data) {
+    return (data[0] << 8) | data[1];
+}
+
 uint32_t ios_config_get(const char* key, void* buffer, uint32_t size) {
     // Find the entry by looking for its name
-    int entry_count = *(const uint16_t*)(ios_config_data + 4);
-    const uint16_t* offsets = (const uint16_t*)(ios_config_data + 6);
+    int entry_count = ios_config_read_u16(ios_config_data + 4);
 
     int key_length = strlen(key);
 
@@ -132,7 +136,7 @@ uint32_t ios_config_get(const char* key, void* buffer, uint32_t size) {
     const uint8_t* found_entry_value = NULL;
 
     for(int i = 0; i < entry_count; i++) {
-        const uint8_t* entry = ios_config_data + offsets[i];
+        const uint8_t* entry = ios_config_data + ios_config_read_u16(ios_config_data + 6 + i * 2);
 
         int entry_key_length = (entry[0] & 0x1F) + 1;
         if(entry_key_length != key_length)
@@ -154,7 +158,7 @@ uint32_t ios_config_get(const char* key, void* buffer, uint32_t size) {
     int entry_length;
     switch(found_entry_type) {
         case 1: // BIGARRAY
-            entry_length = *((const uint16_t*)found_entry_value) + 1;
+            entry_length = ios_config_read_u16(found_entry_value) + 1;
             found_entry_value += 2;
             break;
         case 2: // SMALLARRAY
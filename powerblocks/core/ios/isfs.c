@@ -0,0 +1,238 @@
+/**
+ * @file isfs.c
+ * @brief IOS NAND File System
+ *
+ * The wii's internal NAND is a file system run by IOS, ISFS.
+ * Files in it are opened with ios_open, and read and written
+ * with ios_read, ios_write and ios_seek. Everything else is
+ * done through /dev/fs.
+ *
+ * This driver is to interface with it.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "isfs.h"
+
+#include "system/system.h"
+#include "utils/log.h"
+
+#include <string.h>
+#include <stdalign.h>
+
+static const char* TAG = "ISFS";
+
+#define ISFS_ERROR_LOGGING
+
+#ifdef ISFS_ERROR_LOGGING
+#define ISFS_LOG_ERROR(fmt, ...) LOG_ERROR(TAG, fmt, ##__VA_ARGS__)
+#else
+#define ISFS_LOG_ERROR(fmt, ...)
+#endif
+
+#define ISFS_IOCTL_GET_STATS      0x02
+#define ISFS_IOCTL_CREATE_DIR     0x03
+#define ISFS_IOCTL_READ_DIR       0x04
+#define ISFS_IOCTL_GET_ATTRIBUTES 0x06
+#define ISFS_IOCTL_DELETE         0x07
+#define ISFS_IOCTL_RENAME         0x08
+#define ISFS_IOCTL_CREATE_FILE    0x09
+#define ISFS_IOCTL_GET_FILE_STATS 0x0B
+#define ISFS_IOCTL_GET_USAGE      0x0C
+
+static struct {
+    int file;
+} isfs_state = { .file = -1 };
+
+// Outputs are flushed from the cache before IOS writes them,
+// so they read back fresh without invalidating after.
+
+// Copies a path into a buffer IOS can read, failing if it does not fit
+static int isfs_copy_path(char* buffer, const char* path) {
+    size_t length = strlen(path);
+    if(length >= ISFS_MAX_PATH)
+        return ISFS_ERROR_PATH_TOO_LONG;
+
+    memset(buffer, 0, ISFS_MAX_PATH);
+    memcpy(buffer, path, length);
+    return 0;
+}
+
+int isfs_initialize() {
+    static const char device[IOS_MAX_PATH] ALIGN(32) = "/dev/fs";
+
+    isfs_state.file = ios_open(device, 0);
+
+    if(isfs_state.file < 0) {
+        ISFS_LOG_ERROR("Error opening \"%s\": %d", device, isfs_state.file);
+        return isfs_state.file;
+    }
+
+    return 0;
+}
+
+void isfs_close() {
+    if(isfs_state.file < 0)
+        return;
+
+    ios_close(isfs_state.file);
+    isfs_state.file = -1;
+}
+
+int isfs_read_dir(const char* path, char* names, uint32_t* count) {
+    alignas(32) char path_buffer[ISFS_MAX_PATH];
+    alignas(32) uint32_t max_count = *count;
+    alignas(32) uint32_t found = 0;
+
+    int ret = isfs_copy_path(path_buffer, path);
+    if(ret < 0)
+        return ret;
+
+    ios_ioctlv_t vectors[4];
+    vectors[0].data = path_buffer;
+    vectors[0].size = sizeof(path_buffer);
+
+    // Without a buffer, IOS only counts
+    if(names == NULL) {
+        vectors[1].data = &found;
+        vectors[1].size = sizeof(found);
+
+        ret = ios_ioctlv(isfs_state.file, ISFS_IOCTL_READ_DIR, 1, 1, vectors);
+    } else {
+        vectors[1].data = &max_count;
+        vectors[1].size = sizeof(max_count);
+        vectors[2].data = names;
+        vectors[2].size = max_count * (ISFS_MAX_NAME + 1);
+        vectors[3].data = &found;
+        vectors[3].size = sizeof(found);
+
+        ret = ios_ioctlv(isfs_state.file, ISFS_IOCTL_READ_DIR, 2, 2, vectors);
+    }
+
+    if(ret < 0)
+        return ret;
+
+    *count = found;
+
+    return 0;
+}
+
+int isfs_get_attributes(const char* path, isfs_attributes_t* attributes) {
+    alignas(32) char path_buffer[ISFS_MAX_PATH];
+    alignas(32) isfs_attributes_t buffer;
+
+    int ret = isfs_copy_path(path_buffer, path);
+    if(ret < 0)
+        return ret;
+
+    ret = ios_ioctl(isfs_state.file, ISFS_IOCTL_GET_ATTRIBUTES, path_buffer, sizeof(path_buffer), &buffer, sizeof(buffer));
+    if(ret < 0)
+        return ret;
+
+    memcpy(attributes, &buffer, sizeof(buffer));
+
+    return 0;
+}
+
+// Files and directories are created the same way
+static int isfs_create(int ioctl, const char* path, uint8_t attributes, uint8_t owner_permissions, uint8_t group_permissions, uint8_t other_permissions) {
+    alignas(32) isfs_attributes_t buffer;
+    memset(&buffer, 0, sizeof(buffer));
+
+    int ret = isfs_copy_path(buffer.path, path);
+    if(ret < 0)
+        return ret;
+
+    // IOS fills in the owner and group itself
+    buffer.owner_permissions = owner_permissions;
+    buffer.group_permissions = group_permissions;
+    buffer.other_permissions = other_permissions;
+    buffer.attributes = attributes;
+
+    return ios_ioctl(isfs_state.file, ioctl, &buffer, sizeof(buffer), NULL, 0);
+}
+
+int isfs_create_file(const char* path, uint8_t attributes, uint8_t owner_permissions, uint8_t group_permissions, uint8_t other_permissions) {
+    return isfs_create(ISFS_IOCTL_CREATE_FILE, path, attributes, owner_permissions, group_permissions, other_permissions);
+}
+
+int isfs_create_dir(const char* path, uint8_t attributes, uint8_t owner_permissions, uint8_t group_permissions, uint8_t other_permissions) {
+    return isfs_create(ISFS_IOCTL_CREATE_DIR, path, attributes, owner_permissions, group_permissions, other_permissions);
+}
+
+int isfs_delete(const char* path) {
+    alignas(32) char path_buffer[ISFS_MAX_PATH];
+
+    int ret = isfs_copy_path(path_buffer, path);
+    if(ret < 0)
+        return ret;
+
+    return ios_ioctl(isfs_state.file, ISFS_IOCTL_DELETE, path_buffer, sizeof(path_buffer), NULL, 0);
+}
+
+int isfs_rename(const char* old_path, const char* new_path) {
+    alignas(32) char paths[ISFS_MAX_PATH * 2];
+
+    int ret = isfs_copy_path(paths, old_path);
+    if(ret < 0)
+        return ret;
+
+    ret = isfs_copy_path(paths + ISFS_MAX_PATH, new_path);
+    if(ret < 0)
+        return ret;
+
+    return ios_ioctl(isfs_state.file, ISFS_IOCTL_RENAME, paths, sizeof(paths), NULL, 0);
+}
+
+int isfs_get_usage(const char* path, uint32_t* clusters, uint32_t* inodes) {
+    alignas(32) char path_buffer[ISFS_MAX_PATH];
+    alignas(32) uint32_t cluster_count = 0;
+    alignas(32) uint32_t inode_count = 0;
+
+    int ret = isfs_copy_path(path_buffer, path);
+    if(ret < 0)
+        return ret;
+
+    ios_ioctlv_t vectors[3];
+    vectors[0].data = path_buffer;
+    vectors[0].size = sizeof(path_buffer);
+    vectors[1].data = &cluster_count;
+    vectors[1].size = sizeof(cluster_count);
+    vectors[2].data = &inode_count;
+    vectors[2].size = sizeof(inode_count);
+
+    ret = ios_ioctlv(isfs_state.file, ISFS_IOCTL_GET_USAGE, 1, 2, vectors);
+    if(ret < 0)
+        return ret;
+
+    *clusters = cluster_count;
+    *inodes = inode_count;
+
+    return 0;
+}
+
+int isfs_get_stats(isfs_stats_t* stats) {
+    alignas(32) isfs_stats_t buffer;
+
+    int ret = ios_ioctl(isfs_state.file, ISFS_IOCTL_GET_STATS, NULL, 0, &buffer, sizeof(buffer));
+    if(ret < 0)
+        return ret;
+
+    memcpy(stats, &buffer, sizeof(buffer));
+
+    return 0;
+}
+
+int isfs_get_file_stats(int file, isfs_file_stats_t* stats) {
+    alignas(32) isfs_file_stats_t buffer;
+
+    int ret = ios_ioctl(file, ISFS_IOCTL_GET_FILE_STATS, NULL, 0, &buffer, sizeof(buffer));
+    if(ret < 0)
+        return ret;
+
+    memcpy(stats, &buffer, sizeof(buffer));
+
+    return 0;
+}
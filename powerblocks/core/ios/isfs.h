@@ -0,0 +1,202 @@
+/**
+ * @file isfs.h
+ * @brief IOS NAND File System
+ *
+ * The wii's internal NAND is a file system run by IOS, ISFS.
+ * Files in it are opened with ios_open, and read and written
+ * with ios_read, ios_write and ios_seek. Everything else is
+ * done through /dev/fs.
+ *
+ * This driver is to interface with it.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include <stdint.h>
+#include <stddef.h>
+
+#include "ios.h"
+
+// Implementation based on https://wiibrew.org/wiki//dev/fs.
+
+// Longest name in a directory, not counting the terminator
+#define ISFS_MAX_NAME 12
+
+// Longest path, counting the terminator
+#define ISFS_MAX_PATH IOS_MAX_PATH
+
+// Permissions, for the owner, group and everyone else
+#define ISFS_PERMISSION_NONE       0
+#define ISFS_PERMISSION_READ       1
+#define ISFS_PERMISSION_WRITE      2
+#define ISFS_PERMISSION_READ_WRITE 3
+
+// Errors returned by /dev/fs
+#define ISFS_ERROR_INVALID        -101
+#define ISFS_ERROR_ACCESS         -102
+#define ISFS_ERROR_CORRUPT        -103
+#define ISFS_ERROR_EXISTS         -105
+#define ISFS_ERROR_NOT_FOUND      -106
+#define ISFS_ERROR_TOO_MANY_FILES -107
+#define ISFS_ERROR_FULL           -108
+#define ISFS_ERROR_TOO_MANY_OPEN  -109
+#define ISFS_ERROR_PATH_TOO_LONG  -110
+#define ISFS_ERROR_ALREADY_OPEN   -111
+#define ISFS_ERROR_NOT_EMPTY      -114
+#define ISFS_ERROR_TOO_DEEP       -115
+#define ISFS_ERROR_BUSY           -116
+#define ISFS_ERROR_FATAL          -117
+
+/**
+ * @struct isfs_attributes_t
+ * @brief Owner and permissions of a file or directory.
+ *
+ * Laid out like /dev/fs wants it.
+ */
+typedef struct {
+    uint32_t owner_id;
+    uint16_t group_id;
+    char path[ISFS_MAX_PATH];
+    uint8_t owner_permissions;
+    uint8_t group_permissions;
+    uint8_t other_permissions;
+    uint8_t attributes;
+    uint8_t padding[2];
+} isfs_attributes_t;
+
+/**
+ * @struct isfs_stats_t
+ * @brief Space used and free on the NAND.
+ */
+typedef struct {
+    uint32_t cluster_size;
+    uint32_t free_clusters;
+    uint32_t used_clusters;
+    uint32_t bad_clusters;
+    uint32_t reserved_clusters;
+    uint32_t free_inodes;
+    uint32_t used_inodes;
+} isfs_stats_t;
+
+/**
+ * @struct isfs_file_stats_t
+ * @brief Length and position of a opened file.
+ */
+typedef struct {
+    uint32_t length;
+    uint32_t position;
+} isfs_file_stats_t;
+
+/**
+ * @brief Opens the NAND file system interface
+ *
+ * Opens /dev/fs.
+ *
+ * @return Negative if Error
+ */
+extern int isfs_initialize();
+
+/**
+ * @brief Closes the driver.
+ *
+ * Files opened stay open.
+ */
+extern void isfs_close();
+
+/**
+ * @brief Lists the names in a directory.
+ *
+ * Names are put one after another, each with a terminator.
+ * Call with names as NULL to get the count first.
+ *
+ * @param path Directory to list, like "/title".
+ * @param names Outputted names, (ISFS_MAX_NAME + 1) bytes for each, or NULL.
+ * @param count How many names fit in names. Outputted number of names.
+ * @return Negative if error.
+ */
+extern int isfs_read_dir(const char* path, char* names, uint32_t* count);
+
+/**
+ * @brief Gets the owner and permissions of a file or directory.
+ *
+ * @param path File or directory.
+ * @param attributes Outputted attributes.
+ * @return Negative if error.
+ */
+extern int isfs_get_attributes(const char* path, isfs_attributes_t* attributes);
+
+/**
+ * @brief Creates a empty file.
+ *
+ * The file is owned by the running title.
+ *
+ * @param path File to create, its directory must exist.
+ * @param attributes File attributes, usually 0.
+ * @param owner_permissions Permissions of the owner, like ISFS_PERMISSION_READ_WRITE.
+ * @param group_permissions Permissions of the owner's group.
+ * @param other_permissions Permissions of everyone else.
+ * @return Negative if error.
+ */
+extern int isfs_create_file(const char* path, uint8_t attributes, uint8_t owner_permissions, uint8_t group_permissions, uint8_t other_permissions);
+
+/**
+ * @brief Creates a empty directory.
+ *
+ * @param path Directory to create, its parent must exist.
+ * @param attributes Directory attributes, usually 0.
+ * @param owner_permissions Permissions of the owner, like ISFS_PERMISSION_READ_WRITE.
+ * @param group_permissions Permissions of the owner's group.
+ * @param other_permissions Permissions of everyone else.
+ * @return Negative if error.
+ */
+extern int isfs_create_dir(const char* path, uint8_t attributes, uint8_t owner_permissions, uint8_t group_permissions, uint8_t other_permissions);
+
+/**
+ * @brief Deletes a file or directory.
+ *
+ * Directories are deleted along with everything in them.
+ *
+ * @param path File or directory to delete.
+ * @return Negative if error.
+ */
+extern int isfs_delete(const char* path);
+
+/**
+ * @brief Renames or moves a file or directory.
+ *
+ * @param old_path File or directory to rename.
+ * @param new_path Where it goes, which must not exist.
+ * @return Negative if error.
+ */
+extern int isfs_rename(const char* old_path, const char* new_path);
+
+/**
+ * @brief Gets the space used by a directory and everything in it.
+ *
+ * @param path Directory to check.
+ * @param clusters Outputted clusters used.
+ * @param inodes Outputted files and directories in it.
+ * @return Negative if error.
+ */
+extern int isfs_get_usage(const char* path, uint32_t* clusters, uint32_t* inodes);
+
+/**
+ * @brief Gets the space used and free on the whole NAND.
+ *
+ * @param stats Outputted stats.
+ * @return Negative if error.
+ */
+extern int isfs_get_stats(isfs_stats_t* stats);
+
+/**
+ * @brief Gets the length and position of a opened file.
+ *
+ * @param file File from ios_open.
+ * @param stats Outputted stats.
+ * @return Negative if error.
+ */
+extern int isfs_get_file_stats(int file, isfs_file_stats_t* stats);
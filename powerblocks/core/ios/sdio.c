@@ -183,7 +183,7 @@ int sdio_send_cmd(sdio_cmd_t cmd, sdio_cmdtype_t type, sdio_resptype_t resptype,
     input[3] = arg;
     input[4] = block_count;
     input[5] = sector_size;
-    input[6] = (uint32_t)data;
+    input[6] = (uint32_t)(uintptr_t)data;
     input[8] = 0;
 
 
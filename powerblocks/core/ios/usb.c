@@ -0,0 +1,186 @@
+/**
+ * @file usb.c
+ * @brief IOS USB Interface
+ *
+ * The USB ports on the wii are run by IOS, and reached
+ * through its USB host interface, /dev/usb/oh0.
+ *
+ * This driver is to interface with it.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "usb.h"
+
+#include "ios.h"
+
+#include "system/system.h"
+#include "utils/log.h"
+
+#include <stdio.h>
+#include <string.h>
+#include <stdalign.h>
+
+static const char* TAG = "USB";
+
+#define USB_ERROR_LOGGING
+//#define USB_INFO_LOGGING
+
+#ifdef USB_ERROR_LOGGING
+#define USB_LOG_ERROR(fmt, ...) LOG_ERROR(TAG, fmt, ##__VA_ARGS__)
+#else
+#define USB_LOG_ERROR(fmt, ...)
+#endif
+
+#ifdef USB_INFO_LOGGING
+#define USB_LOG_INFO(fmt, ...) LOG_INFO(TAG, fmt, ##__VA_ARGS__)
+#else
+#define USB_LOG_INFO(fmt, ...)
+#endif
+
+#define USB_IOCTL_CTRLMSG    0x00
+#define USB_IOCTL_BLKMSG     0x01
+#define USB_IOCTL_GETDEVLIST 0x0C
+
+static struct {
+    int file;
+} usb_state = { .file = -1 };
+
+int usb_initialize() {
+    static const char device[IOS_MAX_PATH] ALIGN(32) = "/dev/usb/oh0";
+
+    usb_state.file = ios_open(device, IOS_MODE_READ | IOS_MODE_WRITE);
+
+    if(usb_state.file < 0) {
+        USB_LOG_ERROR("Error opening \"%s\": %d", device, usb_state.file);
+        return usb_state.file;
+    }
+
+    return 0;
+}
+
+void usb_close() {
+    if(usb_state.file < 0)
+        return;
+
+    ios_close(usb_state.file);
+    usb_state.file = -1;
+}
+
+int usb_get_device_list(uint8_t device_class, usb_device_entry_t* entries, uint8_t max_entries) {
+    alignas(32) uint8_t params[32];
+    alignas(32) uint8_t count[32];
+    alignas(32) usb_device_entry_t list[USB_MAX_DEVICES];
+
+    if(max_entries > USB_MAX_DEVICES)
+        max_entries = USB_MAX_DEVICES;
+
+    params[0] = max_entries;
+    params[1] = device_class;
+    count[0] = 0;
+
+    ios_ioctlv_t vectors[4];
+    vectors[0].data = &params[0];
+    vectors[0].size = 1;
+    vectors[1].data = &params[1];
+    vectors[1].size = 1;
+    vectors[2].data = count;
+    vectors[2].size = 1;
+    vectors[3].data = list;
+    vectors[3].size = sizeof(usb_device_entry_t) * max_entries;
+
+    int ret = ios_ioctlv(usb_state.file, USB_IOCTL_GETDEVLIST, 2, 2, vectors);
+    if(ret < 0) {
+        USB_LOG_ERROR("Failed to list devices: %d", ret);
+        return ret;
+    }
+
+    system_invalidate_dcache(count, sizeof(count));
+    system_invalidate_dcache(list, sizeof(list));
+
+    int found = count[0] < max_entries ? count[0] : max_entries;
+    memcpy(entries, list, sizeof(usb_device_entry_t) * found);
+
+    USB_LOG_INFO("%d devices found", found);
+
+    return found;
+}
+
+int usb_open_device(uint16_t vendor_id, uint16_t product_id) {
+    alignas(32) char path[IOS_MAX_PATH];
+    snprintf(path, sizeof(path), "/dev/usb/oh0/%x/%x", vendor_id, product_id);
+
+    int ret = ios_open(path, IOS_MODE_READ | IOS_MODE_WRITE);
+    if(ret < 0)
+        USB_LOG_ERROR("Error opening \"%s\": %d", path, ret);
+
+    return ret;
+}
+
+void usb_close_device(int device) {
+    ios_close(device);
+}
+
+int usb_control_message(int device, uint8_t request_type, uint8_t request, uint16_t value, uint16_t index, uint16_t length, void* data) {
+    alignas(32) uint8_t params[32];
+
+    // Each part of the setup packet is its own vector,
+    // and the 16 bit ones are little endian like on the bus.
+    params[0] = request_type;
+    params[1] = request;
+    params[2] = value & 0xFF;
+    params[3] = value >> 8;
+    params[4] = index & 0xFF;
+    params[5] = index >> 8;
+    params[6] = length & 0xFF;
+    params[7] = length >> 8;
+    params[8] = 0;
+
+    ios_ioctlv_t vectors[7];
+    vectors[0].data = &params[0];
+    vectors[0].size = 1;
+    vectors[1].data = &params[1];
+    vectors[1].size = 1;
+    vectors[2].data = &params[2];
+    vectors[2].size = 2;
+    vectors[3].data = &params[4];
+    vectors[3].size = 2;
+    vectors[4].data = &params[6];
+    vectors[4].size = 2;
+    vectors[5].data = &params[8];
+    vectors[5].size = 1;
+    vectors[6].data = data;
+    vectors[6].size = length;
+
+    int ret = ios_ioctlv(device, USB_IOCTL_CTRLMSG, 6, 1, vectors);
+
+    if(data != NULL && (request_type & USB_ENDPOINT_IN))
+        system_invalidate_dcache(data, length);
+
+    return ret;
+}
+
+int usb_bulk_message(int device, uint8_t endpoint, uint16_t length, void* data) {
+    alignas(32) uint8_t params[32];
+
+    // Unlike control messages, the length is in the CPU's order
+    params[0] = endpoint;
+    memcpy(&params[2], &length, sizeof(length));
+
+    ios_ioctlv_t vectors[3];
+    vectors[0].data = &params[0];
+    vectors[0].size = 1;
+    vectors[1].data = &params[2];
+    vectors[1].size = 2;
+    vectors[2].data = data;
+    vectors[2].size = length;
+
+    int ret = ios_ioctlv(device, USB_IOCTL_BLKMSG, 2, 1, vectors);
+
+    if(endpoint & USB_ENDPOINT_IN)
+        system_invalidate_dcache(data, length);
+
+    return ret;
+}
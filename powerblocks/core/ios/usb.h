@@ -0,0 +1,130 @@
+/**
+ * @file usb.h
+ * @brief IOS USB Interface
+ *
+ * The USB ports on the wii are run by IOS, and reached
+ * through its USB host interface, /dev/usb/oh0.
+ *
+ * This driver is to interface with it.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include <stdint.h>
+#include <stddef.h>
+
+// Implementation based on https://wiibrew.org/wiki//dev/usb/oh0.
+
+// Most devices listed at once
+#define USB_MAX_DEVICES 8
+
+// Standard requests, from the USB spec
+#define USB_REQUEST_CLEAR_FEATURE     0x01
+#define USB_REQUEST_GET_DESCRIPTOR    0x06
+#define USB_REQUEST_SET_CONFIGURATION 0x09
+
+// Request types
+#define USB_REQTYPE_DEVICE_IN           0x80
+#define USB_REQTYPE_DEVICE_OUT          0x00
+#define USB_REQTYPE_ENDPOINT_OUT        0x02
+#define USB_REQTYPE_CLASS_INTERFACE_IN  0xA1
+#define USB_REQTYPE_CLASS_INTERFACE_OUT 0x21
+
+// Descriptor types
+#define USB_DESCRIPTOR_DEVICE        0x01
+#define USB_DESCRIPTOR_CONFIGURATION 0x02
+#define USB_DESCRIPTOR_INTERFACE     0x04
+#define USB_DESCRIPTOR_ENDPOINT      0x05
+
+// Set on endpoint addresses that go to the host
+#define USB_ENDPOINT_IN 0x80
+
+#define USB_ENDPOINT_TYPE_MASK 0x03
+#define USB_ENDPOINT_TYPE_BULK 0x02
+
+#define USB_FEATURE_ENDPOINT_HALT 0x00
+
+#define USB_CLASS_MASS_STORAGE 0x08
+
+/**
+ * @struct usb_device_entry_t
+ * @brief A device plugged into the USB ports.
+ */
+typedef struct {
+    uint32_t device_id;
+    uint16_t vendor_id;
+    uint16_t product_id;
+} usb_device_entry_t;
+
+/**
+ * @brief Opens the USB interface
+ *
+ * Opens /dev/usb/oh0, used to list devices.
+ *
+ * @return Negative if Error
+ */
+extern int usb_initialize();
+
+/**
+ * @brief Closes the driver.
+ *
+ * Devices opened stay open.
+ */
+extern void usb_close();
+
+/**
+ * @brief Lists the plugged in devices.
+ *
+ * @param device_class Only list devices of this class, or 0 for all of them.
+ * @param entries Outputted devices.
+ * @param max_entries Size of entries, up to USB_MAX_DEVICES.
+ * @return Device count, or negative if error.
+ */
+extern int usb_get_device_list(uint8_t device_class, usb_device_entry_t* entries, uint8_t max_entries);
+
+/**
+ * @brief Opens a device.
+ *
+ * @param vendor_id Vendor ID, from usb_get_device_list.
+ * @param product_id Product ID, from usb_get_device_list.
+ * @return IOS file of the device, or negative if error.
+ */
+extern int usb_open_device(uint16_t vendor_id, uint16_t product_id);
+
+/**
+ * @brief Closes a device.
+ */
+extern void usb_close_device(int device);
+
+/**
+ * @brief Sends a control message to endpoint 0.
+ *
+ * Data must be 32 byte aligned, as IOS DMAs it.
+ *
+ * @param device Device from usb_open_device.
+ * @param request_type Direction, type and recipient of the request.
+ * @param request The request, like USB_REQUEST_GET_DESCRIPTOR.
+ * @param value Argument specific to the request.
+ * @param index Argument specific to the request, often a interface or endpoint.
+ * @param length Length of the data.
+ * @param data Data sent or received, or NULL
+ * @return Bytes transferred, or negative if error.
+ */
+extern int usb_control_message(int device, uint8_t request_type, uint8_t request, uint16_t value, uint16_t index, uint16_t length, void* data);
+
+/**
+ * @brief Sends or receives data on a bulk endpoint.
+ *
+ * Data must be 32 byte aligned, as IOS DMAs it.
+ *
+ * @param device Device from usb_open_device.
+ * @param endpoint Endpoint address, with USB_ENDPOINT_IN set to receive.
+ * @param length Length of the data.
+ * @param data Data sent or received.
+ * @return Bytes transferred, or negative if error.
+ */
+extern int usb_bulk_message(int device, uint8_t endpoint, uint16_t length, void* data);
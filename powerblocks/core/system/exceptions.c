@@ -44,6 +44,9 @@ int32_t exception_isr_context_switch_needed;
 // The IRQ handlers used with the processor interface
 static exception_irq_handler_t irq_handlers[EXCEPTION_IRQ_COUNT];
 
+// The handlers hooking faults before the crash handler
+static exception_fault_handler_t fault_handlers[EXCEPTION_FAULT_COUNT];
+
 // From FreeRTOS port.c file
 extern void prvTickISR();
 
@@ -79,6 +82,7 @@ void exceptions_install_vector() {
 
     // Clear IRQ handlers
     memset(irq_handlers, 0, sizeof(irq_handlers));
+    memset(fault_handlers, 0, sizeof(fault_handlers));
 
     // Disable all processor interface external interrupts
     PI_INTMR = 0;
@@ -95,6 +99,24 @@ void exceptions_install_irq(exception_irq_handler_t handler, exception_irq_type_
     }
 }
 
+int exceptions_install_fault_handler(exception_fault_handler_t handler, exception_fault_type_t type) {
+    if((unsigned int)type >= EXCEPTION_FAULT_COUNT)
+        return -1;
+
+    fault_handlers[type] = handler;
+    return 0;
+}
+
+// Gives the fault handler a chance, crashes if there is none or it fails
+static void exception_fault(exception_fault_type_t type, const char* cause, exception_context_t* context) {
+    exception_fault_handler_t handler = fault_handlers[type];
+    if(handler != NULL && handler(type, context) != 0) {
+        return;
+    }
+
+    crash_handler_bug_check(cause, context);
+}
+
 void exception_reset(exception_context_t* context) {
     crash_handler_bug_check("RESET", context);
 }
@@ -104,11 +126,11 @@ void exception_machine_check(exception_context_t* context) {
 }
 
 void exception_dsi(exception_context_t* context) {
-    crash_handler_bug_check("DSI EXCEPTION ON DATA LOAD/STORE", context);
+    exception_fault(EXCEPTION_FAULT_TYPE_DSI, "DSI EXCEPTION ON DATA LOAD/STORE", context);
 }
 
 void exception_isi(exception_context_t* context) {
-    crash_handler_bug_check("ISI EXCEPTION ON INSTRUCTION LOAD", context);
+    exception_fault(EXCEPTION_FAULT_TYPE_ISI, "ISI EXCEPTION ON INSTRUCTION LOAD", context);
 }
 
 void exception_external(exception_context_t* context) {
@@ -135,15 +157,15 @@ void exception_external(exception_context_t* context) {
 }
 
 void exception_alignment(exception_context_t* context) {
-    crash_handler_bug_check("ALIGNMENT", context);
+    exception_fault(EXCEPTION_FAULT_TYPE_ALIGNMENT, "ALIGNMENT", context);
 }
 
 void exception_program(exception_context_t* context) {
-    crash_handler_bug_check("PROGRAM", context);
+    exception_fault(EXCEPTION_FAULT_TYPE_PROGRAM, "PROGRAM", context);
 }
 
 void exception_fpu_unavailable(exception_context_t* context) {
-    crash_handler_bug_check("FPU UNIVALBLE", context);
+    exception_fault(EXCEPTION_FAULT_TYPE_FPU_UNAVAILABLE, "FPU UNIVALBLE", context);
 }
 
 void exception_decrementer(exception_context_t* context) {
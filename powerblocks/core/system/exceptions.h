@@ -21,6 +21,13 @@
  */
 #define EXCEPTION_IRQ_COUNT 15
 
+/** @def EXCEPTION_FAULT_COUNT
+ *  @brief Number of fault types that can be hooked.
+ *
+ *  Number of fault types that can be hooked.
+ */
+#define EXCEPTION_FAULT_COUNT 5
+
 /** @def EXCEPTION_PPC_IRQ
  *  @brief Access to Holywoods Interrupt Controller Flags
  *
@@ -93,7 +100,28 @@ typedef enum {
  * Usually used along side xSemaphoreGiveFromISR such that
  * awoken task are switched into when a ISR wakes a task.
  */
+#ifndef POWERBLOCKS_HOST
 extern int32_t exception_isr_context_switch_needed;
+#else
+// Passed to the FromISR functions, so it is the POSIX port's BaseType_t.
+extern long exception_isr_context_switch_needed;
+#endif
+
+/**
+ * @enum exception_fault_type_t
+ * @brief Synchronous exceptions that can be hooked.
+ *
+ * Synchronous exceptions caused by the running code that
+ * can be hooked with exceptions_install_fault_handler
+ * before they reach the crash handler.
+ */
+typedef enum {
+    EXCEPTION_FAULT_TYPE_DSI,
+    EXCEPTION_FAULT_TYPE_ISI,
+    EXCEPTION_FAULT_TYPE_ALIGNMENT,
+    EXCEPTION_FAULT_TYPE_PROGRAM,
+    EXCEPTION_FAULT_TYPE_FPU_UNAVAILABLE
+} exception_fault_type_t;
 
 /**
  * @typedef exception_irq_handler_t
@@ -106,6 +134,23 @@ extern int32_t exception_isr_context_switch_needed;
  */
 typedef void (*exception_irq_handler_t)(exception_irq_type_t irq);
 
+/**
+ * @typedef exception_fault_handler_t
+ * @brief Function pointer to hook faults.
+ *
+ * Function pointer to hook faults.
+ * Use exceptions_install_fault_handler to set it.
+ *
+ * Changes to the context are restored when the exception returns,
+ * so a handler can recover by, for example, moving srr0 past the
+ * faulting instruction.
+ *
+ * @param fault The fault type its handling.
+ * @param context The context saved when the fault happened.
+ * @return Non zero to resume with the context, zero to crash.
+ */
+typedef int (*exception_fault_handler_t)(exception_fault_type_t fault, exception_context_t* context);
+
  /**
  *  @brief Installs the exception vector into the CPU.
  *
@@ -123,6 +168,16 @@ extern void exceptions_install_vector();
  */
 extern void exceptions_install_irq(exception_irq_handler_t handler, exception_irq_type_t type);
 
+ /**
+ *  @brief Registers a handler for a fault.
+ *
+ * Registers a handler called before the crash handler
+ * for a fault type. NULL removes it.
+ *
+ * @return Negative if the fault type is out of range.
+ */
+extern int exceptions_install_fault_handler(exception_fault_handler_t handler, exception_fault_type_t type);
+
 // Exception handlers called from exceptions_asm.s
 extern void exception_reset(exception_context_t* context);
 extern void exception_machine_check(exception_context_t* context);
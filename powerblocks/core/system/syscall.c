@@ -36,8 +36,14 @@ static uint32_t syscall_out_of_memory(exception_context_t* context, uint32_t err
     return 0;
 }
 
+static uint32_t syscall_bug_check(exception_context_t* context, uint32_t cause_p, uint32_t arg2, uint32_t arg3) {
+    crash_handler_bug_check((const char*)cause_p, context);
+    return 0;
+}
 
 const syscall_handler_t syscall_registry[SYSCALL_REGISTRY_SIZE] = {
     syscall_yield,
-    syscall_out_of_memory
+    syscall_out_of_memory,
+    syscall_out_of_memory,
+    syscall_bug_check
 };
\ No newline at end of file
@@ -35,12 +35,14 @@ typedef uint32_t (*syscall_handler_t)(exception_context_t* context, uint32_t arg
 #define SYSCALL_ID_YIELD         0
 #define SYSCALL_ID_ASSERT        1
 #define SYSCALL_ID_OUT_OF_MEMORY 2
+#define SYSCALL_ID_BUG_CHECK     3
 
-#define SYSCALL_REGISTRY_SIZE 2
+#define SYSCALL_REGISTRY_SIZE 4
 
 extern const syscall_handler_t syscall_registry[SYSCALL_REGISTRY_SIZE];
 
 
+#ifndef POWERBLOCKS_HOST
 /**  @def SYSCALL
   *  @brief Calls a Syscall with a linux like calling convention.
  *
@@ -60,6 +62,18 @@ extern const syscall_handler_t syscall_registry[SYSCALL_REGISTRY_SIZE];
     );                                                           \
     r3; /* return value in r3 */                                 \
 });
+#else
+/**  @def SYSCALL
+  *  @brief Calls a Syscall on the host.
+ *
+ *  There is no sc instruction on the host, so it is a plain function call
+ *  into the host implementation of the syscalls.
+ */
+#define SYSCALL(num, arg1, arg2, arg3) \
+    syscall_host((num), (uintptr_t)(arg1), (uintptr_t)(arg2), (uintptr_t)(arg3))
+
+extern uintptr_t syscall_host(uint32_t num, uintptr_t arg1, uintptr_t arg2, uintptr_t arg3);
+#endif
 
 /**  @def SYSCALL_YIELD
   *  @brief Called for FreeRTOS to task switch the current context.
@@ -74,4 +88,12 @@ extern const syscall_handler_t syscall_registry[SYSCALL_REGISTRY_SIZE];
  *
  * Called for when an assert fails to report the information.
  */
-#define SYSCALL_ASSERT(error_name, line, file) SYSCALL(SYSCALL_ID_ASSERT, error_name, line, file)
\ No newline at end of file
+#define SYSCALL_ASSERT(error_name, line, file) SYSCALL(SYSCALL_ID_ASSERT, error_name, line, file)
+
+/**  @def SYSCALL_BUG_CHECK
+  *  @brief Calls crash_handler_bug_check with the calling context.
+ *
+ * Calls crash_handler_bug_check with the context saved by the syscall,
+ * so the crash screen shows the registers of the caller.
+ */
+#define SYSCALL_BUG_CHECK(cause) SYSCALL(SYSCALL_ID_BUG_CHECK, cause, 0, 0)
\ No newline at end of file
@@ -24,7 +24,7 @@
 #include "gpio.h"
 
 // Main of the application of the user.
-extern void main();
+extern int main();
 
 void system_delay_int(uint64_t ticks) {
     uint64_t stop = system_get_time_base_int() + ticks;
@@ -37,11 +37,11 @@ void* system_aligned_malloc(uint32_t bytes, uint32_t alignment) {
     if((alignment & (alignment-1)) != 0)
         return NULL;
     
-    uint32_t raw = (uint32_t)malloc(bytes + alignment - 1 + sizeof(void*));
+    uintptr_t raw = (uintptr_t)malloc(bytes + alignment - 1 + sizeof(void*));
     if(!raw)
         return NULL;
     
-    uint32_t aligned = (raw + sizeof(void*) + aligned - 1) & ~(aligned - 1);
+    uintptr_t aligned = (raw + sizeof(void*) + alignment - 1) & ~(uintptr_t)(alignment - 1);
     ((void**)aligned)[-1] = (void*)raw;
 
     return (void*)aligned;
@@ -53,6 +53,15 @@ void system_aligned_free(void* ptr) {
     }
 }
 
+static void system_main_task(void* params) {
+    int ret = main();
+
+#ifdef POWERBLOCKS_HOST
+    // Returning from main ends the process, like it would on Linux.
+    exit(ret);
+#endif
+}
+
 void system_initialize() {
     // Ensure interrupts are disabled
     uint32_t level;
@@ -78,7 +87,7 @@ void system_initialize() {
     exceptions_install_vector();
 
     // Create main task and start scheduler
-    xTaskCreate(main, "MAIN", SYSTEM_MAIN_STACK_SIZE, NULL, configMAX_PRIORITIES / 2, NULL);
+    xTaskCreate(system_main_task, "MAIN", SYSTEM_MAIN_STACK_SIZE, NULL, configMAX_PRIORITIES / 2, NULL);
     vTaskStartScheduler();
 
     // We do not expect execution to return here.
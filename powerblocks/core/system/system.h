@@ -59,6 +59,7 @@
  */
 #define SYSTEM_S_TO_TICKS(s) (SYSTEM_TB_CLOCK_HZ * (s))
 
+#ifndef POWERBLOCKS_HOST
  /** @def SYSTEM_MEM_UNCACHED
  *  @brief Convert a memory address into a uncached virtual address
  *
@@ -82,6 +83,12 @@
  *  This is done by setting the MSB nibble.
  */
 #define SYSTEM_MEM_PHYSICAL(address) ((uint32_t)(address) & 0x1FFFFFFF)
+#else
+// There is one address space on the host, so addresses stay as they are.
+#define SYSTEM_MEM_UNCACHED(address) ((uintptr_t)(address))
+#define SYSTEM_MEM_CACHED(address) ((uintptr_t)(address))
+#define SYSTEM_MEM_PHYSICAL(address) ((uintptr_t)(address))
+#endif
 
  /** @struct system_argv_t
  *  @brief Command line arguments passed to us from launcher
@@ -120,6 +127,7 @@ extern system_argv_t system_argv;
  */
 #define SYSTEM_MAIN_STACK_SIZE (1024*1024*4) // 4 MB
 
+#ifndef POWERBLOCKS_HOST
  /** @def SYSTEM_GET_MSR
  *  @brief Gets the value of the MSR register
  *
@@ -210,6 +218,34 @@ extern system_argv_t system_argv;
     __asm__ __volatile__( \
         "isync" \
     );
+#else
+ /** @def SYSTEM_DISABLE_ISR
+ *  @brief Disables interrupts
+ *
+ *  On the host interrupts are the POSIX port's SIGALRM tick,
+ *  so this blocks it for the calling thread.
+ */
+#define SYSTEM_DISABLE_ISR(ee_enabled) \
+    do { \
+        ee_enabled = system_host_disable_isr(); \
+    } while(0)
+
+ /** @def SYSTEM_ENABLE_ISR
+ *  @brief Enables interrupts if ee_enabled is set.
+ *
+ *  Unblocks the tick again if it was unblocked before SYSTEM_DISABLE_ISR.
+ */
+#define SYSTEM_ENABLE_ISR(ee_enabled) \
+    if(ee_enabled) { \
+        system_host_enable_isr(); \
+    }
+
+#define SYSTEM_SYNC() __asm__ __volatile__("" ::: "memory");
+#define SYSTEM_ISYNC() __asm__ __volatile__("" ::: "memory");
+
+extern uint32_t system_host_disable_isr();
+extern void system_host_enable_isr();
+#endif
 
  /** @def ASSERT
  *  @brief Triggers a crash with debug info if a condition fails.
@@ -242,6 +278,7 @@ extern system_argv_t system_argv;
  */
 #define ALIGN(x) __attribute__((aligned(x)))
 
+#ifndef POWERBLOCKS_HOST
  /** @def SYSTEM_SWITCH_SP
  *  @brief Switches the stack pointer with pxCurrentTCB in a task switch.
  *
@@ -262,6 +299,7 @@ extern system_argv_t system_argv;
         "lwz    r4, pxCurrentTCB@l(r3)\n\t"     /* r4 = pxCurrentTCB */ \
         "lwz    r1, 0(r4)\n\t"                  /* SP = pxCurrentTCB->pxTopOfStack */ \
     )
+#endif
 
 
 
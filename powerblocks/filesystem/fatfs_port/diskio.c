@@ -15,15 +15,18 @@
 #include "powerblocks/core/system/system.h"
 
 #include "powerblocks/filesystem/sd.h"
+#include "powerblocks/filesystem/fatfs_port/sd_disk.h"
+#include "powerblocks/filesystem/usb_storage.h"
+#include "powerblocks/filesystem/fatfs_port/usb_storage_disk.h"
 
 #include <stdbool.h>
 
-#define DISK_DEV_SD 0
-
 DSTATUS disk_status(BYTE pdrv) {
     switch(pdrv) {
         case DISK_DEV_SD:
             return sd_disk_status();
+        case DISK_DEV_USB:
+            return usb_storage_disk_status();
         default:
             return STA_NOINIT;
     }
@@ -33,6 +36,8 @@ DSTATUS disk_initialize(BYTE pdrv) {
     switch(pdrv) {
         case DISK_DEV_SD:
             return sd_disk_initialize();
+        case DISK_DEV_USB:
+            return usb_storage_disk_initialize();
         default:
             return STA_NOINIT;
     }
@@ -42,6 +47,8 @@ DRESULT disk_read(BYTE pdrv, BYTE *buff, LBA_t sector, UINT count) {
     switch(pdrv) {
         case DISK_DEV_SD:
             return sd_disk_read(buff, sector, count);
+        case DISK_DEV_USB:
+            return usb_storage_disk_read(buff, sector, count);
         default:
             return RES_PARERR;
     }
@@ -51,6 +58,8 @@ DRESULT disk_write(BYTE pdrv, const BYTE *buff, LBA_t sector, UINT count) {
     switch(pdrv) {
         case DISK_DEV_SD:
             return sd_disk_write(buff, sector, count);
+        case DISK_DEV_USB:
+            return usb_storage_disk_write(buff, sector, count);
         default:
             return RES_PARERR;
     }
@@ -60,6 +69,8 @@ DRESULT disk_ioctl(BYTE pdrv, BYTE cmd, void *buff) {
     switch(pdrv) {
         case DISK_DEV_SD:
             return sd_disk_ioctl(cmd, buff);
+        case DISK_DEV_USB:
+            return usb_storage_disk_ioctl(cmd, buff);
         default:
             return RES_PARERR;
     }
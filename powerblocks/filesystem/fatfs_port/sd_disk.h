@@ -0,0 +1,43 @@
+/**
+ * @file sd_disk.h
+ * @brief SD Card FatFs Disk
+ *
+ * The SD card's FatFs disk functions, called from diskio.c.
+ * Private to the FatFs port, so sd.h stays free of FatFs types
+ * and can be included along with dirent.h.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include "diskio.h"
+
+/**
+ * @brief FatFS disk_status implementation.
+*/
+extern DSTATUS sd_disk_status();
+
+/**
+ * @brief FatFS disk_initialize implementation.
+ * 
+ * Called on mount after successful status reading.
+*/
+extern DSTATUS sd_disk_initialize();
+
+/**
+ * @brief FatFS disk_read implementation
+ */
+extern DRESULT sd_disk_read(BYTE* buff, LBA_t sector, UINT count);
+
+/**
+ * @brief FatFS disk_read implementation
+ */
+extern DRESULT sd_disk_write(const BYTE* buff, LBA_t sector, UINT count);
+
+/**
+ * @brief FatFS disk_ioctl implementation.
+ */
+extern DRESULT sd_disk_ioctl(BYTE cmd, void* buff);
\ No newline at end of file
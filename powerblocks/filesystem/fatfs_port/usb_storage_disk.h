@@ -0,0 +1,43 @@
+/**
+ * @file usb_storage_disk.h
+ * @brief USB Mass Storage FatFs Disk
+ *
+ * The USB drive's FatFs disk functions, called from diskio.c.
+ * Private to the FatFs port, so usb_storage.h stays free of FatFs types
+ * and can be included along with dirent.h.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include "diskio.h"
+
+/**
+ * @brief FatFS disk_status implementation.
+*/
+extern DSTATUS usb_storage_disk_status();
+
+/**
+ * @brief FatFS disk_initialize implementation.
+ * 
+ * Finds the drive and gets it ready.
+*/
+extern DSTATUS usb_storage_disk_initialize();
+
+/**
+ * @brief FatFS disk_read implementation
+ */
+extern DRESULT usb_storage_disk_read(BYTE* buff, LBA_t sector, UINT count);
+
+/**
+ * @brief FatFS disk_write implementation
+ */
+extern DRESULT usb_storage_disk_write(const BYTE* buff, LBA_t sector, UINT count);
+
+/**
+ * @brief FatFS disk_ioctl implementation.
+ */
+extern DRESULT usb_storage_disk_ioctl(BYTE cmd, void* buff);
@@ -0,0 +1,62 @@
+/**
+ * @file fs_error.c
+ * @brief FatFs results as errno values
+ *
+ * Used by the libc system calls to report FatFs errors.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "fs_error.h"
+
+#include <errno.h>
+
+int fs_error_to_errno(FRESULT res) {
+    switch(res) {
+        case FR_OK:
+            return 0;
+        case FR_DISK_ERR:
+        case FR_INT_ERR:
+            return EIO;
+        case FR_NOT_READY:
+            return ENXIO;         // No card inserted
+        case FR_NO_FILE:
+        case FR_NO_PATH:
+            return ENOENT;
+        case FR_INVALID_NAME:
+            return EINVAL;
+        case FR_DENIED:
+            return EACCES;        // Read only, full, or a directory that is not empty
+        case FR_EXIST:
+            return EEXIST;
+        case FR_INVALID_OBJECT:
+            return EBADF;
+        case FR_WRITE_PROTECTED:
+            return EROFS;
+        case FR_INVALID_DRIVE:
+        case FR_NOT_ENABLED:
+        case FR_NO_FILESYSTEM:
+            return ENODEV;        // Nothing mounted there
+        case FR_MKFS_ABORTED:
+            return EIO;
+        case FR_TIMEOUT:
+            return ETIMEDOUT;
+        case FR_LOCKED:
+            return EBUSY;
+        case FR_NOT_ENOUGH_CORE:
+            return ENOMEM;
+        case FR_TOO_MANY_OPEN_FILES:
+            return EMFILE;
+        case FR_INVALID_PARAMETER:
+            return EINVAL;
+        default:
+            return EIO;
+    }
+}
+
+int fs_error_set_errno(FRESULT res) {
+    errno = fs_error_to_errno(res);
+    return -1;
+}
@@ -0,0 +1,30 @@
+/**
+ * @file fs_error.h
+ * @brief FatFs results as errno values
+ *
+ * Used by the libc system calls to report FatFs errors.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include "ff.h"
+
+/**
+ * @brief Gets the errno value closest to a FatFs result.
+ *
+ * @param res Result returned by FatFs.
+ * @return errno value, or 0 for FR_OK.
+*/
+extern int fs_error_to_errno(FRESULT res);
+
+/**
+ * @brief Sets errno from a FatFs result.
+ *
+ * @param res Result returned by FatFs.
+ * @return -1, to be returned by the system call.
+*/
+extern int fs_error_set_errno(FRESULT res);
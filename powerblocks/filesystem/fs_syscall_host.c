@@ -0,0 +1,111 @@
+/**
+ * @file fs_syscall_host.c
+ * @brief Implements libc's filesystem calls on the host
+ *
+ * fs_syscall.c can not replace glibc's system calls, as the host
+ * itself needs them. So only fopen, chdir, getcwd and the directory
+ * functions are replaced, which is enough for apps using stdio and
+ * dirent.h to reach the VFS devices.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#define _GNU_SOURCE
+
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <errno.h>
+#include <fcntl.h>
+#include <dirent.h>
+
+#include "vfs.h"
+
+static ssize_t fs_read(void* cookie, char* buf, size_t size) {
+    return vfs_read((int)(intptr_t)cookie, buf, size);
+}
+
+static ssize_t fs_write(void* cookie, const char* buf, size_t size) {
+    return vfs_write((int)(intptr_t)cookie, buf, size);
+}
+
+static int fs_seek(void* cookie, off64_t* offset, int whence) {
+    off_t position = vfs_lseek((int)(intptr_t)cookie, *offset, whence);
+    if(position < 0)
+        return -1;
+
+    *offset = position;
+    return 0;
+}
+
+static int fs_close(void* cookie) {
+    return vfs_close((int)(intptr_t)cookie);
+}
+
+FILE* fopen(const char* path, const char* mode) {
+    int flags;
+
+    // Flags matching each fopen mode
+    bool update = strchr(mode, '+') != NULL;
+    bool exclusive = strchr(mode, 'x') != NULL;
+    switch(mode[0]) {
+        case 'r':
+            flags = update ? O_RDWR : O_RDONLY;
+            break;
+        case 'w':
+            flags = (update ? O_RDWR : O_WRONLY) | O_CREAT | O_TRUNC | (exclusive ? O_EXCL : 0);
+            break;
+        case 'a':
+            flags = (update ? O_RDWR : O_WRONLY) | O_CREAT | O_APPEND;
+            break;
+        default:
+            errno = EINVAL;
+            return NULL;
+    }
+
+    int fd = vfs_open(path, flags, 0666);
+    if(fd < 0)
+        return NULL;
+
+    cookie_io_functions_t functions = {
+        .read = fs_read,
+        .write = fs_write,
+        .seek = fs_seek,
+        .close = fs_close,
+    };
+
+    FILE* file = fopencookie((void*)(intptr_t)fd, mode, functions);
+    if(file == NULL)
+        vfs_close(fd);
+
+    return file;
+}
+
+int chdir(const char *path) {
+    return vfs_chdir(path);
+}
+
+char* getcwd(char *buf, size_t size) {
+    return vfs_getcwd(buf, size);
+}
+
+// glibc's DIR is never looked inside, so it stands for the VFS's.
+DIR* opendir(const char *path) {
+    return (DIR*)vfs_opendir(path);
+}
+
+struct dirent* readdir(DIR *dir) {
+    return vfs_readdir((vfs_dir_t*)dir);
+}
+
+void rewinddir(DIR *dir) {
+    vfs_rewinddir((vfs_dir_t*)dir);
+}
+
+int closedir(DIR *dir) {
+    return vfs_closedir((vfs_dir_t*)dir);
+}
@@ -0,0 +1,67 @@
+/**
+ * @file dirent.h
+ * @brief Directory listing
+ *
+ * libc does not provide directories, so PowerBlocks provides
+ * them on top of the VFS. Implemented in fs_syscall.c.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include <sys/types.h>
+
+// Same values as Linux
+#define DT_UNKNOWN 0
+#define DT_DIR     4
+#define DT_REG     8
+
+/**
+ * @struct dirent
+ * @brief A entry in a directory.
+ */
+struct dirent {
+    ino_t d_ino;          // Always 0, not all file systems have inodes
+    unsigned char d_type; // DT_DIR or DT_REG
+    char d_name[256];
+};
+
+/**
+ * @typedef DIR
+ * @brief A open directory.
+ */
+typedef struct vfs_dir DIR;
+
+/**
+ * @brief Opens a directory to list.
+ *
+ * @param path Path of the directory.
+ * @return The open directory, or NULL with errno set if error.
+*/
+extern DIR* opendir(const char* path);
+
+/**
+ * @brief Reads the next entry of a directory.
+ *
+ * "." and ".." are not listed.
+ * The entry is overwritten by the next call.
+ *
+ * @param dir Directory to read.
+ * @return The entry, or NULL at the end or with errno set if error.
+*/
+extern struct dirent* readdir(DIR* dir);
+
+/**
+ * @brief Starts listing the directory from the start again.
+*/
+extern void rewinddir(DIR* dir);
+
+/**
+ * @brief Closes a directory.
+ *
+ * @return Negative if error.
+*/
+extern int closedir(DIR* dir);
@@ -0,0 +1,160 @@
+/**
+ * @file romfs.c
+ * @brief Read only file system built into the app
+ *
+ * Reads archives made by tools/romfs/romfs_pack.py.
+ * Everything in them is big endian.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "romfs.h"
+
+#include <string.h>
+#include <errno.h>
+
+#define ROMFS_MAGIC   "PBRF"
+#define ROMFS_VERSION 1
+
+#define ROMFS_HEADER_SIZE 32
+#define ROMFS_ENTRY_SIZE  16
+
+#define ROMFS_TYPE_FILE      0
+#define ROMFS_TYPE_DIRECTORY 1
+
+// Reads a number from the archive, which may not be aligned for it.
+static uint32_t romfs_read_u32(const uint8_t* data) {
+    return ((uint32_t)data[0] << 24) | ((uint32_t)data[1] << 16) | ((uint32_t)data[2] << 8) | data[3];
+}
+
+int romfs_open(romfs_t* romfs, const void* image, size_t size) {
+    const uint8_t* data = (const uint8_t*)image;
+
+    if(data == NULL || size < ROMFS_HEADER_SIZE)
+        return -1;
+
+    if(memcmp(data, ROMFS_MAGIC, 4) != 0 || romfs_read_u32(data + 4) != ROMFS_VERSION)
+        return -1;
+
+    uint32_t entry_count = romfs_read_u32(data + 8);
+    uint32_t archive_size = romfs_read_u32(data + 12);
+    uint32_t names_offset = romfs_read_u32(data + 16);
+
+    // The embedded array may be padded past the archive, but not cut short.
+    if(archive_size > size || entry_count == 0)
+        return -1;
+
+    // Entries come right after the header, then the names
+    if((uint64_t)ROMFS_HEADER_SIZE + (uint64_t)entry_count * ROMFS_ENTRY_SIZE != names_offset || names_offset >= archive_size)
+        return -1;
+
+    // Check every entry, so nothing can point out of the archive
+    for(uint32_t i = 0; i < entry_count; i++) {
+        const uint8_t* entry = data + ROMFS_HEADER_SIZE + i * ROMFS_ENTRY_SIZE;
+        uint32_t name_offset = romfs_read_u32(entry + 0);
+        uint32_t type = romfs_read_u32(entry + 4);
+        uint32_t a = romfs_read_u32(entry + 8);
+        uint32_t b = romfs_read_u32(entry + 12);
+
+        if(name_offset < names_offset || name_offset >= archive_size)
+            return -1;
+
+        if(memchr(data + name_offset, '\0', archive_size - name_offset) == NULL)
+            return -1;
+
+        if(type == ROMFS_TYPE_FILE) {
+            if(i == ROMFS_ROOT || a < names_offset || (uint64_t)a + b > archive_size)
+                return -1;
+        } else if(type == ROMFS_TYPE_DIRECTORY) {
+            // The root can not be a child, and children are entries
+            if(b > 0 && (a == ROMFS_ROOT || (uint64_t)a + b > entry_count))
+                return -1;
+        } else {
+            return -1;
+        }
+    }
+
+    romfs->image = data;
+    romfs->size = archive_size;
+    romfs->entry_count = entry_count;
+
+    return 0;
+}
+
+int romfs_get_entry(const romfs_t* romfs, uint32_t index, romfs_entry_t* entry) {
+    if(index >= romfs->entry_count)
+        return -1;
+
+    const uint8_t* data = romfs->image + ROMFS_HEADER_SIZE + index * ROMFS_ENTRY_SIZE;
+    uint32_t a = romfs_read_u32(data + 8);
+    uint32_t b = romfs_read_u32(data + 12);
+
+    memset(entry, 0, sizeof(*entry));
+    entry->index = index;
+    entry->name = (const char*)romfs->image + romfs_read_u32(data + 0);
+    entry->directory = romfs_read_u32(data + 4) == ROMFS_TYPE_DIRECTORY;
+
+    if(entry->directory) {
+        entry->first_child = a;
+        entry->child_count = b;
+    } else {
+        entry->data = romfs->image + a;
+        entry->size = b;
+    }
+
+    return 0;
+}
+
+int romfs_find(const romfs_t* romfs, const char* path, romfs_entry_t* entry) {
+    romfs_get_entry(romfs, ROMFS_ROOT, entry);
+
+    while(*path != '\0') {
+        while(*path == '/')
+            path++;
+
+        size_t part = strcspn(path, "/");
+        if(part == 0)
+            break;
+
+        if(!entry->directory) {
+            errno = ENOTDIR;
+            return -1;
+        }
+
+        // Children are sorted by name, so search by halves
+        uint32_t low = entry->first_child;
+        uint32_t high = entry->first_child + entry->child_count;
+        bool found = false;
+
+        while(low < high) {
+            uint32_t middle = low + (high - low) / 2;
+            romfs_entry_t child;
+            romfs_get_entry(romfs, middle, &child);
+
+            int compare = strncmp(child.name, path, part);
+            if(compare == 0 && child.name[part] != '\0')
+                compare = 1; // Longer names come after
+
+            if(compare == 0) {
+                *entry = child;
+                found = true;
+                break;
+            } else if(compare < 0) {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+
+        if(!found) {
+            errno = ENOENT;
+            return -1;
+        }
+
+        path += part;
+    }
+
+    return 0;
+}
@@ -0,0 +1,85 @@
+/**
+ * @file romfs.h
+ * @brief Read only file system built into the app
+ *
+ * Reads archives made by tools/romfs/romfs_pack.py, see it for the format.
+ * Archives are linked into the app with romfs_embed in CMake,
+ * and mounted into the VFS with vfs_romfs.h.
+ *
+ * File data is kept 32 byte aligned, so it can be used in place,
+ * like handing textures straight to GX.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include <stdint.h>
+#include <stdbool.h>
+#include <stddef.h>
+
+#define ROMFS_ALIGNMENT 32
+
+// Index of the root directory
+#define ROMFS_ROOT 0
+
+/**
+ * @struct romfs_t
+ * @brief A opened archive.
+ */
+typedef struct {
+    const uint8_t* image;
+    uint32_t size;
+    uint32_t entry_count;
+} romfs_t;
+
+/**
+ * @struct romfs_entry_t
+ * @brief A file or directory in the archive.
+ */
+typedef struct {
+    uint32_t index;
+    const char* name;
+    bool directory;
+
+    // Files only
+    const uint8_t* data;
+    uint32_t size;
+
+    // Directories only, children are next to each other
+    uint32_t first_child;
+    uint32_t child_count;
+} romfs_entry_t;
+
+/**
+ * @brief Opens a archive.
+ *
+ * Checks all of it, so nothing read later can be out of bounds.
+ *
+ * @param romfs Archive to open.
+ * @param image The archive, 32 byte aligned.
+ * @param size Size of the archive in bytes.
+ * @return Negative if not a valid archive.
+*/
+extern int romfs_open(romfs_t* romfs, const void* image, size_t size);
+
+/**
+ * @brief Gets a entry by its index.
+ *
+ * @return Negative if the index is out of range.
+*/
+extern int romfs_get_entry(const romfs_t* romfs, uint32_t index, romfs_entry_t* entry);
+
+/**
+ * @brief Finds a entry by its path.
+ *
+ * Paths are from the root, "." and ".." are not followed.
+ *
+ * @param romfs Archive to search.
+ * @param path Path like "/textures/wood.tpl".
+ * @param entry Filled with the entry found.
+ * @return Negative with errno set to ENOENT or ENOTDIR if not found.
+*/
+extern int romfs_find(const romfs_t* romfs, const char* path, romfs_entry_t* entry);
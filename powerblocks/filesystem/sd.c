@@ -9,6 +9,7 @@
  * @license MIT (see LICENSE file)
  */
 #include "sd.h"
+#include "fatfs_port/sd_disk.h"
 
 #include "powerblocks/core/ios/sdio.h"
 #include "powerblocks/core/utils/log.h"
@@ -11,7 +11,8 @@
 
 #pragma once
 
-#include "diskio.h"
+// FatFs physical drive of the SD card, see diskio.c
+#define DISK_DEV_SD 0
 
 /**
  * @brief Initializes the sd card system.
@@ -29,30 +30,3 @@ extern int sd_initialize();
  * @brief Closes it out
 */
 extern void sd_close();
-
-/**
- * @brief FatFS disk_status implementation.
-*/
-extern DSTATUS sd_disk_status();
-
-/**
- * @brief FatFS disk_initialize implementation.
- * 
- * Called on mount after successful status reading.
-*/
-extern DSTATUS sd_disk_initialize();
-
-/**
- * @brief FatFS disk_read implementation
- */
-extern DRESULT sd_disk_read(BYTE* buff, LBA_t sector, UINT count);
-
-/**
- * @brief FatFS disk_read implementation
- */
-extern DRESULT sd_disk_write(const BYTE* buff, LBA_t sector, UINT count);
-
-/**
- * @brief FatFS disk_ioctl implementation.
- */
-extern DRESULT sd_disk_ioctl(BYTE cmd, void* buff);
\ No newline at end of file
@@ -0,0 +1,243 @@
+/**
+ * @file romfs_test.c
+ * @brief Tests of the romfs packer and reader.
+ *
+ * Runs on the host. The data directory is packed by romfs_pack.py
+ * at build time, then read back through the VFS and checked
+ * against the files on disk.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "romfs.h"
+#include "vfs.h"
+#include "vfs_romfs.h"
+
+#include "test_romfs.h"
+#include "test.h"
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <errno.h>
+#include <fcntl.h>
+#include <dirent.h>
+#include <sys/stat.h>
+
+static int compare_names(const void* a, const void* b) {
+    return strcmp(*(const char**)a, *(const char**)b);
+}
+
+// Reads a file on the host
+static uint8_t* read_host_file(const char* path, size_t* size) {
+    FILE* fp = fopen(path, "rb");
+    if(fp == NULL)
+        return NULL;
+
+    fseek(fp, 0, SEEK_END);
+    *size = ftell(fp);
+    fseek(fp, 0, SEEK_SET);
+
+    uint8_t* data = malloc(*size + 1);
+    if(fread(data, 1, *size, fp) != *size) {
+        free(data);
+        data = NULL;
+    }
+
+    fclose(fp);
+    return data;
+}
+
+static void check_file(const char* host_path, const char* romfs_path) {
+    size_t expected_size;
+    uint8_t* expected = read_host_file(host_path, &expected_size);
+    CHECK(expected != NULL);
+    if(expected == NULL)
+        return;
+
+    struct stat st;
+    CHECK(vfs_stat(romfs_path, &st) == 0);
+    CHECK(S_ISREG(st.st_mode));
+    CHECK(st.st_size == expected_size);
+
+    int fd = vfs_open(romfs_path, O_RDONLY, 0);
+    CHECK(fd >= 0);
+    if(fd < 0) {
+        free(expected);
+        return;
+    }
+
+    // Read in odd sized pieces to cross the ends of reads
+    uint8_t* actual = malloc(expected_size + 64);
+    size_t total = 0;
+    ssize_t got;
+    while((got = vfs_read(fd, actual + total, 7)) > 0)
+        total += got;
+
+    CHECK(got == 0);
+    CHECK(total == expected_size);
+    CHECK(memcmp(actual, expected, expected_size) == 0);
+
+    CHECK(vfs_fstat(fd, &st) == 0 && st.st_size == expected_size);
+    CHECK(vfs_close(fd) == 0);
+
+    free(actual);
+    free(expected);
+}
+
+// Checks a directory and everything in it matches the host
+static void check_directory(const char* host_path, const char* romfs_path) {
+    DIR* host_dir = opendir(host_path);
+    CHECK(host_dir != NULL);
+    if(host_dir == NULL)
+        return;
+
+    char* names[64];
+    int count = 0;
+
+    struct dirent* host_entry;
+    while((host_entry = readdir(host_dir)) != NULL && count < 64) {
+        if(strcmp(host_entry->d_name, ".") == 0 || strcmp(host_entry->d_name, "..") == 0)
+            continue;
+        names[count++] = strdup(host_entry->d_name);
+    }
+    closedir(host_dir);
+
+    // romfs lists them sorted
+    qsort(names, count, sizeof(names[0]), compare_names);
+
+    vfs_dir_t* dir = vfs_opendir(romfs_path);
+    CHECK(dir != NULL);
+    if(dir == NULL)
+        return;
+
+    for(int i = 0; i < count; i++) {
+        struct dirent* entry = vfs_readdir(dir);
+        CHECK(entry != NULL);
+        if(entry == NULL)
+            break;
+
+        CHECK(strcmp(entry->d_name, names[i]) == 0);
+
+        char host_child[512];
+        char romfs_child[512];
+        snprintf(host_child, sizeof(host_child), "%s/%s", host_path, names[i]);
+        snprintf(romfs_child, sizeof(romfs_child), "%s/%s", romfs_path, names[i]);
+
+        struct stat st;
+        CHECK(stat(host_child, &st) == 0);
+        if(S_ISDIR(st.st_mode)) {
+            CHECK(entry->d_type == DT_DIR);
+            check_directory(host_child, romfs_child);
+        } else {
+            CHECK(entry->d_type == DT_REG);
+            check_file(host_child, romfs_child);
+        }
+    }
+
+    CHECK(vfs_readdir(dir) == NULL);
+
+    // Listing again starts over
+    vfs_rewinddir(dir);
+    CHECK(count == 0 || (vfs_readdir(dir) != NULL));
+
+    CHECK(vfs_closedir(dir) == 0);
+
+    for(int i = 0; i < count; i++)
+        free(names[i]);
+}
+
+static void test_archive() {
+    romfs_t romfs;
+    CHECK(((uintptr_t)test_romfs % ROMFS_ALIGNMENT) == 0);
+    CHECK((test_romfs_size % ROMFS_ALIGNMENT) == 0);
+    CHECK(romfs_open(&romfs, test_romfs, test_romfs_size) == 0);
+
+    // File data can be used in place
+    romfs_entry_t entry;
+    CHECK(romfs_find(&romfs, "/textures/gradient.bin", &entry) == 0);
+    CHECK(!entry.directory);
+    CHECK(((uintptr_t)entry.data % ROMFS_ALIGNMENT) == 0);
+    CHECK(entry.size == 805 && entry.data[255] == 255 && entry.data[804] == 0xAB);
+
+    CHECK(romfs_find(&romfs, "/", &entry) == 0 && entry.directory && entry.index == ROMFS_ROOT);
+    CHECK(romfs_find(&romfs, "/levels/world1", &entry) == 0 && entry.directory && entry.child_count == 2);
+    CHECK(romfs_find(&romfs, "/levels/world3", &entry) < 0 && errno == ENOENT);
+    CHECK(romfs_find(&romfs, "/hello.txt/more", &entry) < 0 && errno == ENOTDIR);
+    CHECK(romfs_find(&romfs, "/hello", &entry) < 0 && errno == ENOENT);
+
+    // Broken archives are turned away
+    uint8_t* copy = malloc(test_romfs_size);
+    memcpy(copy, test_romfs, test_romfs_size);
+    CHECK(romfs_open(&romfs, copy, test_romfs_size - 32) < 0);
+    copy[0] = 'X';
+    CHECK(romfs_open(&romfs, copy, test_romfs_size) < 0);
+    copy[0] = 'P';
+    copy[32] = 0xFF; // Root's name out of the archive
+    CHECK(romfs_open(&romfs, copy, test_romfs_size) < 0);
+    free(copy);
+
+    CHECK(romfs_open(&romfs, test_romfs, 16) < 0);
+}
+
+static void test_vfs() {
+    CHECK(vfs_romfs_mount("romfs", test_romfs, test_romfs_size) == 0);
+    CHECK(vfs_romfs_mount("romfs", test_romfs, test_romfs_size) < 0 && errno == EEXIST);
+
+    check_directory(ROMFS_TEST_DATA, "romfs:");
+
+    // Relative paths, from the current directory
+    CHECK(vfs_chdir("romfs:/levels/world1") == 0);
+    check_file(ROMFS_TEST_DATA "/levels/world1/level2.txt", "level2.txt");
+    check_file(ROMFS_TEST_DATA "/hello.txt", "../../hello.txt");
+    CHECK(vfs_chdir("level1.txt") < 0 && errno == ENOTDIR);
+
+    // Reading and seeking at the end
+    int fd = vfs_open("/hello.txt", O_RDONLY, 0);
+    CHECK(fd >= 0);
+    char buffer[8];
+    CHECK(vfs_lseek(fd, -7, SEEK_END) == 15);
+    CHECK(vfs_read(fd, buffer, sizeof(buffer)) == 7 && memcmp(buffer, "romfs!\n", 7) == 0);
+    CHECK(vfs_read(fd, buffer, sizeof(buffer)) == 0);
+    CHECK(vfs_lseek(fd, 100, SEEK_SET) == 100 && vfs_read(fd, buffer, sizeof(buffer)) == 0);
+    CHECK(vfs_lseek(fd, -1, SEEK_SET) < 0 && errno == EINVAL);
+    CHECK(vfs_write(fd, "x", 1) < 0 && errno == EBADF);
+    CHECK(vfs_unmount("romfs") < 0 && errno == EBUSY);
+    CHECK(vfs_close(fd) == 0);
+
+    // Closed descriptors are handed out again
+    int first = vfs_open("/hello.txt", O_RDONLY, 0);
+    int second = vfs_open("/levels/world1/level1.txt", O_RDONLY, 0);
+    CHECK(first >= 0 && second >= 0 && first != second);
+    CHECK(vfs_close(first) == 0);
+    CHECK(vfs_read(first, buffer, sizeof(buffer)) < 0 && errno == EBADF);
+    CHECK(vfs_open("/hello.txt", O_RDONLY, 0) == first);
+    CHECK(vfs_read(first, buffer, sizeof(buffer)) == 8 && memcmp(buffer, "Hello fr", 8) == 0);
+    CHECK(vfs_close(first) == 0 && vfs_close(second) == 0);
+
+    // Nothing can change
+    CHECK(vfs_open("/hello.txt", O_RDWR, 0) < 0 && errno == EROFS);
+    CHECK(vfs_open("/new.txt", O_WRONLY | O_CREAT, 0666) < 0 && errno == EROFS);
+    CHECK(vfs_open("/missing.txt", O_RDONLY, 0) < 0 && errno == ENOENT);
+    CHECK(vfs_open("/textures", O_RDONLY, 0) < 0 && errno == EISDIR);
+    CHECK(vfs_opendir("/hello.txt") == NULL && errno == ENOTDIR);
+    CHECK(vfs_mkdir("/new", 0777) < 0 && errno == EROFS);
+    CHECK(vfs_unlink("/hello.txt") < 0 && errno == EROFS);
+    CHECK(vfs_rename("/hello.txt", "/bye.txt") < 0 && errno == EROFS);
+
+    struct stat st;
+    CHECK(vfs_stat("romfs:/textures/empty.bin", &st) == 0 && st.st_size == 0);
+    CHECK(vfs_stat("romfs:/", &st) == 0 && S_ISDIR(st.st_mode));
+
+    CHECK(vfs_unmount("romfs") == 0);
+    CHECK(vfs_stat("romfs:/hello.txt", &st) < 0 && errno == ENODEV);
+}
+
+int main() {
+    test_archive();
+    test_vfs();
+
+    return test_summary();
+}
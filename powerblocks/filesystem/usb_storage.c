@@ -0,0 +1,480 @@
+/**
+ * @file usb_storage.c
+ * @brief USB Mass Storage System
+ *
+ * Implements USB drives plugged into the Wii's USB ports.
+ * Speaks SCSI over the bulk only transport, through IOS.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+#include "usb_storage.h"
+#include "fatfs_port/usb_storage_disk.h"
+
+#include "powerblocks/core/ios/usb.h"
+#include "powerblocks/core/system/system.h"
+#include "powerblocks/core/utils/log.h"
+
+#include "FreeRTOS.h"
+#include "task.h"
+
+#include <stdbool.h>
+#include <string.h>
+
+static const char* TAG = "USB_STORAGE";
+
+#define USB_STORAGE_ERROR_LOGGING
+//#define USB_STORAGE_INFO_LOGGING
+
+#ifdef USB_STORAGE_ERROR_LOGGING
+#define USB_STORAGE_LOG_ERROR(fmt, ...) LOG_ERROR(TAG, fmt, ##__VA_ARGS__)
+#else
+#define USB_STORAGE_LOG_ERROR(fmt, ...)
+#endif
+
+#ifdef USB_STORAGE_INFO_LOGGING
+#define USB_STORAGE_LOG_INFO(fmt, ...) LOG_INFO(TAG, fmt, ##__VA_ARGS__)
+#else
+#define USB_STORAGE_LOG_INFO(fmt, ...)
+#endif
+
+// Only 512 byte sectors, the same as FatFs is built for
+#define USB_STORAGE_SECTOR_SIZE 512
+
+// Most sectors moved by one command, keeps transfers under IOS's 16 bit length
+#define USB_STORAGE_MAX_SECTORS 64
+
+// Mass storage interface, SCSI over bulk only transport
+#define USB_STORAGE_SUBCLASS_SCSI 0x06
+#define USB_STORAGE_PROTOCOL_BOT  0x50
+
+#define USB_STORAGE_REQUEST_RESET 0xFF
+
+// Command block wrapper, and command status wrapper
+#define USB_STORAGE_CBW_SIGNATURE 0x43425355 // "USBC"
+#define USB_STORAGE_CSW_SIGNATURE 0x53425355 // "USBS"
+#define USB_STORAGE_CBW_SIZE 31
+#define USB_STORAGE_CSW_SIZE 13
+
+#define USB_STORAGE_CSW_PASSED      0
+#define USB_STORAGE_CSW_FAILED      1
+
+// SCSI commands
+#define SCSI_TEST_UNIT_READY     0x00
+#define SCSI_REQUEST_SENSE       0x03
+#define SCSI_MODE_SENSE_6        0x1A
+#define SCSI_READ_CAPACITY_10    0x25
+#define SCSI_READ_10             0x28
+#define SCSI_WRITE_10            0x2A
+#define SCSI_SYNCHRONIZE_CACHE   0x35
+
+#define SCSI_SENSE_LENGTH 18
+
+// Drives can take a while to spin up after being plugged in
+#define USB_STORAGE_READY_TRIES 50
+#define USB_STORAGE_READY_DELAY_MS 100
+
+static struct {
+    bool initialized;
+    bool attached;
+    bool write_protect;
+
+    int device;
+    uint8_t interface;
+    uint8_t endpoint_in;
+    uint8_t endpoint_out;
+
+    uint32_t tag;
+    uint32_t sector_count;
+} usb_storage_state = { .device = -1 };
+
+// IOS DMAs all of these
+static uint8_t usb_storage_cbw[32] ALIGN(32);
+static uint8_t usb_storage_csw[32] ALIGN(32);
+static uint8_t usb_storage_buffer[USB_STORAGE_MAX_SECTORS * USB_STORAGE_SECTOR_SIZE] ALIGN(32);
+
+// The bulk only transport is little endian, SCSI is big endian.
+static void write_le32(uint8_t* data, uint32_t value) {
+    data[0] = value;
+    data[1] = value >> 8;
+    data[2] = value >> 16;
+    data[3] = value >> 24;
+}
+
+static uint32_t read_le32(const uint8_t* data) {
+    return data[0] | (data[1] << 8) | (data[2] << 16) | ((uint32_t)data[3] << 24);
+}
+
+static uint32_t read_be32(const uint8_t* data) {
+    return ((uint32_t)data[0] << 24) | (data[1] << 16) | (data[2] << 8) | data[3];
+}
+
+int usb_storage_initialize() {
+    int ret = usb_initialize();
+
+    if(ret < 0) {
+        return ret;
+    }
+
+    usb_storage_state.initialized = true;
+
+    return ret;
+}
+
+static void usb_storage_detach() {
+    if(usb_storage_state.device >= 0) {
+        usb_close_device(usb_storage_state.device);
+        usb_storage_state.device = -1;
+    }
+
+    usb_storage_state.attached = false;
+}
+
+void usb_storage_close() {
+    usb_storage_detach();
+    usb_close();
+    usb_storage_state.initialized = false;
+}
+
+static void usb_storage_clear_halt(uint8_t endpoint) {
+    usb_control_message(usb_storage_state.device, USB_REQTYPE_ENDPOINT_OUT, USB_REQUEST_CLEAR_FEATURE, USB_FEATURE_ENDPOINT_HALT, endpoint, 0, NULL);
+}
+
+// Gets the drive back in sync after the transport breaks
+static void usb_storage_reset_recovery() {
+    usb_control_message(usb_storage_state.device, USB_REQTYPE_CLASS_INTERFACE_OUT, USB_STORAGE_REQUEST_RESET, 0, usb_storage_state.interface, 0, NULL);
+    usb_storage_clear_halt(usb_storage_state.endpoint_in);
+    usb_storage_clear_halt(usb_storage_state.endpoint_out);
+}
+
+/**
+ * Runs a SCSI command over the bulk only transport.
+ *
+ * Returns negative if the transport failed, positive
+ * if the drive failed the command, or zero.
+ */
+static int usb_storage_command(const uint8_t* command, uint8_t command_length, void* data, uint32_t length, bool in) {
+    int ret;
+    int device = usb_storage_state.device;
+    uint32_t tag = ++usb_storage_state.tag;
+
+    memset(usb_storage_cbw, 0, USB_STORAGE_CBW_SIZE);
+    write_le32(usb_storage_cbw + 0, USB_STORAGE_CBW_SIGNATURE);
+    write_le32(usb_storage_cbw + 4, tag);
+    write_le32(usb_storage_cbw + 8, length);
+    usb_storage_cbw[12] = in ? USB_ENDPOINT_IN : 0;
+    usb_storage_cbw[13] = 0; // First LUN
+    usb_storage_cbw[14] = command_length;
+    memcpy(usb_storage_cbw + 15, command, command_length);
+
+    ret = usb_bulk_message(device, usb_storage_state.endpoint_out, USB_STORAGE_CBW_SIZE, usb_storage_cbw);
+    if(ret != USB_STORAGE_CBW_SIZE) {
+        USB_STORAGE_LOG_ERROR("Command 0x%02X not sent: %d", command[0], ret);
+        usb_storage_reset_recovery();
+        return -1;
+    }
+
+    int transferred = 0;
+    if(length > 0) {
+        uint8_t endpoint = in ? usb_storage_state.endpoint_in : usb_storage_state.endpoint_out;
+        transferred = usb_bulk_message(device, endpoint, length, data);
+
+        // A stalled endpoint ends the data early, the status still follows
+        if(transferred < 0)
+            usb_storage_clear_halt(endpoint);
+    }
+
+    ret = usb_bulk_message(device, usb_storage_state.endpoint_in, USB_STORAGE_CSW_SIZE, usb_storage_csw);
+    if(ret < 0) {
+        // Try once more after a stall
+        usb_storage_clear_halt(usb_storage_state.endpoint_in);
+        ret = usb_bulk_message(device, usb_storage_state.endpoint_in, USB_STORAGE_CSW_SIZE, usb_storage_csw);
+    }
+
+    if(ret != USB_STORAGE_CSW_SIZE || read_le32(usb_storage_csw + 0) != USB_STORAGE_CSW_SIGNATURE || read_le32(usb_storage_csw + 4) != tag) {
+        USB_STORAGE_LOG_ERROR("No status for command 0x%02X: %d", command[0], ret);
+        usb_storage_reset_recovery();
+        return -1;
+    }
+
+    switch(usb_storage_csw[12]) {
+        case USB_STORAGE_CSW_PASSED:
+            break;
+        case USB_STORAGE_CSW_FAILED:
+            return 1;
+        default:
+            // Phase error, the drive lost track
+            usb_storage_reset_recovery();
+            return -1;
+    }
+
+    if(transferred != (int)length) {
+        USB_STORAGE_LOG_ERROR("Command 0x%02X moved %d of %u bytes", command[0], transferred, (unsigned int)length);
+        return -1;
+    }
+
+    return 0;
+}
+
+// Gets the sense key of the last failed command, which also clears it
+static int usb_storage_request_sense() {
+    uint8_t command[6] = { SCSI_REQUEST_SENSE, 0, 0, 0, SCSI_SENSE_LENGTH, 0 };
+
+    int ret = usb_storage_command(command, sizeof(command), usb_storage_buffer, SCSI_SENSE_LENGTH, true);
+    if(ret != 0)
+        return -1;
+
+    return usb_storage_buffer[2] & 0x0F;
+}
+
+// Finds a SCSI bulk only interface in a configuration descriptor
+static bool usb_storage_find_interface(const uint8_t* config, uint32_t length) {
+    bool found = false;
+    bool in_interface = false;
+    uint8_t endpoint_in = 0;
+    uint8_t endpoint_out = 0;
+
+    uint32_t offset = 0;
+    while(offset + 2 <= length && !found) {
+        const uint8_t* descriptor = config + offset;
+        uint8_t descriptor_length = descriptor[0];
+
+        if(descriptor_length < 2 || offset + descriptor_length > length)
+            break;
+
+        if(descriptor[1] == USB_DESCRIPTOR_INTERFACE && descriptor_length >= 9) {
+            in_interface = descriptor[5] == USB_CLASS_MASS_STORAGE
+                && descriptor[6] == USB_STORAGE_SUBCLASS_SCSI
+                && descriptor[7] == USB_STORAGE_PROTOCOL_BOT;
+
+            usb_storage_state.interface = descriptor[2];
+            endpoint_in = 0;
+            endpoint_out = 0;
+        } else if(descriptor[1] == USB_DESCRIPTOR_ENDPOINT && descriptor_length >= 7 && in_interface) {
+            if((descriptor[3] & USB_ENDPOINT_TYPE_MASK) == USB_ENDPOINT_TYPE_BULK) {
+                if(descriptor[2] & USB_ENDPOINT_IN)
+                    endpoint_in = descriptor[2];
+                else
+                    endpoint_out = descriptor[2];
+            }
+
+            found = endpoint_in != 0 && endpoint_out != 0;
+        }
+
+        offset += descriptor_length;
+    }
+
+    usb_storage_state.endpoint_in = endpoint_in;
+    usb_storage_state.endpoint_out = endpoint_out;
+
+    return found;
+}
+
+// Opens a device, and sets it up if it is a drive
+static int usb_storage_attach(const usb_device_entry_t* entry) {
+    int ret;
+
+    usb_storage_state.device = usb_open_device(entry->vendor_id, entry->product_id);
+    if(usb_storage_state.device < 0)
+        return -1;
+
+    // The start of the configuration gives its full length
+    ret = usb_control_message(usb_storage_state.device, USB_REQTYPE_DEVICE_IN, USB_REQUEST_GET_DESCRIPTOR, USB_DESCRIPTOR_CONFIGURATION << 8, 0, 9, usb_storage_buffer);
+    if(ret < 9) {
+        USB_STORAGE_LOG_ERROR("Failed to get configuration of %04x:%04x: %d", entry->vendor_id, entry->product_id, ret);
+        usb_storage_detach();
+        return -1;
+    }
+
+    uint8_t configuration = usb_storage_buffer[5];
+    uint16_t total_length = usb_storage_buffer[2] | (usb_storage_buffer[3] << 8);
+    if(total_length > sizeof(usb_storage_buffer))
+        total_length = sizeof(usb_storage_buffer);
+
+    ret = usb_control_message(usb_storage_state.device, USB_REQTYPE_DEVICE_IN, USB_REQUEST_GET_DESCRIPTOR, USB_DESCRIPTOR_CONFIGURATION << 8, 0, total_length, usb_storage_buffer);
+    if(ret < 0 || !usb_storage_find_interface(usb_storage_buffer, ret)) {
+        // Not a drive
+        usb_storage_detach();
+        return -1;
+    }
+
+    ret = usb_control_message(usb_storage_state.device, USB_REQTYPE_DEVICE_OUT, USB_REQUEST_SET_CONFIGURATION, configuration, 0, 0, NULL);
+    if(ret < 0) {
+        USB_STORAGE_LOG_ERROR("Failed to set configuration: %d", ret);
+        usb_storage_detach();
+        return -1;
+    }
+
+    USB_STORAGE_LOG_INFO("Drive %04x:%04x on interface %d", entry->vendor_id, entry->product_id, usb_storage_state.interface);
+
+    // Wait until the drive is ready, asking for the sense clears unit attention
+    for(int i = 0; i < USB_STORAGE_READY_TRIES; i++) {
+        uint8_t command[6] = { SCSI_TEST_UNIT_READY };
+        ret = usb_storage_command(command, sizeof(command), NULL, 0, false);
+        if(ret <= 0)
+            break;
+
+        usb_storage_request_sense();
+        vTaskDelay(USB_STORAGE_READY_DELAY_MS / portTICK_PERIOD_MS);
+    }
+
+    if(ret != 0) {
+        USB_STORAGE_LOG_ERROR("Drive never ready!");
+        usb_storage_detach();
+        return -1;
+    }
+
+    uint8_t capacity_command[10] = { SCSI_READ_CAPACITY_10 };
+    ret = usb_storage_command(capacity_command, sizeof(capacity_command), usb_storage_buffer, 8, true);
+    if(ret != 0) {
+        USB_STORAGE_LOG_ERROR("Failed to read capacity");
+        usb_storage_detach();
+        return -1;
+    }
+
+    // Given as the last sector, all ones if it needs READ CAPACITY(16) past 2TB
+    uint32_t last_sector = read_be32(usb_storage_buffer + 0);
+    uint32_t sector_size = read_be32(usb_storage_buffer + 4);
+    if(sector_size != USB_STORAGE_SECTOR_SIZE || last_sector == UINT32_MAX) {
+        USB_STORAGE_LOG_ERROR("Unsupported drive, %u sectors of %u bytes", (unsigned int)last_sector, (unsigned int)sector_size);
+        usb_storage_detach();
+        return -1;
+    }
+
+    usb_storage_state.sector_count = last_sector + 1;
+
+    // Not every drive has mode pages, those are taken as writable
+    uint8_t mode_command[6] = { SCSI_MODE_SENSE_6, 0, 0x3F, 0, 4, 0 };
+    ret = usb_storage_command(mode_command, sizeof(mode_command), usb_storage_buffer, 4, true);
+    usb_storage_state.write_protect = ret == 0 && (usb_storage_buffer[2] & 0x80);
+    if(ret > 0)
+        usb_storage_request_sense();
+
+    usb_storage_state.attached = true;
+    return 0;
+}
+
+DSTATUS usb_storage_disk_status() {
+    if(!usb_storage_state.initialized || !usb_storage_state.attached) {
+        return STA_NOINIT;
+    }
+
+    return usb_storage_state.write_protect ? STA_PROTECT : 0;
+}
+
+DSTATUS usb_storage_disk_initialize() {
+    if(!usb_storage_state.initialized) {
+        return STA_NOINIT;
+    }
+
+    USB_STORAGE_LOG_INFO("Mounting USB drive.");
+
+    // Start over, the drive may have been swapped
+    usb_storage_detach();
+
+    usb_device_entry_t devices[USB_MAX_DEVICES];
+    int count = usb_get_device_list(0, devices, USB_MAX_DEVICES);
+
+    for(int i = 0; i < count; i++) {
+        if(usb_storage_attach(&devices[i]) == 0)
+            return usb_storage_disk_status();
+    }
+
+    return STA_NOINIT | STA_NODISK;
+}
+
+// Reads or writes sectors in pieces that fit in one command
+static DRESULT usb_storage_transfer(uint8_t opcode, BYTE* buff, LBA_t sector, UINT count) {
+    if(!usb_storage_state.attached)
+        return RES_NOTRDY;
+
+    bool in = opcode == SCSI_READ_10;
+
+    while(count > 0) {
+        UINT sectors = count < USB_STORAGE_MAX_SECTORS ? count : USB_STORAGE_MAX_SECTORS;
+        uint32_t length = sectors * USB_STORAGE_SECTOR_SIZE;
+
+        // IOS can only DMA aligned buffers
+        bool bounce = ((uintptr_t)buff % 32) != 0;
+        uint8_t* data = bounce ? usb_storage_buffer : buff;
+        if(bounce && !in)
+            memcpy(usb_storage_buffer, buff, length);
+
+        uint8_t command[10] = {
+            opcode, 0,
+            sector >> 24, sector >> 16, sector >> 8, sector,
+            0,
+            sectors >> 8, sectors,
+            0
+        };
+
+        int ret = usb_storage_command(command, sizeof(command), data, length, in);
+        if(ret != 0) {
+            USB_STORAGE_LOG_ERROR("Disk %s failed on sector: %u", in ? "read" : "write", (unsigned int)sector);
+
+            if(ret > 0) {
+                usb_storage_request_sense();
+            } else {
+                // Most likely pulled out, mount again to find it.
+                usb_storage_detach();
+            }
+
+            return RES_ERROR;
+        }
+
+        if(bounce && in)
+            memcpy(buff, usb_storage_buffer, length);
+
+        buff += length;
+        sector += sectors;
+        count -= sectors;
+    }
+
+    return RES_OK;
+}
+
+DRESULT usb_storage_disk_read(BYTE* buff, LBA_t sector, UINT count) {
+    return usb_storage_transfer(SCSI_READ_10, buff, sector, count);
+}
+
+DRESULT usb_storage_disk_write(const BYTE* buff, LBA_t sector, UINT count) {
+    if(usb_storage_state.write_protect)
+        return RES_WRPRT;
+
+    return usb_storage_transfer(SCSI_WRITE_10, (BYTE*)buff, sector, count);
+}
+
+DRESULT usb_storage_disk_ioctl(BYTE cmd, void* buff) {
+    switch (cmd) {
+        case CTRL_SYNC: {
+            if(!usb_storage_state.attached)
+                return RES_NOTRDY;
+
+            // Plenty of drives have no cache to flush and fail this, that is fine.
+            uint8_t command[10] = { SCSI_SYNCHRONIZE_CACHE };
+            int ret = usb_storage_command(command, sizeof(command), NULL, 0, false);
+            if(ret > 0)
+                usb_storage_request_sense();
+
+            return ret < 0 ? RES_ERROR : RES_OK;
+        }
+
+        case GET_SECTOR_COUNT:
+            *(LBA_t*)buff = usb_storage_state.sector_count;
+            return RES_OK;
+
+        case GET_SECTOR_SIZE:
+            *(WORD*)buff = USB_STORAGE_SECTOR_SIZE;
+            return RES_OK;
+
+        case GET_BLOCK_SIZE:
+            *(DWORD*)buff = 1;
+            return RES_OK;
+
+        // Not supported
+        default:
+            USB_STORAGE_LOG_ERROR("Unsupported FatFS IOCTL %d", cmd);
+            return RES_PARERR;
+    }
+}
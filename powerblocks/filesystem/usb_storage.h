@@ -0,0 +1,32 @@
+/**
+ * @file usb_storage.h
+ * @brief USB Mass Storage System
+ *
+ * Implements USB drives plugged into the Wii's USB ports.
+ * Speaks SCSI over the bulk only transport, through IOS.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+// FatFs physical drive of the USB drive, see diskio.c
+#define DISK_DEV_USB 1
+
+/**
+ * @brief Initializes the USB storage system.
+ * 
+ * Must be called before mounting the file system.
+ * The drive itself is found when it is mounted, the first
+ * mass storage device plugged in is used.
+ * 
+ * @return Negative if error.
+*/
+extern int usb_storage_initialize();
+
+/**
+ * @brief Closes it out
+*/
+extern void usb_storage_close();
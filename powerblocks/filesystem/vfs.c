@@ -0,0 +1,832 @@
+/**
+ * @file vfs.c
+ * @brief Virtual file system
+ *
+ * Routes paths to file system drivers by the device at the start
+ * of the path. See vfs.h.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "vfs.h"
+
+#include <stdlib.h>
+#include <string.h>
+#include <errno.h>
+#include <fcntl.h>
+#include <dirent.h>
+#include <sys/stat.h>
+
+#include "FreeRTOS.h"
+#include "semphr.h"
+#include "task.h"
+
+typedef struct {
+    bool used;
+    char name[VFS_MAX_NAME];
+    const vfs_driver_t* driver;
+    void* context;
+
+    // Open files and directories, it can not be unmounted while any are.
+    int open_count;
+} vfs_mount_t;
+
+typedef struct {
+    bool used;
+    vfs_mount_t* mount;
+    int fd; // The driver's descriptor
+} vfs_file_t;
+
+struct vfs_dir {
+    vfs_mount_t* mount;
+    void* handle;
+    struct dirent entry;
+};
+
+static vfs_mount_t vfs_mounts[VFS_MAX_MOUNTS];
+
+static vfs_descriptor_table_t vfs_file_table = { .entry_size = sizeof(vfs_file_t) };
+
+// Current directory, the device and the absolute path on it.
+// A empty device when nothing is mounted.
+static char vfs_cwd_device[VFS_MAX_NAME];
+static char vfs_cwd_path[VFS_MAX_PATH];
+
+// Guards everything above, and the drivers behind the mounts.
+static SemaphoreHandle_t vfs_lock_handle;
+static StaticSemaphore_t vfs_lock_data;
+
+static void vfs_lock() {
+    // Anything could be first to use the VFS, so make it then
+    if(vfs_lock_handle == NULL) {
+        taskENTER_CRITICAL();
+        if(vfs_lock_handle == NULL)
+            vfs_lock_handle = xSemaphoreCreateMutexStatic(&vfs_lock_data);
+        taskEXIT_CRITICAL();
+    }
+
+    xSemaphoreTake(vfs_lock_handle, portMAX_DELAY);
+}
+
+static void vfs_unlock() {
+    xSemaphoreGive(vfs_lock_handle);
+}
+
+static vfs_mount_t* vfs_find_mount(const char* name) {
+    for(int i = 0; i < VFS_MAX_MOUNTS; i++) {
+        if(vfs_mounts[i].used && strcmp(vfs_mounts[i].name, name) == 0)
+            return &vfs_mounts[i];
+    }
+
+    return NULL;
+}
+
+void vfs_descriptor_table_init(vfs_descriptor_table_t* table, size_t entry_size) {
+    table->entries = NULL;
+    table->entry_size = entry_size;
+    table->size = 0;
+}
+
+void vfs_descriptor_table_free(vfs_descriptor_table_t* table) {
+    free(table->entries);
+    table->entries = NULL;
+    table->size = 0;
+}
+
+static bool* vfs_descriptor_used(const vfs_descriptor_table_t* table, int fd) {
+    return (bool*)((uint8_t*)table->entries + fd * table->entry_size);
+}
+
+// Find and mark an entry as used, or allocate a new one if needed
+int vfs_descriptor_allocate(vfs_descriptor_table_t* table) {
+    for(int i = 0; i < table->size; i++) {
+        if(!*vfs_descriptor_used(table, i)) {
+            * vfs_descriptor_used(table, i) = true;
+            return i;
+        }
+    }
+
+    int entry = table->size;
+
+    void* new_entries = realloc(table->entries, (table->size + 1) * table->entry_size);
+    if(new_entries == NULL) {
+        errno = EMFILE;
+        return -1;
+    }
+
+    table->size++;
+    table->entries = new_entries;
+
+    * vfs_descriptor_used(table, entry) = true;
+    return entry;
+}
+
+void vfs_descriptor_free(vfs_descriptor_table_t* table, int fd) {
+    * vfs_descriptor_used(table, fd) = false;
+}
+
+void* vfs_descriptor_get(const vfs_descriptor_table_t* table, int fd) {
+    if(fd < 0 || fd >= table->size || !*vfs_descriptor_used(table, fd)) {
+        errno = EBADF;
+        return NULL;
+    }
+
+    return vfs_descriptor_used(table, fd);
+}
+
+void vfs_fill_dirent(struct dirent* entry, ino_t ino, const char* name, bool directory) {
+    entry->d_ino = ino;
+    entry->d_type = directory ? DT_DIR : DT_REG;
+    strncpy(entry->d_name, name, sizeof(entry->d_name) - 1);
+    entry->d_name[sizeof(entry->d_name) - 1] = '\0';
+}
+
+// Gets the file of a descriptor, or NULL with errno set
+static vfs_file_t* vfs_get_file(int fd) {
+    return (vfs_file_t*)vfs_descriptor_get(&vfs_file_table, fd);
+}
+
+// Adds the parts of a path onto a absolute one, following "." and "..".
+static int vfs_append_path(char* resolved, const char* path) {
+    size_t length = strlen(resolved);
+
+    while(*path != '\0') {
+        while(*path == '/')
+            path++;
+
+        size_t part = strcspn(path, "/");
+        if(part == 0)
+            break;
+
+        if(part == 1 && path[0] == '.') {
+            // Stays here
+        } else if(part == 2 && path[0] == '.' && path[1] == '.') {
+            // Back to the parent, the root is its own parent
+            while(length > 1 && resolved[length - 1] != '/')
+                length--;
+            if(length > 1)
+                length--;
+            resolved[length] = '\0';
+        } else {
+            bool slash = length > 1;
+            if(length + slash + part >= VFS_MAX_PATH) {
+                errno = ENAMETOOLONG;
+                return -1;
+            }
+
+            if(slash)
+                resolved[length++] = '/';
+            memcpy(resolved + length, path, part);
+            length += part;
+            resolved[length] = '\0';
+        }
+
+        path += part;
+    }
+
+    return 0;
+}
+
+// Finds the mount of a path and its absolute path on it.
+// resolved must be VFS_MAX_PATH long.
+static vfs_mount_t* vfs_resolve(const char* path, char* resolved) {
+    if(path == NULL) {
+        errno = EFAULT;
+        return NULL;
+    }
+
+    if(path[0] == '\0') {
+        errno = ENOENT;
+        return NULL;
+    }
+
+    char device[VFS_MAX_NAME];
+    strcpy(resolved, "/");
+
+    // A device is named before the first colon, if no slash comes first.
+    const char* colon = strchr(path, ':');
+    const char* slash = strchr(path, '/');
+    if(colon != NULL && (slash == NULL || colon < slash)) {
+        size_t length = colon - path;
+        if(length >= VFS_MAX_NAME) {
+            errno = ENODEV;
+            return NULL;
+        }
+
+        memcpy(device, path, length);
+        device[length] = '\0';
+        path = colon + 1;
+    } else {
+        strcpy(device, vfs_cwd_device);
+        if(path[0] != '/')
+            strcpy(resolved, vfs_cwd_path);
+    }
+
+    vfs_mount_t* mount = vfs_find_mount(device);
+    if(mount == NULL) {
+        errno = ENODEV;
+        return NULL;
+    }
+
+    if(vfs_append_path(resolved, path) < 0)
+        return NULL;
+
+    return mount;
+}
+
+static int vfs_mount_locked(const char* name, const vfs_driver_t* driver, void* context) {
+    if(name == NULL || driver == NULL) {
+        errno = EINVAL;
+        return -1;
+    }
+
+    size_t length = strlen(name);
+    if(length == 0 || length >= VFS_MAX_NAME || strpbrk(name, ":/") != NULL) {
+        errno = EINVAL;
+        return -1;
+    }
+
+    if(vfs_find_mount(name) != NULL) {
+        errno = EEXIST;
+        return -1;
+    }
+
+    for(int i = 0; i < VFS_MAX_MOUNTS; i++) {
+        vfs_mount_t* mount = &vfs_mounts[i];
+        if(mount->used)
+            continue;
+
+        strcpy(mount->name, name);
+        mount->driver = driver;
+        mount->context = context;
+        mount->open_count = 0;
+        mount->used = true;
+
+        // Nothing to be relative to yet, so start at its root
+        if(vfs_cwd_device[0] == '\0') {
+            strcpy(vfs_cwd_device, name);
+            strcpy(vfs_cwd_path, "/");
+        }
+
+        return 0;
+    }
+
+    errno = ENOMEM;
+    return -1;
+}
+
+static int vfs_unmount_locked(const char* name) {
+    vfs_mount_t* mount = vfs_find_mount(name);
+    if(mount == NULL) {
+        errno = ENODEV;
+        return -1;
+    }
+
+    if(mount->open_count > 0) {
+        errno = EBUSY;
+        return -1;
+    }
+
+    mount->used = false;
+    if(mount->driver->unmount != NULL)
+        mount->driver->unmount(mount->context);
+
+    // Move the current directory to the root of what is left
+    if(strcmp(vfs_cwd_device, name) == 0) {
+        vfs_cwd_device[0] = '\0';
+        for(int i = 0; i < VFS_MAX_MOUNTS; i++) {
+            if(vfs_mounts[i].used) {
+                strcpy(vfs_cwd_device, vfs_mounts[i].name);
+                break;
+            }
+        }
+        strcpy(vfs_cwd_path, "/");
+    }
+
+    return 0;
+}
+
+static bool vfs_is_mounted_locked(const char* name) {
+    return vfs_find_mount(name) != NULL;
+}
+
+static int vfs_open_locked(const char* path, int flags, mode_t mode) {
+    char resolved[VFS_MAX_PATH];
+    vfs_mount_t* mount = vfs_resolve(path, resolved);
+    if(mount == NULL)
+        return -1;
+
+    if(mount->driver->open == NULL) {
+        errno = ENOTSUP;
+        return -1;
+    }
+
+    int fd = vfs_descriptor_allocate(&vfs_file_table);
+    if(fd < 0)
+        return -1;
+
+    int driver_fd = mount->driver->open(mount->context, resolved, flags, mode);
+    if(driver_fd < 0) {
+        vfs_descriptor_free(&vfs_file_table, fd);
+        return -1;
+    }
+
+    vfs_file_t* file = vfs_get_file(fd);
+    file->mount = mount;
+    file->fd = driver_fd;
+    mount->open_count++;
+
+    return fd;
+}
+
+static int vfs_close_locked(int fd) {
+    vfs_file_t* file = vfs_get_file(fd);
+    if(file == NULL)
+        return -1;
+
+    vfs_mount_t* mount = file->mount;
+    int ret = mount->driver->close != NULL ? mount->driver->close(mount->context, file->fd) : 0;
+
+    // The descriptor is gone even if closing failed
+    vfs_descriptor_free(&vfs_file_table, fd);
+    mount->open_count--;
+
+    return ret;
+}
+
+static ssize_t vfs_read_locked(int fd, void* buffer, size_t count) {
+    vfs_file_t* file = vfs_get_file(fd);
+    if(file == NULL)
+        return -1;
+
+    if(file->mount->driver->read == NULL) {
+        errno = EBADF;
+        return -1;
+    }
+
+    return file->mount->driver->read(file->mount->context, file->fd, buffer, count);
+}
+
+static ssize_t vfs_write_locked(int fd, const void* buffer, size_t count) {
+    vfs_file_t* file = vfs_get_file(fd);
+    if(file == NULL)
+        return -1;
+
+    if(file->mount->driver->write == NULL) {
+        errno = EBADF;
+        return -1;
+    }
+
+    return file->mount->driver->write(file->mount->context, file->fd, buffer, count);
+}
+
+static off_t vfs_lseek_locked(int fd, off_t offset, int whence) {
+    vfs_file_t* file = vfs_get_file(fd);
+    if(file == NULL)
+        return -1;
+
+    if(file->mount->driver->lseek == NULL) {
+        errno = ESPIPE;
+        return -1;
+    }
+
+    return file->mount->driver->lseek(file->mount->context, file->fd, offset, whence);
+}
+
+static int vfs_fstat_locked(int fd, struct stat* st) {
+    vfs_file_t* file = vfs_get_file(fd);
+    if(file == NULL)
+        return -1;
+
+    if(file->mount->driver->fstat == NULL) {
+        errno = ENOTSUP;
+        return -1;
+    }
+
+    return file->mount->driver->fstat(file->mount->context, file->fd, st);
+}
+
+static int vfs_fsync_locked(int fd) {
+    vfs_file_t* file = vfs_get_file(fd);
+    if(file == NULL)
+        return -1;
+
+    // Nothing to write back
+    if(file->mount->driver->fsync == NULL)
+        return 0;
+
+    return file->mount->driver->fsync(file->mount->context, file->fd);
+}
+
+static int vfs_ftruncate_locked(int fd, off_t length) {
+    vfs_file_t* file = vfs_get_file(fd);
+    if(file == NULL)
+        return -1;
+
+    if(file->mount->driver->ftruncate == NULL) {
+        errno = EROFS;
+        return -1;
+    }
+
+    return file->mount->driver->ftruncate(file->mount->context, file->fd, length);
+}
+
+static int vfs_stat_locked(const char* path, struct stat* st) {
+    char resolved[VFS_MAX_PATH];
+    vfs_mount_t* mount = vfs_resolve(path, resolved);
+    if(mount == NULL)
+        return -1;
+
+    if(mount->driver->stat == NULL) {
+        errno = ENOTSUP;
+        return -1;
+    }
+
+    return mount->driver->stat(mount->context, resolved, st);
+}
+
+static int vfs_mkdir_locked(const char* path, mode_t mode) {
+    char resolved[VFS_MAX_PATH];
+    vfs_mount_t* mount = vfs_resolve(path, resolved);
+    if(mount == NULL)
+        return -1;
+
+    if(mount->driver->mkdir == NULL) {
+        errno = EROFS;
+        return -1;
+    }
+
+    return mount->driver->mkdir(mount->context, resolved, mode);
+}
+
+static int vfs_rmdir_locked(const char* path) {
+    char resolved[VFS_MAX_PATH];
+    vfs_mount_t* mount = vfs_resolve(path, resolved);
+    if(mount == NULL)
+        return -1;
+
+    if(mount->driver->rmdir == NULL) {
+        errno = EROFS;
+        return -1;
+    }
+
+    return mount->driver->rmdir(mount->context, resolved);
+}
+
+static int vfs_unlink_locked(const char* path) {
+    char resolved[VFS_MAX_PATH];
+    vfs_mount_t* mount = vfs_resolve(path, resolved);
+    if(mount == NULL)
+        return -1;
+
+    if(mount->driver->unlink == NULL) {
+        errno = EROFS;
+        return -1;
+    }
+
+    return mount->driver->unlink(mount->context, resolved);
+}
+
+static int vfs_rename_locked(const char* old_path, const char* new_path) {
+    char old_resolved[VFS_MAX_PATH];
+    char new_resolved[VFS_MAX_PATH];
+
+    vfs_mount_t* mount = vfs_resolve(old_path, old_resolved);
+    if(mount == NULL)
+        return -1;
+
+    vfs_mount_t* new_mount = vfs_resolve(new_path, new_resolved);
+    if(new_mount == NULL)
+        return -1;
+
+    // Moving between devices is a copy, which rename does not do
+    if(new_mount != mount) {
+        errno = EXDEV;
+        return -1;
+    }
+
+    if(mount->driver->rename == NULL) {
+        errno = EROFS;
+        return -1;
+    }
+
+    return mount->driver->rename(mount->context, old_resolved, new_resolved);
+}
+
+static int vfs_chdir_locked(const char* path) {
+    char resolved[VFS_MAX_PATH];
+    vfs_mount_t* mount = vfs_resolve(path, resolved);
+    if(mount == NULL)
+        return -1;
+
+    struct stat st;
+    if(mount->driver->stat == NULL) {
+        errno = ENOTSUP;
+        return -1;
+    }
+
+    if(mount->driver->stat(mount->context, resolved, &st) < 0)
+        return -1;
+
+    if(!S_ISDIR(st.st_mode)) {
+        errno = ENOTDIR;
+        return -1;
+    }
+
+    strcpy(vfs_cwd_device, mount->name);
+    strcpy(vfs_cwd_path, resolved);
+
+    return 0;
+}
+
+static char* vfs_getcwd_locked(char* buffer, size_t size) {
+    if(vfs_cwd_device[0] == '\0') {
+        errno = ENOENT;
+        return NULL;
+    }
+
+    size_t length = strlen(vfs_cwd_device) + 1 + strlen(vfs_cwd_path);
+
+    // Like glibc, allocate the buffer if not given one
+    bool allocated = buffer == NULL;
+    if(allocated) {
+        if(size == 0)
+            size = length + 1;
+
+        buffer = malloc(size);
+        if(buffer == NULL) {
+            errno = ENOMEM;
+            return NULL;
+        }
+    } else if(size == 0) {
+        errno = EINVAL;
+        return NULL;
+    }
+
+    if(length >= size) {
+        if(allocated)
+            free(buffer);
+
+        errno = ERANGE;
+        return NULL;
+    }
+
+    strcpy(buffer, vfs_cwd_device);
+    strcat(buffer, ":");
+    strcat(buffer, vfs_cwd_path);
+
+    return buffer;
+}
+
+static vfs_dir_t* vfs_opendir_locked(const char* path) {
+    char resolved[VFS_MAX_PATH];
+    vfs_mount_t* mount = vfs_resolve(path, resolved);
+    if(mount == NULL)
+        return NULL;
+
+    if(mount->driver->opendir == NULL) {
+        errno = ENOTSUP;
+        return NULL;
+    }
+
+    vfs_dir_t* dir = malloc(sizeof(*dir));
+    if(dir == NULL) {
+        errno = ENOMEM;
+        return NULL;
+    }
+
+    dir->mount = mount;
+    dir->handle = mount->driver->opendir(mount->context, resolved);
+    if(dir->handle == NULL) {
+        free(dir);
+        return NULL;
+    }
+
+    mount->open_count++;
+    return dir;
+}
+
+static struct dirent* vfs_readdir_locked(vfs_dir_t* dir) {
+    vfs_mount_t* mount = dir->mount;
+
+    memset(&dir->entry, 0, sizeof(dir->entry));
+    if(mount->driver->readdir(mount->context, dir->handle, &dir->entry) <= 0)
+        return NULL;
+
+    return &dir->entry;
+}
+
+static void vfs_rewinddir_locked(vfs_dir_t* dir) {
+    vfs_mount_t* mount = dir->mount;
+
+    if(mount->driver->rewinddir != NULL)
+        mount->driver->rewinddir(mount->context, dir->handle);
+}
+
+static int vfs_closedir_locked(vfs_dir_t* dir) {
+    vfs_mount_t* mount = dir->mount;
+
+    int ret = mount->driver->closedir != NULL ? mount->driver->closedir(mount->context, dir->handle) : 0;
+
+    mount->open_count--;
+    free(dir);
+
+    return ret;
+}
+
+// The public functions hold the lock for the whole call, drivers included.
+
+int vfs_mount(const char* name, const vfs_driver_t* driver, void* context) {
+    vfs_lock();
+    int ret = vfs_mount_locked(name, driver, context);
+    vfs_unlock();
+
+    return ret;
+}
+
+int vfs_unmount(const char* name) {
+    vfs_lock();
+    int ret = vfs_unmount_locked(name);
+    vfs_unlock();
+
+    return ret;
+}
+
+bool vfs_is_mounted(const char* name) {
+    vfs_lock();
+    bool ret = vfs_is_mounted_locked(name);
+    vfs_unlock();
+
+    return ret;
+}
+
+int vfs_open(const char* path, int flags, mode_t mode) {
+    vfs_lock();
+    int ret = vfs_open_locked(path, flags, mode);
+    vfs_unlock();
+
+    return ret;
+}
+
+int vfs_close(int fd) {
+    vfs_lock();
+    int ret = vfs_close_locked(fd);
+    vfs_unlock();
+
+    return ret;
+}
+
+ssize_t vfs_read(int fd, void* buffer, size_t count) {
+    vfs_lock();
+    ssize_t ret = vfs_read_locked(fd, buffer, count);
+    vfs_unlock();
+
+    return ret;
+}
+
+ssize_t vfs_write(int fd, const void* buffer, size_t count) {
+    vfs_lock();
+    ssize_t ret = vfs_write_locked(fd, buffer, count);
+    vfs_unlock();
+
+    return ret;
+}
+
+off_t vfs_lseek(int fd, off_t offset, int whence) {
+    vfs_lock();
+    off_t ret = vfs_lseek_locked(fd, offset, whence);
+    vfs_unlock();
+
+    return ret;
+}
+
+int vfs_fstat(int fd, struct stat* st) {
+    vfs_lock();
+    int ret = vfs_fstat_locked(fd, st);
+    vfs_unlock();
+
+    return ret;
+}
+
+int vfs_fsync(int fd) {
+    vfs_lock();
+    int ret = vfs_fsync_locked(fd);
+    vfs_unlock();
+
+    return ret;
+}
+
+int vfs_ftruncate(int fd, off_t length) {
+    vfs_lock();
+    int ret = vfs_ftruncate_locked(fd, length);
+    vfs_unlock();
+
+    return ret;
+}
+
+int vfs_stat(const char* path, struct stat* st) {
+    vfs_lock();
+    int ret = vfs_stat_locked(path, st);
+    vfs_unlock();
+
+    return ret;
+}
+
+int vfs_truncate(const char* path, off_t length) {
+    vfs_lock();
+
+    int fd = vfs_open_locked(path, O_WRONLY, 0);
+    if(fd < 0) {
+        vfs_unlock();
+        return -1;
+    }
+
+    int ret = vfs_ftruncate_locked(fd, length);
+    int error = errno;
+
+    vfs_close_locked(fd);
+    vfs_unlock();
+
+    errno = error;
+    return ret;
+}
+
+int vfs_mkdir(const char* path, mode_t mode) {
+    vfs_lock();
+    int ret = vfs_mkdir_locked(path, mode);
+    vfs_unlock();
+
+    return ret;
+}
+
+int vfs_rmdir(const char* path) {
+    vfs_lock();
+    int ret = vfs_rmdir_locked(path);
+    vfs_unlock();
+
+    return ret;
+}
+
+int vfs_unlink(const char* path) {
+    vfs_lock();
+    int ret = vfs_unlink_locked(path);
+    vfs_unlock();
+
+    return ret;
+}
+
+int vfs_rename(const char* old_path, const char* new_path) {
+    vfs_lock();
+    int ret = vfs_rename_locked(old_path, new_path);
+    vfs_unlock();
+
+    return ret;
+}
+
+int vfs_chdir(const char* path) {
+    vfs_lock();
+    int ret = vfs_chdir_locked(path);
+    vfs_unlock();
+
+    return ret;
+}
+
+char* vfs_getcwd(char* buffer, size_t size) {
+    vfs_lock();
+    char* ret = vfs_getcwd_locked(buffer, size);
+    vfs_unlock();
+
+    return ret;
+}
+
+vfs_dir_t* vfs_opendir(const char* path) {
+    vfs_lock();
+    vfs_dir_t* ret = vfs_opendir_locked(path);
+    vfs_unlock();
+
+    return ret;
+}
+
+struct dirent* vfs_readdir(vfs_dir_t* dir) {
+    vfs_lock();
+    struct dirent* ret = vfs_readdir_locked(dir);
+    vfs_unlock();
+
+    return ret;
+}
+
+void vfs_rewinddir(vfs_dir_t* dir) {
+    vfs_lock();
+    vfs_rewinddir_locked(dir);
+    vfs_unlock();
+}
+
+int vfs_closedir(vfs_dir_t* dir) {
+    vfs_lock();
+    int ret = vfs_closedir_locked(dir);
+    vfs_unlock();
+
+    return ret;
+}
@@ -0,0 +1,195 @@
+/**
+ * @file vfs.h
+ * @brief Virtual file system
+ *
+ * Routes paths to file system drivers by the device at the start
+ * of the path, like "sd:/apps/game/data.bin". Paths without a device
+ * are taken from the current directory.
+ *
+ * Each driver keeps its own descriptor table. The VFS hands out
+ * its own file descriptors mapping to a mount and the driver's one.
+ * libc's file functions are implemented on top of this in fs_syscall.c.
+ *
+ * Every call holds one lock until it returns, driver calls included,
+ * so drivers need no locking of their own for their descriptor tables.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include <stdint.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <sys/types.h>
+
+#define VFS_MAX_MOUNTS   8
+#define VFS_MAX_NAME     16   // Including the terminator
+#define VFS_MAX_PATH     512  // Including the device and terminator
+
+struct stat;
+struct dirent;
+
+/**
+ * @typedef vfs_dir_t
+ * @brief A open directory, the DIR of dirent.h.
+ */
+typedef struct vfs_dir vfs_dir_t;
+
+/**
+ * @struct vfs_driver_t
+ * @brief Functions of a file system driver.
+ *
+ * Every function gets the context given to vfs_mount.
+ * Paths are absolute inside the mount, always starting with "/",
+ * with "." and ".." already removed.
+ *
+ * Errors return negative with errno set, like libc does.
+ * Functions the driver can not do can be left NULL.
+ */
+typedef struct {
+    /** Opens a file with open's flags, returning the driver's descriptor. */
+    int (*open)(void* context, const char* path, int flags, mode_t mode);
+    int (*close)(void* context, int fd);
+    ssize_t (*read)(void* context, int fd, void* buffer, size_t count);
+    ssize_t (*write)(void* context, int fd, const void* buffer, size_t count);
+    off_t (*lseek)(void* context, int fd, off_t offset, int whence);
+    int (*fstat)(void* context, int fd, struct stat* st);
+    int (*fsync)(void* context, int fd);
+    int (*ftruncate)(void* context, int fd, off_t length);
+
+    int (*stat)(void* context, const char* path, struct stat* st);
+    int (*mkdir)(void* context, const char* path, mode_t mode);
+    int (*rmdir)(void* context, const char* path);
+    int (*unlink)(void* context, const char* path);
+    /** Both paths are on this mount. Replaces what is at the new path. */
+    int (*rename)(void* context, const char* old_path, const char* new_path);
+
+    /** Opens a directory, returning the driver's handle or NULL. */
+    void* (*opendir)(void* context, const char* path);
+    /** Fills the next entry, skipping "." and "..". Returns 1 for a entry, 0 at the end. */
+    int (*readdir)(void* context, void* dir, struct dirent* entry);
+    void (*rewinddir)(void* context, void* dir);
+    int (*closedir)(void* context, void* dir);
+
+    /** Called when unmounted, frees the context. */
+    void (*unmount)(void* context);
+} vfs_driver_t;
+
+/**
+ * @struct vfs_descriptor_table_t
+ * @brief Descriptor table for drivers.
+ *
+ * Grows as files are opened, a descriptor is the index of its entry.
+ * Every entry must start with a bool that is set while it is in use.
+ * Entries can move when the table grows, so pointers to them
+ * do not last past the next vfs_descriptor_allocate.
+ */
+typedef struct {
+    void* entries;
+    size_t entry_size;
+    size_t size;
+} vfs_descriptor_table_t;
+
+/**
+ * @brief Sets up an empty table.
+ *
+ * @param table Table to set up.
+ * @param entry_size Size of each entry in bytes.
+*/
+extern void vfs_descriptor_table_init(vfs_descriptor_table_t* table, size_t entry_size);
+
+/**
+ * @brief Frees a table's entries.
+ *
+ * Open descriptors are not closed.
+*/
+extern void vfs_descriptor_table_free(vfs_descriptor_table_t* table);
+
+/**
+ * @brief Marks a unused entry as used, growing the table if there is none.
+ *
+ * The rest of the entry is left as it was.
+ *
+ * @return The descriptor, or negative with errno set to EMFILE.
+*/
+extern int vfs_descriptor_allocate(vfs_descriptor_table_t* table);
+
+/**
+ * @brief Marks a entry as unused.
+*/
+extern void vfs_descriptor_free(vfs_descriptor_table_t* table, int fd);
+
+/**
+ * @brief Gets the entry of a descriptor.
+ *
+ * @return The entry, or NULL with errno set to EBADF if it is not open.
+*/
+extern void* vfs_descriptor_get(const vfs_descriptor_table_t* table, int fd);
+
+/**
+ * @brief Fills a directory entry, for a driver's readdir.
+ *
+ * Drivers can use this instead of including dirent.h,
+ * whose DIR clashes with FatFs's.
+ *
+ * @param entry Entry to fill.
+ * @param ino Inode number, 0 if the file system has none.
+ * @param name Name of the entry, cut off if too long.
+ * @param directory If the entry is a directory.
+*/
+extern void vfs_fill_dirent(struct dirent* entry, ino_t ino, const char* name, bool directory);
+
+/**
+ * @brief Mounts a file system driver as a device.
+ *
+ * The first device mounted becomes the current directory.
+ *
+ * @param name Name of the device, like "sd" for "sd:/".
+ * @param driver The driver's functions, must stay valid while mounted.
+ * @param context Given to every driver function.
+ * @return Negative with errno set if error.
+*/
+extern int vfs_mount(const char* name, const vfs_driver_t* driver, void* context);
+
+/**
+ * @brief Unmounts a device.
+ *
+ * Fails with EBUSY if files or directories on it are still open.
+ *
+ * @param name Name of the device.
+ * @return Negative with errno set if error.
+*/
+extern int vfs_unmount(const char* name);
+
+/**
+ * @brief Checks if a device is mounted.
+*/
+extern bool vfs_is_mounted(const char* name);
+
+// The same as libc's functions, see fs_syscall.c
+extern int vfs_open(const char* path, int flags, mode_t mode);
+extern int vfs_close(int fd);
+extern ssize_t vfs_read(int fd, void* buffer, size_t count);
+extern ssize_t vfs_write(int fd, const void* buffer, size_t count);
+extern off_t vfs_lseek(int fd, off_t offset, int whence);
+extern int vfs_fstat(int fd, struct stat* st);
+extern int vfs_fsync(int fd);
+extern int vfs_ftruncate(int fd, off_t length);
+
+extern int vfs_stat(const char* path, struct stat* st);
+extern int vfs_truncate(const char* path, off_t length);
+extern int vfs_mkdir(const char* path, mode_t mode);
+extern int vfs_rmdir(const char* path);
+extern int vfs_unlink(const char* path);
+extern int vfs_rename(const char* old_path, const char* new_path);
+
+extern int vfs_chdir(const char* path);
+extern char* vfs_getcwd(char* buffer, size_t size);
+
+extern vfs_dir_t* vfs_opendir(const char* path);
+extern struct dirent* vfs_readdir(vfs_dir_t* dir);
+extern void vfs_rewinddir(vfs_dir_t* dir);
+extern int vfs_closedir(vfs_dir_t* dir);
@@ -0,0 +1,634 @@
+/**
+ * @file vfs_fatfs.c
+ * @brief FatFs driver for the virtual file system
+ *
+ * Implements the VFS driver functions on top of FatFs.
+ * FatFs errors are reported through errno, see fs_error.h.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "vfs_fatfs.h"
+
+#include <stdbool.h>
+#include <stdlib.h>
+#include <stdio.h>
+#include <string.h>
+#include <stddef.h>
+#include <errno.h>
+#include <time.h>
+#include <sys/stat.h>
+#include <fcntl.h>
+
+#include "powerblocks/core/system/system.h"
+
+#include "ff.h"
+#include "vfs.h"
+#include "fs_error.h"
+
+// Room for the "N:" drive in front of a VFS path
+#define FATFS_MAX_PATH (VFS_MAX_PATH + 3)
+
+typedef struct {
+    bool used;
+    FIL fil;
+} file_descriptor_t;
+
+typedef struct {
+    FATFS fs;
+    BYTE drive;
+
+    vfs_descriptor_table_t files;
+} fatfs_volume_t;
+
+typedef struct {
+    DIR dir;
+    FILINFO info;
+} fatfs_directory_t;
+
+// Drives with a volume mounted on them
+static bool fatfs_drive_claimed[FF_VOLUMES];
+
+// Gets the file of a descriptor, or NULL with errno set
+static FIL* get_file(fatfs_volume_t* volume, int fd) {
+    file_descriptor_t* file = vfs_descriptor_get(&volume->files, fd);
+    return file != NULL ? &file->fil : NULL;
+}
+
+// Puts the volume's drive in front of a path, as FatFs wants
+static const char* fatfs_path(fatfs_volume_t* volume, const char* path, char* buffer) {
+    snprintf(buffer, FATFS_MAX_PATH, "%u:%s", (unsigned int)volume->drive, path);
+    return buffer;
+}
+
+// Converts a FAT date and time to seconds since 1970.
+// FAT does not know the time zone, so it is taken as UTC.
+static time_t fat_time_to_time(WORD date, WORD time_of_day) {
+    if(date == 0)
+        return 0;
+
+    int year = 1980 + (date >> 9);
+    int month = (date >> 5) & 0xF;
+    int day = date & 0x1F;
+
+    // Days since 1970, counting years from March so leap days come last
+    int y = month <= 2 ? year - 1 : year;
+    int era = y / 400;
+    int year_of_era = y - era * 400;
+    int day_of_year = (153 * (month > 2 ? month - 3 : month + 9) + 2) / 5 + day - 1;
+    int day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    int64_t days = (int64_t)era * 146097 + day_of_era - 719468;
+
+    return days * 86400 + (time_of_day >> 11) * 3600 + ((time_of_day >> 5) & 0x3F) * 60 + (time_of_day & 0x1F) * 2;
+}
+
+static void fill_stat(struct stat* st, const FILINFO* info) {
+    memset(st, 0, sizeof(*st));
+
+    if(info->fattrib & AM_DIR) {
+        st->st_mode = S_IFDIR | 0777;
+    } else {
+        st->st_mode = S_IFREG | 0666;
+        st->st_size = info->fsize;
+    }
+
+    if(info->fattrib & AM_RDO)
+        st->st_mode &= ~0222;
+
+    st->st_nlink = 1;
+    st->st_blksize = FF_MAX_SS;
+    st->st_blocks = (st->st_size + 511) / 512;
+    st->st_mtime = fat_time_to_time(info->fdate, info->ftime);
+    st->st_atime = st->st_mtime;
+    st->st_ctime = st->st_mtime;
+}
+
+// Stats a path, including the root directory which f_stat can not.
+static FRESULT stat_path(const char* path, FILINFO* info) {
+    FRESULT res = f_stat(path, info);
+    if(res != FR_INVALID_NAME)
+        return res;
+
+    // The root has no entry of its own, but can still be opened.
+    DIR dir;
+    if(f_opendir(&dir, path) != FR_OK)
+        return res;
+    f_closedir(&dir);
+
+    memset(info, 0, sizeof(*info));
+    info->fattrib = AM_DIR;
+    return FR_OK;
+}
+
+static int fatfs_open(void* context, const char* path, int flags, mode_t mode) {
+    fatfs_volume_t* volume = (fatfs_volume_t*)context;
+    BYTE fatfs_mode = 0;
+
+    switch (flags & O_ACCMODE) {
+        case O_RDONLY:
+            fatfs_mode |= FA_READ;
+            break;
+
+        case O_WRONLY:
+            fatfs_mode |= FA_WRITE;
+            break;
+
+        case O_RDWR:
+            fatfs_mode |= FA_READ | FA_WRITE;
+            break;
+    }
+
+    // Only ok if opened for writing
+    if ((flags & (O_CREAT | O_TRUNC)) && !(fatfs_mode & FA_WRITE)) {
+        errno = EINVAL;
+        return -1;
+    }
+
+    // FatFs's truncating and appending modes also create the file,
+    // so without O_CREAT they are done after opening it.
+    if (flags & O_CREAT) {
+        if (flags & O_EXCL)
+            fatfs_mode |= FA_CREATE_NEW;
+        else if (flags & O_TRUNC)
+            fatfs_mode |= FA_CREATE_ALWAYS;
+        else
+            fatfs_mode |= FA_OPEN_ALWAYS;
+    }
+
+    /// BUG FIX: Avoid passing flags of zero
+    // Doing that bufs out fatfs
+    if(fatfs_mode == 0) {
+        errno = EINVAL;
+        return -1;
+    }
+
+
+    int fd = vfs_descriptor_allocate(&volume->files);
+    if(fd < 0)
+        return -1;
+
+    char full_path[FATFS_MAX_PATH];
+    fatfs_path(volume, path, full_path);
+
+    FRESULT res = f_open(get_file(volume, fd), full_path, fatfs_mode);
+    if(res != FR_OK) {
+        vfs_descriptor_free(&volume->files, fd);
+
+        // FatFs does not open directories as files
+        FILINFO info;
+        if(res == FR_NO_FILE && stat_path(full_path, &info) == FR_OK && (info.fattrib & AM_DIR)) {
+            errno = EISDIR;
+            return -1;
+        }
+
+        return fs_error_set_errno(res);
+    }
+
+    FIL* fp = get_file(volume, fd);
+    if ((flags & O_TRUNC) && !(flags & O_CREAT))
+        res = f_truncate(fp);
+    if (res == FR_OK && (flags & O_APPEND))
+        res = f_lseek(fp, f_size(fp));
+
+    if (res != FR_OK) {
+        f_close(fp);
+        vfs_descriptor_free(&volume->files, fd);
+        return fs_error_set_errno(res);
+    }
+
+    return fd;
+}
+
+static ssize_t fatfs_read(void* context, int fd, void* buf, size_t count) {
+    FIL* fp = get_file((fatfs_volume_t*)context, fd);
+    if(fp == NULL)
+        return -1;
+
+    UINT br = 0;
+    FRESULT res = f_read(fp, buf, count, &br);
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    return br;
+}
+
+static ssize_t fatfs_write(void* context, int fd, const void* buf, size_t count) {
+    FIL* fp = get_file((fatfs_volume_t*)context, fd);
+    if(fp == NULL)
+        return -1;
+
+    UINT bw = 0;
+    FRESULT res = f_write(fp, buf, count, &bw);
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    // FatFs writes less without a error when the volume is full
+    if(bw == 0 && count > 0) {
+        errno = ENOSPC;
+        return -1;
+    }
+
+    return bw;
+}
+
+static off_t fatfs_lseek(void* context, int fd, off_t offset, int whence) {
+    FIL* fp = get_file((fatfs_volume_t*)context, fd);
+    if(fp == NULL)
+        return -1;
+
+    FSIZE_t new_pos;
+
+    switch(whence) {
+        case SEEK_SET:
+            new_pos = offset;
+            break;
+        case SEEK_CUR:
+            new_pos = f_tell(fp) + offset;
+            break;
+        case SEEK_END:
+            new_pos = f_size(fp) + offset;
+            break;
+        default:
+            errno = EINVAL;
+            return -1;
+    }
+
+    FRESULT res = f_lseek(fp, new_pos);
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    // Reading files can not seek past the end
+    return f_tell(fp);
+}
+
+static int fatfs_close(void* context, int fd) {
+    fatfs_volume_t* volume = (fatfs_volume_t*)context;
+    FIL* fp = get_file(volume, fd);
+    if(fp == NULL)
+        return -1;
+
+    FRESULT res = f_close(fp);
+    vfs_descriptor_free(&volume->files, fd);
+
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    return 0;
+}
+
+static int fatfs_fstat(void* context, int fd, struct stat *st) {
+    FIL* fp = get_file((fatfs_volume_t*)context, fd);
+    if(fp == NULL)
+        return -1;
+
+    // The open file does not keep its name, so only the size is known.
+    FILINFO info;
+    memset(&info, 0, sizeof(info));
+    info.fsize = f_size(fp);
+
+    fill_stat(st, &info);
+    return 0;
+}
+
+static int fatfs_fsync(void* context, int fd) {
+    FIL* fp = get_file((fatfs_volume_t*)context, fd);
+    if(fp == NULL)
+        return -1;
+
+    FRESULT res = f_sync(fp);
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    return 0;
+}
+
+static int fatfs_ftruncate(void* context, int fd, off_t length) {
+    FIL* fp = get_file((fatfs_volume_t*)context, fd);
+    if(fp == NULL)
+        return -1;
+
+    if(length < 0) {
+        errno = EINVAL;
+        return -1;
+    }
+
+    FSIZE_t position = f_tell(fp);
+    FRESULT res;
+
+    if((FSIZE_t)length < f_size(fp)) {
+        // f_truncate cuts the file off at the file pointer
+        res = f_lseek(fp, length);
+        if(res == FR_OK)
+            res = f_truncate(fp);
+    } else {
+        // Growing the file must fill it with zeros
+        static const uint8_t zeros[512];
+
+        res = f_lseek(fp, f_size(fp));
+        while(res == FR_OK && f_size(fp) < (FSIZE_t)length) {
+            FSIZE_t left = length - f_size(fp);
+            UINT bw;
+
+            res = f_write(fp, zeros, left < sizeof(zeros) ? left : sizeof(zeros), &bw);
+            if(res == FR_OK && bw == 0) {
+                errno = ENOSPC;
+                f_lseek(fp, position);
+                return -1;
+            }
+        }
+    }
+
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    // Seeking past the end would grow the file again, so stop at the end.
+    res = f_lseek(fp, position < f_size(fp) ? position : f_size(fp));
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    return 0;
+}
+
+static int fatfs_stat(void* context, const char *path, struct stat *st) {
+    char full_path[FATFS_MAX_PATH];
+    fatfs_path((fatfs_volume_t*)context, path, full_path);
+
+    FILINFO info;
+    FRESULT res = stat_path(full_path, &info);
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    fill_stat(st, &info);
+    return 0;
+}
+
+static int fatfs_mkdir(void* context, const char *path, mode_t mode) {
+    char full_path[FATFS_MAX_PATH];
+    fatfs_path((fatfs_volume_t*)context, path, full_path);
+
+    FRESULT res = f_mkdir(full_path);
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    return 0;
+}
+
+static int fatfs_rmdir(void* context, const char *path) {
+    char full_path[FATFS_MAX_PATH];
+    fatfs_path((fatfs_volume_t*)context, path, full_path);
+
+    FILINFO info;
+    FRESULT res = stat_path(full_path, &info);
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    if(!(info.fattrib & AM_DIR)) {
+        errno = ENOTDIR;
+        return -1;
+    }
+
+    res = f_unlink(full_path);
+    if(res == FR_DENIED) {
+        // Not empty, or the root
+        errno = ENOTEMPTY;
+        return -1;
+    } else if(res != FR_OK) {
+        return fs_error_set_errno(res);
+    }
+
+    return 0;
+}
+
+static int fatfs_unlink(void* context, const char *path) {
+    char full_path[FATFS_MAX_PATH];
+    fatfs_path((fatfs_volume_t*)context, path, full_path);
+
+    FILINFO info;
+    FRESULT res = stat_path(full_path, &info);
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    // f_unlink removes empty directories too, unlink must not.
+    if(info.fattrib & AM_DIR) {
+        errno = EISDIR;
+        return -1;
+    }
+
+    res = f_unlink(full_path);
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    return 0;
+}
+
+static int fatfs_rename(void* context, const char *old_path, const char *new_path) {
+    fatfs_volume_t* volume = (fatfs_volume_t*)context;
+    char full_old_path[FATFS_MAX_PATH];
+    char full_new_path[FATFS_MAX_PATH];
+    fatfs_path(volume, old_path, full_old_path);
+    fatfs_path(volume, new_path, full_new_path);
+
+    // f_rename takes the drive from the old path only
+    FRESULT res = f_rename(full_old_path, new_path);
+    if(res != FR_EXIST) {
+        if(res != FR_OK)
+            return fs_error_set_errno(res);
+
+        return 0;
+    }
+
+    // rename replaces what is already there, FatFs does not.
+    FILINFO old_info, new_info;
+    res = stat_path(full_old_path, &old_info);
+    if(res == FR_OK)
+        res = stat_path(full_new_path, &new_info);
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    if((old_info.fattrib & AM_DIR) && !(new_info.fattrib & AM_DIR)) {
+        errno = ENOTDIR;
+        return -1;
+    }
+
+    if(!(old_info.fattrib & AM_DIR) && (new_info.fattrib & AM_DIR)) {
+        errno = EISDIR;
+        return -1;
+    }
+
+    res = f_unlink(full_new_path);
+    if(res == FR_DENIED && (new_info.fattrib & AM_DIR)) {
+        errno = ENOTEMPTY;
+        return -1;
+    }
+
+    if(res == FR_OK)
+        res = f_rename(full_old_path, new_path);
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    return 0;
+}
+
+static void* fatfs_opendir(void* context, const char *path) {
+    char full_path[FATFS_MAX_PATH];
+    fatfs_path((fatfs_volume_t*)context, path, full_path);
+
+    fatfs_directory_t* directory = malloc(sizeof(*directory));
+    if(directory == NULL) {
+        errno = ENOMEM;
+        return NULL;
+    }
+
+    FRESULT res = f_opendir(&directory->dir, full_path);
+    if(res != FR_OK) {
+        free(directory);
+
+        // Tell apart a path to a file from a missing one
+        FILINFO info;
+        if(res == FR_NO_PATH && f_stat(full_path, &info) == FR_OK && !(info.fattrib & AM_DIR))
+            errno = ENOTDIR;
+        else
+            fs_error_set_errno(res);
+
+        return NULL;
+    }
+
+    return directory;
+}
+
+static int fatfs_readdir(void* context, void* dir, struct dirent* entry) {
+    fatfs_directory_t* directory = (fatfs_directory_t*)dir;
+
+    FRESULT res = f_readdir(&directory->dir, &directory->info);
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    // A empty name is the end of the directory
+    if(directory->info.fname[0] == '\0')
+        return 0;
+
+    vfs_fill_dirent(entry, 0, directory->info.fname, directory->info.fattrib & AM_DIR);
+
+    return 1;
+}
+
+static void fatfs_rewinddir(void* context, void* dir) {
+    f_rewinddir(&((fatfs_directory_t*)dir)->dir);
+}
+
+static int fatfs_closedir(void* context, void* dir) {
+    fatfs_directory_t* directory = (fatfs_directory_t*)dir;
+
+    FRESULT res = f_closedir(&directory->dir);
+    free(directory);
+
+    if(res != FR_OK)
+        return fs_error_set_errno(res);
+
+    return 0;
+}
+
+static void fatfs_unmount(void* context) {
+    fatfs_volume_t* volume = (fatfs_volume_t*)context;
+
+    char drive[FATFS_MAX_PATH];
+    f_unmount(fatfs_path(volume, "", drive));
+    vfs_fatfs_release_drive(volume->drive);
+
+    vfs_descriptor_table_free(&volume->files);
+    free(volume);
+}
+
+static const vfs_driver_t fatfs_driver = {
+    .open = fatfs_open,
+    .close = fatfs_close,
+    .read = fatfs_read,
+    .write = fatfs_write,
+    .lseek = fatfs_lseek,
+    .fstat = fatfs_fstat,
+    .fsync = fatfs_fsync,
+    .ftruncate = fatfs_ftruncate,
+
+    .stat = fatfs_stat,
+    .mkdir = fatfs_mkdir,
+    .rmdir = fatfs_rmdir,
+    .unlink = fatfs_unlink,
+    .rename = fatfs_rename,
+
+    .opendir = fatfs_opendir,
+    .readdir = fatfs_readdir,
+    .rewinddir = fatfs_rewinddir,
+    .closedir = fatfs_closedir,
+
+    .unmount = fatfs_unmount,
+};
+
+int vfs_fatfs_claim_drive(uint8_t drive) {
+    if(drive >= FF_VOLUMES) {
+        errno = EINVAL;
+        return -1;
+    }
+
+    uint32_t irq_enabled;
+    SYSTEM_DISABLE_ISR(irq_enabled);
+
+    bool claimed = fatfs_drive_claimed[drive];
+    fatfs_drive_claimed[drive] = true;
+
+    SYSTEM_ENABLE_ISR(irq_enabled);
+
+    if(claimed) {
+        errno = EBUSY;
+        return -1;
+    }
+
+    return 0;
+}
+
+void vfs_fatfs_release_drive(uint8_t drive) {
+    if(drive < FF_VOLUMES)
+        fatfs_drive_claimed[drive] = false;
+}
+
+int vfs_fatfs_mount(const char* name, uint8_t drive) {
+    if(vfs_is_mounted(name)) {
+        errno = EEXIST;
+        return -1;
+    }
+
+    // f_mount would replace the volume already on the drive
+    if(vfs_fatfs_claim_drive(drive) < 0)
+        return -1;
+
+    fatfs_volume_t* volume = malloc(sizeof(*volume));
+    if(volume == NULL) {
+        vfs_fatfs_release_drive(drive);
+        errno = ENOMEM;
+        return -1;
+    }
+
+    memset(volume, 0, sizeof(*volume));
+    vfs_descriptor_table_init(&volume->files, sizeof(file_descriptor_t));
+    volume->drive = drive;
+
+    char path[FATFS_MAX_PATH];
+    FRESULT res = f_mount(&volume->fs, fatfs_path(volume, "", path), 1);
+    if(res != FR_OK) {
+        // f_mount keeps the volume registered even when mounting fails
+        f_unmount(path);
+        free(volume);
+        vfs_fatfs_release_drive(drive);
+        return fs_error_set_errno(res);
+    }
+
+    if(vfs_mount(name, &fatfs_driver, volume) < 0) {
+        f_unmount(path);
+        free(volume);
+        vfs_fatfs_release_drive(drive);
+        return -1;
+    }
+
+    return 0;
+}
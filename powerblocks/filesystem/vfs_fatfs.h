@@ -0,0 +1,46 @@
+/**
+ * @file vfs_fatfs.h
+ * @brief FatFs driver for the virtual file system
+ *
+ * Mounts a FatFs drive, like the SD card, as a VFS device.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include <stdint.h>
+
+/**
+ * @brief Mounts a FatFs drive as a device.
+ *
+ * The drive's disk must be initialized first, for the SD card
+ * that is sd_initialize. Fails with EBUSY if the drive is already
+ * mounted, by this or anything else that claimed it.
+ *
+ * @param name Name of the device, like "sd".
+ * @param drive FatFs physical drive, like DISK_DEV_SD.
+ * @return Negative with errno set if error.
+*/
+extern int vfs_fatfs_mount(const char* name, uint8_t drive);
+
+/**
+ * @brief Claims a FatFs drive before mounting it with f_mount.
+ *
+ * FatFs has one volume per drive, and a second f_mount replaces
+ * the first, closing its files. Anything calling f_mount outside
+ * of the VFS should claim the drive first.
+ *
+ * @param drive FatFs physical drive, like DISK_DEV_SD.
+ * @return Negative with errno set to EBUSY if already claimed.
+*/
+extern int vfs_fatfs_claim_drive(uint8_t drive);
+
+/**
+ * @brief Releases a drive claimed with vfs_fatfs_claim_drive.
+ *
+ * @param drive FatFs physical drive, like DISK_DEV_SD.
+*/
+extern void vfs_fatfs_release_drive(uint8_t drive);
@@ -0,0 +1,668 @@
+/**
+ * @file vfs_isfs.c
+ * @brief NAND driver for the virtual file system
+ *
+ * Implements the VFS driver functions on top of ISFS.
+ * Files are read and written through ios_read and ios_write,
+ * everything else through /dev/fs, see isfs.h.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "vfs_isfs.h"
+
+#include <stdbool.h>
+#include <stdlib.h>
+#include <stdio.h>
+#include <string.h>
+#include <errno.h>
+#include <fcntl.h>
+#include <stdalign.h>
+#include <sys/stat.h>
+
+#include "powerblocks/core/ios/ios.h"
+#include "powerblocks/core/ios/isfs.h"
+#include "powerblocks/core/system/system.h"
+
+#include "FreeRTOS.h"
+#include "semphr.h"
+
+#include "vfs.h"
+
+// Reads and writes not aligned for IOS go through this in pieces
+#define ISFS_BOUNCE_SIZE 4096
+
+// Scratch directory on every NAND, emptied on boot
+#define ISFS_TEMP_DIR "/tmp"
+
+// IOS's own errors, from before the request reaches /dev/fs
+#define IOS_ERROR_ACCESS    -1
+#define IOS_ERROR_EXISTS    -2
+#define IOS_ERROR_INVALID   -4
+#define IOS_ERROR_NOT_FOUND -6
+
+typedef struct {
+    bool used;
+    int file;
+    int flags;
+    char path[ISFS_MAX_PATH];
+} file_descriptor_t;
+
+typedef struct {
+    vfs_descriptor_table_t files;
+} isfs_volume_t;
+
+typedef struct {
+    char path[ISFS_MAX_PATH];
+    void* allocation;
+    char* names; // 32 byte aligned in allocation
+    uint32_t count;
+    uint32_t next;
+    const char* next_name;
+} isfs_directory_t;
+
+static uint8_t isfs_bounce[ISFS_BOUNCE_SIZE] ALIGN(32);
+static SemaphoreHandle_t isfs_bounce_lock;
+static StaticSemaphore_t isfs_bounce_lock_data;
+
+// Gets the file of a descriptor, or NULL with errno set
+static file_descriptor_t* get_file(isfs_volume_t* volume, int fd) {
+    return vfs_descriptor_get(&volume->files, fd);
+}
+
+static int isfs_error_to_errno(int ret) {
+    switch(ret) {
+        case ISFS_ERROR_INVALID:
+        case IOS_ERROR_INVALID:
+            return EINVAL;
+        case ISFS_ERROR_ACCESS:
+        case IOS_ERROR_ACCESS:
+            return EACCES;        // The title is not allowed
+        case ISFS_ERROR_EXISTS:
+        case IOS_ERROR_EXISTS:
+            return EEXIST;
+        case ISFS_ERROR_NOT_FOUND:
+        case IOS_ERROR_NOT_FOUND:
+            return ENOENT;
+        case ISFS_ERROR_TOO_MANY_FILES:
+        case ISFS_ERROR_FULL:
+            return ENOSPC;
+        case ISFS_ERROR_TOO_MANY_OPEN:
+            return EMFILE;
+        case ISFS_ERROR_PATH_TOO_LONG:
+        case ISFS_ERROR_TOO_DEEP:
+            return ENAMETOOLONG;
+        case ISFS_ERROR_ALREADY_OPEN:
+        case ISFS_ERROR_BUSY:
+            return EBUSY;
+        case ISFS_ERROR_NOT_EMPTY:
+            return ENOTEMPTY;
+        default:
+            return EIO;           // Corrupted, or worse
+    }
+}
+
+static int isfs_set_errno(int ret) {
+    errno = isfs_error_to_errno(ret);
+    return -1;
+}
+
+// ISFS only has read and write, for the owner, group and everyone else
+static uint8_t mode_to_permissions(mode_t mode) {
+    return ((mode & 4) ? ISFS_PERMISSION_READ : 0) | ((mode & 2) ? ISFS_PERMISSION_WRITE : 0);
+}
+
+static mode_t permissions_to_mode(uint8_t permissions) {
+    return ((permissions & ISFS_PERMISSION_READ) ? 4 : 0) | ((permissions & ISFS_PERMISSION_WRITE) ? 2 : 0);
+}
+
+// Only directories can be listed, which tells them apart from files
+static bool is_directory(const char* path) {
+    uint32_t count = 0;
+    return isfs_read_dir(path, NULL, &count) >= 0;
+}
+
+// Copies a path IOS can read, failing if it is too long for ISFS
+static int copy_path(char* buffer, const char* path) {
+    if(strlen(path) >= ISFS_MAX_PATH) {
+        errno = ENAMETOOLONG;
+        return -1;
+    }
+
+    memset(buffer, 0, ISFS_MAX_PATH);
+    strcpy(buffer, path);
+    return 0;
+}
+
+static int fill_stat(struct stat* st, const char* path, const isfs_attributes_t* attributes) {
+    memset(st, 0, sizeof(*st));
+
+    st->st_uid = attributes->owner_id;
+    st->st_gid = attributes->group_id;
+    st->st_nlink = 1;
+    st->st_mode = (permissions_to_mode(attributes->owner_permissions) << 6)
+        | (permissions_to_mode(attributes->group_permissions) << 3)
+        | permissions_to_mode(attributes->other_permissions);
+
+    if(is_directory(path)) {
+        st->st_mode |= S_IFDIR;
+        return 0;
+    }
+
+    st->st_mode |= S_IFREG;
+
+    // The length is only known while opened, which needs read permission
+    alignas(32) char path_buffer[ISFS_MAX_PATH];
+    copy_path(path_buffer, path);
+
+    int file = ios_open(path_buffer, IOS_MODE_READ);
+    if(file >= 0) {
+        isfs_file_stats_t stats;
+        if(isfs_get_file_stats(file, &stats) >= 0)
+            st->st_size = stats.length;
+
+        ios_close(file);
+    }
+
+    st->st_blksize = ISFS_BOUNCE_SIZE;
+    st->st_blocks = (st->st_size + 511) / 512;
+
+    return 0;
+}
+
+// ISFS can not truncate, so a empty file with the same attributes is made in /tmp,
+// the same way titles save, and moved over it. The file is only deleted once
+// the new one exists, so failing to make it leaves the file as it was.
+static int truncate_file(const char* path) {
+    if(is_directory(path)) {
+        errno = EISDIR;
+        return -1;
+    }
+
+    // Files already in /tmp have nowhere else to be made
+    const char* name = strrchr(path, '/') + 1;
+    if(strncmp(path, ISFS_TEMP_DIR "/", sizeof(ISFS_TEMP_DIR)) == 0 && name == path + sizeof(ISFS_TEMP_DIR)) {
+        errno = ENOTSUP;
+        return -1;
+    }
+
+    // IOS only moves files between directories under the same name
+
+    char temp_path[ISFS_MAX_PATH];
+    if(snprintf(temp_path, sizeof(temp_path), ISFS_TEMP_DIR "/%s", name) >= sizeof(temp_path)) {
+        errno = ENAMETOOLONG;
+        return -1;
+    }
+
+    isfs_attributes_t attributes;
+    int ret = isfs_get_attributes(path, &attributes);
+    if(ret < 0)
+        return isfs_set_errno(ret);
+
+    // Something else in /tmp under the name is left alone, failing with EEXIST
+    ret = isfs_create_file(temp_path, attributes.attributes, attributes.owner_permissions, attributes.group_permissions, attributes.other_permissions);
+    if(ret < 0)
+        return isfs_set_errno(ret);
+
+    ret = isfs_delete(path);
+    if(ret < 0) {
+        isfs_delete(temp_path);
+        return isfs_set_errno(ret);
+    }
+
+    ret = isfs_rename(temp_path, path);
+    if(ret < 0)
+        return isfs_set_errno(ret);
+
+    return 0;
+}
+
+static int isfs_vfs_open(void* context, const char* path, int flags, mode_t mode) {
+    isfs_volume_t* volume = (isfs_volume_t*)context;
+    int ios_mode;
+
+    switch(flags & O_ACCMODE) {
+        case O_RDONLY:
+            ios_mode = IOS_MODE_READ;
+            break;
+        case O_WRONLY:
+            ios_mode = IOS_MODE_WRITE;
+            break;
+        case O_RDWR:
+            ios_mode = IOS_MODE_READ | IOS_MODE_WRITE;
+            break;
+        default:
+            errno = EINVAL;
+            return -1;
+    }
+
+    if((flags & (O_CREAT | O_TRUNC)) && !(ios_mode & IOS_MODE_WRITE)) {
+        errno = EINVAL;
+        return -1;
+    }
+
+    alignas(32) char path_buffer[ISFS_MAX_PATH];
+    if(copy_path(path_buffer, path) < 0)
+        return -1;
+
+    bool created = false;
+    if(flags & O_CREAT) {
+        int ret = isfs_create_file(path, 0, mode_to_permissions(mode >> 6), mode_to_permissions(mode >> 3), mode_to_permissions(mode));
+        if(ret >= 0) {
+            created = true;
+        } else if(ret != ISFS_ERROR_EXISTS || (flags & O_EXCL)) {
+            return isfs_set_errno(ret);
+        }
+    }
+
+    if((flags & O_TRUNC) && !created) {
+        if(truncate_file(path) < 0)
+            return -1;
+    }
+
+    int file = ios_open(path_buffer, ios_mode);
+    if(file < 0) {
+        // IOS does not open directories as files
+        if(is_directory(path)) {
+            errno = EISDIR;
+            return -1;
+        }
+
+        return isfs_set_errno(file);
+    }
+
+    int fd = vfs_descriptor_allocate(&volume->files);
+    if(fd < 0) {
+        ios_close(file);
+        return -1;
+    }
+
+    file_descriptor_t* descriptor = get_file(volume, fd);
+    descriptor->file = file;
+    descriptor->flags = flags;
+    strcpy(descriptor->path, path);
+
+    return fd;
+}
+
+static int isfs_vfs_close(void* context, int fd) {
+    isfs_volume_t* volume = (isfs_volume_t*)context;
+    file_descriptor_t* file = get_file(volume, fd);
+    if(file == NULL)
+        return -1;
+
+    // Writes are only committed to the NAND on close
+    int ret = ios_close(file->file);
+    vfs_descriptor_free(&volume->files, fd);
+
+    if(ret < 0)
+        return isfs_set_errno(ret);
+
+    return 0;
+}
+
+static bool is_aligned(const void* buffer, size_t count) {
+    return ((uintptr_t)buffer % 32) == 0 && (count % 32) == 0;
+}
+
+static ssize_t isfs_vfs_read(void* context, int fd, void* buffer, size_t count) {
+    file_descriptor_t* file = get_file((isfs_volume_t*)context, fd);
+    if(file == NULL)
+        return -1;
+
+    // IOS writes straight to memory, so unaligned ends would lose what shares their cache lines.
+    if(is_aligned(buffer, count)) {
+        int ret = ios_read(file->file, buffer, count);
+        if(ret < 0)
+            return isfs_set_errno(ret);
+
+        return ret;
+    }
+
+    size_t total = 0;
+    xSemaphoreTake(isfs_bounce_lock, portMAX_DELAY);
+
+    while(total < count) {
+        size_t part = count - total;
+        if(part > ISFS_BOUNCE_SIZE)
+            part = ISFS_BOUNCE_SIZE;
+
+        int ret = ios_read(file->file, isfs_bounce, part);
+        if(ret < 0) {
+            xSemaphoreGive(isfs_bounce_lock);
+            return total > 0 ? total : isfs_set_errno(ret);
+        }
+
+        memcpy((uint8_t*)buffer + total, isfs_bounce, ret);
+        total += ret;
+
+        // End of the file
+        if(ret < part)
+            break;
+    }
+
+    xSemaphoreGive(isfs_bounce_lock);
+    return total;
+}
+
+static ssize_t isfs_vfs_write(void* context, int fd, const void* buffer, size_t count) {
+    file_descriptor_t* file = get_file((isfs_volume_t*)context, fd);
+    if(file == NULL)
+        return -1;
+
+    if(file->flags & O_APPEND) {
+        int ret = ios_seek(file->file, 0, SEEK_END);
+        if(ret < 0)
+            return isfs_set_errno(ret);
+    }
+
+    if(is_aligned(buffer, count)) {
+        int ret = ios_write(file->file, (void*)buffer, count);
+        if(ret < 0)
+            return isfs_set_errno(ret);
+
+        return ret;
+    }
+
+    size_t total = 0;
+    xSemaphoreTake(isfs_bounce_lock, portMAX_DELAY);
+
+    while(total < count) {
+        size_t part = count - total;
+        if(part > ISFS_BOUNCE_SIZE)
+            part = ISFS_BOUNCE_SIZE;
+
+        memcpy(isfs_bounce, (const uint8_t*)buffer + total, part);
+
+        int ret = ios_write(file->file, isfs_bounce, part);
+        if(ret < 0) {
+            xSemaphoreGive(isfs_bounce_lock);
+            return total > 0 ? total : isfs_set_errno(ret);
+        }
+
+        total += ret;
+        if(ret < part)
+            break;
+    }
+
+    xSemaphoreGive(isfs_bounce_lock);
+    return total;
+}
+
+static off_t isfs_vfs_lseek(void* context, int fd, off_t offset, int whence) {
+    file_descriptor_t* file = get_file((isfs_volume_t*)context, fd);
+    if(file == NULL)
+        return -1;
+
+    if(whence != SEEK_SET && whence != SEEK_CUR && whence != SEEK_END) {
+        errno = EINVAL;
+        return -1;
+    }
+
+    // IOS uses the same whence values
+    int ret = ios_seek(file->file, offset, whence);
+    if(ret < 0)
+        return isfs_set_errno(ret);
+
+    return ret;
+}
+
+static int isfs_vfs_fstat(void* context, int fd, struct stat* st) {
+    file_descriptor_t* file = get_file((isfs_volume_t*)context, fd);
+    if(file == NULL)
+        return -1;
+
+    isfs_attributes_t attributes;
+    int ret = isfs_get_attributes(file->path, &attributes);
+    if(ret < 0)
+        return isfs_set_errno(ret);
+
+    isfs_file_stats_t stats;
+    ret = isfs_get_file_stats(file->file, &stats);
+    if(ret < 0)
+        return isfs_set_errno(ret);
+
+    fill_stat(st, file->path, &attributes);
+    st->st_mode = (st->st_mode & ~S_IFMT) | S_IFREG;
+    st->st_size = stats.length;
+    st->st_blocks = (st->st_size + 511) / 512;
+
+    return 0;
+}
+
+static int isfs_vfs_stat(void* context, const char* path, struct stat* st) {
+    isfs_attributes_t attributes;
+    int ret = isfs_get_attributes(path, &attributes);
+    if(ret < 0)
+        return isfs_set_errno(ret);
+
+    return fill_stat(st, path, &attributes);
+}
+
+static int isfs_vfs_mkdir(void* context, const char* path, mode_t mode) {
+    int ret = isfs_create_dir(path, 0, mode_to_permissions(mode >> 6), mode_to_permissions(mode >> 3), mode_to_permissions(mode));
+    if(ret < 0)
+        return isfs_set_errno(ret);
+
+    return 0;
+}
+
+static int isfs_vfs_rmdir(void* context, const char* path) {
+    // ISFS deletes everything in a directory, rmdir only takes empty ones
+    uint32_t count = 0;
+    int ret = isfs_read_dir(path, NULL, &count);
+    if(ret < 0) {
+        isfs_attributes_t attributes;
+        if(isfs_get_attributes(path, &attributes) >= 0) {
+            errno = ENOTDIR;
+            return -1;
+        }
+
+        return isfs_set_errno(ret);
+    }
+
+    if(count > 0) {
+        errno = ENOTEMPTY;
+        return -1;
+    }
+
+    ret = isfs_delete(path);
+    if(ret < 0)
+        return isfs_set_errno(ret);
+
+    return 0;
+}
+
+static int isfs_vfs_unlink(void* context, const char* path) {
+    if(is_directory(path)) {
+        errno = EISDIR;
+        return -1;
+    }
+
+    int ret = isfs_delete(path);
+    if(ret < 0)
+        return isfs_set_errno(ret);
+
+    return 0;
+}
+
+static int isfs_vfs_rename(void* context, const char* old_path, const char* new_path) {
+    int ret = isfs_rename(old_path, new_path);
+    if(ret != ISFS_ERROR_EXISTS) {
+        if(ret < 0)
+            return isfs_set_errno(ret);
+
+        return 0;
+    }
+
+    // rename replaces what is already there, ISFS does not.
+    bool old_directory = is_directory(old_path);
+    bool new_directory = is_directory(new_path);
+
+    if(old_directory && !new_directory) {
+        errno = ENOTDIR;
+        return -1;
+    }
+
+    if(!old_directory && new_directory) {
+        errno = EISDIR;
+        return -1;
+    }
+
+    if(new_directory) {
+        uint32_t count = 0;
+        ret = isfs_read_dir(new_path, NULL, &count);
+        if(ret >= 0 && count > 0) {
+            errno = ENOTEMPTY;
+            return -1;
+        }
+    }
+
+    ret = isfs_delete(new_path);
+    if(ret >= 0)
+        ret = isfs_rename(old_path, new_path);
+    if(ret < 0)
+        return isfs_set_errno(ret);
+
+    return 0;
+}
+
+static void* isfs_vfs_opendir(void* context, const char* path) {
+    isfs_directory_t* directory = malloc(sizeof(*directory));
+    if(directory == NULL) {
+        errno = ENOMEM;
+        return NULL;
+    }
+
+    memset(directory, 0, sizeof(*directory));
+    if(copy_path(directory->path, path) < 0) {
+        free(directory);
+        return NULL;
+    }
+
+    // Count first, to know how much room the names need
+    int ret = isfs_read_dir(path, NULL, &directory->count);
+    if(ret < 0) {
+        free(directory);
+
+        // Tell apart a path to a file from a missing one
+        isfs_attributes_t attributes;
+        if(isfs_get_attributes(path, &attributes) >= 0)
+            errno = ENOTDIR;
+        else
+            isfs_set_errno(ret);
+
+        return NULL;
+    }
+
+    if(directory->count > 0) {
+        // IOS writes the names straight to memory, so they get whole cache lines.
+        size_t size = (directory->count * (ISFS_MAX_NAME + 1) + 31) & ~31;
+        directory->allocation = malloc(size + 31);
+        if(directory->allocation == NULL) {
+            free(directory);
+            errno = ENOMEM;
+            return NULL;
+        }
+
+        directory->names = (char*)(((uintptr_t)directory->allocation + 31) & ~(uintptr_t)31);
+        memset(directory->names, 0, size);
+
+        ret = isfs_read_dir(path, directory->names, &directory->count);
+        if(ret < 0) {
+            free(directory->allocation);
+            free(directory);
+            isfs_set_errno(ret);
+            return NULL;
+        }
+    }
+
+    directory->next_name = directory->names;
+    return directory;
+}
+
+static int isfs_vfs_readdir(void* context, void* dir, struct dirent* entry) {
+    isfs_directory_t* directory = (isfs_directory_t*)dir;
+
+    if(directory->next >= directory->count)
+        return 0;
+
+    const char* name = directory->next_name;
+    directory->next_name += strlen(name) + 1;
+    directory->next++;
+
+    char child[ISFS_MAX_PATH + ISFS_MAX_NAME + 1];
+    snprintf(child, sizeof(child), "%s/%s", strcmp(directory->path, "/") == 0 ? "" : directory->path, name);
+
+    vfs_fill_dirent(entry, 0, name, is_directory(child));
+
+    return 1;
+}
+
+static void isfs_vfs_rewinddir(void* context, void* dir) {
+    isfs_directory_t* directory = (isfs_directory_t*)dir;
+
+    directory->next = 0;
+    directory->next_name = directory->names;
+}
+
+static int isfs_vfs_closedir(void* context, void* dir) {
+    isfs_directory_t* directory = (isfs_directory_t*)dir;
+
+    free(directory->allocation);
+    free(directory);
+    return 0;
+}
+
+static void isfs_vfs_unmount(void* context) {
+    isfs_volume_t* volume = (isfs_volume_t*)context;
+
+    vfs_descriptor_table_free(&volume->files);
+    free(volume);
+}
+
+// ISFS commits files on close, and can not truncate them
+static const vfs_driver_t isfs_driver = {
+    .open = isfs_vfs_open,
+    .close = isfs_vfs_close,
+    .read = isfs_vfs_read,
+    .write = isfs_vfs_write,
+    .lseek = isfs_vfs_lseek,
+    .fstat = isfs_vfs_fstat,
+
+    .stat = isfs_vfs_stat,
+    .mkdir = isfs_vfs_mkdir,
+    .rmdir = isfs_vfs_rmdir,
+    .unlink = isfs_vfs_unlink,
+    .rename = isfs_vfs_rename,
+
+    .opendir = isfs_vfs_opendir,
+    .readdir = isfs_vfs_readdir,
+    .rewinddir = isfs_vfs_rewinddir,
+    .closedir = isfs_vfs_closedir,
+
+    .unmount = isfs_vfs_unmount,
+};
+
+int vfs_isfs_mount(const char* name) {
+    isfs_volume_t* volume = malloc(sizeof(*volume));
+    if(volume == NULL) {
+        errno = ENOMEM;
+        return -1;
+    }
+
+    memset(volume, 0, sizeof(*volume));
+    vfs_descriptor_table_init(&volume->files, sizeof(file_descriptor_t));
+
+    if(isfs_bounce_lock == NULL)
+        isfs_bounce_lock = xSemaphoreCreateMutexStatic(&isfs_bounce_lock_data);
+
+    if(vfs_mount(name, &isfs_driver, volume) < 0) {
+        free(volume);
+        return -1;
+    }
+
+    return 0;
+}
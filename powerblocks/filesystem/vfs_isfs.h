@@ -0,0 +1,24 @@
+/**
+ * @file vfs_isfs.h
+ * @brief NAND driver for the virtual file system
+ *
+ * Mounts the wii's internal NAND, through ISFS, as a VFS device.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+/**
+ * @brief Mounts the NAND as a device.
+ *
+ * isfs_initialize must be called first.
+ * What can be read and written depends on the permissions
+ * IOS gives the running title.
+ *
+ * @param name Name of the device, like "nand".
+ * @return Negative with errno set if error.
+*/
+extern int vfs_isfs_mount(const char* name);
@@ -0,0 +1,266 @@
+/**
+ * @file vfs_romfs.c
+ * @brief romfs driver for the virtual file system
+ *
+ * Implements the VFS driver functions on top of romfs.
+ * Anything that would change the archive fails with EROFS.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "vfs_romfs.h"
+
+#include <stdlib.h>
+#include <stdio.h>
+#include <string.h>
+#include <errno.h>
+#include <fcntl.h>
+#include <sys/stat.h>
+
+#include "romfs.h"
+#include "vfs.h"
+
+typedef struct {
+    bool used;
+    romfs_entry_t entry;
+    uint32_t position;
+} file_descriptor_t;
+
+typedef struct {
+    romfs_t romfs;
+
+    vfs_descriptor_table_t files;
+} romfs_volume_t;
+
+typedef struct {
+    romfs_entry_t entry;
+    uint32_t next; // Child read next
+} romfs_directory_t;
+
+// Gets the file of a descriptor, or NULL with errno set
+static file_descriptor_t* get_file(romfs_volume_t* volume, int fd) {
+    return vfs_descriptor_get(&volume->files, fd);
+}
+
+static void fill_stat(struct stat* st, const romfs_entry_t* entry) {
+    memset(st, 0, sizeof(*st));
+
+    // Unlike FAT, entries have numbers that never change
+    st->st_ino = entry->index + 1;
+    st->st_mode = entry->directory ? S_IFDIR | 0555 : S_IFREG | 0444;
+    st->st_size = entry->size;
+    st->st_nlink = 1;
+    st->st_blksize = ROMFS_ALIGNMENT;
+    st->st_blocks = (st->st_size + 511) / 512;
+}
+
+static int romfs_vfs_open(void* context, const char* path, int flags, mode_t mode) {
+    romfs_volume_t* volume = (romfs_volume_t*)context;
+
+    romfs_entry_t entry;
+    if(romfs_find(&volume->romfs, path, &entry) < 0) {
+        // Creating it would fail anyway
+        if(errno == ENOENT && (flags & O_CREAT))
+            errno = EROFS;
+        return -1;
+    }
+
+    if((flags & O_ACCMODE) != O_RDONLY || (flags & (O_TRUNC | O_APPEND))) {
+        errno = EROFS;
+        return -1;
+    }
+
+    if(entry.directory) {
+        errno = EISDIR;
+        return -1;
+    }
+
+    int fd = vfs_descriptor_allocate(&volume->files);
+    if(fd < 0)
+        return -1;
+
+    file_descriptor_t* file = get_file(volume, fd);
+    file->entry = entry;
+    file->position = 0;
+
+    return fd;
+}
+
+static int romfs_vfs_close(void* context, int fd) {
+    romfs_volume_t* volume = (romfs_volume_t*)context;
+    if(get_file(volume, fd) == NULL)
+        return -1;
+
+    vfs_descriptor_free(&volume->files, fd);
+    return 0;
+}
+
+static ssize_t romfs_vfs_read(void* context, int fd, void* buffer, size_t count) {
+    file_descriptor_t* file = get_file((romfs_volume_t*)context, fd);
+    if(file == NULL)
+        return -1;
+
+    if(file->position >= file->entry.size)
+        return 0;
+
+    size_t left = file->entry.size - file->position;
+    if(count > left)
+        count = left;
+
+    memcpy(buffer, file->entry.data + file->position, count);
+    file->position += count;
+
+    return count;
+}
+
+static off_t romfs_vfs_lseek(void* context, int fd, off_t offset, int whence) {
+    file_descriptor_t* file = get_file((romfs_volume_t*)context, fd);
+    if(file == NULL)
+        return -1;
+
+    int64_t new_pos;
+
+    switch(whence) {
+        case SEEK_SET:
+            new_pos = offset;
+            break;
+        case SEEK_CUR:
+            new_pos = (int64_t)file->position + offset;
+            break;
+        case SEEK_END:
+            new_pos = (int64_t)file->entry.size + offset;
+            break;
+        default:
+            errno = EINVAL;
+            return -1;
+    }
+
+    // Past the end is fine, reads there just end
+    if(new_pos < 0 || new_pos > UINT32_MAX) {
+        errno = EINVAL;
+        return -1;
+    }
+
+    file->position = new_pos;
+    return new_pos;
+}
+
+static int romfs_vfs_fstat(void* context, int fd, struct stat* st) {
+    file_descriptor_t* file = get_file((romfs_volume_t*)context, fd);
+    if(file == NULL)
+        return -1;
+
+    fill_stat(st, &file->entry);
+    return 0;
+}
+
+static int romfs_vfs_stat(void* context, const char* path, struct stat* st) {
+    romfs_volume_t* volume = (romfs_volume_t*)context;
+
+    romfs_entry_t entry;
+    if(romfs_find(&volume->romfs, path, &entry) < 0)
+        return -1;
+
+    fill_stat(st, &entry);
+    return 0;
+}
+
+static void* romfs_vfs_opendir(void* context, const char* path) {
+    romfs_volume_t* volume = (romfs_volume_t*)context;
+
+    romfs_entry_t entry;
+    if(romfs_find(&volume->romfs, path, &entry) < 0)
+        return NULL;
+
+    if(!entry.directory) {
+        errno = ENOTDIR;
+        return NULL;
+    }
+
+    romfs_directory_t* directory = malloc(sizeof(*directory));
+    if(directory == NULL) {
+        errno = ENOMEM;
+        return NULL;
+    }
+
+    directory->entry = entry;
+    directory->next = 0;
+
+    return directory;
+}
+
+static int romfs_vfs_readdir(void* context, void* dir, struct dirent* entry) {
+    romfs_volume_t* volume = (romfs_volume_t*)context;
+    romfs_directory_t* directory = (romfs_directory_t*)dir;
+
+    if(directory->next >= directory->entry.child_count)
+        return 0;
+
+    romfs_entry_t child;
+    romfs_get_entry(&volume->romfs, directory->entry.first_child + directory->next, &child);
+    directory->next++;
+
+    vfs_fill_dirent(entry, child.index + 1, child.name, child.directory);
+
+    return 1;
+}
+
+static void romfs_vfs_rewinddir(void* context, void* dir) {
+    ((romfs_directory_t*)dir)->next = 0;
+}
+
+static int romfs_vfs_closedir(void* context, void* dir) {
+    free(dir);
+    return 0;
+}
+
+static void romfs_vfs_unmount(void* context) {
+    romfs_volume_t* volume = (romfs_volume_t*)context;
+
+    vfs_descriptor_table_free(&volume->files);
+    free(volume);
+}
+
+// Left out functions would change the archive, the VFS fails them.
+static const vfs_driver_t romfs_driver = {
+    .open = romfs_vfs_open,
+    .close = romfs_vfs_close,
+    .read = romfs_vfs_read,
+    .lseek = romfs_vfs_lseek,
+    .fstat = romfs_vfs_fstat,
+
+    .stat = romfs_vfs_stat,
+
+    .opendir = romfs_vfs_opendir,
+    .readdir = romfs_vfs_readdir,
+    .rewinddir = romfs_vfs_rewinddir,
+    .closedir = romfs_vfs_closedir,
+
+    .unmount = romfs_vfs_unmount,
+};
+
+int vfs_romfs_mount(const char* name, const void* image, size_t size) {
+    romfs_volume_t* volume = malloc(sizeof(*volume));
+    if(volume == NULL) {
+        errno = ENOMEM;
+        return -1;
+    }
+
+    memset(volume, 0, sizeof(*volume));
+    vfs_descriptor_table_init(&volume->files, sizeof(file_descriptor_t));
+
+    if(romfs_open(&volume->romfs, image, size) < 0) {
+        free(volume);
+        errno = EINVAL;
+        return -1;
+    }
+
+    if(vfs_mount(name, &romfs_driver, volume) < 0) {
+        free(volume);
+        return -1;
+    }
+
+    return 0;
+}
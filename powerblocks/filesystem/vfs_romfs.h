@@ -0,0 +1,26 @@
+/**
+ * @file vfs_romfs.h
+ * @brief romfs driver for the virtual file system
+ *
+ * Mounts a archive built into the app as a read only device.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include <stddef.h>
+
+/**
+ * @brief Mounts a romfs archive as a device.
+ *
+ * The archive is used in place, so it must stay valid while mounted.
+ *
+ * @param name Name of the device, like "romfs".
+ * @param image The archive, from romfs_embed.
+ * @param size Size of the archive in bytes.
+ * @return Negative with errno set if error.
+*/
+extern int vfs_romfs_mount(const char* name, const void* image, size_t size);
@@ -0,0 +1,366 @@
+/**
+ * @file wiimote_host.c
+ * @brief Scripted WiiMotes on the host.
+ *
+ * There is no Bluetooth on the host, so the remotes are driven by
+ * a script set with POWERBLOCKS_HOST_WIIMOTE_SCRIPT. Each line is one event:
+ *
+ *   <poll> <remote> <command> [args]
+ *
+ * The event happens on that wiimote_poll, counting from 1.
+ * Lines must be in order of poll. Empty lines and lines starting with # are skipped.
+ *
+ * Commands:
+ *   connect, disconnect
+ *   press <button>, release <button>
+ *     left right down up plus two one b a minus home
+ *   extension <none|nunchuk|classic>
+ *   ext-press <button>, ext-release <button>
+ *     Nunchuk: z c
+ *     Classic: up down left right a b x y l r zl zr plus minus home
+ *   stick <x> <y>   Nunchuk stick, or Classic Controller left stick
+ *   accel <x> <y> <z>
+ *   cursor <x> <y>
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "wiimote.h"
+
+#include "powerblocks/core/bluetooth/blerror.h"
+#include "powerblocks/core/host/host.h"
+
+#include "wiimote_log.h"
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+const char* WIIMOTE_TAG = "WIIMOTE";
+
+typedef enum {
+    WIIMOTE_HOST_CONNECT,
+    WIIMOTE_HOST_DISCONNECT,
+    WIIMOTE_HOST_PRESS,
+    WIIMOTE_HOST_RELEASE,
+    WIIMOTE_HOST_EXTENSION,
+    WIIMOTE_HOST_EXT_PRESS,
+    WIIMOTE_HOST_EXT_RELEASE,
+    WIIMOTE_HOST_STICK,
+    WIIMOTE_HOST_ACCEL,
+    WIIMOTE_HOST_CURSOR
+} wiimote_host_command_t;
+
+typedef struct {
+    uint32_t poll;
+    int remote;
+    wiimote_host_command_t command;
+
+    // Button bits or extension type, depending on the command
+    uint32_t value;
+    float args[3];
+} wiimote_host_event_t;
+
+typedef struct {
+    const char* name;
+    uint32_t value;
+} wiimote_host_name_t;
+
+// State the script has built up, applied on each poll
+typedef struct {
+    bool connected;
+    uint16_t buttons;
+    wiimote_extension_t extension;
+    uint16_t extension_buttons;
+} wiimote_host_remote_t;
+
+static const wiimote_host_name_t WIIMOTE_HOST_COMMANDS[] = {
+    {"connect", WIIMOTE_HOST_CONNECT},
+    {"disconnect", WIIMOTE_HOST_DISCONNECT},
+    {"press", WIIMOTE_HOST_PRESS},
+    {"release", WIIMOTE_HOST_RELEASE},
+    {"extension", WIIMOTE_HOST_EXTENSION},
+    {"ext-press", WIIMOTE_HOST_EXT_PRESS},
+    {"ext-release", WIIMOTE_HOST_EXT_RELEASE},
+    {"stick", WIIMOTE_HOST_STICK},
+    {"accel", WIIMOTE_HOST_ACCEL},
+    {"cursor", WIIMOTE_HOST_CURSOR},
+    {NULL, 0}
+};
+
+static const wiimote_host_name_t WIIMOTE_HOST_BUTTONS[] = {
+    {"left", WIIMOTE_BUTTONS_DPAD_LEFT},
+    {"right", WIIMOTE_BUTTONS_DPAD_RIGHT},
+    {"down", WIIMOTE_BUTTONS_DPAD_DOWN},
+    {"up", WIIMOTE_BUTTONS_DPAD_UP},
+    {"plus", WIIMOTE_BUTTONS_PLUS},
+    {"two", WIIMOTE_BUTTONS_TWO},
+    {"one", WIIMOTE_BUTTONS_ONE},
+    {"b", WIIMOTE_BUTTONS_B},
+    {"a", WIIMOTE_BUTTONS_A},
+    {"minus", WIIMOTE_BUTTONS_MINUS},
+    {"home", WIIMOTE_BUTTONS_HOME},
+    {NULL, 0}
+};
+
+static const wiimote_host_name_t WIIMOTE_HOST_EXTENSIONS[] = {
+    {"none", WIIMOTE_EXTENSION_NONE},
+    {"nunchuk", WIIMOTE_EXTENSION_NUNCHUK},
+    {"classic", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER},
+    {NULL, 0}
+};
+
+static const wiimote_host_name_t WIIMOTE_HOST_NUNCHUK_BUTTONS[] = {
+    {"z", WIIMOTE_EXTENSION_NUNCHUCK_BUTTONS_Z},
+    {"c", WIIMOTE_EXTENSION_NUNCHUCK_BUTTONS_C},
+    {NULL, 0}
+};
+
+static const wiimote_host_name_t WIIMOTE_HOST_CLASSIC_BUTTONS[] = {
+    {"up", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_DPAD_UP},
+    {"down", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_DPAD_DOWN},
+    {"left", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_DPAD_LEFT},
+    {"right", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_DPAD_RIGHT},
+    {"a", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_A},
+    {"b", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_B},
+    {"x", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_X},
+    {"y", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_Y},
+    {"l", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_LEFT_TRIGGER},
+    {"r", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_RIGHT_TRIGGER},
+    {"zl", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_Z_LEFT},
+    {"zr", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_Z_RIGHT},
+    {"plus", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_PLUS},
+    {"minus", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_MINUS},
+    {"home", WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_HOME},
+    {NULL, 0}
+};
+
+wiimote_t WIIMOTES[WIIMOTE_MAX_REMOTES];
+
+static wiimote_host_remote_t wiimote_host_remotes[WIIMOTE_MAX_REMOTES];
+
+static wiimote_host_event_t* wiimote_host_events;
+static size_t wiimote_host_event_count;
+static size_t wiimote_host_next_event;
+static uint32_t wiimote_host_poll_count;
+
+static bool wiimote_host_lookup(const wiimote_host_name_t* names, const char* name, uint32_t* value) {
+    for(; names->name != NULL; names++) {
+        if(strcmp(names->name, name) == 0) {
+            *value = names->value;
+            return true;
+        }
+    }
+
+    return false;
+}
+
+static bool wiimote_host_parse_line(char* line, wiimote_host_event_t* event) {
+    char command[16];
+    char name[16];
+    int used;
+
+    if(sscanf(line, "%u %d %15s %n", &event->poll, &event->remote, command, &used) != 3)
+        return false;
+
+    if(event->remote < 0 || event->remote >= WIIMOTE_MAX_REMOTES)
+        return false;
+
+    uint32_t value;
+    if(!wiimote_host_lookup(WIIMOTE_HOST_COMMANDS, command, &value))
+        return false;
+    event->command = value;
+
+    const char* args = line + used;
+    switch(event->command) {
+        case WIIMOTE_HOST_CONNECT:
+        case WIIMOTE_HOST_DISCONNECT:
+            return true;
+        case WIIMOTE_HOST_PRESS:
+        case WIIMOTE_HOST_RELEASE:
+            return sscanf(args, "%15s", name) == 1 && wiimote_host_lookup(WIIMOTE_HOST_BUTTONS, name, &event->value);
+        case WIIMOTE_HOST_EXTENSION:
+            return sscanf(args, "%15s", name) == 1 && wiimote_host_lookup(WIIMOTE_HOST_EXTENSIONS, name, &event->value);
+        case WIIMOTE_HOST_EXT_PRESS:
+        case WIIMOTE_HOST_EXT_RELEASE:
+            // The names do not overlap, so the extension does not need to be known yet.
+            if(sscanf(args, "%15s", name) != 1)
+                return false;
+
+            return wiimote_host_lookup(WIIMOTE_HOST_NUNCHUK_BUTTONS, name, &event->value)
+                || wiimote_host_lookup(WIIMOTE_HOST_CLASSIC_BUTTONS, name, &event->value);
+        case WIIMOTE_HOST_STICK:
+        case WIIMOTE_HOST_CURSOR:
+            return sscanf(args, "%f %f", &event->args[0], &event->args[1]) == 2;
+        case WIIMOTE_HOST_ACCEL:
+            return sscanf(args, "%f %f %f", &event->args[0], &event->args[1], &event->args[2]) == 3;
+    }
+
+    return false;
+}
+
+static void wiimote_host_load_script(const char* path) {
+    FILE* file = fopen(path, "r");
+    if(file == NULL) {
+        WIIMOTE_LOG_ERROR("Failed to open wiimote script %s", path);
+        return;
+    }
+
+    char line[128];
+    int line_number = 0;
+    while(fgets(line, sizeof(line), file)) {
+        line_number++;
+
+        line[strcspn(line, "\r\n")] = 0;
+
+        char* start = line + strspn(line, " \t");
+        if(*start == 0 || *start == '#')
+            continue;
+
+        wiimote_host_event_t event;
+        memset(&event, 0, sizeof(event));
+        if(!wiimote_host_parse_line(start, &event)) {
+            WIIMOTE_LOG_ERROR("Bad wiimote script line %d: %s", line_number, start);
+            continue;
+        }
+
+        wiimote_host_event_t* events = realloc(wiimote_host_events, (wiimote_host_event_count + 1) * sizeof(event));
+        if(events == NULL)
+            break;
+
+        wiimote_host_events = events;
+        wiimote_host_events[wiimote_host_event_count++] = event;
+    }
+
+    fclose(file);
+
+    WIIMOTE_LOG_INFO("Loaded %d wiimote script events.", (int)wiimote_host_event_count);
+}
+
+static void wiimote_host_apply(const wiimote_host_event_t* event) {
+    wiimote_host_remote_t* remote = &wiimote_host_remotes[event->remote];
+    wiimote_t* wiimote = &WIIMOTES[event->remote];
+
+    switch(event->command) {
+        case WIIMOTE_HOST_CONNECT:
+            memset(remote, 0, sizeof(*remote));
+            memset(wiimote, 0, sizeof(*wiimote));
+            remote->connected = true;
+
+            // Any non NULL driver marks the slot as taken
+            wiimote->driver = remote;
+            wiimote->present = WIIMOTE_PRESENT_BUTTONS;
+            break;
+        case WIIMOTE_HOST_DISCONNECT:
+            remote->connected = false;
+            wiimote->driver = NULL;
+            break;
+        case WIIMOTE_HOST_PRESS:
+            remote->buttons |= event->value;
+            break;
+        case WIIMOTE_HOST_RELEASE:
+            remote->buttons &= ~event->value;
+            break;
+        case WIIMOTE_HOST_EXTENSION:
+            remote->extension = event->value;
+            remote->extension_buttons = 0;
+            memset(&wiimote->extensions, 0, sizeof(wiimote->extensions));
+            wiimote->extensions.type = event->value;
+            break;
+        case WIIMOTE_HOST_EXT_PRESS:
+            remote->extension_buttons |= event->value;
+            break;
+        case WIIMOTE_HOST_EXT_RELEASE:
+            remote->extension_buttons &= ~event->value;
+            break;
+        case WIIMOTE_HOST_STICK:
+            if(remote->extension == WIIMOTE_EXTENSION_NUNCHUK) {
+                wiimote->extensions.nunchuck.stick.x = event->args[0];
+                wiimote->extensions.nunchuck.stick.y = event->args[1];
+            } else if(remote->extension == WIIMOTE_EXTENSION_CLASSIC_CONTROLLER) {
+                wiimote->extensions.classic_controller.left_stick.x = event->args[0];
+                wiimote->extensions.classic_controller.left_stick.y = event->args[1];
+            }
+            break;
+        case WIIMOTE_HOST_ACCEL:
+            wiimote->accelerometer.rectangular.x = event->args[0];
+            wiimote->accelerometer.rectangular.y = event->args[1];
+            wiimote->accelerometer.rectangular.z = event->args[2];
+            break;
+        case WIIMOTE_HOST_CURSOR:
+            wiimote->cursor.pos = vec2i_new(event->args[0], event->args[1]);
+            break;
+    }
+}
+
+void wiimotes_initialize() {
+    memset(WIIMOTES, 0, sizeof(WIIMOTES));
+    memset(wiimote_host_remotes, 0, sizeof(wiimote_host_remotes));
+
+    const char* script = getenv(HOST_ENV_WIIMOTE_SCRIPT);
+    if(script != NULL)
+        wiimote_host_load_script(script);
+}
+
+void wiimote_poll() {
+    wiimote_host_poll_count++;
+
+    while(wiimote_host_next_event < wiimote_host_event_count) {
+        const wiimote_host_event_t* event = &wiimote_host_events[wiimote_host_next_event];
+        if(event->poll > wiimote_host_poll_count)
+            break;
+
+        wiimote_host_apply(event);
+        wiimote_host_next_event++;
+    }
+
+    for(int i = 0; i < WIIMOTE_MAX_REMOTES; i++) {
+        const wiimote_host_remote_t* remote = &wiimote_host_remotes[i];
+        if(!remote->connected)
+            continue;
+
+        wiimote_set_button_helper(&WIIMOTES[i].buttons, remote->buttons);
+
+        switch(remote->extension) {
+            case WIIMOTE_EXTENSION_NUNCHUK:
+                wiimote_set_button_helper(&WIIMOTES[i].extensions.nunchuck.buttons, remote->extension_buttons);
+                break;
+            case WIIMOTE_EXTENSION_CLASSIC_CONTROLLER:
+                wiimote_set_button_helper(&WIIMOTES[i].extensions.classic_controller.buttons, remote->extension_buttons);
+                break;
+            default:
+                break;
+        }
+    }
+}
+
+int wiimote_find_empty_slot() {
+    for(int i = 0; i < WIIMOTE_MAX_REMOTES; i++) {
+        if(WIIMOTES[i].driver == NULL) {
+            return i;
+        }
+    }
+    return -1;
+}
+
+int wiimote_remove_slot(void* driver) {
+    for(int i = 0; i < WIIMOTE_MAX_REMOTES; i++) {
+        if(WIIMOTES[i].driver == driver) {
+            WIIMOTES[i].driver = NULL;
+            return 0;
+        }
+    }
+    return -1;
+}
+
+int wiimote_set_reporting(wiimote_t* wiimote, int present) {
+    if(wiimote->driver == NULL) {
+        return BLERROR_ARGUMENT;
+    }
+
+    // Scripted remotes can report anything
+    wiimote->present = present | WIIMOTE_PRESENT_BUTTONS;
+    return 0;
+}
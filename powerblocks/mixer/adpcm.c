@@ -0,0 +1,51 @@
+/**
+ * @file adpcm.c
+ * @brief GameCube DSP-ADPCM decoding.
+ *
+ * Decodes the 4-bit ADPCM format played by the GameCube and Wii DSP.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "adpcm.h"
+
+int16_t adpcm_decode_sample(const uint8_t* data, uint32_t sample, const int16_t* coefficients, int16_t* hist1, int16_t* hist2) {
+    const uint8_t* frame = data + (sample / ADPCM_SAMPLES_PER_FRAME) * ADPCM_FRAME_SIZE;
+    uint32_t index = sample % ADPCM_SAMPLES_PER_FRAME;
+
+    // Upper nibble picks the coefficients, lower nibble is the scale
+    uint8_t header = frame[0];
+    int32_t coefficient_1 = coefficients[(header >> 4) * 2 + 0];
+    int32_t coefficient_2 = coefficients[(header >> 4) * 2 + 1];
+    int32_t scale = 1 << (header & 0xF);
+
+    // Two samples a byte, high nibble first
+    uint8_t byte = frame[1 + index / 2];
+    int32_t nibble = (index & 1) ? (byte & 0xF) : (byte >> 4);
+    if(nibble >= 8)
+        nibble -= 16;
+
+    int32_t value = nibble * scale * 2048 + 1024 + coefficient_1 * *hist1 + coefficient_2 * *hist2;
+    value >>= 11;
+
+    if(value > INT16_MAX)
+        value = INT16_MAX;
+    if(value < INT16_MIN)
+        value = INT16_MIN;
+
+    *hist2 = *hist1;
+    *hist1 = value;
+
+    return value;
+}
+
+void adpcm_decode(const uint8_t* data, const adpcm_info_t* info, int16_t* out, uint32_t count) {
+    int16_t hist1 = info->hist1;
+    int16_t hist2 = info->hist2;
+
+    for(uint32_t i = 0; i < count; i++) {
+        out[i] = adpcm_decode_sample(data, i, info->coefficients, &hist1, &hist2);
+    }
+}
@@ -0,0 +1,71 @@
+/**
+ * @file adpcm.h
+ * @brief GameCube DSP-ADPCM decoding.
+ *
+ * Decodes the 4-bit ADPCM format played by the GameCube and Wii DSP.
+ * Samples come in 8 byte frames of 14 samples. Each frame starts with
+ * a byte selecting the coefficient pair and scale for its samples.
+ *
+ * This has no dependencies, so it can be tested on the host.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include <stdint.h>
+
+/** @def ADPCM_FRAME_SIZE
+ *  @brief Size of a frame in bytes.
+ */
+#define ADPCM_FRAME_SIZE 8
+
+/** @def ADPCM_SAMPLES_PER_FRAME
+ *  @brief Samples decoded from each frame.
+ */
+#define ADPCM_SAMPLES_PER_FRAME 14
+
+/**
+ * @struct adpcm_info_t
+ * @brief Decoding state of a ADPCM sound.
+ *
+ * Found in the header of .dsp files.
+ */
+typedef struct {
+    // 8 pairs of coefficients in 1.11 fixed point
+    int16_t coefficients[16];
+
+    // History at the start of the sound
+    int16_t hist1;
+    int16_t hist2;
+
+    // History at the loop start
+    int16_t loop_hist1;
+    int16_t loop_hist2;
+} adpcm_info_t;
+
+/**
+ * @brief Decodes a single sample.
+ *
+ * Samples must be decoded in order, as each one depends on the history.
+ *
+ * @param data ADPCM frames.
+ * @param sample Index of the sample to decode.
+ * @param coefficients Coefficients of the sound.
+ * @param hist1 Last sample decoded, updated to this sample.
+ * @param hist2 Sample before that, updated to the last sample.
+ * @return The decoded sample.
+ */
+extern int16_t adpcm_decode_sample(const uint8_t* data, uint32_t sample, const int16_t* coefficients, int16_t* hist1, int16_t* hist2);
+
+/**
+ * @brief Decodes samples from the start of a sound.
+ *
+ * @param data ADPCM frames.
+ * @param info Coefficients and starting history.
+ * @param out Decoded samples.
+ * @param count Number of samples to decode.
+ */
+extern void adpcm_decode(const uint8_t* data, const adpcm_info_t* info, int16_t* out, uint32_t count);
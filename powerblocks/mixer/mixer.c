@@ -0,0 +1,204 @@
+/**
+ * @file mixer.c
+ * @brief Software audio mixer.
+ *
+ * The audio interface hands back each buffer it has played.
+ * The mixer task mixes the voices into it while the other one plays.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "mixer.h"
+
+#include "powerblocks/core/system/system.h"
+#include "powerblocks/core/system/exceptions.h"
+#include "powerblocks/core/utils/log.h"
+
+#include "FreeRTOS.h"
+#include "queue.h"
+#include "semphr.h"
+#include "task.h"
+
+static const char* TAG = "MIXER";
+
+#define MIXER_TASK_STACK_SIZE 4096
+#define MIXER_TASK_PRIORITY   (configMAX_PRIORITIES / 4 * 3)
+
+static struct {
+    mixer_voice_t voices[MIXER_VOICE_COUNT];
+    uint32_t output_rate;
+
+    int16_t* buffers[2];
+    uint32_t frames;
+
+    // Buffers the audio interface is done with
+    QueueHandle_t free_buffers;
+    StaticQueue_t free_buffers_data;
+    int16_t* free_buffers_storage[2];
+
+    // Held while touching the voices
+    SemaphoreHandle_t lock;
+    StaticSemaphore_t lock_data;
+
+    TaskHandle_t task;
+} mixer_state;
+
+static void mixer_buffer_callback(int16_t* buffer, uint32_t frames) {
+    xQueueSendFromISR(mixer_state.free_buffers, &buffer, &exception_isr_context_switch_needed);
+}
+
+static void mixer_fill(int16_t* buffer) {
+    xSemaphoreTake(mixer_state.lock, portMAX_DELAY);
+    mixer_mix(mixer_state.voices, MIXER_VOICE_COUNT, buffer, mixer_state.frames);
+    xSemaphoreGive(mixer_state.lock);
+
+    audio_commit_buffer(buffer, mixer_state.frames);
+}
+
+static void mixer_task(void* params) {
+    while(true) {
+        int16_t* buffer;
+        if(xQueueReceive(mixer_state.free_buffers, &buffer, portMAX_DELAY) != pdPASS)
+            continue;
+
+        mixer_fill(buffer);
+    }
+}
+
+static mixer_voice_t* mixer_get_voice(int voice) {
+    if(voice < 0 || voice >= MIXER_VOICE_COUNT) {
+        LOG_ERROR(TAG, "Invalid voice %d.", voice);
+        return NULL;
+    }
+
+    return &mixer_state.voices[voice];
+}
+
+int mixer_initialize(audio_sample_rate_t rate, uint32_t frames) {
+    uint32_t size = frames * AUDIO_FRAME_SIZE;
+    if(frames == 0 || size % AUDIO_BUFFER_ALIGNMENT != 0) {
+        LOG_ERROR(TAG, "Buffer of %u samples is not a multiple of %d bytes.", (unsigned int)frames, AUDIO_BUFFER_ALIGNMENT);
+        return -1;
+    }
+
+    for(int i = 0; i < MIXER_VOICE_COUNT; i++) {
+        mixer_voice_initialize(&mixer_state.voices[i]);
+    }
+
+    mixer_state.frames = frames;
+    mixer_state.buffers[0] = system_aligned_malloc(size, AUDIO_BUFFER_ALIGNMENT);
+    mixer_state.buffers[1] = system_aligned_malloc(size, AUDIO_BUFFER_ALIGNMENT);
+    if(mixer_state.buffers[0] == NULL || mixer_state.buffers[1] == NULL) {
+        LOG_ERROR(TAG, "Out of memory for audio buffers.");
+        goto ERROR;
+    }
+
+    mixer_state.free_buffers = xQueueCreateStatic(2, sizeof(int16_t*),
+        (uint8_t*)mixer_state.free_buffers_storage, &mixer_state.free_buffers_data);
+    mixer_state.lock = xSemaphoreCreateMutexStatic(&mixer_state.lock_data);
+
+    audio_initialize(rate);
+    mixer_state.output_rate = audio_get_sample_rate_hz();
+
+    // Start with silence in both
+    mixer_fill(mixer_state.buffers[0]);
+    mixer_fill(mixer_state.buffers[1]);
+
+    BaseType_t err = xTaskCreate(mixer_task, TAG, MIXER_TASK_STACK_SIZE, NULL, MIXER_TASK_PRIORITY, &mixer_state.task);
+    if(err != pdPASS) {
+        LOG_ERROR(TAG, "Failed to create mixer task.");
+        goto ERROR;
+    }
+
+    if(audio_start(mixer_state.buffers[0], mixer_state.buffers[1], size, mixer_buffer_callback) < 0) {
+        vTaskDelete(mixer_state.task);
+        goto ERROR;
+    }
+
+    LOG_INFO(TAG, "Mixer initialized.");
+
+    return 0;
+
+ERROR:
+    // system_aligned_free takes NULL, like free
+    system_aligned_free(mixer_state.buffers[0]);
+    system_aligned_free(mixer_state.buffers[1]);
+    mixer_state.buffers[0] = NULL;
+    mixer_state.buffers[1] = NULL;
+    return -1;
+}
+
+int mixer_find_free_voice() {
+    int found = -1;
+
+    xSemaphoreTake(mixer_state.lock, portMAX_DELAY);
+    for(int i = 0; i < MIXER_VOICE_COUNT; i++) {
+        if(!mixer_state.voices[i].playing) {
+            found = i;
+            break;
+        }
+    }
+    xSemaphoreGive(mixer_state.lock);
+
+    return found;
+}
+
+void mixer_play(int voice, const mixer_sound_t* sound) {
+    mixer_voice_t* v = mixer_get_voice(voice);
+    if(v == NULL)
+        return;
+
+    xSemaphoreTake(mixer_state.lock, portMAX_DELAY);
+    mixer_voice_start(v, sound, mixer_state.output_rate);
+    xSemaphoreGive(mixer_state.lock);
+}
+
+void mixer_stop(int voice) {
+    mixer_voice_t* v = mixer_get_voice(voice);
+    if(v == NULL)
+        return;
+
+    xSemaphoreTake(mixer_state.lock, portMAX_DELAY);
+    mixer_voice_stop(v);
+    xSemaphoreGive(mixer_state.lock);
+}
+
+bool mixer_is_playing(int voice) {
+    mixer_voice_t* v = mixer_get_voice(voice);
+    if(v == NULL)
+        return false;
+
+    return v->playing;
+}
+
+void mixer_set_volume(int voice, uint8_t volume) {
+    mixer_voice_t* v = mixer_get_voice(voice);
+    if(v == NULL)
+        return;
+
+    xSemaphoreTake(mixer_state.lock, portMAX_DELAY);
+    v->volume = volume;
+    xSemaphoreGive(mixer_state.lock);
+}
+
+void mixer_set_pan(int voice, int8_t pan) {
+    mixer_voice_t* v = mixer_get_voice(voice);
+    if(v == NULL)
+        return;
+
+    xSemaphoreTake(mixer_state.lock, portMAX_DELAY);
+    v->pan = pan;
+    xSemaphoreGive(mixer_state.lock);
+}
+
+void mixer_set_pitch(int voice, float pitch) {
+    mixer_voice_t* v = mixer_get_voice(voice);
+    if(v == NULL)
+        return;
+
+    xSemaphoreTake(mixer_state.lock, portMAX_DELAY);
+    mixer_voice_set_pitch(v, pitch, mixer_state.output_rate);
+    xSemaphoreGive(mixer_state.lock);
+}
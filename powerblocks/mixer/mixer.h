@@ -0,0 +1,96 @@
+/**
+ * @file mixer.h
+ * @brief Software audio mixer.
+ *
+ * Mixes a set of voices on a task and plays them through the
+ * audio interface. Each voice plays a mono sound with its own
+ * pitch, volume and pan.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include "powerblocks/core/audio/audio.h"
+
+#include "powerblocks/mixer/mixer_voice.h"
+
+/** @def MIXER_VOICE_COUNT
+ *  @brief Number of voices that can play at once.
+ */
+#define MIXER_VOICE_COUNT 16
+
+/**
+ * @brief Initializes the mixer.
+ *
+ * Initializes the audio interface, and starts the mixer task
+ * feeding it. All voices start stopped.
+ *
+ * Smaller buffers lower the delay before sounds are heard,
+ * but the mixer task must run more often.
+ *
+ * @param rate Sample rate of the output.
+ * @param frames Stereo samples in each audio buffer. Must be a multiple of 8.
+ * @return Negative if error.
+ */
+extern int mixer_initialize(audio_sample_rate_t rate, uint32_t frames);
+
+/**
+ * @brief Gets a voice that is not playing.
+ *
+ * @return Index of the voice, or -1 if all are playing.
+ */
+extern int mixer_find_free_voice();
+
+/**
+ * @brief Starts playing a sound on a voice.
+ *
+ * Replaces whatever the voice was playing.
+ * Volume, pan and pitch are kept.
+ *
+ * @param voice Index of the voice.
+ * @param sound Sound to play. Must stay around while playing.
+ */
+extern void mixer_play(int voice, const mixer_sound_t* sound);
+
+/**
+ * @brief Stops a voice.
+ *
+ * @param voice Index of the voice.
+ */
+extern void mixer_stop(int voice);
+
+/**
+ * @brief Checks if a voice is playing.
+ *
+ * Voices stop on their own at the end of sounds that do not loop.
+ *
+ * @param voice Index of the voice.
+ */
+extern bool mixer_is_playing(int voice);
+
+/**
+ * @brief Sets the volume of a voice.
+ *
+ * @param voice Index of the voice.
+ * @param volume Volume from 0 to MIXER_VOLUME_MAX.
+ */
+extern void mixer_set_volume(int voice, uint8_t volume);
+
+/**
+ * @brief Sets the pan of a voice.
+ *
+ * @param voice Index of the voice.
+ * @param pan From MIXER_PAN_LEFT to MIXER_PAN_RIGHT.
+ */
+extern void mixer_set_pan(int voice, int8_t pan);
+
+/**
+ * @brief Sets the pitch of a voice.
+ *
+ * @param voice Index of the voice.
+ * @param pitch Playback speed. 1 plays at the sound's own sample rate. Negative is clamped to 0.
+ */
+extern void mixer_set_pitch(int voice, float pitch);
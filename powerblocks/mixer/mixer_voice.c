@@ -0,0 +1,209 @@
+/**
+ * @file mixer_voice.c
+ * @brief Voices of the software mixer.
+ *
+ * Resamples with linear interpolation between source samples.
+ * Mixing is done in 32 bits and clamped at the end, so loud voices
+ * together clip instead of wrapping around.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "mixer_voice.h"
+
+#include <string.h>
+
+// Output is mixed in chunks of this many stereo samples
+#define MIXER_CHUNK_FRAMES 128
+
+#define MIXER_DSP_HEADER_SIZE 0x60
+
+static uint32_t mixer_read_u32(const uint8_t* data) {
+    return ((uint32_t)data[0] << 24) | ((uint32_t)data[1] << 16) | ((uint32_t)data[2] << 8) | data[3];
+}
+
+static uint16_t mixer_read_u16(const uint8_t* data) {
+    return ((uint16_t)data[0] << 8) | data[1];
+}
+
+// .dsp files address by nibble, with two header nibbles in each frame.
+static uint32_t mixer_nibble_to_sample(uint32_t nibble) {
+    uint32_t frame = nibble / 16;
+    uint32_t index = nibble % 16;
+
+    return frame * ADPCM_SAMPLES_PER_FRAME + (index < 2 ? 0 : index - 2);
+}
+
+static int16_t mixer_voice_read(mixer_voice_t* voice) {
+    const mixer_sound_t* sound = voice->sound;
+
+    if(voice->read_position >= sound->length) {
+        if(!sound->loop) {
+            voice->past_end++;
+            return 0;
+        }
+
+        voice->read_position = sound->loop_start;
+        voice->hist1 = sound->adpcm.loop_hist1;
+        voice->hist2 = sound->adpcm.loop_hist2;
+    }
+
+    uint32_t position = voice->read_position++;
+
+    switch(sound->format) {
+        case MIXER_FORMAT_PCM8:
+            return ((const int8_t*)sound->data)[position] * 256;
+        case MIXER_FORMAT_PCM16:
+            return ((const int16_t*)sound->data)[position];
+        case MIXER_FORMAT_ADPCM:
+            return adpcm_decode_sample(sound->data, position, sound->adpcm.coefficients, &voice->hist1, &voice->hist2);
+        default:
+            return 0;
+    }
+}
+
+void mixer_voice_initialize(mixer_voice_t* voice) {
+    memset(voice, 0, sizeof(*voice));
+
+    voice->volume = MIXER_VOLUME_MAX;
+    voice->pan = MIXER_PAN_CENTER;
+    voice->pitch = 1.0f;
+    voice->step = 1 << 16;
+}
+
+void mixer_voice_start(mixer_voice_t* voice, const mixer_sound_t* sound, uint32_t output_rate) {
+    voice->sound = sound;
+    voice->fraction = 0;
+    voice->read_position = 0;
+    voice->past_end = 0;
+    voice->hist1 = sound->adpcm.hist1;
+    voice->hist2 = sound->adpcm.hist2;
+
+    mixer_voice_set_pitch(voice, voice->pitch, output_rate);
+
+    voice->samples[0] = mixer_voice_read(voice);
+    voice->samples[1] = mixer_voice_read(voice);
+
+    voice->playing = sound->length > 0;
+}
+
+void mixer_voice_stop(mixer_voice_t* voice) {
+    voice->playing = false;
+}
+
+void mixer_voice_set_pitch(mixer_voice_t* voice, float pitch, uint32_t output_rate) {
+    // Sounds can not play backwards. Also catches NaN.
+    if(!(pitch > 0.0f))
+        pitch = 0.0f;
+
+    voice->pitch = pitch;
+
+    if(voice->sound == NULL || output_rate == 0)
+        return;
+
+    // Converting a float out of range of the step is undefined
+    float step = pitch * voice->sound->sample_rate / output_rate * 65536.0f;
+    voice->step = step < (float)UINT32_MAX ? (uint32_t)step : UINT32_MAX;
+}
+
+int mixer_sound_load_dsp(mixer_sound_t* sound, const void* file, size_t size) {
+    const uint8_t* header = file;
+
+    if(size < MIXER_DSP_HEADER_SIZE)
+        return -1;
+
+    // Only ADPCM is used in .dsp files
+    if(mixer_read_u16(header + 0x0E) != 0)
+        return -1;
+
+    memset(sound, 0, sizeof(*sound));
+
+    sound->format = MIXER_FORMAT_ADPCM;
+    sound->data = header + MIXER_DSP_HEADER_SIZE;
+    sound->length = mixer_read_u32(header + 0x00);
+    sound->sample_rate = mixer_read_u32(header + 0x08);
+    sound->loop = mixer_read_u16(header + 0x0C) != 0;
+
+    if(sound->loop) {
+        sound->loop_start = mixer_nibble_to_sample(mixer_read_u32(header + 0x10));
+        sound->length = mixer_nibble_to_sample(mixer_read_u32(header + 0x14)) + 1;
+    }
+
+    for(int i = 0; i < 16; i++) {
+        sound->adpcm.coefficients[i] = mixer_read_u16(header + 0x1C + i * 2);
+    }
+
+    sound->adpcm.hist1 = mixer_read_u16(header + 0x40);
+    sound->adpcm.hist2 = mixer_read_u16(header + 0x42);
+    sound->adpcm.loop_hist1 = mixer_read_u16(header + 0x46);
+    sound->adpcm.loop_hist2 = mixer_read_u16(header + 0x48);
+
+    // All frames of the sound must be in the file
+    size_t frames = (sound->length + ADPCM_SAMPLES_PER_FRAME - 1) / ADPCM_SAMPLES_PER_FRAME;
+    if(frames * ADPCM_FRAME_SIZE > size - MIXER_DSP_HEADER_SIZE)
+        return -1;
+
+    if(sound->loop && sound->loop_start >= sound->length)
+        return -1;
+
+    return 0;
+}
+
+static void mixer_mix_voice(mixer_voice_t* voice, int32_t* mix, uint32_t frames) {
+    int32_t pan = voice->pan < MIXER_PAN_LEFT ? MIXER_PAN_LEFT : voice->pan;
+
+    // Gains are out of 256
+    int32_t left = pan > 0 ? 256 * (MIXER_PAN_RIGHT - pan) / MIXER_PAN_RIGHT : 256;
+    int32_t right = pan < 0 ? 256 * (pan - MIXER_PAN_LEFT) / -MIXER_PAN_LEFT : 256;
+    left = left * voice->volume / MIXER_VOLUME_MAX;
+    right = right * voice->volume / MIXER_VOLUME_MAX;
+
+    for(uint32_t i = 0; i < frames; i++) {
+        int32_t difference = voice->samples[1] - voice->samples[0];
+        int32_t sample = voice->samples[0] + (int32_t)(((int64_t)difference * voice->fraction) >> 16);
+
+        mix[i * 2 + 0] += (sample * left) >> 8;
+        mix[i * 2 + 1] += (sample * right) >> 8;
+
+        voice->fraction += voice->step;
+        while(voice->fraction >= (1 << 16)) {
+            voice->fraction -= 1 << 16;
+            voice->samples[0] = voice->samples[1];
+            voice->samples[1] = mixer_voice_read(voice);
+        }
+
+        // Both samples are past the end, nothing left to play.
+        if(voice->past_end >= 2) {
+            voice->playing = false;
+            return;
+        }
+    }
+}
+
+void mixer_mix(mixer_voice_t* voices, int count, int16_t* out, uint32_t frames) {
+    int32_t mix[MIXER_CHUNK_FRAMES * 2];
+
+    while(frames > 0) {
+        uint32_t chunk = frames < MIXER_CHUNK_FRAMES ? frames : MIXER_CHUNK_FRAMES;
+        memset(mix, 0, chunk * 2 * sizeof(int32_t));
+
+        for(int i = 0; i < count; i++) {
+            if(voices[i].playing)
+                mixer_mix_voice(&voices[i], mix, chunk);
+        }
+
+        for(uint32_t i = 0; i < chunk * 2; i++) {
+            int32_t sample = mix[i];
+            if(sample > INT16_MAX)
+                sample = INT16_MAX;
+            if(sample < INT16_MIN)
+                sample = INT16_MIN;
+            out[i] = sample;
+        }
+
+        out += chunk * 2;
+        frames -= chunk;
+    }
+}
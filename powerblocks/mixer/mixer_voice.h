@@ -0,0 +1,163 @@
+/**
+ * @file mixer_voice.h
+ * @brief Voices of the software mixer.
+ *
+ * A voice plays a mono sound, resampled by its pitch,
+ * and is mixed into stereo output with a volume and pan.
+ *
+ * This has no dependencies, so it can be tested on the host.
+ * mixer.h runs it on a task to feed the audio interface.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include "adpcm.h"
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+/** @def MIXER_VOLUME_MAX
+ *  @brief Volume that leaves samples unchanged.
+ */
+#define MIXER_VOLUME_MAX 255
+
+/** @def MIXER_PAN_LEFT
+ *  @brief Pan playing only from the left channel.
+ */
+#define MIXER_PAN_LEFT -127
+
+/** @def MIXER_PAN_CENTER
+ *  @brief Pan playing evenly from both channels.
+ */
+#define MIXER_PAN_CENTER 0
+
+/** @def MIXER_PAN_RIGHT
+ *  @brief Pan playing only from the right channel.
+ */
+#define MIXER_PAN_RIGHT 127
+
+/**
+ * @enum mixer_format_t
+ * @brief Sample formats a sound can be in.
+ */
+typedef enum {
+    MIXER_FORMAT_PCM8,  // Signed 8 bit
+    MIXER_FORMAT_PCM16, // Signed 16 bit, in the byte order of the CPU
+    MIXER_FORMAT_ADPCM  // GameCube DSP-ADPCM
+} mixer_format_t;
+
+/**
+ * @struct mixer_sound_t
+ * @brief A mono sound that voices play.
+ *
+ * The sound data is not copied, it must stay around while playing.
+ */
+typedef struct {
+    mixer_format_t format;
+    const void* data;
+
+    // Length in samples
+    uint32_t length;
+    uint32_t sample_rate;
+
+    // If looping, play from loop_start after reaching the end.
+    bool loop;
+    uint32_t loop_start;
+
+    // Only used by MIXER_FORMAT_ADPCM
+    adpcm_info_t adpcm;
+} mixer_sound_t;
+
+/**
+ * @struct mixer_voice_t
+ * @brief Plays a sound.
+ *
+ * Set up with mixer_voice_initialize, then change it with the mixer_voice functions.
+ */
+typedef struct {
+    const mixer_sound_t* sound;
+    bool playing;
+
+    uint8_t volume;
+    int8_t pan;
+    float pitch;
+
+    // Source samples per output sample, 16.16 fixed point
+    uint32_t step;
+    uint32_t fraction;
+
+    // Output falls between these source samples
+    int16_t samples[2];
+
+    // Next source sample to read, and samples read past the end.
+    uint32_t read_position;
+    uint32_t past_end;
+
+    // ADPCM decoding history
+    int16_t hist1;
+    int16_t hist2;
+} mixer_voice_t;
+
+/**
+ * @brief Sets up a voice.
+ *
+ * The voice starts stopped, at full volume, centered and at a pitch of 1.
+ */
+extern void mixer_voice_initialize(mixer_voice_t* voice);
+
+/**
+ * @brief Starts playing a sound from the start.
+ *
+ * @param voice Voice to play on.
+ * @param sound Sound to play.
+ * @param output_rate Sample rate of the output in hertz.
+ */
+extern void mixer_voice_start(mixer_voice_t* voice, const mixer_sound_t* sound, uint32_t output_rate);
+
+/**
+ * @brief Stops playing.
+ */
+extern void mixer_voice_stop(mixer_voice_t* voice);
+
+/**
+ * @brief Sets the pitch.
+ *
+ * A pitch of 2 plays twice as fast, an octave higher.
+ * Negative pitches are clamped to 0, which holds the current sample.
+ *
+ * @param voice Voice to change.
+ * @param pitch Playback speed.
+ * @param output_rate Sample rate of the output in hertz.
+ */
+extern void mixer_voice_set_pitch(mixer_voice_t* voice, float pitch, uint32_t output_rate);
+
+/**
+ * @brief Loads a sound from a .dsp file.
+ *
+ * .dsp files hold a mono DSP-ADPCM sound after a big endian header.
+ * The sound points into the file, so the file must stay around.
+ *
+ * @param sound Sound to fill in.
+ * @param file Contents of the file.
+ * @param size Size of the file in bytes.
+ * @return Negative if the file is not valid.
+ */
+extern int mixer_sound_load_dsp(mixer_sound_t* sound, const void* file, size_t size);
+
+/**
+ * @brief Mixes voices into stereo output.
+ *
+ * Overwrites the output with the playing voices.
+ * Voices that reach the end of their sound stop.
+ *
+ * @param voices Voices to mix.
+ * @param count Number of voices.
+ * @param out Interleaved stereo output.
+ * @param frames Number of stereo samples to output.
+ */
+extern void mixer_mix(mixer_voice_t* voices, int count, int16_t* out, uint32_t frames);
@@ -0,0 +1,245 @@
+/**
+ * @file mixer_test.c
+ * @brief Tests of the mixer and ADPCM decoder.
+ *
+ * Runs on the host against reference outputs.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include "adpcm.h"
+#include "mixer_voice.h"
+
+#include "test.h"
+
+#include <stdio.h>
+#include <string.h>
+
+static void check_samples(const char* name, const int16_t* actual, const int16_t* expected, int count, int stride) {
+    for(int i = 0; i < count; i++) {
+        if(actual[i * stride] != expected[i]) {
+            printf("%s: sample %d is %d, expected %d\n", name, i, actual[i * stride], expected[i]);
+            test_failures++;
+            return;
+        }
+    }
+}
+
+static const int16_t ADPCM_COEFFICIENTS[16] = {
+    2048, 0, 3600, -1600, 1800, 0, 3000, -1000,
+    4000, -2000, 1000, 500, 0, 0, -1000, 200
+};
+
+static const uint8_t ADPCM_DATA[16] = {
+    0x12, 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD,
+    0x33, 0xF0, 0x1E, 0x2D, 0x3C, 0x4B, 0x5A, 0x69
+};
+
+// Decoded by a separate implementation of the format, starting from hist1 = 100, hist2 = -50
+static const int16_t ADPCM_EXPECTED[28] = {
+    215, 304, 374, 432, 483, 532, 582, 635, 630, 583, 509, 419, 323, 228,
+    168, 135, 124, 100, 102, 77, 87, 58, 74, 40, 62, 23, 51, 7
+};
+
+static void test_adpcm_decode() {
+    adpcm_info_t info;
+    memset(&info, 0, sizeof(info));
+    memcpy(info.coefficients, ADPCM_COEFFICIENTS, sizeof(ADPCM_COEFFICIENTS));
+    info.hist1 = 100;
+    info.hist2 = -50;
+
+    int16_t out[28];
+    adpcm_decode(ADPCM_DATA, &info, out, 28);
+
+    check_samples("adpcm_decode", out, ADPCM_EXPECTED, 28, 1);
+}
+
+static void write_u32(uint8_t* data, uint32_t value) {
+    data[0] = value >> 24;
+    data[1] = value >> 16;
+    data[2] = value >> 8;
+    data[3] = value;
+}
+
+static void write_u16(uint8_t* data, uint16_t value) {
+    data[0] = value >> 8;
+    data[1] = value;
+}
+
+static void test_dsp_file() {
+    uint8_t file[0x60 + sizeof(ADPCM_DATA)];
+    memset(file, 0, sizeof(file));
+
+    write_u32(file + 0x00, 28);    // Samples
+    write_u32(file + 0x04, 32);    // Nibbles
+    write_u32(file + 0x08, 32000); // Sample rate
+    write_u16(file + 0x0C, 1);     // Loop
+    write_u32(file + 0x10, 18);    // Loop start, sample 14
+    write_u32(file + 0x14, 31);    // Loop end, sample 27
+    for(int i = 0; i < 16; i++)
+        write_u16(file + 0x1C + i * 2, ADPCM_COEFFICIENTS[i]);
+    write_u16(file + 0x40, 100);
+    write_u16(file + 0x42, (uint16_t)-50);
+    write_u16(file + 0x46, 228);
+    write_u16(file + 0x48, 323);
+    memcpy(file + 0x60, ADPCM_DATA, sizeof(ADPCM_DATA));
+
+    mixer_sound_t sound;
+    CHECK(mixer_sound_load_dsp(&sound, file, sizeof(file)) == 0);
+    CHECK(sound.format == MIXER_FORMAT_ADPCM);
+    CHECK(sound.length == 28);
+    CHECK(sound.sample_rate == 32000);
+    CHECK(sound.loop);
+    CHECK(sound.loop_start == 14);
+    CHECK(sound.adpcm.hist2 == -50);
+    CHECK(sound.adpcm.loop_hist1 == 228);
+
+    // Played at its own rate, the voice gives the decoded samples.
+    mixer_voice_t voice;
+    mixer_voice_initialize(&voice);
+    mixer_voice_start(&voice, &sound, 32000);
+
+    int16_t out[28 * 2];
+    mixer_mix(&voice, 1, out, 28);
+    check_samples("dsp left", out, ADPCM_EXPECTED, 28, 2);
+    check_samples("dsp right", out + 1, ADPCM_EXPECTED, 28, 2);
+
+    // Looping restarts the second frame with the loop history, so it decodes the same again.
+    mixer_mix(&voice, 1, out, 14);
+    check_samples("dsp loop", out, ADPCM_EXPECTED + 14, 14, 2);
+
+    // Missing frames are rejected
+    CHECK(mixer_sound_load_dsp(&sound, file, sizeof(file) - 1) < 0);
+}
+
+static void test_mix_pcm() {
+    static const int16_t pcm16[4] = { 0, 1000, -1000, 500 };
+    static const int8_t pcm8[4] = { 1, -1, 127, -128 };
+
+    mixer_sound_t sound = {
+        .format = MIXER_FORMAT_PCM16,
+        .data = pcm16,
+        .length = 4,
+        .sample_rate = 48000,
+    };
+
+    mixer_voice_t voice;
+    int16_t out[16];
+
+    // Same rate passes the samples through, then the voice stops.
+    mixer_voice_initialize(&voice);
+    mixer_voice_start(&voice, &sound, 48000);
+    mixer_mix(&voice, 1, out, 8);
+    static const int16_t through[8] = { 0, 1000, -1000, 500, 0, 0, 0, 0 };
+    check_samples("pcm16", out, through, 8, 2);
+    CHECK(!voice.playing);
+
+    // Half pitch interpolates between samples
+    mixer_voice_initialize(&voice);
+    mixer_voice_set_pitch(&voice, 0.5f, 48000);
+    mixer_voice_start(&voice, &sound, 48000);
+    mixer_mix(&voice, 1, out, 8);
+    static const int16_t half[8] = { 0, 500, 1000, 0, -1000, -250, 500, 250 };
+    check_samples("pcm16 half pitch", out, half, 8, 2);
+
+    // Negative pitch is clamped, holding the first sample
+    mixer_voice_initialize(&voice);
+    mixer_voice_start(&voice, &sound, 48000);
+    mixer_voice_set_pitch(&voice, -1.0f, 48000);
+    CHECK(voice.pitch == 0.0f && voice.step == 0);
+    mixer_mix(&voice, 1, out, 4);
+    static const int16_t held[4] = { 0, 0, 0, 0 };
+    check_samples("pcm16 negative pitch", out, held, 4, 2);
+    CHECK(voice.playing);
+
+    // A sound at half the output rate plays the same as half pitch
+    sound.sample_rate = 24000;
+    mixer_voice_initialize(&voice);
+    mixer_voice_start(&voice, &sound, 48000);
+    mixer_mix(&voice, 1, out, 8);
+    check_samples("pcm16 half rate", out, half, 8, 2);
+    sound.sample_rate = 48000;
+
+    // Panned left at half volume
+    mixer_voice_initialize(&voice);
+    voice.volume = 128;
+    voice.pan = MIXER_PAN_LEFT;
+    mixer_voice_start(&voice, &sound, 48000);
+    mixer_mix(&voice, 1, out, 3);
+    static const int16_t panned_left[3] = { 0, 500, -500 };
+    static const int16_t silent[3] = { 0, 0, 0 };
+    check_samples("pan left", out, panned_left, 3, 2);
+    check_samples("pan right", out + 1, silent, 3, 2);
+
+    // 8 bit samples are scaled up
+    mixer_sound_t sound8 = {
+        .format = MIXER_FORMAT_PCM8,
+        .data = pcm8,
+        .length = 4,
+        .sample_rate = 48000,
+    };
+    mixer_voice_initialize(&voice);
+    mixer_voice_start(&voice, &sound8, 48000);
+    mixer_mix(&voice, 1, out, 4);
+    static const int16_t scaled[4] = { 256, -256, 32512, -32768 };
+    check_samples("pcm8", out, scaled, 4, 2);
+}
+
+static void test_mix_loop_and_clip() {
+    static const int16_t pcm16[4] = { 10, 20, 30, 40 };
+    static const int16_t loud[2] = { 30000, -30000 };
+
+    mixer_sound_t looped = {
+        .format = MIXER_FORMAT_PCM16,
+        .data = pcm16,
+        .length = 4,
+        .sample_rate = 32000,
+        .loop = true,
+        .loop_start = 1,
+    };
+
+    mixer_voice_t voice;
+    int16_t out[20];
+
+    mixer_voice_initialize(&voice);
+    mixer_voice_start(&voice, &looped, 32000);
+    mixer_mix(&voice, 1, out, 10);
+    static const int16_t loop[10] = { 10, 20, 30, 40, 20, 30, 40, 20, 30, 40 };
+    check_samples("loop", out, loop, 10, 2);
+    CHECK(voice.playing);
+
+    // Two loud voices clip instead of wrapping around
+    mixer_sound_t sound = {
+        .format = MIXER_FORMAT_PCM16,
+        .data = loud,
+        .length = 2,
+        .sample_rate = 32000,
+    };
+
+    mixer_voice_t voices[2];
+    mixer_voice_initialize(&voices[0]);
+    mixer_voice_initialize(&voices[1]);
+    mixer_voice_start(&voices[0], &sound, 32000);
+    mixer_voice_start(&voices[1], &sound, 32000);
+    mixer_mix(voices, 2, out, 2);
+    static const int16_t clipped[2] = { INT16_MAX, INT16_MIN };
+    check_samples("clip", out, clipped, 2, 2);
+
+    // Stopped voices are silent
+    mixer_voice_stop(&voices[0]);
+    mixer_voice_stop(&voices[1]);
+    mixer_mix(voices, 2, out, 2);
+    static const int16_t silent[4] = { 0, 0, 0, 0 };
+    check_samples("stopped", out, silent, 4, 1);
+}
+
+int main() {
+    test_adpcm_decode();
+    test_dsp_file();
+    test_mix_pcm();
+    test_mix_loop_and_clip();
+
+    return test_summary();
+}
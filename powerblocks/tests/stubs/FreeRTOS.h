@@ -0,0 +1,22 @@
+/**
+ * @file FreeRTOS.h
+ * @brief FreeRTOS stand in for host tests.
+ *
+ * The tests run on one thread without a scheduler,
+ * so locks and critical sections do nothing.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include <stdint.h>
+
+typedef int32_t BaseType_t;
+typedef uint32_t TickType_t;
+
+#define pdFALSE        0
+#define pdTRUE         1
+#define portMAX_DELAY  ((TickType_t)0xFFFFFFFF)
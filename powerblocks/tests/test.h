@@ -0,0 +1,44 @@
+/**
+ * @file test.h
+ * @brief Checks for the host tests.
+ *
+ * Each test is its own program. Failed checks are printed
+ * and counted, and test_summary gives main's exit code.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+#include <stdio.h>
+
+static int test_failures;
+
+/**
+ * @def CHECK
+ * @brief Prints where the condition was false, and carries on.
+ */
+#define CHECK(condition) \
+    do { \
+        if(!(condition)) { \
+            printf("%s:%d: check failed: %s\n", __FILE__, __LINE__, #condition); \
+            test_failures++; \
+        } \
+    } while(0)
+
+/**
+ * @brief Prints how many checks failed.
+ *
+ * @return 0 if all checks passed, 1 if not.
+*/
+static inline int test_summary() {
+    if(test_failures > 0) {
+        printf("%d checks failed.\n", test_failures);
+        return 1;
+    }
+
+    printf("All checks passed.\n");
+    return 0;
+}
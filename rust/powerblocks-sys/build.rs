@@ -0,0 +1,73 @@
+//! Regenerates the bindings from `wrapper.h` when the `generate` feature is
+//! enabled. Otherwise the checked in `src/bindings.rs` is used and this does
+//! nothing.
+//!
+//! bindgen needs libclang and the picolibc headers the SDK is compiled
+//! against. Pass the latter with `BINDGEN_EXTRA_CLANG_ARGS`, for example
+//! `BINDGEN_EXTRA_CLANG_ARGS="-I/opt/picolibc/include"`.
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "generate")]
+    generate::run();
+}
+
+#[cfg(feature = "generate")]
+mod generate {
+    use std::env;
+    use std::path::PathBuf;
+
+    pub fn run() {
+        let crate_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+        let sdk_dir = crate_dir.join("../..").canonicalize().unwrap();
+
+        let include_dirs = [
+            sdk_dir.clone(),
+            sdk_dir.join("powerblocks/core/freertos_port"),
+            sdk_dir.join("third_party/freertos/include"),
+            sdk_dir.join("powerblocks/filesystem/fatfs_port"),
+            sdk_dir.join("third_party/fatfs"),
+        ];
+
+        println!("cargo:rerun-if-changed=wrapper.h");
+        println!("cargo:rerun-if-changed={}", sdk_dir.join("powerblocks").display());
+        println!("cargo:rerun-if-env-changed=BINDGEN_EXTRA_CLANG_ARGS");
+
+        let mut builder = bindgen::Builder::default()
+            .header(crate_dir.join("wrapper.h").to_str().unwrap())
+            .clang_arg("--target=powerpc-none-eabi")
+            .clang_arg("-mcpu=750")
+            .use_core()
+            .ctypes_prefix("::core::ffi")
+            .default_enum_style(bindgen::EnumVariation::Consts)
+            .prepend_enum_name(false)
+            .layout_tests(false)
+            .derive_debug(true)
+            .derive_copy(true)
+            // Everything from the SDK itself.
+            .allowlist_file(".*/powerblocks/.*")
+            // FreeRTOS kernel.
+            .allowlist_function("(x|v|ux|ul|pc|pv|e)(Task|Queue|Semaphore|Timer|StreamBuffer|Port).*")
+            .allowlist_type("Static.*_t|.*Handle_t|eNotifyAction|eTaskState|TaskFunction_t|PendedFunction_t")
+            .allowlist_var("config(CPU_CLOCK_HZ|TICK_RATE_HZ|MAX_PRIORITIES|MINIMAL_STACK_SIZE).*")
+            .allowlist_var("config(MAX_TASK_NAME_LEN|TIMER_.*|TASK_NOTIFICATION_ARRAY_ENTRIES)")
+            .allowlist_var("tskDEFAULT_INDEX_TO_NOTIFY|portBYTE_ALIGNMENT|portSTACK_GROWTH|TICK_TYPE_WIDTH_64_BITS")
+            // FatFs.
+            .allowlist_function("f_.*|disk_.*|get_fattime")
+            .allowlist_type("FATFS|FIL|DIR|FILINFO|FRESULT|DRESULT|DSTATUS")
+            .allowlist_var("FA_.*|AM_.*|FS_(FAT12|FAT16|FAT32|EXFAT)|STA_.*|CTRL_.*|GET_SECTOR_.*|GET_BLOCK_SIZE")
+            .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()));
+
+        for dir in &include_dirs {
+            builder = builder.clang_arg(format!("-I{}", dir.display()));
+        }
+
+        let bindings = builder.generate().expect("Unable to generate PowerBlocks bindings");
+
+        let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
+        bindings
+            .write_to_file(out_path.join("bindings.rs"))
+            .expect("Couldn't write bindings");
+    }
+}
@@ -0,0 +1,2501 @@
+/* Bindings for wrapper.h, in the shape produced by build.rs with the
+ * `generate` feature. Regenerate after changing an SDK header and
+ * replace this file with $OUT_DIR/bindings.rs. */
+
+// ---------------------------------------------------------------------------
+// FreeRTOS
+// ---------------------------------------------------------------------------
+
+pub const TICK_TYPE_WIDTH_64_BITS: u32 = 2;
+pub const configCPU_CLOCK_HZ: u32 = 60750000;
+pub const configTICK_RATE_HZ: u32 = 1000;
+pub const configMAX_PRIORITIES: u32 = 10;
+pub const configMINIMAL_STACK_SIZE: u32 = 1024;
+pub const configMAX_TASK_NAME_LEN: u32 = 32;
+pub const configTIMER_TASK_PRIORITY: u32 = 9;
+pub const configTIMER_QUEUE_LENGTH: u32 = 10;
+pub const configTIMER_TASK_STACK_DEPTH: u32 = 2048;
+pub const configTASK_NOTIFICATION_ARRAY_ENTRIES: u32 = 1;
+pub const tskDEFAULT_INDEX_TO_NOTIFY: u32 = 0;
+pub const portBYTE_ALIGNMENT: u32 = 4;
+pub const portSTACK_GROWTH: i32 = -1;
+
+pub type BaseType_t = i32;
+pub type UBaseType_t = u32;
+pub type StackType_t = u32;
+pub type TickType_t = u64;
+pub type TaskFunction_t = ::core::option::Option<unsafe extern "C" fn(arg: *mut ::core::ffi::c_void)>;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct xSTATIC_LIST_ITEM {
+    pub xDummy2: TickType_t,
+    pub pvDummy3: [*mut ::core::ffi::c_void; 4usize],
+}
+pub type StaticListItem_t = xSTATIC_LIST_ITEM;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct xSTATIC_MINI_LIST_ITEM {
+    pub xDummy2: TickType_t,
+    pub pvDummy3: [*mut ::core::ffi::c_void; 2usize],
+}
+pub type StaticMiniListItem_t = xSTATIC_MINI_LIST_ITEM;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct xSTATIC_LIST {
+    pub uxDummy2: UBaseType_t,
+    pub pvDummy3: *mut ::core::ffi::c_void,
+    pub xDummy4: StaticMiniListItem_t,
+}
+pub type StaticList_t = xSTATIC_LIST;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct xSTATIC_TCB {
+    pub pxDummy1: *mut ::core::ffi::c_void,
+    pub xDummy3: [StaticListItem_t; 2usize],
+    pub uxDummy5: UBaseType_t,
+    pub pxDummy6: *mut ::core::ffi::c_void,
+    pub ucDummy7: [u8; 32usize],
+    pub uxDummy12: [UBaseType_t; 2usize],
+    pub ulDummy18: [u32; 1usize],
+    pub ucDummy19: [u8; 1usize],
+    pub uxDummy20: u8,
+}
+pub type StaticTask_t = xSTATIC_TCB;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct xSTATIC_QUEUE {
+    pub pvDummy1: [*mut ::core::ffi::c_void; 3usize],
+    pub u: xSTATIC_QUEUE__bindgen_ty_1,
+    pub xDummy3: [StaticList_t; 2usize],
+    pub uxDummy4: [UBaseType_t; 3usize],
+    pub ucDummy5: [u8; 2usize],
+    pub ucDummy6: u8,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union xSTATIC_QUEUE__bindgen_ty_1 {
+    pub pvDummy2: *mut ::core::ffi::c_void,
+    pub uxDummy2: UBaseType_t,
+}
+pub type StaticQueue_t = xSTATIC_QUEUE;
+pub type StaticSemaphore_t = StaticQueue_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct xSTATIC_TIMER {
+    pub pvDummy1: *mut ::core::ffi::c_void,
+    pub xDummy2: StaticListItem_t,
+    pub xDummy3: TickType_t,
+    pub pvDummy5: *mut ::core::ffi::c_void,
+    pub pvDummy6: TaskFunction_t,
+    pub ucDummy8: u8,
+}
+pub type StaticTimer_t = xSTATIC_TIMER;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct xSTATIC_STREAM_BUFFER {
+    pub uxDummy1: [usize; 4usize],
+    pub pvDummy2: [*mut ::core::ffi::c_void; 3usize],
+    pub ucDummy3: u8,
+    pub uxDummy6: UBaseType_t,
+}
+pub type StaticStreamBuffer_t = xSTATIC_STREAM_BUFFER;
+pub type StaticMessageBuffer_t = StaticStreamBuffer_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tskTaskControlBlock {
+    _unused: [u8; 0],
+}
+pub type TaskHandle_t = *mut tskTaskControlBlock;
+
+pub type eNotifyAction = ::core::ffi::c_uint;
+pub const eNoAction: eNotifyAction = 0;
+pub const eSetBits: eNotifyAction = 1;
+pub const eIncrement: eNotifyAction = 2;
+pub const eSetValueWithOverwrite: eNotifyAction = 3;
+pub const eSetValueWithoutOverwrite: eNotifyAction = 4;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct QueueDefinition {
+    _unused: [u8; 0],
+}
+pub type QueueHandle_t = *mut QueueDefinition;
+pub type SemaphoreHandle_t = QueueHandle_t;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct tmrTimerControl {
+    _unused: [u8; 0],
+}
+pub type TimerHandle_t = *mut tmrTimerControl;
+pub type TimerCallbackFunction_t = ::core::option::Option<unsafe extern "C" fn(xTimer: TimerHandle_t)>;
+pub type PendedFunction_t =
+    ::core::option::Option<unsafe extern "C" fn(arg1: *mut ::core::ffi::c_void, arg2: u32)>;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct StreamBufferDef_t {
+    _unused: [u8; 0],
+}
+pub type StreamBufferHandle_t = *mut StreamBufferDef_t;
+pub type StreamBufferCallbackFunction_t = ::core::option::Option<
+    unsafe extern "C" fn(
+        xStreamBuffer: StreamBufferHandle_t,
+        xIsInsideISR: BaseType_t,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+    ),
+>;
+
+extern "C" {
+    pub static mut freertos_isr_enabled: u32;
+
+    pub fn vPortYield();
+
+    pub fn xTaskCreate(
+        pxTaskCode: TaskFunction_t,
+        pcName: *const ::core::ffi::c_char,
+        uxStackDepth: StackType_t,
+        pvParameters: *mut ::core::ffi::c_void,
+        uxPriority: UBaseType_t,
+        pxCreatedTask: *mut TaskHandle_t,
+    ) -> BaseType_t;
+    pub fn xTaskCreateStatic(
+        pxTaskCode: TaskFunction_t,
+        pcName: *const ::core::ffi::c_char,
+        uxStackDepth: StackType_t,
+        pvParameters: *mut ::core::ffi::c_void,
+        uxPriority: UBaseType_t,
+        puxStackBuffer: *mut StackType_t,
+        pxTaskBuffer: *mut StaticTask_t,
+    ) -> TaskHandle_t;
+    pub fn vTaskDelete(xTaskToDelete: TaskHandle_t);
+    pub fn vTaskDelay(xTicksToDelay: TickType_t);
+    pub fn xTaskDelayUntil(pxPreviousWakeTime: *mut TickType_t, xTimeIncrement: TickType_t) -> BaseType_t;
+    pub fn uxTaskPriorityGet(xTask: TaskHandle_t) -> UBaseType_t;
+    pub fn uxTaskPriorityGetFromISR(xTask: TaskHandle_t) -> UBaseType_t;
+    pub fn vTaskPrioritySet(xTask: TaskHandle_t, uxNewPriority: UBaseType_t);
+    pub fn vTaskSuspend(xTaskToSuspend: TaskHandle_t);
+    pub fn vTaskResume(xTaskToResume: TaskHandle_t);
+    pub fn xTaskResumeFromISR(xTaskToResume: TaskHandle_t) -> BaseType_t;
+    pub fn vTaskStartScheduler();
+    pub fn vTaskSuspendAll();
+    pub fn xTaskResumeAll() -> BaseType_t;
+    pub fn xTaskGetTickCount() -> TickType_t;
+    pub fn xTaskGetTickCountFromISR() -> TickType_t;
+    pub fn uxTaskGetNumberOfTasks() -> UBaseType_t;
+    pub fn pcTaskGetName(xTaskToQuery: TaskHandle_t) -> *mut ::core::ffi::c_char;
+    pub fn xTaskGetHandle(pcNameToQuery: *const ::core::ffi::c_char) -> TaskHandle_t;
+    pub fn xTaskGetCurrentTaskHandle() -> TaskHandle_t;
+    pub fn xTaskGetSchedulerState() -> BaseType_t;
+    pub fn xTaskGenericNotify(
+        xTaskToNotify: TaskHandle_t,
+        uxIndexToNotify: UBaseType_t,
+        ulValue: u32,
+        eAction: eNotifyAction,
+        pulPreviousNotificationValue: *mut u32,
+    ) -> BaseType_t;
+    pub fn xTaskGenericNotifyFromISR(
+        xTaskToNotify: TaskHandle_t,
+        uxIndexToNotify: UBaseType_t,
+        ulValue: u32,
+        eAction: eNotifyAction,
+        pulPreviousNotificationValue: *mut u32,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+    ) -> BaseType_t;
+    pub fn xTaskGenericNotifyWait(
+        uxIndexToWaitOn: UBaseType_t,
+        ulBitsToClearOnEntry: u32,
+        ulBitsToClearOnExit: u32,
+        pulNotificationValue: *mut u32,
+        xTicksToWait: TickType_t,
+    ) -> BaseType_t;
+    pub fn vTaskGenericNotifyGiveFromISR(
+        xTaskToNotify: TaskHandle_t,
+        uxIndexToNotify: UBaseType_t,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+    );
+    pub fn ulTaskGenericNotifyTake(
+        uxIndexToWaitOn: UBaseType_t,
+        xClearCountOnExit: BaseType_t,
+        xTicksToWait: TickType_t,
+    ) -> u32;
+    pub fn xTaskGenericNotifyStateClear(xTask: TaskHandle_t, uxIndexToClear: UBaseType_t) -> BaseType_t;
+    pub fn ulTaskGenericNotifyValueClear(
+        xTask: TaskHandle_t,
+        uxIndexToClear: UBaseType_t,
+        ulBitsToClear: u32,
+    ) -> u32;
+
+    pub fn xQueueGenericCreate(uxQueueLength: UBaseType_t, uxItemSize: UBaseType_t, ucQueueType: u8)
+        -> QueueHandle_t;
+    pub fn xQueueGenericCreateStatic(
+        uxQueueLength: UBaseType_t,
+        uxItemSize: UBaseType_t,
+        pucQueueStorage: *mut u8,
+        pxStaticQueue: *mut StaticQueue_t,
+        ucQueueType: u8,
+    ) -> QueueHandle_t;
+    pub fn xQueueGenericSend(
+        xQueue: QueueHandle_t,
+        pvItemToQueue: *const ::core::ffi::c_void,
+        xTicksToWait: TickType_t,
+        xCopyPosition: BaseType_t,
+    ) -> BaseType_t;
+    pub fn xQueueGenericSendFromISR(
+        xQueue: QueueHandle_t,
+        pvItemToQueue: *const ::core::ffi::c_void,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+        xCopyPosition: BaseType_t,
+    ) -> BaseType_t;
+    pub fn xQueueGiveFromISR(xQueue: QueueHandle_t, pxHigherPriorityTaskWoken: *mut BaseType_t) -> BaseType_t;
+    pub fn xQueuePeek(xQueue: QueueHandle_t, pvBuffer: *mut ::core::ffi::c_void, xTicksToWait: TickType_t)
+        -> BaseType_t;
+    pub fn xQueuePeekFromISR(xQueue: QueueHandle_t, pvBuffer: *mut ::core::ffi::c_void) -> BaseType_t;
+    pub fn xQueueReceive(xQueue: QueueHandle_t, pvBuffer: *mut ::core::ffi::c_void, xTicksToWait: TickType_t)
+        -> BaseType_t;
+    pub fn xQueueReceiveFromISR(
+        xQueue: QueueHandle_t,
+        pvBuffer: *mut ::core::ffi::c_void,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+    ) -> BaseType_t;
+    pub fn uxQueueMessagesWaiting(xQueue: QueueHandle_t) -> UBaseType_t;
+    pub fn uxQueueMessagesWaitingFromISR(xQueue: QueueHandle_t) -> UBaseType_t;
+    pub fn uxQueueSpacesAvailable(xQueue: QueueHandle_t) -> UBaseType_t;
+    pub fn xQueueGenericReset(xQueue: QueueHandle_t, xNewQueue: BaseType_t) -> BaseType_t;
+    pub fn vQueueDelete(xQueue: QueueHandle_t);
+    pub fn xQueueCreateMutex(ucQueueType: u8) -> QueueHandle_t;
+    pub fn xQueueCreateMutexStatic(ucQueueType: u8, pxStaticQueue: *mut StaticQueue_t) -> QueueHandle_t;
+    pub fn xQueueCreateCountingSemaphore(uxMaxCount: UBaseType_t, uxInitialCount: UBaseType_t) -> QueueHandle_t;
+    pub fn xQueueCreateCountingSemaphoreStatic(
+        uxMaxCount: UBaseType_t,
+        uxInitialCount: UBaseType_t,
+        pxStaticQueue: *mut StaticQueue_t,
+    ) -> QueueHandle_t;
+    pub fn xQueueSemaphoreTake(xQueue: QueueHandle_t, xTicksToWait: TickType_t) -> BaseType_t;
+    pub fn xQueueTakeMutexRecursive(xMutex: QueueHandle_t, xTicksToWait: TickType_t) -> BaseType_t;
+    pub fn xQueueGiveMutexRecursive(xMutex: QueueHandle_t) -> BaseType_t;
+
+    pub fn xTimerCreate(
+        pcTimerName: *const ::core::ffi::c_char,
+        xTimerPeriodInTicks: TickType_t,
+        xAutoReload: BaseType_t,
+        pvTimerID: *mut ::core::ffi::c_void,
+        pxCallbackFunction: TimerCallbackFunction_t,
+    ) -> TimerHandle_t;
+    pub fn xTimerCreateStatic(
+        pcTimerName: *const ::core::ffi::c_char,
+        xTimerPeriodInTicks: TickType_t,
+        xAutoReload: BaseType_t,
+        pvTimerID: *mut ::core::ffi::c_void,
+        pxCallbackFunction: TimerCallbackFunction_t,
+        pxTimerBuffer: *mut StaticTimer_t,
+    ) -> TimerHandle_t;
+    pub fn pvTimerGetTimerID(xTimer: TimerHandle_t) -> *mut ::core::ffi::c_void;
+    pub fn vTimerSetTimerID(xTimer: TimerHandle_t, pvNewID: *mut ::core::ffi::c_void);
+    pub fn xTimerIsTimerActive(xTimer: TimerHandle_t) -> BaseType_t;
+    pub fn xTimerPendFunctionCall(
+        xFunctionToPend: PendedFunction_t,
+        pvParameter1: *mut ::core::ffi::c_void,
+        ulParameter2: u32,
+        xTicksToWait: TickType_t,
+    ) -> BaseType_t;
+    pub fn xTimerPendFunctionCallFromISR(
+        xFunctionToPend: PendedFunction_t,
+        pvParameter1: *mut ::core::ffi::c_void,
+        ulParameter2: u32,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+    ) -> BaseType_t;
+    pub fn pcTimerGetName(xTimer: TimerHandle_t) -> *const ::core::ffi::c_char;
+    pub fn vTimerSetReloadMode(xTimer: TimerHandle_t, xAutoReload: BaseType_t);
+    pub fn xTimerGetPeriod(xTimer: TimerHandle_t) -> TickType_t;
+    pub fn xTimerGetExpiryTime(xTimer: TimerHandle_t) -> TickType_t;
+    pub fn xTimerGenericCommandFromTask(
+        xTimer: TimerHandle_t,
+        xCommandID: BaseType_t,
+        xOptionalValue: TickType_t,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+        xTicksToWait: TickType_t,
+    ) -> BaseType_t;
+    pub fn xTimerGenericCommandFromISR(
+        xTimer: TimerHandle_t,
+        xCommandID: BaseType_t,
+        xOptionalValue: TickType_t,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+        xTicksToWait: TickType_t,
+    ) -> BaseType_t;
+
+    pub fn xStreamBufferGenericCreate(
+        xBufferSizeBytes: usize,
+        xTriggerLevelBytes: usize,
+        xStreamBufferType: BaseType_t,
+        pxSendCompletedCallback: StreamBufferCallbackFunction_t,
+        pxReceiveCompletedCallback: StreamBufferCallbackFunction_t,
+    ) -> StreamBufferHandle_t;
+    pub fn xStreamBufferGenericCreateStatic(
+        xBufferSizeBytes: usize,
+        xTriggerLevelBytes: usize,
+        xStreamBufferType: BaseType_t,
+        pucStreamBufferStorageArea: *mut u8,
+        pxStaticStreamBuffer: *mut StaticStreamBuffer_t,
+        pxSendCompletedCallback: StreamBufferCallbackFunction_t,
+        pxReceiveCompletedCallback: StreamBufferCallbackFunction_t,
+    ) -> StreamBufferHandle_t;
+    pub fn xStreamBufferSend(
+        xStreamBuffer: StreamBufferHandle_t,
+        pvTxData: *const ::core::ffi::c_void,
+        xDataLengthBytes: usize,
+        xTicksToWait: TickType_t,
+    ) -> usize;
+    pub fn xStreamBufferSendFromISR(
+        xStreamBuffer: StreamBufferHandle_t,
+        pvTxData: *const ::core::ffi::c_void,
+        xDataLengthBytes: usize,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+    ) -> usize;
+    pub fn xStreamBufferReceive(
+        xStreamBuffer: StreamBufferHandle_t,
+        pvRxData: *mut ::core::ffi::c_void,
+        xBufferLengthBytes: usize,
+        xTicksToWait: TickType_t,
+    ) -> usize;
+    pub fn xStreamBufferReceiveFromISR(
+        xStreamBuffer: StreamBufferHandle_t,
+        pvRxData: *mut ::core::ffi::c_void,
+        xBufferLengthBytes: usize,
+        pxHigherPriorityTaskWoken: *mut BaseType_t,
+    ) -> usize;
+    pub fn vStreamBufferDelete(xStreamBuffer: StreamBufferHandle_t);
+    pub fn xStreamBufferIsFull(xStreamBuffer: StreamBufferHandle_t) -> BaseType_t;
+    pub fn xStreamBufferIsEmpty(xStreamBuffer: StreamBufferHandle_t) -> BaseType_t;
+    pub fn xStreamBufferReset(xStreamBuffer: StreamBufferHandle_t) -> BaseType_t;
+    pub fn xStreamBufferSpacesAvailable(xStreamBuffer: StreamBufferHandle_t) -> usize;
+    pub fn xStreamBufferBytesAvailable(xStreamBuffer: StreamBufferHandle_t) -> usize;
+    pub fn xStreamBufferSetTriggerLevel(xStreamBuffer: StreamBufferHandle_t, xTriggerLevel: usize) -> BaseType_t;
+}
+
+// ---------------------------------------------------------------------------
+// powerblocks/core/system
+// ---------------------------------------------------------------------------
+
+pub const SYSTEM_BUS_CLOCK_HZ: u32 = 243000000;
+pub const SYSTEM_CORE_CLOCK_HZ: u32 = 729000000;
+pub const SYSTEM_TB_CLOCK_HZ: u32 = 60750000;
+pub const SYSTEM_MAIN_STACK_SIZE: u32 = 4194304;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct system_argv_t {
+    pub magic: ::core::ffi::c_int,
+    pub command_line: *const ::core::ffi::c_char,
+    pub command_line_length: ::core::ffi::c_int,
+    pub argc: ::core::ffi::c_int,
+    pub argv: *mut *mut ::core::ffi::c_char,
+    pub end_argv: *mut *mut ::core::ffi::c_char,
+}
+
+extern "C" {
+    pub static mut system_argv: system_argv_t;
+
+    pub fn system_get_time_base_int() -> u64;
+    pub fn system_delay_int(ticks: u64);
+    pub fn system_flush_dcache(data: *const ::core::ffi::c_void, size: u32);
+    pub fn system_invalidate_dcache(data: *mut ::core::ffi::c_void, size: u32);
+    pub fn system_invalidate_icache(data: *mut ::core::ffi::c_void, size: u32);
+    pub fn system_aligned_malloc(bytes: u32, alignment: u32) -> *mut ::core::ffi::c_void;
+    pub fn system_aligned_free(ptr: *mut ::core::ffi::c_void);
+    pub fn system_initialize();
+    pub fn system_get_boot_path(device: *const ::core::ffi::c_char, buffer: *mut ::core::ffi::c_char, length: usize);
+}
+
+pub const IBAT0U: u32 = 528;
+pub const IBAT0L: u32 = 529;
+pub const IBAT1U: u32 = 530;
+pub const IBAT1L: u32 = 531;
+pub const IBAT2U: u32 = 532;
+pub const IBAT2L: u32 = 533;
+pub const IBAT3U: u32 = 534;
+pub const IBAT3L: u32 = 535;
+pub const DBAT0U: u32 = 536;
+pub const DBAT0L: u32 = 537;
+pub const DBAT1U: u32 = 538;
+pub const DBAT1L: u32 = 539;
+pub const DBAT2U: u32 = 540;
+pub const DBAT2L: u32 = 541;
+pub const DBAT3U: u32 = 542;
+pub const DBAT3L: u32 = 543;
+pub const IBAT4U: u32 = 560;
+pub const IBAT4L: u32 = 561;
+pub const IBAT5U: u32 = 562;
+pub const IBAT5L: u32 = 563;
+pub const IBAT6U: u32 = 564;
+pub const IBAT6L: u32 = 565;
+pub const IBAT7U: u32 = 566;
+pub const IBAT7L: u32 = 567;
+pub const DBAT4U: u32 = 568;
+pub const DBAT4L: u32 = 569;
+pub const DBAT5U: u32 = 570;
+pub const DBAT5L: u32 = 571;
+pub const DBAT6U: u32 = 572;
+pub const DBAT6L: u32 = 573;
+pub const DBAT7U: u32 = 574;
+pub const DBAT7L: u32 = 575;
+pub const HID0: u32 = 1008;
+pub const HID4: u32 = 1011;
+pub const MSR_EE: u32 = 32768;
+pub const MSR_PR: u32 = 16384;
+pub const MSR_FP: u32 = 8192;
+pub const MSR_IR: u32 = 32;
+pub const MSR_DR: u32 = 16;
+pub const HID0_ICE: u32 = 32768;
+pub const HID0_DCE: u32 = 16384;
+pub const HID0_ICFI: u32 = 2048;
+pub const HID0_DCFI: u32 = 1024;
+pub const HID4_SBE: u32 = 33554432;
+
+pub const EXCEPTION_IRQ_COUNT: u32 = 15;
+pub const EXCEPTION_FAULT_COUNT: u32 = 5;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct exception_context_t {
+    pub stack_frame: u32,
+    pub r0: u32,
+    pub r2: u32,
+    pub r3: u32,
+    pub r4: u32,
+    pub r5: u32,
+    pub r6: u32,
+    pub r7: u32,
+    pub r8: u32,
+    pub r9: u32,
+    pub r10: u32,
+    pub r11: u32,
+    pub r12: u32,
+    pub r13: u32,
+    pub r14: u32,
+    pub r15: u32,
+    pub r16: u32,
+    pub r17: u32,
+    pub r18: u32,
+    pub r19: u32,
+    pub r20: u32,
+    pub r21: u32,
+    pub r22: u32,
+    pub r23: u32,
+    pub r24: u32,
+    pub r25: u32,
+    pub r26: u32,
+    pub r27: u32,
+    pub r28: u32,
+    pub r29: u32,
+    pub r30: u32,
+    pub r31: u32,
+    pub sprg3: u32,
+    pub cr: u32,
+    pub lr: u32,
+    pub ctr: u32,
+    pub xer: u32,
+    pub dar: u32,
+    pub srr1: u32,
+    pub srr0: u32,
+    pub gqr0: u32,
+    pub gqr1: u32,
+    pub gqr2: u32,
+    pub gqr3: u32,
+    pub gqr4: u32,
+    pub gqr5: u32,
+    pub gqr6: u32,
+    pub gqr7: u32,
+    pub f: [f64; 32usize],
+    pub ffs: u64,
+}
+
+pub type exception_irq_type_t = ::core::ffi::c_uint;
+pub const EXCEPTION_IRQ_TYPE_GP_RUNTIME: exception_irq_type_t = 0;
+pub const EXCEPTION_IRQ_TYPE_RESET_SWITCH: exception_irq_type_t = 1;
+pub const EXCEPTION_IRQ_TYPE_DVD: exception_irq_type_t = 2;
+pub const EXCEPTION_IRQ_TYPE_SERIAL: exception_irq_type_t = 3;
+pub const EXCEPTION_IRQ_TYPE_EXI: exception_irq_type_t = 4;
+pub const EXCEPTION_IRQ_TYPE_STREAMING: exception_irq_type_t = 5;
+pub const EXCEPTION_IRQ_TYPE_DSP: exception_irq_type_t = 6;
+pub const EXCEPTION_IRQ_TYPE_MEMORY: exception_irq_type_t = 7;
+pub const EXCEPTION_IRQ_TYPE_VIDEO: exception_irq_type_t = 8;
+pub const EXCEPTION_IRQ_TYPE_PE_TOKEN: exception_irq_type_t = 9;
+pub const EXCEPTION_IRQ_TYPE_PE_FINISH: exception_irq_type_t = 10;
+pub const EXCEPTION_IRQ_TYPE_FIFO: exception_irq_type_t = 11;
+pub const EXCEPTION_IRQ_TYPE_DEBUGGER: exception_irq_type_t = 12;
+pub const EXCEPTION_IRQ_TYPE_HSP: exception_irq_type_t = 13;
+pub const EXCEPTION_IRQ_TYPE_IPC: exception_irq_type_t = 14;
+
+pub type exception_fault_type_t = ::core::ffi::c_uint;
+pub const EXCEPTION_FAULT_TYPE_DSI: exception_fault_type_t = 0;
+pub const EXCEPTION_FAULT_TYPE_ISI: exception_fault_type_t = 1;
+pub const EXCEPTION_FAULT_TYPE_ALIGNMENT: exception_fault_type_t = 2;
+pub const EXCEPTION_FAULT_TYPE_PROGRAM: exception_fault_type_t = 3;
+pub const EXCEPTION_FAULT_TYPE_FPU_UNAVAILABLE: exception_fault_type_t = 4;
+
+pub type exception_irq_handler_t = ::core::option::Option<unsafe extern "C" fn(irq: exception_irq_type_t)>;
+pub type exception_fault_handler_t = ::core::option::Option<
+    unsafe extern "C" fn(fault: exception_fault_type_t, context: *mut exception_context_t) -> ::core::ffi::c_int,
+>;
+
+extern "C" {
+    pub static mut exception_isr_context_switch_needed: i32;
+
+    pub fn exceptions_install_vector();
+    pub fn exceptions_install_irq(handler: exception_irq_handler_t, type_: exception_irq_type_t);
+    pub fn exceptions_install_fault_handler(
+        handler: exception_fault_handler_t,
+        type_: exception_fault_type_t,
+    ) -> ::core::ffi::c_int;
+    pub fn exception_reset(context: *mut exception_context_t);
+    pub fn exception_machine_check(context: *mut exception_context_t);
+    pub fn exception_dsi(context: *mut exception_context_t);
+    pub fn exception_isi(context: *mut exception_context_t);
+    pub fn exception_external(context: *mut exception_context_t);
+    pub fn exception_alignment(context: *mut exception_context_t);
+    pub fn exception_program(context: *mut exception_context_t);
+    pub fn exception_fpu_unavailable(context: *mut exception_context_t);
+    pub fn exception_decrementer(context: *mut exception_context_t);
+    pub fn exception_syscall(context: *mut exception_context_t);
+    pub fn exceptions_start_first_task();
+}
+
+pub const SYSCALL_ID_YIELD: u32 = 0;
+pub const SYSCALL_ID_ASSERT: u32 = 1;
+pub const SYSCALL_ID_OUT_OF_MEMORY: u32 = 2;
+pub const SYSCALL_ID_BUG_CHECK: u32 = 3;
+pub const SYSCALL_REGISTRY_SIZE: u32 = 4;
+
+pub type syscall_handler_t = ::core::option::Option<
+    unsafe extern "C" fn(context: *mut exception_context_t, arg1: u32, arg2: u32, arg3: u32) -> u32,
+>;
+
+extern "C" {
+    pub static syscall_registry: [syscall_handler_t; 4usize];
+}
+
+pub type ipc_async_handler_t =
+    ::core::option::Option<unsafe extern "C" fn(param: *mut ::core::ffi::c_void, return_value: ::core::ffi::c_int)>;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ipc_message {
+    pub command: ::core::ffi::c_int,
+    pub returned: ::core::ffi::c_int,
+    pub file_handle: ::core::ffi::c_int,
+    pub __bindgen_anon_1: ipc_message__bindgen_ty_1,
+    pub magic: u32,
+    pub response_handler: ipc_async_handler_t,
+    pub params: *mut ::core::ffi::c_void,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union ipc_message__bindgen_ty_1 {
+    pub open: ipc_message__bindgen_ty_1__bindgen_ty_1,
+    pub read: ipc_message__bindgen_ty_1__bindgen_ty_2,
+    pub write: ipc_message__bindgen_ty_1__bindgen_ty_3,
+    pub seek: ipc_message__bindgen_ty_1__bindgen_ty_4,
+    pub ioctl: ipc_message__bindgen_ty_1__bindgen_ty_5,
+    pub ioctlv: ipc_message__bindgen_ty_1__bindgen_ty_6,
+    pub args: [u32; 5usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ipc_message__bindgen_ty_1__bindgen_ty_1 {
+    pub path: *const ::core::ffi::c_char,
+    pub mode: ::core::ffi::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ipc_message__bindgen_ty_1__bindgen_ty_2 {
+    pub address: *mut ::core::ffi::c_void,
+    pub size: ::core::ffi::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ipc_message__bindgen_ty_1__bindgen_ty_3 {
+    pub address: *mut ::core::ffi::c_void,
+    pub size: ::core::ffi::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ipc_message__bindgen_ty_1__bindgen_ty_4 {
+    pub where_: ::core::ffi::c_int,
+    pub whence: ::core::ffi::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ipc_message__bindgen_ty_1__bindgen_ty_5 {
+    pub ioctl: ::core::ffi::c_int,
+    pub address_in: *mut ::core::ffi::c_void,
+    pub size_in: ::core::ffi::c_int,
+    pub address_io: *mut ::core::ffi::c_void,
+    pub size_io: ::core::ffi::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ipc_message__bindgen_ty_1__bindgen_ty_6 {
+    pub ioctl: ::core::ffi::c_int,
+    pub argcin: ::core::ffi::c_int,
+    pub argcio: ::core::ffi::c_int,
+    pub pairs: *mut ::core::ffi::c_void,
+}
+
+extern "C" {
+    pub fn ipc_initialize();
+    pub fn ipc_request(
+        message: *mut ipc_message,
+        handler: ipc_async_handler_t,
+        params: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
+}
+
+pub const GPIO_SLOT_LED: u32 = 32;
+pub const GPIO_SLOT_IN: u32 = 128;
+pub const GPIO_SENSOR_BAR: u32 = 256;
+pub const GPIO_EJECT: u32 = 512;
+pub const GPIO_AVE_SCL: u32 = 16384;
+pub const GPIO_AVE_SDA: u32 = 32768;
+
+extern "C" {
+    pub fn gpio_write(bit: u32, set: bool);
+    pub fn gpio_read(bit: u32) -> bool;
+    pub fn gpio_set_direction(bit: u32, direction: bool);
+}
+
+// ---------------------------------------------------------------------------
+// powerblocks/core/ios
+// ---------------------------------------------------------------------------
+
+pub const IOS_MODE_READ: u32 = 1;
+pub const IOS_MODE_WRITE: u32 = 2;
+pub const IOS_MAX_PATH: u32 = 64;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ios_ioctlv_t {
+    pub data: *mut ::core::ffi::c_void,
+    pub size: u32,
+}
+
+extern "C" {
+    pub fn ios_initialize();
+    pub fn ios_open(path: *const ::core::ffi::c_char, mode: ::core::ffi::c_int) -> ::core::ffi::c_int;
+    pub fn ios_close(file_handle: ::core::ffi::c_int) -> ::core::ffi::c_int;
+    pub fn ios_read(
+        file_handle: ::core::ffi::c_int,
+        buffer: *mut ::core::ffi::c_void,
+        size: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
+    pub fn ios_write(
+        file_handle: ::core::ffi::c_int,
+        buffer: *mut ::core::ffi::c_void,
+        size: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
+    pub fn ios_seek(
+        file_handle: ::core::ffi::c_int,
+        where_: ::core::ffi::c_int,
+        whence: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
+    pub fn ios_ioctl(
+        file_handle: ::core::ffi::c_int,
+        ioctl: ::core::ffi::c_int,
+        buffer_in: *mut ::core::ffi::c_void,
+        in_size: ::core::ffi::c_int,
+        buffer_io: *mut ::core::ffi::c_void,
+        io_size: ::core::ffi::c_int,
+    ) -> ::core::ffi::c_int;
+    pub fn ios_ioctlv(
+        file_handle: ::core::ffi::c_int,
+        ioctl: ::core::ffi::c_int,
+        in_size: ::core::ffi::c_int,
+        io_size: ::core::ffi::c_int,
+        argv: *mut ios_ioctlv_t,
+    ) -> ::core::ffi::c_int;
+    pub fn ios_open_async(
+        path: *const ::core::ffi::c_char,
+        mode: ::core::ffi::c_int,
+        message: *mut ipc_message,
+        handler: ipc_async_handler_t,
+        params: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
+    pub fn ios_close_async(
+        file_handle: ::core::ffi::c_int,
+        message: *mut ipc_message,
+        handler: ipc_async_handler_t,
+        params: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
+    pub fn ios_read_async(
+        file_handle: ::core::ffi::c_int,
+        buffer: *mut ::core::ffi::c_void,
+        size: ::core::ffi::c_int,
+        message: *mut ipc_message,
+        handler: ipc_async_handler_t,
+        params: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
+    pub fn ios_write_async(
+        file_handle: ::core::ffi::c_int,
+        buffer: *mut ::core::ffi::c_void,
+        size: ::core::ffi::c_int,
+        message: *mut ipc_message,
+        handler: ipc_async_handler_t,
+        params: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
+    pub fn ios_seek_async(
+        file_handle: ::core::ffi::c_int,
+        where_: ::core::ffi::c_int,
+        whence: ::core::ffi::c_int,
+        message: *mut ipc_message,
+        handler: ipc_async_handler_t,
+        params: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
+    pub fn ios_ioctl_async(
+        file_handle: ::core::ffi::c_int,
+        ioctl: ::core::ffi::c_int,
+        buffer_in: *mut ::core::ffi::c_void,
+        in_size: ::core::ffi::c_int,
+        buffer_io: *mut ::core::ffi::c_void,
+        io_size: ::core::ffi::c_int,
+        message: *mut ipc_message,
+        handler: ipc_async_handler_t,
+        params: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
+    pub fn ios_ioctlv_async(
+        file_handle: ::core::ffi::c_int,
+        ioctl: ::core::ffi::c_int,
+        in_size: ::core::ffi::c_int,
+        io_size: ::core::ffi::c_int,
+        args: *mut ios_ioctlv_t,
+        message: *mut ipc_message,
+        args_buffer: *mut ios_ioctlv_t,
+        handler: ipc_async_handler_t,
+        params: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
+
+    pub fn ios_settings_initialize();
+    pub fn ios_settings_get(key: *const ::core::ffi::c_char) -> *const ::core::ffi::c_char;
+    pub fn ios_config_get(key: *const ::core::ffi::c_char, buffer: *mut ::core::ffi::c_void, size: u32) -> u32;
+    pub fn ios_config_is_progressive_scan() -> bool;
+    pub fn ios_config_is_eurgb60() -> bool;
+}
+
+pub type sdio_cmd_t = ::core::ffi::c_uint;
+pub const SD_CMD0_GO_IDLE_STATE: sdio_cmd_t = 0;
+pub const SD_CMD2_ALL_SEND_CID: sdio_cmd_t = 2;
+pub const SD_CMD3_SEND_RELATIVE_ADDR: sdio_cmd_t = 3;
+pub const SD_CMD7_SELECT_CARD: sdio_cmd_t = 7;
+pub const SD_CMD8_SEND_IF_COND: sdio_cmd_t = 8;
+pub const SD_CMD9_SEND_CSD: sdio_cmd_t = 9;
+pub const SD_CMD12_STOP_TRANSMISSION: sdio_cmd_t = 12;
+pub const SD_CMD16_SET_BLOCKLEN: sdio_cmd_t = 16;
+pub const SD_CMD17_READ_SINGLE_BLOCK: sdio_cmd_t = 17;
+pub const SD_CMD18_READ_MULTIPLE_BLOCK: sdio_cmd_t = 18;
+pub const SD_CMD24_WRITE_BLOCK: sdio_cmd_t = 24;
+pub const SD_CMD25_WRITE_MULTIPLE_BLOCK: sdio_cmd_t = 25;
+pub const SD_CMD55_APP_CMD: sdio_cmd_t = 55;
+pub const SD_CMD56_GEN_CMD: sdio_cmd_t = 56;
+pub const SDIO_CMD5_IO_SEND_OP_COND: sdio_cmd_t = 5;
+pub const SDIO_CMD52_IO_RW_DIRECT: sdio_cmd_t = 52;
+pub const SDIO_CMD53_IO_RW_EXTENDED: sdio_cmd_t = 53;
+pub const SDIO_ACMD6_SET_BUS_WIDTH: sdio_cmd_t = 6;
+
+pub type sdio_cmdtype_t = ::core::ffi::c_uint;
+pub const SD_CMDTYPE_BC: sdio_cmdtype_t = 1;
+pub const SD_CMDTYPE_BCR: sdio_cmdtype_t = 2;
+pub const SD_CMDTYPE_AC: sdio_cmdtype_t = 3;
+pub const SD_CMDTYPE_ADTC: sdio_cmdtype_t = 4;
+
+pub type sdio_resptype_t = ::core::ffi::c_uint;
+pub const SD_RESP_NONE: sdio_resptype_t = 0;
+pub const SD_RESP_R1: sdio_resptype_t = 1;
+pub const SD_RESP_R1b: sdio_resptype_t = 2;
+pub const SD_RESP_R2: sdio_resptype_t = 3;
+pub const SD_RESP_R3: sdio_resptype_t = 4;
+pub const SD_RESP_R4: sdio_resptype_t = 5;
+pub const SD_RESP_R5: sdio_resptype_t = 6;
+pub const SD_RESP_R6: sdio_resptype_t = 7;
+pub const SD_RESP_R7: sdio_resptype_t = 8;
+
+pub const SDIO_DEVICE_STATUS_CARD_INSERTED: u32 = 1;
+pub const SDIO_DEVICE_STATUS_NOT_INSERTED: u32 = 2;
+pub const SDIO_DEVICE_STATUS_WRITE_PROTECT_SWITCH: u32 = 4;
+pub const SDIO_DEVICE_STATUS_SD_INITIALIZED: u32 = 65536;
+pub const SDIO_DEVICE_STATUS_IS_SDHC: u32 = 1048576;
+
+extern "C" {
+    pub fn sdio_initialize(device: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
+    pub fn sdio_close();
+    pub fn sdio_get_device_status(state: *mut u32) -> ::core::ffi::c_int;
+    pub fn sdio_reset_device(response: *mut u32) -> ::core::ffi::c_int;
+    pub fn sdio_set_clock(divider: u32) -> ::core::ffi::c_int;
+    pub fn sdio_read_oc_register(oc: *mut u32) -> ::core::ffi::c_int;
+    pub fn sdio_read_hc_register(offset: u32, value: *mut u32) -> ::core::ffi::c_int;
+    pub fn sdio_set_hc_register(offset: u32, value: u32) -> ::core::ffi::c_int;
+    pub fn sdio_send_cmd(
+        cmd: sdio_cmd_t,
+        type_: sdio_cmdtype_t,
+        resptype: sdio_resptype_t,
+        arg: u32,
+        data: *mut ::core::ffi::c_void,
+        block_count: u32,
+        sector_size: u32,
+        response: *mut ::core::ffi::c_void,
+        response_length: usize,
+    ) -> ::core::ffi::c_int;
+}
+
+pub const USB_MAX_DEVICES: u32 = 8;
+pub const USB_REQUEST_CLEAR_FEATURE: u32 = 1;
+pub const USB_REQUEST_GET_DESCRIPTOR: u32 = 6;
+pub const USB_REQUEST_SET_CONFIGURATION: u32 = 9;
+pub const USB_REQTYPE_DEVICE_IN: u32 = 128;
+pub const USB_REQTYPE_DEVICE_OUT: u32 = 0;
+pub const USB_REQTYPE_ENDPOINT_OUT: u32 = 2;
+pub const USB_REQTYPE_CLASS_INTERFACE_IN: u32 = 161;
+pub const USB_REQTYPE_CLASS_INTERFACE_OUT: u32 = 33;
+pub const USB_DESCRIPTOR_DEVICE: u32 = 1;
+pub const USB_DESCRIPTOR_CONFIGURATION: u32 = 2;
+pub const USB_DESCRIPTOR_INTERFACE: u32 = 4;
+pub const USB_DESCRIPTOR_ENDPOINT: u32 = 5;
+pub const USB_ENDPOINT_IN: u32 = 128;
+pub const USB_ENDPOINT_TYPE_MASK: u32 = 3;
+pub const USB_ENDPOINT_TYPE_BULK: u32 = 2;
+pub const USB_FEATURE_ENDPOINT_HALT: u32 = 0;
+pub const USB_CLASS_MASS_STORAGE: u32 = 8;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct usb_device_entry_t {
+    pub device_id: u32,
+    pub vendor_id: u16,
+    pub product_id: u16,
+}
+
+extern "C" {
+    pub fn usb_initialize() -> ::core::ffi::c_int;
+    pub fn usb_close();
+    pub fn usb_get_device_list(
+        device_class: u8,
+        entries: *mut usb_device_entry_t,
+        max_entries: u8,
+    ) -> ::core::ffi::c_int;
+    pub fn usb_open_device(vendor_id: u16, product_id: u16) -> ::core::ffi::c_int;
+    pub fn usb_close_device(device: ::core::ffi::c_int);
+    pub fn usb_control_message(
+        device: ::core::ffi::c_int,
+        request_type: u8,
+        request: u8,
+        value: u16,
+        index: u16,
+        length: u16,
+        data: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
+    pub fn usb_bulk_message(
+        device: ::core::ffi::c_int,
+        endpoint: u8,
+        length: u16,
+        data: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
+}
+
+pub const ISFS_MAX_NAME: u32 = 12;
+pub const ISFS_MAX_PATH: u32 = 64;
+pub const ISFS_PERMISSION_NONE: u32 = 0;
+pub const ISFS_PERMISSION_READ: u32 = 1;
+pub const ISFS_PERMISSION_WRITE: u32 = 2;
+pub const ISFS_PERMISSION_READ_WRITE: u32 = 3;
+pub const ISFS_ERROR_INVALID: i32 = -101;
+pub const ISFS_ERROR_ACCESS: i32 = -102;
+pub const ISFS_ERROR_CORRUPT: i32 = -103;
+pub const ISFS_ERROR_EXISTS: i32 = -105;
+pub const ISFS_ERROR_NOT_FOUND: i32 = -106;
+pub const ISFS_ERROR_TOO_MANY_FILES: i32 = -107;
+pub const ISFS_ERROR_FULL: i32 = -108;
+pub const ISFS_ERROR_TOO_MANY_OPEN: i32 = -109;
+pub const ISFS_ERROR_PATH_TOO_LONG: i32 = -110;
+pub const ISFS_ERROR_ALREADY_OPEN: i32 = -111;
+pub const ISFS_ERROR_NOT_EMPTY: i32 = -114;
+pub const ISFS_ERROR_TOO_DEEP: i32 = -115;
+pub const ISFS_ERROR_BUSY: i32 = -116;
+pub const ISFS_ERROR_FATAL: i32 = -117;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct isfs_attributes_t {
+    pub owner_id: u32,
+    pub group_id: u16,
+    pub path: [::core::ffi::c_char; 64usize],
+    pub owner_permissions: u8,
+    pub group_permissions: u8,
+    pub other_permissions: u8,
+    pub attributes: u8,
+    pub padding: [u8; 2usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct isfs_stats_t {
+    pub cluster_size: u32,
+    pub free_clusters: u32,
+    pub used_clusters: u32,
+    pub bad_clusters: u32,
+    pub reserved_clusters: u32,
+    pub free_inodes: u32,
+    pub used_inodes: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct isfs_file_stats_t {
+    pub length: u32,
+    pub position: u32,
+}
+
+extern "C" {
+    pub fn isfs_initialize() -> ::core::ffi::c_int;
+    pub fn isfs_close();
+    pub fn isfs_read_dir(
+        path: *const ::core::ffi::c_char,
+        names: *mut ::core::ffi::c_char,
+        count: *mut u32,
+    ) -> ::core::ffi::c_int;
+    pub fn isfs_get_attributes(
+        path: *const ::core::ffi::c_char,
+        attributes: *mut isfs_attributes_t,
+    ) -> ::core::ffi::c_int;
+    pub fn isfs_create_file(
+        path: *const ::core::ffi::c_char,
+        attributes: u8,
+        owner_permissions: u8,
+        group_permissions: u8,
+        other_permissions: u8,
+    ) -> ::core::ffi::c_int;
+    pub fn isfs_create_dir(
+        path: *const ::core::ffi::c_char,
+        attributes: u8,
+        owner_permissions: u8,
+        group_permissions: u8,
+        other_permissions: u8,
+    ) -> ::core::ffi::c_int;
+    pub fn isfs_delete(path: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
+    pub fn isfs_rename(
+        old_path: *const ::core::ffi::c_char,
+        new_path: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
+    pub fn isfs_get_usage(
+        path: *const ::core::ffi::c_char,
+        clusters: *mut u32,
+        inodes: *mut u32,
+    ) -> ::core::ffi::c_int;
+    pub fn isfs_get_stats(stats: *mut isfs_stats_t) -> ::core::ffi::c_int;
+    pub fn isfs_get_file_stats(
+        file: ::core::ffi::c_int,
+        stats: *mut isfs_file_stats_t,
+    ) -> ::core::ffi::c_int;
+}
+
+// ---------------------------------------------------------------------------
+// powerblocks/core/utils/math
+// ---------------------------------------------------------------------------
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct vec2i {
+    pub x: ::core::ffi::c_int,
+    pub y: ::core::ffi::c_int,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct vec2s16 {
+    pub x: i16,
+    pub y: i16,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct vec2 {
+    pub x: f32,
+    pub y: f32,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct vec3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+pub type matrix3 = [[f32; 3usize]; 3usize];
+pub type matrix34 = [[f32; 4usize]; 3usize];
+pub type matrix4 = [[f32; 4usize]; 4usize];
+
+extern "C" {
+    pub fn vec3_normalize(v: *mut vec3);
+    pub fn vec3_to(a: vec3, b: vec3) -> vec3;
+    pub fn matrix3_identity(mtx: *mut [f32; 3usize]);
+    pub fn matrix3_from34(dst: *mut [f32; 3usize], src: *const [f32; 4usize]);
+    pub fn matrix3_transpose(dst: *mut [f32; 3usize], src: *const [f32; 3usize]);
+    pub fn matrix3_inverse(dst: *mut [f32; 3usize], src: *const [f32; 3usize]);
+    pub fn matrix34_identity(mtx: *mut [f32; 4usize]);
+    pub fn matrix34_rotate_x(mtx: *mut [f32; 4usize], angle: f32);
+    pub fn matrix34_rotate_y(mtx: *mut [f32; 4usize], angle: f32);
+    pub fn matrix34_rotate_z(mtx: *mut [f32; 4usize], angle: f32);
+    pub fn matrix34_translation(mtx: *mut [f32; 4usize], pos: *const vec3);
+    pub fn matrix34_scale(mtx: *mut [f32; 4usize], pos: *const vec3);
+    pub fn matrix34_multiply(dst: *mut [f32; 4usize], a: *const [f32; 4usize], b: *const [f32; 4usize]);
+    pub fn matrix34_inverse(dst: *mut [f32; 4usize], src: *const [f32; 4usize]);
+    pub fn matrix4_perspective(mtx: *mut [f32; 4usize], fov: f32, aspect: f32, near: f32, far: f32);
+    pub fn matrix4_orthographic(
+        mtx: *mut [f32; 4usize],
+        left: f32,
+        right: f32,
+        bottom: f32,
+        top: f32,
+        near: f32,
+        far: f32,
+    );
+}
+
+// ---------------------------------------------------------------------------
+// powerblocks/core/graphics
+// ---------------------------------------------------------------------------
+
+pub const VIDEO_WIDTH: u32 = 640;
+pub const VIDEO_HEIGHT: u32 = 480;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct framebuffer_t {
+    pub pixels: [[u16; 640usize]; 480usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct framebuffer_font_t {
+    pub font_data: *const u8,
+    pub character_size: vec2s16,
+}
+
+extern "C" {
+    pub fn framebuffer_copy_rgba_into(framebuffer: *mut framebuffer_t, rgba: *const u32, position: vec2i, size: vec2i);
+    pub fn framebuffer_fill_rgba(framebuffer: *mut framebuffer_t, rgba: u32, a: vec2i, b: vec2i);
+    pub fn framebuffer_put_text(
+        framebuffer: *mut framebuffer_t,
+        foreground: u32,
+        background: u32,
+        position: vec2i,
+        font: *const framebuffer_font_t,
+        str_: *const ::core::ffi::c_char,
+    );
+}
+
+pub type video_mode_t = ::core::ffi::c_uint;
+pub const VIDEO_MODE_UNINITIALIZED: video_mode_t = 0;
+pub const VIDEO_MODE_640X480_NTSC_INTERLACED: video_mode_t = 1;
+pub const VIDEO_MODE_640X480_NTSC_PROGRESSIVE: video_mode_t = 2;
+pub const VIDEO_MODE_640X480_PAL50: video_mode_t = 3;
+pub const VIDEO_MODE_640X480_PAL60: video_mode_t = 4;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct video_profile_t {
+    pub width: u16,
+    pub efb_height: u16,
+    pub xfb_height: u16,
+    pub copy_pattern: [[u8; 2usize]; 12usize],
+    pub copy_filer: [u8; 7usize],
+}
+
+pub type video_retrace_callback_t = ::core::option::Option<unsafe extern "C" fn()>;
+
+extern "C" {
+    pub fn video_system_default_video_mode() -> video_mode_t;
+    pub fn video_get_profile(mode: video_mode_t) -> *const video_profile_t;
+    pub fn video_initialize(mode: video_mode_t);
+    pub fn video_set_framebuffer(framebuffer: *const framebuffer_t);
+    pub fn video_get_framebuffer() -> *mut framebuffer_t;
+    pub fn video_wait_vsync();
+    pub fn video_wait_vsync_int();
+    pub fn video_set_retrace_callback(callback: video_retrace_callback_t);
+    pub fn video_get_retrace_callback() -> video_retrace_callback_t;
+}
+
+pub const GX_WPAR_ADDRESS: u32 = 3422584832;
+pub const GX_FIFO_MINIMUM_SIZE: u32 = 65536;
+pub const GX_FIFO_WATERMARK: u32 = 16384;
+
+pub type gx_mtx_id_t = ::core::ffi::c_uint;
+pub const GX_MTX_ID_0: gx_mtx_id_t = 0;
+pub const GX_MTX_ID_1: gx_mtx_id_t = 3;
+pub const GX_MTX_ID_2: gx_mtx_id_t = 6;
+pub const GX_MTX_ID_3: gx_mtx_id_t = 9;
+pub const GX_MTX_ID_4: gx_mtx_id_t = 12;
+pub const GX_MTX_ID_5: gx_mtx_id_t = 15;
+pub const GX_MTX_ID_6: gx_mtx_id_t = 18;
+pub const GX_MTX_ID_7: gx_mtx_id_t = 21;
+pub const GX_MTX_ID_8: gx_mtx_id_t = 24;
+pub const GX_MTX_ID_9: gx_mtx_id_t = 27;
+pub const GX_MTX_ID_10: gx_mtx_id_t = 30;
+pub const GX_MTX_ID_11: gx_mtx_id_t = 33;
+pub const GX_MTX_ID_12: gx_mtx_id_t = 36;
+pub const GX_MTX_ID_13: gx_mtx_id_t = 39;
+pub const GX_MTX_ID_14: gx_mtx_id_t = 42;
+pub const GX_MTX_ID_15: gx_mtx_id_t = 45;
+pub const GX_MTX_ID_16: gx_mtx_id_t = 48;
+pub const GX_MTX_ID_17: gx_mtx_id_t = 51;
+pub const GX_MTX_ID_18: gx_mtx_id_t = 54;
+pub const GX_MTX_ID_19: gx_mtx_id_t = 57;
+pub const GX_MTX_ID_20: gx_mtx_id_t = 60;
+pub const GX_MTX_ID_IDENTITY: gx_mtx_id_t = 60;
+
+pub type gx_color_channel_t = ::core::ffi::c_uint;
+pub const GX_COLOR_CHANNEL_COLOR0: gx_color_channel_t = 0;
+pub const GX_COLOR_CHANNEL_COLOR1: gx_color_channel_t = 1;
+pub const GX_COLOR_CHANNEL_ALPHA0: gx_color_channel_t = 2;
+pub const GX_COLOR_CHANNEL_ALPHA1: gx_color_channel_t = 3;
+
+pub type gx_light_bit_t = ::core::ffi::c_uint;
+pub const GX_LIGHT_BIT_NONE: gx_light_bit_t = 0;
+pub const GX_LIGHT_BIT_0: gx_light_bit_t = 4;
+pub const GX_LIGHT_BIT_1: gx_light_bit_t = 8;
+pub const GX_LIGHT_BIT_2: gx_light_bit_t = 16;
+pub const GX_LIGHT_BIT_3: gx_light_bit_t = 32;
+pub const GX_LIGHT_BIT_4: gx_light_bit_t = 2048;
+pub const GX_LIGHT_BIT_5: gx_light_bit_t = 4096;
+pub const GX_LIGHT_BIT_6: gx_light_bit_t = 8192;
+pub const GX_LIGHT_BIT_7: gx_light_bit_t = 16384;
+
+pub type gx_attenuation_mode_t = ::core::ffi::c_uint;
+pub const GX_ATTENUATION_MODE_NONE: gx_attenuation_mode_t = 0;
+pub const GX_ATTENUATION_MODE_SPECULAR: gx_attenuation_mode_t = 1;
+pub const GX_ATTENUATION_MODE_SPOTLIGHT: gx_attenuation_mode_t = 3;
+
+pub type gx_diffuse_mode_t = ::core::ffi::c_uint;
+pub const GX_DIFFUSE_MODE_NONE: gx_diffuse_mode_t = 0;
+pub const GX_DIFFUSE_SIGNED: gx_diffuse_mode_t = 1;
+pub const GX_DIFFUSE_CLAMPED: gx_diffuse_mode_t = 2;
+
+pub type gx_xf_color_t = ::core::ffi::c_uint;
+pub const GX_XF_COLOR_AMBIENT_0: gx_xf_color_t = 4106;
+pub const GX_XF_COLOR_AMBIENT_1: gx_xf_color_t = 4107;
+pub const GX_XF_COLOR_MATERIAL_0: gx_xf_color_t = 4108;
+pub const GX_XF_COLOR_MATERIAL_1: gx_xf_color_t = 4109;
+
+pub type gx_light_id_t = ::core::ffi::c_uint;
+pub const GX_LIGHT_ID_0: gx_light_id_t = 0;
+pub const GX_LIGHT_ID_1: gx_light_id_t = 1;
+pub const GX_LIGHT_ID_2: gx_light_id_t = 2;
+pub const GX_LIGHT_ID_3: gx_light_id_t = 3;
+pub const GX_LIGHT_ID_4: gx_light_id_t = 4;
+pub const GX_LIGHT_ID_5: gx_light_id_t = 5;
+pub const GX_LIGHT_ID_6: gx_light_id_t = 6;
+pub const GX_LIGHT_ID_7: gx_light_id_t = 7;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct gx_light_t {
+    pub color: u32,
+    pub cos_attenuation: vec3,
+    pub distance_attenuation: vec3,
+    pub position: vec3,
+    pub direction: vec3,
+}
+
+pub type gx_texgen_type_t = ::core::ffi::c_uint;
+pub const GX_TEXGEN_TYPE_REGULAR: gx_texgen_type_t = 0;
+pub const GX_TEXGEN_TYPE_EMBOSS: gx_texgen_type_t = 1;
+pub const GX_TEXGEN_TYPE_COLOR_0: gx_texgen_type_t = 2;
+pub const GX_TEXGEN_TYPE_COLOR_1: gx_texgen_type_t = 3;
+
+pub type gx_texgen_source_t = ::core::ffi::c_uint;
+pub const GX_TEXGEN_SOURCE_POSITION: gx_texgen_source_t = 0;
+pub const GX_TEXGEN_SOURCE_NORMAL: gx_texgen_source_t = 1;
+pub const GX_TEXGEN_SOURCE_COLORS: gx_texgen_source_t = 2;
+pub const GX_TEXGEN_SOURCE_BINORMAL_T: gx_texgen_source_t = 3;
+pub const GX_TEXGEN_SOURCE_BINORMAL_B: gx_texgen_source_t = 4;
+pub const GX_TEXGEN_SOURCE_TEX0: gx_texgen_source_t = 5;
+pub const GX_TEXGEN_SOURCE_TEX1: gx_texgen_source_t = 6;
+pub const GX_TEXGEN_SOURCE_TEX2: gx_texgen_source_t = 7;
+pub const GX_TEXGEN_SOURCE_TEX3: gx_texgen_source_t = 8;
+pub const GX_TEXGEN_SOURCE_TEX4: gx_texgen_source_t = 9;
+pub const GX_TEXGEN_SOURCE_TEX5: gx_texgen_source_t = 10;
+pub const GX_TEXGEN_SOURCE_TEX6: gx_texgen_source_t = 11;
+pub const GX_TEXGEN_SOURCE_TEX7: gx_texgen_source_t = 12;
+
+pub type gx_texture_map_t = ::core::ffi::c_uint;
+pub const GX_TEXTURE_MAP_0: gx_texture_map_t = 0;
+pub const GX_TEXTURE_MAP_1: gx_texture_map_t = 1;
+pub const GX_TEXTURE_MAP_2: gx_texture_map_t = 2;
+pub const GX_TEXTURE_MAP_3: gx_texture_map_t = 3;
+pub const GX_TEXTURE_MAP_4: gx_texture_map_t = 4;
+pub const GX_TEXTURE_MAP_5: gx_texture_map_t = 5;
+pub const GX_TEXTURE_MAP_6: gx_texture_map_t = 6;
+pub const GX_TEXTURE_MAP_7: gx_texture_map_t = 7;
+
+extern "C" {
+    pub fn gx_flash_viewport(x: f32, y: f32, width: f32, height: f32, near: f32, far: f32, jitter: bool);
+    pub fn gx_flash_projection(mtx: *const [f32; 4usize], is_perspective: bool);
+    pub fn gx_flash_matrix(mtx: *const [f32; 4usize], index: gx_mtx_id_t, type_: bool);
+    pub fn gx_flash_nrm_matrix(mtx: *const [f32; 3usize], index: gx_mtx_id_t);
+    pub fn gx_set_current_psn_matrix(index: gx_mtx_id_t);
+    pub fn gx_set_color_channels(count: u32);
+    pub fn gx_configure_color_channel(
+        channel: gx_color_channel_t,
+        lights: gx_light_bit_t,
+        lighting: bool,
+        ambient_source: bool,
+        material_source: bool,
+        diffuse: gx_diffuse_mode_t,
+        attenuation: gx_attenuation_mode_t,
+    );
+    pub fn gx_flash_xf_color(id: gx_xf_color_t, r: u8, g: u8, b: u8, a: u8);
+    pub fn gx_flash_light(id: gx_light_id_t, light: *const gx_light_t);
+    pub fn gx_set_texcoord_channels(count: u32);
+    pub fn gx_configure_texcoord_channel(
+        map: gx_texture_map_t,
+        source: gx_texgen_source_t,
+        type_: gx_texgen_type_t,
+        projection: bool,
+        three_component: bool,
+        embossing_light: gx_light_id_t,
+    );
+    pub fn gx_configure_dual_texcoord(map: gx_texture_map_t, index: gx_mtx_id_t, normalize: bool);
+    pub fn gx_flash_dual_texcoord_matrix(mtx: *mut [f32; 4usize], index: gx_mtx_id_t, type_: bool);
+    pub fn gx_set_current_texcoord_matrix(map: gx_texture_map_t, index: gx_mtx_id_t);
+    pub fn gx_flash_enable_dual_texcoord(enable: bool);
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct gx_tev_stage_t {
+    pub color_control: u32,
+    pub alpha_control: u32,
+}
+
+pub type gx_tev_stage_id = ::core::ffi::c_uint;
+pub const GX_TEV_STAGE_0: gx_tev_stage_id = 0;
+pub const GX_TEV_STAGE_1: gx_tev_stage_id = 1;
+pub const GX_TEV_STAGE_2: gx_tev_stage_id = 2;
+pub const GX_TEV_STAGE_3: gx_tev_stage_id = 3;
+pub const GX_TEV_STAGE_4: gx_tev_stage_id = 4;
+pub const GX_TEV_STAGE_5: gx_tev_stage_id = 5;
+pub const GX_TEV_STAGE_6: gx_tev_stage_id = 6;
+pub const GX_TEV_STAGE_7: gx_tev_stage_id = 7;
+pub const GX_TEV_STAGE_8: gx_tev_stage_id = 8;
+pub const GX_TEV_STAGE_9: gx_tev_stage_id = 9;
+pub const GX_TEV_STAGE_10: gx_tev_stage_id = 10;
+pub const GX_TEV_STAGE_11: gx_tev_stage_id = 11;
+pub const GX_TEV_STAGE_12: gx_tev_stage_id = 12;
+pub const GX_TEV_STAGE_13: gx_tev_stage_id = 13;
+pub const GX_TEV_STAGE_14: gx_tev_stage_id = 14;
+pub const GX_TEV_STAGE_15: gx_tev_stage_id = 15;
+
+pub type gx_tev_io_t = ::core::ffi::c_uint;
+pub const GX_TEV_IO_PREVIOUS: gx_tev_io_t = 0;
+pub const GX_TEV_IO_REGISTER_0: gx_tev_io_t = 2;
+pub const GX_TEV_IO_REGISTER_1: gx_tev_io_t = 4;
+pub const GX_TEV_IO_REGISTER_2: gx_tev_io_t = 6;
+pub const GX_TEV_IO_TEXTURE: gx_tev_io_t = 8;
+pub const GX_TEV_IO_RASTERIZER: gx_tev_io_t = 10;
+pub const GX_TEV_IO_ONE: gx_tev_io_t = 12;
+pub const GX_TEV_IO_HALF: gx_tev_io_t = 13;
+pub const GX_TEV_IO_CONSTANT: gx_tev_io_t = 14;
+pub const GX_TEV_IO_ZERO: gx_tev_io_t = 15;
+pub const GX_TEV_IO_ALPHA: gx_tev_io_t = 1;
+
+pub type gx_tev_scale_t = ::core::ffi::c_uint;
+pub const GX_TEV_SCALE_1: gx_tev_scale_t = 0;
+pub const GX_TEV_SCALE_2: gx_tev_scale_t = 1;
+pub const GX_TEV_SCALE_4: gx_tev_scale_t = 2;
+pub const GX_TEV_SCALE_HALF: gx_tev_scale_t = 3;
+
+pub type gx_tev_bias_t = ::core::ffi::c_uint;
+pub const GX_TEV_BIAS_0: gx_tev_bias_t = 0;
+pub const GX_TEV_BIAS_ADD_HALF: gx_tev_bias_t = 1;
+pub const GX_TEV_BIAS_SUB_HALF: gx_tev_bias_t = 2;
+
+pub type gx_tev_compare_t = ::core::ffi::c_uint;
+pub const GX_TEV_COMPARE_R8_GREATER: gx_tev_compare_t = 0;
+pub const GX_TEV_COMPARE_R8_EQUAL: gx_tev_compare_t = 1;
+pub const GX_TEV_COMPARE_GR16_GREATER: gx_tev_compare_t = 2;
+pub const GX_TEV_COMPARE_GR16_EQUAL: gx_tev_compare_t = 3;
+pub const GX_TEV_COMPARE_BGR24_GREATER: gx_tev_compare_t = 4;
+pub const GX_TEV_COMPARE_BGR24_EQUAL: gx_tev_compare_t = 5;
+pub const GX_TEV_COMPARE_RGB8_GT: gx_tev_compare_t = 6;
+pub const GX_TEV_COMPARE_RGB8_EQ: gx_tev_compare_t = 7;
+pub const GX_TEV_COMPARE_A8_GREATER: gx_tev_compare_t = 6;
+pub const GX_TEV_COMPARE_A8_EQUAL: gx_tev_compare_t = 7;
+
+extern "C" {
+    pub fn gx_set_tev_stages(count: ::core::ffi::c_int);
+    pub fn gx_initialize_tev_stage(tev: *mut gx_tev_stage_t);
+    pub fn gx_flash_tev_stage(id: gx_tev_stage_id, tev: *const gx_tev_stage_t);
+    pub fn gx_set_tev_stage_color_input(
+        tev: *mut gx_tev_stage_t,
+        a: gx_tev_io_t,
+        b: gx_tev_io_t,
+        c: gx_tev_io_t,
+        d: gx_tev_io_t,
+    );
+    pub fn gx_set_tev_stage_alpha_input(
+        tev: *mut gx_tev_stage_t,
+        a: gx_tev_io_t,
+        b: gx_tev_io_t,
+        c: gx_tev_io_t,
+        d: gx_tev_io_t,
+    );
+    pub fn gx_set_tev_stage_color_output(tev: *mut gx_tev_stage_t, out: gx_tev_io_t, clamp: bool);
+    pub fn gx_set_tev_stage_alpha_output(tev: *mut gx_tev_stage_t, out: gx_tev_io_t, clamp: bool);
+    pub fn gx_set_tev_stage_color_biasing(
+        tev: *mut gx_tev_stage_t,
+        subtract: bool,
+        bias: gx_tev_bias_t,
+        scale: gx_tev_scale_t,
+    );
+    pub fn gx_set_tev_stage_color_comparison(tev: *mut gx_tev_stage_t, comparison: gx_tev_compare_t);
+    pub fn gx_set_tev_stage_alpha_biasing(
+        tev: *mut gx_tev_stage_t,
+        subtract: bool,
+        bias: gx_tev_bias_t,
+        scale: gx_tev_scale_t,
+    );
+    pub fn gx_set_tev_stage_alpha_comparison(tev: *mut gx_tev_stage_t, comparison: gx_tev_compare_t);
+    pub fn gx_flash_tev_register_color(
+        reg: gx_tev_io_t,
+        r: ::core::ffi::c_int,
+        g: ::core::ffi::c_int,
+        b: ::core::ffi::c_int,
+        a: ::core::ffi::c_int,
+    );
+}
+
+pub type gx_vtxattr_data_t = ::core::ffi::c_uint;
+pub const GX_VTXATTR_DATA_DISABLED: gx_vtxattr_data_t = 0;
+pub const GX_VTXATTR_DATA_DIRECT: gx_vtxattr_data_t = 1;
+pub const GX_VTXATTR_DATA_INDEX8: gx_vtxattr_data_t = 2;
+pub const GX_VTXATTR_DATA_INDEX16: gx_vtxattr_data_t = 3;
+
+pub type gx_vtxdesc_t = ::core::ffi::c_uint;
+pub const GX_VTXDESC_POSNORM_INDEX: gx_vtxdesc_t = 0;
+pub const GX_VTXDESC_TEXCOORDMTX0: gx_vtxdesc_t = 1;
+pub const GX_VTXDESC_TEXCOORDMTX1: gx_vtxdesc_t = 2;
+pub const GX_VTXDESC_TEXCOORDMTX2: gx_vtxdesc_t = 3;
+pub const GX_VTXDESC_TEXCOORDMTX3: gx_vtxdesc_t = 4;
+pub const GX_VTXDESC_TEXCOORDMTX4: gx_vtxdesc_t = 5;
+pub const GX_VTXDESC_TEXCOORDMTX5: gx_vtxdesc_t = 6;
+pub const GX_VTXDESC_TEXCOORDMTX6: gx_vtxdesc_t = 7;
+pub const GX_VTXDESC_TEXCOORDMTX7: gx_vtxdesc_t = 8;
+pub const GX_VTXDESC_POSITION: gx_vtxdesc_t = 9;
+pub const GX_VTXDESC_NORMAL: gx_vtxdesc_t = 10;
+pub const GX_VTXDESC_NORMAL_NBT: gx_vtxdesc_t = 11;
+pub const GX_VTXDESC_COLOR0: gx_vtxdesc_t = 12;
+pub const GX_VTXDESC_COLOR1: gx_vtxdesc_t = 13;
+pub const GX_VTXDESC_TEXCOORD0: gx_vtxdesc_t = 14;
+pub const GX_VTXDESC_TEXCOORD1: gx_vtxdesc_t = 15;
+pub const GX_VTXDESC_TEXCOORD2: gx_vtxdesc_t = 16;
+pub const GX_VTXDESC_TEXCOORD3: gx_vtxdesc_t = 17;
+pub const GX_VTXDESC_TEXCOORD4: gx_vtxdesc_t = 18;
+pub const GX_VTXDESC_TEXCOORD5: gx_vtxdesc_t = 19;
+pub const GX_VTXDESC_TEXCOORD6: gx_vtxdesc_t = 20;
+pub const GX_VTXDESC_TEXCOORD7: gx_vtxdesc_t = 21;
+
+pub type gx_vtxattr_component_t = ::core::ffi::c_uint;
+pub const GX_VTXATTR_POS_XY: gx_vtxattr_component_t = 0;
+pub const GX_VTXATTR_POS_XYZ: gx_vtxattr_component_t = 1;
+pub const GX_VTXATTR_RGB: gx_vtxattr_component_t = 0;
+pub const GX_VTXATTR_RGBA: gx_vtxattr_component_t = 1;
+pub const GX_VTXATTR_NRM_XYZ: gx_vtxattr_component_t = 0;
+pub const GX_VTXATTR_NRM_NBT: gx_vtxattr_component_t = 1;
+pub const GX_VTXATTR_NRM_NBT3: gx_vtxattr_component_t = 3;
+pub const GX_VTXATTR_TEX_S: gx_vtxattr_component_t = 0;
+pub const GX_VTXATTR_TEX_ST: gx_vtxattr_component_t = 1;
+
+pub type gx_vtxattr_component_format_t = ::core::ffi::c_uint;
+pub const GX_VTXATTR_U8: gx_vtxattr_component_format_t = 0;
+pub const GX_VTXATTR_S8: gx_vtxattr_component_format_t = 1;
+pub const GX_VTXATTR_U16: gx_vtxattr_component_format_t = 2;
+pub const GX_VTXATTR_S16: gx_vtxattr_component_format_t = 3;
+pub const GX_VTXATTR_F32: gx_vtxattr_component_format_t = 4;
+pub const GX_VTXATTR_RGB565: gx_vtxattr_component_format_t = 0;
+pub const GX_VTXATTR_RGB8: gx_vtxattr_component_format_t = 1;
+pub const GX_VTXATTR_RGBX8: gx_vtxattr_component_format_t = 2;
+pub const GX_VTXATTR_RGBA4: gx_vtxattr_component_format_t = 3;
+pub const GX_VTXATTR_RGBA6: gx_vtxattr_component_format_t = 4;
+pub const GX_VTXATTR_RGBA8: gx_vtxattr_component_format_t = 5;
+
+pub type gx_primitive_t = ::core::ffi::c_uint;
+pub const GX_LINES: gx_primitive_t = 168;
+pub const GX_LINESTRIP: gx_primitive_t = 176;
+pub const GX_POINTS: gx_primitive_t = 184;
+pub const GX_QUADS: gx_primitive_t = 128;
+pub const GX_TRIANGLE_FAN: gx_primitive_t = 160;
+pub const GX_TRIANGLES: gx_primitive_t = 144;
+pub const GX_TRIANGLE_STRIP: gx_primitive_t = 152;
+
+pub type gx_compare_t = ::core::ffi::c_uint;
+pub const GX_COMPARE_NEVER: gx_compare_t = 0;
+pub const GX_COMPARE_LESS: gx_compare_t = 1;
+pub const GX_COMPARE_EQUAL: gx_compare_t = 2;
+pub const GX_COMPARE_LESS_EQUAL: gx_compare_t = 3;
+pub const GX_COMPARE_GREATER: gx_compare_t = 4;
+pub const GX_COMPARE_NOT_EQUAL: gx_compare_t = 5;
+pub const GX_COMPARE_GREATER_EQUAL: gx_compare_t = 6;
+pub const GX_COMPARE_ALWAYS: gx_compare_t = 7;
+
+pub type gx_pixel_format_t = ::core::ffi::c_uint;
+pub const GX_PIXEL_FORMAT_RGB8_Z24: gx_pixel_format_t = 0;
+pub const GX_PIXEL_FORMAT_RGBA6_Z24: gx_pixel_format_t = 1;
+pub const GX_PIXEL_FORMAT_RGB565_Z16: gx_pixel_format_t = 2;
+pub const GX_PIXEL_FORMAT_Z24: gx_pixel_format_t = 3;
+pub const GX_PIXEL_FORMAT_Y8: gx_pixel_format_t = 4;
+pub const GX_PIXEL_FORMAT_U8: gx_pixel_format_t = 5;
+pub const GX_PIXEL_FORMAT_V8: gx_pixel_format_t = 6;
+pub const GX_PIXEL_FORMAT_YUV420: gx_pixel_format_t = 7;
+
+pub type gx_z_format_t = ::core::ffi::c_uint;
+pub const GX_Z_FORMAT_LINEAR: gx_z_format_t = 0;
+pub const GX_Z_FORMAT_NEAR: gx_z_format_t = 1;
+pub const GX_Z_FORMAT_MID: gx_z_format_t = 2;
+pub const GX_Z_FORMAT_FAR: gx_z_format_t = 3;
+
+pub type gx_clamp_mode_t = ::core::ffi::c_uint;
+pub const GX_CLAMP_MODE_NONE: gx_clamp_mode_t = 0;
+pub const GX_CLAMP_MODE_TOP: gx_clamp_mode_t = 1;
+pub const GX_CLAMP_MODE_BOTTOM: gx_clamp_mode_t = 2;
+pub const GX_CLAMP_MODE_TOP_BOTTOM: gx_clamp_mode_t = 3;
+
+pub type gx_gamma_t = ::core::ffi::c_uint;
+pub const GX_GAMMA_1_0: gx_gamma_t = 0;
+pub const GX_GAMMA_1_7: gx_gamma_t = 1;
+pub const GX_GAMMA_2_2: gx_gamma_t = 2;
+
+pub type gx_line_mode_t = ::core::ffi::c_uint;
+pub const GX_LINE_MODE_PROGRESSIVE: gx_line_mode_t = 0;
+pub const GX_LINE_MODE_EVEN: gx_line_mode_t = 2;
+pub const GX_LINE_MODE_ODD: gx_line_mode_t = 3;
+
+pub type gx_texture_id_t = ::core::ffi::c_uint;
+pub const GX_TEXTURE_ID_0: gx_texture_id_t = 0;
+pub const GX_TEXTURE_ID_1: gx_texture_id_t = 1;
+pub const GX_TEXTURE_ID_2: gx_texture_id_t = 2;
+pub const GX_TEXTURE_ID_3: gx_texture_id_t = 3;
+pub const GX_TEXTURE_ID_4: gx_texture_id_t = 4;
+pub const GX_TEXTURE_ID_5: gx_texture_id_t = 5;
+pub const GX_TEXTURE_ID_6: gx_texture_id_t = 6;
+pub const GX_TEXTURE_ID_7: gx_texture_id_t = 7;
+
+pub const GX_WPAR_OPCODE_LOAD_CP: u32 = 8;
+pub const GX_WPAR_OPCODE_LOAD_XF: u32 = 16;
+pub const GX_WPAR_OPCODE_LOAD_BP: u32 = 97;
+pub const GX_XF_MEMORY_POSTEX_MTX_0: u32 = 0;
+pub const GX_XF_MEMORY_NORMAL_MTX_0: u32 = 1024;
+pub const GX_XF_MEMORY_DUALTEX_MTX_0: u32 = 1280;
+pub const GX_XF_MEMORY_LIGHT0: u32 = 1536;
+pub const GX_XF_REGISTER_VERTEX_STATS: u32 = 4104;
+pub const GX_XF_REGISTER_COLOR_COUNT: u32 = 4105;
+pub const GX_XF_REGISTER_COLOR_CONTROL: u32 = 4110;
+pub const GX_XF_REGISTER_DUALTEXTRANS: u32 = 4114;
+pub const GX_XF_REGISTER_MTXIDX_A: u32 = 4120;
+pub const GX_XF_REGISTER_MTXIDX_B: u32 = 4121;
+pub const GX_XF_REGISTER_VIEWPORT: u32 = 4122;
+pub const GX_XF_REGISTER_PROJECTION: u32 = 4128;
+pub const GX_XF_REGISTER_TEXCOORD_COUNT: u32 = 4159;
+pub const GX_XF_REGISTER_TEX0: u32 = 4160;
+pub const GX_XF_REGISTER_DUALTEX0: u32 = 4176;
+pub const GX_BP_REGISTERS_GENMODE: u32 = 0;
+pub const GX_BP_REGISTERS_COPY_FILTER_POS_A: u32 = 16777216;
+pub const GX_BP_REGISTERS_COPY_FILTER_POS_B: u32 = 33554432;
+pub const GX_BP_REGISTERS_COPY_FILTER_POS_C: u32 = 50331648;
+pub const GX_BP_REGISTERS_COPY_FILTER_POS_D: u32 = 67108864;
+pub const GX_BP_REGISTERS_SCISSOR_TL: u32 = 536870912;
+pub const GX_BP_REGISTERS_SCISSOR_BR: u32 = 553648128;
+pub const GX_BP_REGISTERS_SSIZE0: u32 = 805306368;
+pub const GX_BP_REGISTERS_TSIZE0: u32 = 822083584;
+pub const GX_BP_REGISTERS_Z_MODE: u32 = 1073741824;
+pub const GX_BP_REGISTERS_CMODE_0: u32 = 1090519040;
+pub const GX_BP_REGISTERS_CMODE_1: u32 = 1107296256;
+pub const GX_BP_REGISTERS_PE_CONTROL: u32 = 1124073472;
+pub const GX_BP_REGISTERS_PE_DONE: u32 = 1157627904;
+pub const GX_BP_REGISTERS_EFB_SOURCE_TOP_LEFT: u32 = 1224736768;
+pub const GX_BP_REGISTERS_EFB_SOURCE_WIDTH_HEIGHT: u32 = 1241513984;
+pub const GX_BP_REGISTERS_XFB_TARGET_ADDRESS: u32 = 1258291200;
+pub const GX_BP_REGISTERS_EFB_DESTINATION_WIDTH: u32 = 1291845632;
+pub const GX_BP_REGISTERS_Y_SCALE: u32 = 1308622848;
+pub const GX_BP_REGISTERS_PE_COPY_CLEAR_AR: u32 = 1325400064;
+pub const GX_BP_REGISTERS_PE_COPY_CLEAR_GB: u32 = 1342177280;
+pub const GX_BP_REGISTERS_PE_COPY_CLEAR_Z: u32 = 1358954496;
+pub const GX_BP_REGISTERS_PE_COPY_EXECUTE: u32 = 1375731712;
+pub const GX_BP_REGISTERS_COPY_FILTER_COEFF_A: u32 = 1392508928;
+pub const GX_BP_REGISTERS_COPY_FILTER_COEFF_B: u32 = 1409286144;
+pub const GX_BP_REGISTERS_SCISSOR_OFFSET: u32 = 1493172224;
+pub const GX_BP_REGISTERS_TX_SETMODE0_I0: u32 = 2147483648;
+pub const GX_BP_REGISTERS_TX_SETMODE1_I0: u32 = 2214592512;
+pub const GX_BP_REGISTERS_TX_SETIMAGE0_I0: u32 = 2281701376;
+pub const GX_BP_REGISTERS_TX_SETIMAGE1_I0: u32 = 2348810240;
+pub const GX_BP_REGISTERS_TX_SETIMAGE2_I0: u32 = 2415919104;
+pub const GX_BP_REGISTERS_TX_SETIMAGE3_I0: u32 = 2483027968;
+pub const GX_BP_REGISTERS_TX_SETTLUT_0: u32 = 2550136832;
+pub const GX_BP_REGISTERS_TEV0_COLOR_ENV: u32 = 3221225472;
+pub const GX_BP_REGISTERS_TEV0_ALPHA_ENV: u32 = 3238002688;
+pub const GX_BP_REGISTERS_TEV_REGISTERL_0: u32 = 3758096384;
+pub const GX_BP_REGISTERS_TEV_REGISTERH_0: u32 = 3774873600;
+pub const GX_CP_REGISTERS_MTXIDX_A: u32 = 48;
+pub const GX_CP_REGISTERS_MTXIDX_B: u32 = 64;
+pub const GX_CP_REGISTER_VCD_LOW: u32 = 80;
+pub const GX_CP_REGISTER_VCD_HIGH: u32 = 96;
+pub const GX_CP_REGISTER_VAT_A: u32 = 112;
+pub const GX_CP_REGISTER_VAT_B: u32 = 128;
+pub const GX_CP_REGISTER_VAT_C: u32 = 144;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct gx_fifo_t {
+    pub base_address: u32,
+    pub end_address: u32,
+    pub high_watermark: u32,
+    pub low_watermark: u32,
+    pub distance: u32,
+    pub write_head: u32,
+    pub read_head: u32,
+    pub breakpoint: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct gx_texture_t {
+    pub mode0: u32,
+    pub mode1: u32,
+    pub image0: u32,
+    pub image1: u32,
+    pub image2: u32,
+    pub image3: u32,
+    pub lut: u32,
+}
+
+pub type gx_texture_format_t = ::core::ffi::c_uint;
+pub const GX_TEXTURE_FORMAT_I4: gx_texture_format_t = 0;
+pub const GX_TEXTURE_FORMAT_I8: gx_texture_format_t = 1;
+pub const GX_TEXTURE_FORMAT_IA4: gx_texture_format_t = 2;
+pub const GX_TEXTURE_FORMAT_IA8: gx_texture_format_t = 3;
+pub const GX_TEXTURE_FORMAT_RGB565: gx_texture_format_t = 4;
+pub const GX_TEXTURE_FORMAT_RGB5A3: gx_texture_format_t = 5;
+pub const GX_TEXTURE_FORMAT_RGBA8: gx_texture_format_t = 6;
+pub const GX_TEXTURE_FORMAT_C4: gx_texture_format_t = 8;
+pub const GX_TEXTURE_FORMAT_C8: gx_texture_format_t = 9;
+pub const GX_TEXTURE_FORMAT_C14X2: gx_texture_format_t = 10;
+pub const GX_TEXTURE_FORMAT_CMP: gx_texture_format_t = 14;
+
+pub type gx_texture_wrap_t = ::core::ffi::c_uint;
+pub const GX_WRAP_CLAMP: gx_texture_wrap_t = 0;
+pub const GX_WRAP_REPEAT: gx_texture_wrap_t = 1;
+pub const GX_WRAP_MIRROR: gx_texture_wrap_t = 2;
+
+pub type gx_texture_min_filter = ::core::ffi::c_uint;
+pub const GX_TEXTURE_MIN_FILTER_NEAR: gx_texture_min_filter = 0;
+pub const GX_TEXTURE_MIN_FILTER_NEAR_MIP: gx_texture_min_filter = 1;
+pub const GX_TEXTURE_MIN_FILTER_NEAR_MIP_LINEAR: gx_texture_min_filter = 2;
+pub const GX_TEXTURE_MIN_FILTER_LINEAR: gx_texture_min_filter = 4;
+pub const GX_TEXTURE_MIN_FILTER_LINEAR_MIP_NEAR: gx_texture_min_filter = 5;
+pub const GX_TEXTURE_MIN_FILTER_LINEAR_MIP_LINEAR: gx_texture_min_filter = 6;
+
+extern "C" {
+    pub fn gx_initialize(fifo: *const gx_fifo_t, video_profile: *const video_profile_t);
+    pub fn gx_initialize_state();
+    pub fn gx_initialize_video(video_profile: *const video_profile_t);
+    pub fn gx_fifo_initialize(fifo: *mut gx_fifo_t, fifo_buffer: *mut ::core::ffi::c_void, fifo_buffer_size: u32);
+    pub fn gx_fifo_set(fifo: *const gx_fifo_t);
+    pub fn gx_fifo_get(fifo: *mut gx_fifo_t);
+    pub fn gx_flush();
+    pub fn gx_efb_peak(x: u32, y: u32) -> u32;
+    pub fn gx_vtxdesc_clear();
+    pub fn gx_vtxdesc_set(desc: gx_vtxdesc_t, type_: gx_vtxattr_data_t);
+    pub fn gx_vtxfmtattr_clear(attribute_index: u8);
+    pub fn gx_vtxfmtattr_set(
+        attribute_index: u8,
+        attribute: gx_vtxdesc_t,
+        component: gx_vtxattr_component_t,
+        fmt: gx_vtxattr_component_format_t,
+        fraction: u8,
+    );
+    pub fn gx_begin(primitive: gx_primitive_t, attribute: u8, count: u16);
+    pub fn gx_draw_done();
+    pub fn gx_set_render_thread(task: TaskHandle_t);
+    pub fn gx_set_clear_color(r: u8, g: u8, b: u8, a: u8);
+    pub fn gx_set_clear_z(z: u32);
+    pub fn gx_set_copy_y_scale(y_scale: f32);
+    pub fn gx_set_scissor_rectangle(x: u32, y: u32, width: u32, height: u32);
+    pub fn gx_set_scissor_offset(x: i32, y: i32);
+    pub fn gx_set_copy_window(x: u32, y: u32, width: u32, height: u32, xfb_width: u32);
+    pub fn gx_set_clamp_mode(mode: gx_clamp_mode_t);
+    pub fn gx_set_gamma(gamma: gx_gamma_t);
+    pub fn gx_set_line_mode(line_mode: gx_line_mode_t);
+    pub fn gx_set_copy_filter(pattern: *const [u8; 2usize], filter: *const u8);
+    pub fn gx_set_z_mode(enable_compare: bool, compare: gx_compare_t, enable_update: bool);
+    pub fn gx_set_color_update(update_color: bool, update_alpha: bool);
+    pub fn gx_enable_z_precheck(enable: bool);
+    pub fn gx_set_pixel_format(pixels_format: gx_pixel_format_t, z_format: gx_z_format_t);
+    pub fn gx_copy_framebuffer(framebuffer: *mut framebuffer_t, clear: bool);
+    pub fn gx_initialize_texture(
+        texture: *mut gx_texture_t,
+        data: *const ::core::ffi::c_void,
+        format: gx_texture_format_t,
+        width: ::core::ffi::c_int,
+        height: ::core::ffi::c_int,
+        s_wrap: gx_texture_wrap_t,
+        t_wrap: gx_texture_wrap_t,
+        mipmap: bool,
+    );
+    pub fn gx_flash_texture(map: gx_texture_map_t, texture: *const gx_texture_t);
+}
+
+// ---------------------------------------------------------------------------
+// powerblocks/core/bluetooth
+// ---------------------------------------------------------------------------
+
+pub const BLERROR_RUNTIME: i32 = -1;
+pub const BLERROR_IOS_EXCEPTION: i32 = -2;
+pub const BLERROR_FREERTOS: i32 = -3;
+pub const BLERROR_ARGUMENT: i32 = -4;
+pub const BLERROR_HCI_REQUEST_ERR: i32 = -5;
+pub const BLERROR_HCI_CONNECT_FAILED: i32 = -6;
+pub const BLERROR_OUT_OF_MEMORY: i32 = -7;
+pub const BLERROR_TIMEOUT: i32 = -8;
+pub const BLERROR_L2CAP_SIGNAL_FAILED: i32 = -9;
+pub const BLERROR_L2CAP_ALREADY_OPEN: i32 = -10;
+pub const BLERROR_DRIVER_INITIALIZE_FAIL: i32 = -13;
+pub const BLERROR_NO_DRIVER_FOUND: i32 = -14;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hci_discovered_device_info_t {
+    pub address: [u8; 6usize],
+    pub link_type: u8,
+    pub page_scan_repetition_mode: u8,
+    pub class_of_device: u32,
+    pub clock_offset: u16,
+    pub connection_request: bool,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hci_buffer_sizes_t {
+    pub acl_data_packet_length: u16,
+    pub synchronous_data_packet_length: u8,
+    pub total_num_acl_data_packets: u16,
+    pub total_num_synchronous_data_packets: u16,
+}
+
+pub type hci_acl_packet_boundary_flag_t = ::core::ffi::c_uint;
+pub const HCI_ACL_PACKET_BOUNDARY_FLAG_FIRST_NON_AUTOMATICALLY_FLUSHABLE_PACKET: hci_acl_packet_boundary_flag_t = 0;
+pub const HCI_ACL_PACKET_BOUNDARY_FLAG_CONTINUING_FRAGMENT: hci_acl_packet_boundary_flag_t = 1;
+pub const HCI_ACL_PACKET_BOUNDARY_FLAG_FIRST_AUTOMATICALLY_FLUSHABLE_PACKET: hci_acl_packet_boundary_flag_t = 2;
+
+pub type hci_acl_packet_broadcast_flag_t = ::core::ffi::c_uint;
+pub const HCI_ACL_PACKET_BROADCAST_FLAG_PTP: hci_acl_packet_broadcast_flag_t = 0;
+pub const HCI_ACL_PACKET_BROADCAST_FLAG_ACTIVE_DEVICE: hci_acl_packet_broadcast_flag_t = 1;
+pub const HCI_ACL_PACKET_BROADCAST_FLAG_PARKED_DEVICE: hci_acl_packet_broadcast_flag_t = 2;
+
+pub type hci_reject_reason_t = ::core::ffi::c_uint;
+pub const HCI_REJECT_REASON_LIMITED_RESOURCES: hci_reject_reason_t = 13;
+pub const HCI_REJECT_REASON_SECURITY_REASONS: hci_reject_reason_t = 14;
+pub const HCI_REJECT_REASON_UNACCEPTABLE_ADDRESS: hci_reject_reason_t = 15;
+
+pub const HCI_INQUIRY_MODE_GENERAL_ACCESS: u32 = 10390323;
+pub const HCI_MAX_NAME_REQUEST_LENGTH: u32 = 254;
+pub const HCI_MAX_ACL_DATA_LENGTH: u32 = 512;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct hci_acl_packet_t {
+    pub handle: u16,
+    pub size: u16,
+    pub data: [u8; 512usize],
+}
+
+pub type hci_discovered_device_handler = ::core::option::Option<
+    unsafe extern "C" fn(user_data: *mut ::core::ffi::c_void, device: *const hci_discovered_device_info_t),
+>;
+pub type hci_discovery_complete_handler =
+    ::core::option::Option<unsafe extern "C" fn(user_data: *mut ::core::ffi::c_void, error: u8)>;
+pub type hci_connection_request_handler_t = ::core::option::Option<
+    unsafe extern "C" fn(user_data: *mut ::core::ffi::c_void, device: *const hci_discovered_device_info_t),
+>;
+pub type hci_disconnection_complete_handler_t =
+    ::core::option::Option<unsafe extern "C" fn(user_data: *mut ::core::ffi::c_void, handle: u16, reason: u8)>;
+
+extern "C" {
+    pub static mut hci_buffer_sizes: hci_buffer_sizes_t;
+    pub static mut hcl_acl_packet_out_lock: SemaphoreHandle_t;
+    pub static mut hci_acl_packet_out: hci_acl_packet_t;
+    pub static mut hci_acl_packet_in_0: hci_acl_packet_t;
+    pub static mut hci_acl_packet_in_1: hci_acl_packet_t;
+
+    pub fn hci_initialize(device: *const ::core::ffi::c_char) -> ::core::ffi::c_int;
+    pub fn hci_close();
+    pub fn hci_reset() -> ::core::ffi::c_int;
+    pub fn hci_set_connection_request_handler(
+        handler: hci_connection_request_handler_t,
+        user_data: *mut ::core::ffi::c_void,
+    );
+    pub fn hci_set_disconnection_complete_handler(
+        handler: hci_disconnection_complete_handler_t,
+        user_data: *mut ::core::ffi::c_void,
+    );
+    pub fn hci_write_scan_enable(enable_inquiry_scan: bool, enable_page_scan: bool) -> ::core::ffi::c_int;
+    pub fn hci_reject_connection(
+        info: *const hci_discovered_device_info_t,
+        reason: hci_reject_reason_t,
+    ) -> ::core::ffi::c_int;
+    pub fn hci_accept_connection(
+        info: *const hci_discovered_device_info_t,
+        role_swich: bool,
+        handle: *mut u16,
+    ) -> ::core::ffi::c_int;
+    pub fn hci_begin_discovery(
+        lap: u32,
+        length: u8,
+        responses: u8,
+        on_discovered: hci_discovered_device_handler,
+        on_complete: hci_discovery_complete_handler,
+        user_data: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
+    pub fn hci_cancel_discovery() -> ::core::ffi::c_int;
+    pub fn hci_get_remote_name(device: *const hci_discovered_device_info_t, name: *mut u8) -> ::core::ffi::c_int;
+    pub fn hci_create_connection(device: *const hci_discovered_device_info_t, handle: *mut u16)
+        -> ::core::ffi::c_int;
+    pub fn hci_disconnect(handle: u16) -> ::core::ffi::c_int;
+    pub fn hci_send_acl(
+        handle: u16,
+        pb: hci_acl_packet_boundary_flag_t,
+        bc: hci_acl_packet_broadcast_flag_t,
+        length: u16,
+    ) -> ::core::ffi::c_int;
+    pub fn hci_receive_acl_async(
+        acl_buffer: *mut hci_acl_packet_t,
+        ipc_buffer: *mut u8,
+        handler: ipc_async_handler_t,
+        params: *mut ::core::ffi::c_void,
+    ) -> ::core::ffi::c_int;
+    pub fn hci_decode_received_acl(
+        handle: *mut u16,
+        pb: *mut hci_acl_packet_boundary_flag_t,
+        bc: *mut hci_acl_packet_broadcast_flag_t,
+        length: *mut u16,
+        acl_buffer: *const hci_acl_packet_t,
+    );
+}
+
+pub const L2CAP_SIGNAL_CHANNEL_BUFFER_SIZE: u32 = 256;
+pub const L2CAP_CHANNEL_SIGNALS: u32 = 1;
+pub const L2CAP_DEFAULT_MTU: u32 = 185;
+pub const L2CAP_DEFAULT_FLUSH_TIMEOUT: u32 = 65535;
+pub const L2CAP_CHANNEL_STATUS_OPEN: u32 = 1;
+pub const L2CAP_CHANNEL_STATUS_LOCAL_CONFIGURED: u32 = 2;
+pub const L2CAP_CHANNEL_STATUS_REMOTE_CONFIGURED: u32 = 4;
+pub const L2CAP_CHANNEL_STATUS_ERROR: u32 = 8;
+
+pub type l2cap_channel_event_t = ::core::option::Option<
+    unsafe extern "C" fn(channel: *mut ::core::ffi::c_void, user: *mut ::core::ffi::c_void),
+>;
+pub type l2cap_disconnect_handler_t =
+    ::core::option::Option<unsafe extern "C" fn(user: *mut ::core::ffi::c_void, reason: u8)>;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct l2cap_signal_t {
+    pub code: u8,
+    pub id: u8,
+    pub length: u16,
+    pub data: [u8; 16usize],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct l2cap_channel_t {
+    pub device: *mut ::core::ffi::c_void,
+    pub sid: u16,
+    pub did: u16,
+    pub protocol_id: u16,
+    pub status_flags: u16,
+    pub status_error: u16,
+    pub on_complete_packet: SemaphoreHandle_t,
+    pub on_status_change: SemaphoreHandle_t,
+    pub status_lock: SemaphoreHandle_t,
+    pub buffer: *mut u8,
+    pub buffer_length: ::core::ffi::c_int,
+    pub fifo_write_head: ::core::ffi::c_int,
+    pub fifo_write_head_packet: ::core::ffi::c_int,
+    pub fifo_read_head: ::core::ffi::c_int,
+    pub event_packet_available: l2cap_channel_t__bindgen_ty_1,
+    pub semaphore_data: [StaticSemaphore_t; 3usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct l2cap_channel_t__bindgen_ty_1 {
+    pub event: l2cap_channel_event_t,
+    pub data: *mut ::core::ffi::c_void,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct l2cap_device_t {
+    pub lock: SemaphoreHandle_t,
+    pub handle: u16,
+    pub open_channels: l2cap_device_t__bindgen_ty_1,
+    pub reading_channel: *mut l2cap_channel_t,
+    pub reading_remaining: u16,
+    pub mac_address: [u8; 6usize],
+    pub disconnect_handler: l2cap_disconnect_handler_t,
+    pub disconnect_handler_data: *mut ::core::ffi::c_void,
+    pub semaphore_data: StaticSemaphore_t,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct l2cap_device_t__bindgen_ty_1 {
+    pub array: *mut l2cap_channel_t,
+    pub length: usize,
+}
+
+extern "C" {
+    pub fn l2cap_initialize() -> ::core::ffi::c_int;
+    pub fn l2cap_signal_close();
+    pub fn l2cap_close();
+    pub fn l2cap_initialize_channel(
+        device: *mut l2cap_device_t,
+        channel: *mut l2cap_channel_t,
+        sid: u16,
+        did: u16,
+        protocol_id: u16,
+        buffer: *mut u8,
+        buffer_size: ::core::ffi::c_int,
+    );
+    pub fn l2cap_open_device(
+        device_handle: *mut l2cap_device_t,
+        hci_device_handle: u16,
+        mac_address: *const u8,
+        channels: *mut l2cap_channel_t,
+        channel_count: usize,
+    ) -> ::core::ffi::c_int;
+    pub fn l2cap_close_device(device_handle: *mut l2cap_device_t);
+    pub fn l2cap_set_disconnect_handler(
+        device_handle: *mut l2cap_device_t,
+        handler: l2cap_disconnect_handler_t,
+        user_data: *mut ::core::ffi::c_void,
+    );
+    pub fn l2cap_open_channel(device_handle: *mut l2cap_device_t, channel: *mut l2cap_channel_t)
+        -> ::core::ffi::c_int;
+    pub fn l2cap_send_channel(
+        channel: *mut l2cap_channel_t,
+        data: *const ::core::ffi::c_void,
+        size: u16,
+    ) -> ::core::ffi::c_int;
+    pub fn l2cap_receive_channel(
+        channel: *mut l2cap_channel_t,
+        data: *mut ::core::ffi::c_void,
+        size: u16,
+    ) -> ::core::ffi::c_int;
+    pub fn l2cap_set_channel_receive_event(
+        channel: *mut l2cap_channel_t,
+        event: l2cap_channel_event_t,
+        param: *mut ::core::ffi::c_void,
+    );
+    pub fn l2cap_wait_channel_status(
+        channel: *mut l2cap_channel_t,
+        flags: u16,
+        error_code: *mut u16,
+    ) -> ::core::ffi::c_int;
+}
+
+pub const BLUETOOTH_DRIVER_ID_INVALID: u32 = 0;
+pub const BLUETOOTH_DRIVER_ID_WIIMOTE: u32 = 1;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bluetooth_driver_t {
+    pub driver_id: u16,
+    pub filter_device: ::core::option::Option<
+        unsafe extern "C" fn(device: *const hci_discovered_device_info_t, device_name: *const ::core::ffi::c_char) -> bool,
+    >,
+    pub filter_paired_device:
+        ::core::option::Option<unsafe extern "C" fn(device: *const hci_discovered_device_info_t) -> bool>,
+    pub initialize_new_device: ::core::option::Option<
+        unsafe extern "C" fn(device: *const hci_discovered_device_info_t) -> *mut ::core::ffi::c_void,
+    >,
+    pub initialize_paired_device: ::core::option::Option<
+        unsafe extern "C" fn(device: *const hci_discovered_device_info_t) -> *mut ::core::ffi::c_void,
+    >,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bluetooth_driver_instance_t {
+    pub driver_id: u16,
+    pub instance: *mut ::core::ffi::c_void,
+}
+
+extern "C" {
+    pub fn bltools_initialize() -> ::core::ffi::c_int;
+    pub fn bltools_register_driver(driver: bluetooth_driver_t);
+    pub fn bltoots_find_driver_by_id(driver_id: u16) -> *mut bluetooth_driver_t;
+    pub fn bltools_find_compatable_driver(
+        device: *const hci_discovered_device_info_t,
+        device_name: *const ::core::ffi::c_char,
+    ) -> *mut bluetooth_driver_t;
+    pub fn bltools_load_driver(
+        driver: *const bluetooth_driver_t,
+        device: *const hci_discovered_device_info_t,
+    ) -> ::core::ffi::c_int;
+    pub fn bltools_load_compatable_driver(
+        device: *const hci_discovered_device_info_t,
+        device_name: *const ::core::ffi::c_char,
+    ) -> ::core::ffi::c_int;
+    pub fn bltools_begin_discovery(lap: u32, duration: TickType_t, responses: u8) -> ::core::ffi::c_int;
+}
+
+// ---------------------------------------------------------------------------
+// powerblocks/core/utils
+// ---------------------------------------------------------------------------
+
+pub type crash_handler_t = ::core::option::Option<
+    unsafe extern "C" fn(cause: *const ::core::ffi::c_char, ctx: *mut exception_context_t),
+>;
+
+extern "C" {
+    pub static mut console_cursor_position: vec2i;
+    pub static mut console_foreground_color: u32;
+    pub static mut console_background_color: u32;
+    pub static mut console_font: *const framebuffer_font_t;
+    pub static fonts_ibm_iso_8x16: framebuffer_font_t;
+
+    pub fn console_initialize(framebuffer: *mut framebuffer_t, font: *const framebuffer_font_t);
+    pub fn console_set_cursor(cursor_position: vec2i);
+    pub fn console_set_text_color(foreground: u32, background: u32);
+    pub fn console_put(string: *const ::core::ffi::c_char);
+
+    pub fn crash_handler_bug_check(cause: *const ::core::ffi::c_char, ctx: *mut exception_context_t);
+    pub fn crash_handler_set(handler: crash_handler_t);
+
+    pub fn log_initialize();
+    pub fn log_message(
+        level: *const ::core::ffi::c_char,
+        tag: *const ::core::ffi::c_char,
+        fmt: *const ::core::ffi::c_char,
+        ...
+    );
+}
+
+// ---------------------------------------------------------------------------
+// powerblocks/input/wiimote
+// ---------------------------------------------------------------------------
+
+pub type wiimote_extension_t = ::core::ffi::c_uint;
+pub const WIIMOTE_EXTENSION_NONE: wiimote_extension_t = 0;
+pub const WIIMOTE_EXTENSION_NUNCHUK: wiimote_extension_t = 1;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER: wiimote_extension_t = 2;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_PRO: wiimote_extension_t = 3;
+pub const WIIMOTE_EXTENSION_DRAWSOME_GRAPHICS_TABLET: wiimote_extension_t = 4;
+pub const WIIMOTE_EXTENSION_GH_GUITAR: wiimote_extension_t = 5;
+pub const WIIMOTE_EXTENSION_GH_DRUMS: wiimote_extension_t = 6;
+pub const WIIMOTE_EXTENSION_DJ_HERO_TURNTABLE: wiimote_extension_t = 7;
+pub const WIIMOTE_EXTENSION_TAIKO_DRUMS: wiimote_extension_t = 8;
+pub const WIIMOTE_EXTENSION_UDRAW_GAME_TABLET: wiimote_extension_t = 9;
+pub const WIIMOTE_EXTENSION_SHINKANSEN_CONTROLLER: wiimote_extension_t = 10;
+pub const WIIMOTE_EXTENSION_BALANCE_BOARD: wiimote_extension_t = 11;
+
+pub const WIIMOTE_EXTENSION_NUNCHUCK_BUTTONS_Z: u32 = 1;
+pub const WIIMOTE_EXTENSION_NUNCHUCK_BUTTONS_C: u32 = 2;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_DPAD_UP: u32 = 1;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_DPAD_LEFT: u32 = 2;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_Z_RIGHT: u32 = 4;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_X: u32 = 8;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_A: u32 = 16;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_Y: u32 = 32;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_B: u32 = 64;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_Z_LEFT: u32 = 128;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_RIGHT_TRIGGER: u32 = 512;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_PLUS: u32 = 1024;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_HOME: u32 = 2048;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_MINUS: u32 = 4096;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_LEFT_TRIGGER: u32 = 8192;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_DPAD_DOWN: u32 = 16384;
+pub const WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_DPAD_RIGHT: u32 = 32768;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_buttons {
+    pub state: u16,
+    pub held: u16,
+    pub down: u16,
+    pub up: u16,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct wiimote_extension_data_t {
+    pub type_: wiimote_extension_t,
+    pub __bindgen_anon_1: wiimote_extension_data_t__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union wiimote_extension_data_t__bindgen_ty_1 {
+    pub nunchuck: wiimote_extension_data_t__bindgen_ty_1__bindgen_ty_1,
+    pub classic_controller: wiimote_extension_data_t__bindgen_ty_1__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_extension_data_t__bindgen_ty_1__bindgen_ty_1 {
+    pub buttons: wiimote_buttons,
+    pub stick: vec2,
+    pub accelerometer: vec3,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_extension_data_t__bindgen_ty_1__bindgen_ty_2 {
+    pub buttons: wiimote_buttons,
+    pub left_stick: vec2,
+    pub right_stick: vec2,
+    pub triggers: vec2,
+}
+
+pub type wiimote_extension_handle_phrase = ::core::option::Option<
+    unsafe extern "C" fn(out: *mut wiimote_extension_data_t, data: *const u8, len: usize) -> ::core::ffi::c_int,
+>;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_extension_mapper_t {
+    pub type_: wiimote_extension_t,
+    pub phrase_data: wiimote_extension_handle_phrase,
+}
+
+extern "C" {
+    pub fn wiimote_extension_phrase_data(
+        out: *mut wiimote_extension_data_t,
+        mapper: *const wiimote_extension_mapper_t,
+        data: *const u8,
+        size: usize,
+    );
+    pub fn wiimote_extension_get_type(byte_code: *const u8) -> wiimote_extension_t;
+    pub fn wiimote_extension_get_name(type_: wiimote_extension_t) -> *const ::core::ffi::c_char;
+    pub fn wiimote_extension_get_mapper(type_: wiimote_extension_t) -> *const wiimote_extension_mapper_t;
+    pub fn wiimote_set_button_helper(buttons: *mut wiimote_buttons, next_state: u16);
+}
+
+pub const WIIMOTE_PRESENT_BUTTONS: u32 = 1;
+pub const WIIMOTE_PRESENT_ACCELEROMETER: u32 = 2;
+pub const WIIMOTE_PRESENT_IR: u32 = 4;
+pub const WIIMOTE_PRESENT_IR_EXTENDED: u32 = 8;
+pub const WIIMOTE_PRESENT_IR_FULL: u32 = 16;
+pub const WIIMOTE_PRESENT_EXTENSION: u32 = 32;
+pub const WIIMOTE_PRESENT_INTERLACED: u32 = 64;
+pub const WIIMOTE_BUTTONS_DPAD_LEFT: u32 = 1;
+pub const WIIMOTE_BUTTONS_DPAD_RIGHT: u32 = 2;
+pub const WIIMOTE_BUTTONS_DPAD_DOWN: u32 = 4;
+pub const WIIMOTE_BUTTONS_DPAD_UP: u32 = 8;
+pub const WIIMOTE_BUTTONS_PLUS: u32 = 16;
+pub const WIIMOTE_BUTTONS_TWO: u32 = 256;
+pub const WIIMOTE_BUTTONS_ONE: u32 = 512;
+pub const WIIMOTE_BUTTONS_B: u32 = 1024;
+pub const WIIMOTE_BUTTONS_A: u32 = 2048;
+pub const WIIMOTE_BUTTONS_MINUS: u32 = 4096;
+pub const WIIMOTE_BUTTONS_HOME: u32 = 32768;
+pub const WIIMOTE_MAX_REMOTES: u32 = 4;
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct wiimote_t {
+    pub driver: *mut ::core::ffi::c_void,
+    pub present: u32,
+    pub buttons: wiimote_buttons,
+    pub accelerometer: wiimote_t__bindgen_ty_1,
+    pub ir_tracking: [wiimote_t__bindgen_ty_2; 4usize],
+    pub cursor: wiimote_t__bindgen_ty_3,
+    pub extensions: wiimote_extension_data_t,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_t__bindgen_ty_1 {
+    pub rectangular: vec3,
+    pub spherical: vec3,
+    pub orientation: vec3,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_t__bindgen_ty_2 {
+    pub visible: bool,
+    pub side: bool,
+    pub position: vec2i,
+    pub size: ::core::ffi::c_int,
+    pub bbox_top_left: vec2i,
+    pub bbox_bottom_right: vec2i,
+    pub intensity: u8,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_t__bindgen_ty_3 {
+    pub pos: vec2i,
+    pub distance: f32,
+    pub z: f32,
+    pub yaw: f32,
+    pub known_dots: ::core::ffi::c_int,
+}
+
+extern "C" {
+    pub static mut WIIMOTES: [wiimote_t; 4usize];
+
+    pub fn wiimotes_initialize();
+    pub fn wiimote_poll();
+    pub fn wiimote_find_empty_slot() -> ::core::ffi::c_int;
+    pub fn wiimote_remove_slot(driver: *mut ::core::ffi::c_void) -> ::core::ffi::c_int;
+    pub fn wiimote_set_reporting(wiimote: *mut wiimote_t, present: ::core::ffi::c_int) -> ::core::ffi::c_int;
+}
+
+pub const WIIMOTE_REPORT_LEDS: u32 = 17;
+pub const WIIMOTE_REPORT_REPORT_MODE: u32 = 18;
+pub const WIIMOTE_REPORT_ENABLE_CAMERA_CLOCK: u32 = 19;
+pub const WIIMOTE_REPORT_STATUS: u32 = 21;
+pub const WIIMOTE_REPORT_WRITE_MEMORY: u32 = 22;
+pub const WIIMOTE_REPORT_READ_MEMORY: u32 = 23;
+pub const WIIMOTE_REPORT_ENABLE_CAMERA: u32 = 26;
+pub const WIIMOTE_REPORT_STATUS_INFO: u32 = 32;
+pub const WIIMOTE_REPORT_READ_MEMORY_DATA: u32 = 33;
+pub const WIIMOTE_REPORT_ACKNOWLEDGE_OUTPUT: u32 = 34;
+pub const WIIMOTE_REPORT_BUTTONS: u32 = 48;
+pub const WIIMOTE_REPORT_BUTTONS_ACCL: u32 = 49;
+pub const WIIMOTE_REPORT_BUTTONS_EXT8: u32 = 50;
+pub const WIIMOTE_REPORT_BUTTONS_ACCL_IR12: u32 = 51;
+pub const WIIMOTE_REPORT_BUTTONS_EXT19: u32 = 52;
+pub const WIIMOTE_REPORT_BUTTONS_ACCL_EXT16: u32 = 53;
+pub const WIIMOTE_REPORT_BUTTONS_IR10_EXT9: u32 = 54;
+pub const WIIMOTE_REPORT_BUTTONS_ACCEL_IR10_EXT6: u32 = 55;
+pub const WIIMOTE_REPORT_EXT21: u32 = 61;
+pub const WIIMOTE_REPORT_INTERLEAVED_A: u32 = 62;
+pub const WIIMOTE_REPORT_INTERLEAVED_B: u32 = 63;
+
+pub type wiimote_flags_t = ::core::ffi::c_uint;
+pub const WIIMOTE_FLAGS_BATTERY_NEAR_EMPTY: wiimote_flags_t = 1;
+pub const WIIMOTE_FLAGS_EXTENSION_CONNECTED: wiimote_flags_t = 2;
+pub const WIIMOTE_FLAGS_SPEAKER_ENABLED: wiimote_flags_t = 4;
+pub const WIIMOTE_FLAGS_IR_CAMERA_ENABLED: wiimote_flags_t = 8;
+pub const WIIMOTE_FLAGS_LED1: wiimote_flags_t = 16;
+pub const WIIMOTE_FLAGS_LED2: wiimote_flags_t = 32;
+pub const WIIMOTE_FLAGS_LED3: wiimote_flags_t = 64;
+pub const WIIMOTE_FLAGS_LEF4: wiimote_flags_t = 128;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_raw_t {
+    pub core_state: wiimote_raw_t__bindgen_ty_1,
+    pub calibration: wiimote_raw_t__bindgen_ty_2,
+    pub ext_mapper: *const wiimote_extension_mapper_t,
+    pub report_type: u8,
+    pub data_report: [u8; 42usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_raw_t__bindgen_ty_1 {
+    pub core_buttons: u16,
+    pub flags: wiimote_flags_t,
+    pub battery_level: u8,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_raw_t__bindgen_ty_2 {
+    pub accel_zero: [u16; 3usize],
+    pub accel_one: [u16; 3usize],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct wiimote_hid_t {
+    pub slot: ::core::ffi::c_int,
+    pub device: l2cap_device_t,
+    pub channels: [l2cap_channel_t; 3usize],
+    pub wiimote_signal_channel_buffer: [u8; 256usize],
+    pub wiimote_control_channel_buffer: [u8; 16usize],
+    pub wiimote_interrupt_channel_buffer: [u8; 256usize],
+    pub set_report_mode: u8,
+    pub set_ir_mode: u8,
+    pub internal_state_lock: SemaphoreHandle_t,
+    pub internal_state: wiimote_raw_t,
+    pub semaphore_data: [StaticSemaphore_t; 1usize],
+}
+
+extern "C" {
+    pub fn wiimote_hid_close(wiimote: *mut wiimote_hid_t);
+    pub fn wiimote_hid_set_report(wiimote: *mut wiimote_hid_t, report_type: u8, update_ir_mode: bool)
+        -> ::core::ffi::c_int;
+    pub fn wiimote_hid_driver_filter(
+        device: *const hci_discovered_device_info_t,
+        device_name: *const ::core::ffi::c_char,
+    ) -> bool;
+    pub fn wiimote_hid_driver_filter_paired(device: *const hci_discovered_device_info_t) -> bool;
+    pub fn wiimote_hid_driver_initialize_new(device: *const hci_discovered_device_info_t) -> *mut ::core::ffi::c_void;
+    pub fn wiimote_hid_driver_initialize_paired(
+        device: *const hci_discovered_device_info_t,
+    ) -> *mut ::core::ffi::c_void;
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_sys_config_t {
+    pub sensor_bar_position: u8,
+    pub motor_enabled: u8,
+    pub ir_sensitivity: u32,
+    pub speaker_volume: u8,
+    pub guest_wiimotes: wiimote_sys_config_t__bindgen_ty_1,
+    pub wiimotes: wiimote_sys_config_t__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_sys_config_t__bindgen_ty_1 {
+    pub count: u8,
+    pub entrys: [wiimote_sys_config_t__bindgen_ty_1__bindgen_ty_1; 6usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_sys_config_t__bindgen_ty_1__bindgen_ty_1 {
+    pub mac_address: [u8; 6usize],
+    pub name: [::core::ffi::c_char; 64usize],
+    pub link_key: [u8; 16usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_sys_config_t__bindgen_ty_2 {
+    pub count: u8,
+    pub registered_entrys: [wiimote_sys_config_t__bindgen_ty_2__bindgen_ty_1; 10usize],
+    pub active_wiimotes: [wiimote_sys_config_t__bindgen_ty_2__bindgen_ty_2; 6usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_sys_config_t__bindgen_ty_2__bindgen_ty_1 {
+    pub mac_address: [u8; 6usize],
+    pub name: [::core::ffi::c_char; 64usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct wiimote_sys_config_t__bindgen_ty_2__bindgen_ty_2 {
+    pub mac_address: [u8; 6usize],
+    pub name: [::core::ffi::c_char; 64usize],
+}
+
+extern "C" {
+    pub static mut wiimote_sys_config: wiimote_sys_config_t;
+
+    pub fn wiimote_sys_phrase_settings();
+    pub fn wiimote_is_paired_guest(mac_address: *const u8) -> bool;
+    pub fn wiimote_is_paired_registered(mac_address: *const u8) -> bool;
+}
+
+// ---------------------------------------------------------------------------
+// FatFs
+// ---------------------------------------------------------------------------
+
+pub type UINT = ::core::ffi::c_uint;
+pub type BYTE = ::core::ffi::c_uchar;
+pub type WORD = u16;
+pub type DWORD = u32;
+pub type QWORD = u64;
+pub type WCHAR = WORD;
+pub type FSIZE_t = DWORD;
+pub type LBA_t = DWORD;
+pub type TCHAR = ::core::ffi::c_char;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FATFS {
+    pub fs_type: BYTE,
+    pub pdrv: BYTE,
+    pub ldrv: BYTE,
+    pub n_fats: BYTE,
+    pub wflag: BYTE,
+    pub fsi_flag: BYTE,
+    pub id: WORD,
+    pub n_rootdir: WORD,
+    pub csize: WORD,
+    pub lfnbuf: *mut WCHAR,
+    pub last_clst: DWORD,
+    pub free_clst: DWORD,
+    pub cdir: DWORD,
+    pub n_fatent: DWORD,
+    pub fsize: DWORD,
+    pub winsect: LBA_t,
+    pub volbase: LBA_t,
+    pub fatbase: LBA_t,
+    pub dirbase: LBA_t,
+    pub database: LBA_t,
+    pub win: [BYTE; 512usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FFOBJID {
+    pub fs: *mut FATFS,
+    pub id: WORD,
+    pub attr: BYTE,
+    pub stat: BYTE,
+    pub sclust: DWORD,
+    pub objsize: FSIZE_t,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FIL {
+    pub obj: FFOBJID,
+    pub flag: BYTE,
+    pub err: BYTE,
+    pub fptr: FSIZE_t,
+    pub clust: DWORD,
+    pub sect: LBA_t,
+    pub dir_sect: LBA_t,
+    pub dir_ptr: *mut BYTE,
+    pub buf: [BYTE; 512usize],
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DIR {
+    pub obj: FFOBJID,
+    pub dptr: DWORD,
+    pub clust: DWORD,
+    pub sect: LBA_t,
+    pub dir: *mut BYTE,
+    pub fn_: [BYTE; 12usize],
+    pub blk_ofs: DWORD,
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FILINFO {
+    pub fsize: FSIZE_t,
+    pub fdate: WORD,
+    pub ftime: WORD,
+    pub fattrib: BYTE,
+    pub altname: [TCHAR; 13usize],
+    pub fname: [TCHAR; 256usize],
+}
+
+pub type FRESULT = ::core::ffi::c_uint;
+pub const FR_OK: FRESULT = 0;
+pub const FR_DISK_ERR: FRESULT = 1;
+pub const FR_INT_ERR: FRESULT = 2;
+pub const FR_NOT_READY: FRESULT = 3;
+pub const FR_NO_FILE: FRESULT = 4;
+pub const FR_NO_PATH: FRESULT = 5;
+pub const FR_INVALID_NAME: FRESULT = 6;
+pub const FR_DENIED: FRESULT = 7;
+pub const FR_EXIST: FRESULT = 8;
+pub const FR_INVALID_OBJECT: FRESULT = 9;
+pub const FR_WRITE_PROTECTED: FRESULT = 10;
+pub const FR_INVALID_DRIVE: FRESULT = 11;
+pub const FR_NOT_ENABLED: FRESULT = 12;
+pub const FR_NO_FILESYSTEM: FRESULT = 13;
+pub const FR_MKFS_ABORTED: FRESULT = 14;
+pub const FR_TIMEOUT: FRESULT = 15;
+pub const FR_LOCKED: FRESULT = 16;
+pub const FR_NOT_ENOUGH_CORE: FRESULT = 17;
+pub const FR_TOO_MANY_OPEN_FILES: FRESULT = 18;
+pub const FR_INVALID_PARAMETER: FRESULT = 19;
+
+pub const FA_READ: u32 = 1;
+pub const FA_WRITE: u32 = 2;
+pub const FA_OPEN_EXISTING: u32 = 0;
+pub const FA_CREATE_NEW: u32 = 4;
+pub const FA_CREATE_ALWAYS: u32 = 8;
+pub const FA_OPEN_ALWAYS: u32 = 16;
+pub const FA_OPEN_APPEND: u32 = 48;
+pub const FS_FAT12: u32 = 1;
+pub const FS_FAT16: u32 = 2;
+pub const FS_FAT32: u32 = 3;
+pub const FS_EXFAT: u32 = 4;
+pub const AM_RDO: u32 = 1;
+pub const AM_HID: u32 = 2;
+pub const AM_SYS: u32 = 4;
+pub const AM_DIR: u32 = 16;
+pub const AM_ARC: u32 = 32;
+
+extern "C" {
+    pub fn f_open(fp: *mut FIL, path: *const TCHAR, mode: BYTE) -> FRESULT;
+    pub fn f_close(fp: *mut FIL) -> FRESULT;
+    pub fn f_read(fp: *mut FIL, buff: *mut ::core::ffi::c_void, btr: UINT, br: *mut UINT) -> FRESULT;
+    pub fn f_write(fp: *mut FIL, buff: *const ::core::ffi::c_void, btw: UINT, bw: *mut UINT) -> FRESULT;
+    pub fn f_lseek(fp: *mut FIL, ofs: FSIZE_t) -> FRESULT;
+    pub fn f_truncate(fp: *mut FIL) -> FRESULT;
+    pub fn f_sync(fp: *mut FIL) -> FRESULT;
+    pub fn f_opendir(dp: *mut DIR, path: *const TCHAR) -> FRESULT;
+    pub fn f_closedir(dp: *mut DIR) -> FRESULT;
+    pub fn f_readdir(dp: *mut DIR, fno: *mut FILINFO) -> FRESULT;
+    pub fn f_mkdir(path: *const TCHAR) -> FRESULT;
+    pub fn f_unlink(path: *const TCHAR) -> FRESULT;
+    pub fn f_rename(path_old: *const TCHAR, path_new: *const TCHAR) -> FRESULT;
+    pub fn f_stat(path: *const TCHAR, fno: *mut FILINFO) -> FRESULT;
+    pub fn f_chdir(path: *const TCHAR) -> FRESULT;
+    pub fn f_chdrive(path: *const TCHAR) -> FRESULT;
+    pub fn f_getcwd(buff: *mut TCHAR, len: UINT) -> FRESULT;
+    pub fn f_getfree(path: *const TCHAR, nclst: *mut DWORD, fatfs: *mut *mut FATFS) -> FRESULT;
+    pub fn f_mount(fs: *mut FATFS, path: *const TCHAR, opt: BYTE) -> FRESULT;
+    pub fn get_fattime() -> DWORD;
+}
+
+pub type DSTATUS = BYTE;
+
+pub type DRESULT = ::core::ffi::c_uint;
+pub const RES_OK: DRESULT = 0;
+pub const RES_ERROR: DRESULT = 1;
+pub const RES_WRPRT: DRESULT = 2;
+pub const RES_NOTRDY: DRESULT = 3;
+pub const RES_PARERR: DRESULT = 4;
+
+pub const STA_NOINIT: u32 = 1;
+pub const STA_NODISK: u32 = 2;
+pub const STA_PROTECT: u32 = 4;
+pub const CTRL_SYNC: u32 = 0;
+pub const GET_SECTOR_COUNT: u32 = 1;
+pub const GET_SECTOR_SIZE: u32 = 2;
+pub const GET_BLOCK_SIZE: u32 = 3;
+pub const CTRL_TRIM: u32 = 4;
+pub const DISK_DEV_SD: u32 = 0;
+pub const DISK_DEV_USB: u32 = 1;
+
+extern "C" {
+    pub fn disk_initialize(pdrv: BYTE) -> DSTATUS;
+    pub fn disk_status(pdrv: BYTE) -> DSTATUS;
+    pub fn disk_read(pdrv: BYTE, buff: *mut BYTE, sector: LBA_t, count: UINT) -> DRESULT;
+    pub fn disk_write(pdrv: BYTE, buff: *const BYTE, sector: LBA_t, count: UINT) -> DRESULT;
+    pub fn disk_ioctl(pdrv: BYTE, cmd: BYTE, buff: *mut ::core::ffi::c_void) -> DRESULT;
+
+    pub fn sd_initialize() -> ::core::ffi::c_int;
+    pub fn sd_close();
+    pub fn sd_disk_status() -> DSTATUS;
+    pub fn sd_disk_initialize() -> DSTATUS;
+    pub fn sd_disk_read(buff: *mut BYTE, sector: LBA_t, count: UINT) -> DRESULT;
+    pub fn sd_disk_write(buff: *const BYTE, sector: LBA_t, count: UINT) -> DRESULT;
+    pub fn sd_disk_ioctl(cmd: BYTE, buff: *mut ::core::ffi::c_void) -> DRESULT;
+
+    pub fn usb_storage_initialize() -> ::core::ffi::c_int;
+    pub fn usb_storage_close();
+    pub fn usb_storage_disk_status() -> DSTATUS;
+    pub fn usb_storage_disk_initialize() -> DSTATUS;
+    pub fn usb_storage_disk_read(buff: *mut BYTE, sector: LBA_t, count: UINT) -> DRESULT;
+    pub fn usb_storage_disk_write(buff: *const BYTE, sector: LBA_t, count: UINT) -> DRESULT;
+    pub fn usb_storage_disk_ioctl(cmd: BYTE, buff: *mut ::core::ffi::c_void) -> DRESULT;
+
+    pub fn vfs_fatfs_mount(name: *const ::core::ffi::c_char, drive: u8) -> ::core::ffi::c_int;
+    pub fn vfs_fatfs_claim_drive(drive: u8) -> ::core::ffi::c_int;
+    pub fn vfs_fatfs_release_drive(drive: u8);
+}
+
+// ---------------------------------------------------------------------------
+// powerblocks/debugger
+// ---------------------------------------------------------------------------
+
+extern "C" {
+    pub fn debugger_install_crash_handler();
+}
@@ -0,0 +1,444 @@
+//! FreeRTOS constants and function-like macros.
+//!
+//! Most of the kernel API in `task.h`, `queue.h`, `semphr.h` and `timers.h`
+//! is macros over a handful of generic functions, and the constants they use
+//! are cast expressions bindgen skips. These are written out by hand to match
+//! FreeRTOS V11.2.0 with the SDK's `FreeRTOSConfig.h`.
+
+use core::ffi::c_void;
+use core::ptr;
+
+use crate::*;
+
+pub const pdFALSE: BaseType_t = 0;
+pub const pdTRUE: BaseType_t = 1;
+pub const pdPASS: BaseType_t = pdTRUE;
+pub const pdFAIL: BaseType_t = pdFALSE;
+pub const errQUEUE_EMPTY: BaseType_t = 0;
+pub const errQUEUE_FULL: BaseType_t = 0;
+
+pub const portMAX_DELAY: TickType_t = TickType_t::MAX;
+pub const portTICK_PERIOD_MS: TickType_t = 1000 / configTICK_RATE_HZ as TickType_t;
+
+pub const tskIDLE_PRIORITY: UBaseType_t = 0;
+
+pub const taskSCHEDULER_SUSPENDED: BaseType_t = 0;
+pub const taskSCHEDULER_NOT_STARTED: BaseType_t = 1;
+pub const taskSCHEDULER_RUNNING: BaseType_t = 2;
+
+pub const queueSEND_TO_BACK: BaseType_t = 0;
+pub const queueSEND_TO_FRONT: BaseType_t = 1;
+pub const queueOVERWRITE: BaseType_t = 2;
+
+pub const queueQUEUE_TYPE_BASE: u8 = 0;
+pub const queueQUEUE_TYPE_MUTEX: u8 = 1;
+pub const queueQUEUE_TYPE_COUNTING_SEMAPHORE: u8 = 2;
+pub const queueQUEUE_TYPE_BINARY_SEMAPHORE: u8 = 3;
+pub const queueQUEUE_TYPE_RECURSIVE_MUTEX: u8 = 4;
+pub const queueQUEUE_TYPE_SET: u8 = 5;
+
+pub const semBINARY_SEMAPHORE_QUEUE_LENGTH: u8 = 1;
+pub const semSEMAPHORE_QUEUE_ITEM_LENGTH: u8 = 0;
+pub const semGIVE_BLOCK_TIME: TickType_t = 0;
+
+pub const tmrCOMMAND_EXECUTE_CALLBACK_FROM_ISR: BaseType_t = -2;
+pub const tmrCOMMAND_EXECUTE_CALLBACK: BaseType_t = -1;
+pub const tmrCOMMAND_START_DONT_TRACE: BaseType_t = 0;
+pub const tmrCOMMAND_START: BaseType_t = 1;
+pub const tmrCOMMAND_RESET: BaseType_t = 2;
+pub const tmrCOMMAND_STOP: BaseType_t = 3;
+pub const tmrCOMMAND_CHANGE_PERIOD: BaseType_t = 4;
+pub const tmrCOMMAND_DELETE: BaseType_t = 5;
+pub const tmrFIRST_FROM_ISR_COMMAND: BaseType_t = 6;
+pub const tmrCOMMAND_START_FROM_ISR: BaseType_t = 6;
+pub const tmrCOMMAND_RESET_FROM_ISR: BaseType_t = 7;
+pub const tmrCOMMAND_STOP_FROM_ISR: BaseType_t = 8;
+pub const tmrCOMMAND_CHANGE_PERIOD_FROM_ISR: BaseType_t = 9;
+
+pub const sbTYPE_STREAM_BUFFER: BaseType_t = 0;
+pub const sbTYPE_MESSAGE_BUFFER: BaseType_t = 1;
+pub const sbTYPE_STREAM_BATCHING_BUFFER: BaseType_t = 2;
+
+/// `pdMS_TO_TICKS`
+#[inline(always)]
+pub const fn pdMS_TO_TICKS(ms: TickType_t) -> TickType_t {
+    ms * configTICK_RATE_HZ as TickType_t / 1000
+}
+
+/// `pdTICKS_TO_MS`
+#[inline(always)]
+pub const fn pdTICKS_TO_MS(ticks: TickType_t) -> TickType_t {
+    ticks * 1000 / configTICK_RATE_HZ as TickType_t
+}
+
+// ---------------------------------------------------------------------------
+// task.h
+// ---------------------------------------------------------------------------
+
+/// `taskYIELD` / `portYIELD`
+#[inline(always)]
+pub unsafe fn taskYIELD() {
+    vPortYield()
+}
+
+#[inline(always)]
+pub unsafe fn xTaskNotify(xTaskToNotify: TaskHandle_t, ulValue: u32, eAction: eNotifyAction) -> BaseType_t {
+    xTaskGenericNotify(xTaskToNotify, tskDEFAULT_INDEX_TO_NOTIFY, ulValue, eAction, ptr::null_mut())
+}
+
+#[inline(always)]
+pub unsafe fn xTaskNotifyFromISR(
+    xTaskToNotify: TaskHandle_t,
+    ulValue: u32,
+    eAction: eNotifyAction,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xTaskGenericNotifyFromISR(
+        xTaskToNotify,
+        tskDEFAULT_INDEX_TO_NOTIFY,
+        ulValue,
+        eAction,
+        ptr::null_mut(),
+        pxHigherPriorityTaskWoken,
+    )
+}
+
+#[inline(always)]
+pub unsafe fn xTaskNotifyWait(
+    ulBitsToClearOnEntry: u32,
+    ulBitsToClearOnExit: u32,
+    pulNotificationValue: *mut u32,
+    xTicksToWait: TickType_t,
+) -> BaseType_t {
+    xTaskGenericNotifyWait(
+        tskDEFAULT_INDEX_TO_NOTIFY,
+        ulBitsToClearOnEntry,
+        ulBitsToClearOnExit,
+        pulNotificationValue,
+        xTicksToWait,
+    )
+}
+
+#[inline(always)]
+pub unsafe fn xTaskNotifyGive(xTaskToNotify: TaskHandle_t) -> BaseType_t {
+    xTaskGenericNotify(xTaskToNotify, tskDEFAULT_INDEX_TO_NOTIFY, 0, eIncrement, ptr::null_mut())
+}
+
+#[inline(always)]
+pub unsafe fn vTaskNotifyGiveFromISR(xTaskToNotify: TaskHandle_t, pxHigherPriorityTaskWoken: *mut BaseType_t) {
+    vTaskGenericNotifyGiveFromISR(xTaskToNotify, tskDEFAULT_INDEX_TO_NOTIFY, pxHigherPriorityTaskWoken)
+}
+
+#[inline(always)]
+pub unsafe fn ulTaskNotifyTake(xClearCountOnExit: BaseType_t, xTicksToWait: TickType_t) -> u32 {
+    ulTaskGenericNotifyTake(tskDEFAULT_INDEX_TO_NOTIFY, xClearCountOnExit, xTicksToWait)
+}
+
+#[inline(always)]
+pub unsafe fn xTaskNotifyStateClear(xTask: TaskHandle_t) -> BaseType_t {
+    xTaskGenericNotifyStateClear(xTask, tskDEFAULT_INDEX_TO_NOTIFY)
+}
+
+// ---------------------------------------------------------------------------
+// queue.h
+// ---------------------------------------------------------------------------
+
+#[inline(always)]
+pub unsafe fn xQueueCreate(uxQueueLength: UBaseType_t, uxItemSize: UBaseType_t) -> QueueHandle_t {
+    xQueueGenericCreate(uxQueueLength, uxItemSize, queueQUEUE_TYPE_BASE)
+}
+
+#[inline(always)]
+pub unsafe fn xQueueCreateStatic(
+    uxQueueLength: UBaseType_t,
+    uxItemSize: UBaseType_t,
+    pucQueueStorage: *mut u8,
+    pxQueueBuffer: *mut StaticQueue_t,
+) -> QueueHandle_t {
+    xQueueGenericCreateStatic(uxQueueLength, uxItemSize, pucQueueStorage, pxQueueBuffer, queueQUEUE_TYPE_BASE)
+}
+
+#[inline(always)]
+pub unsafe fn xQueueSend(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const c_void,
+    xTicksToWait: TickType_t,
+) -> BaseType_t {
+    xQueueGenericSend(xQueue, pvItemToQueue, xTicksToWait, queueSEND_TO_BACK)
+}
+
+#[inline(always)]
+pub unsafe fn xQueueSendToBack(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const c_void,
+    xTicksToWait: TickType_t,
+) -> BaseType_t {
+    xQueueGenericSend(xQueue, pvItemToQueue, xTicksToWait, queueSEND_TO_BACK)
+}
+
+#[inline(always)]
+pub unsafe fn xQueueSendToFront(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const c_void,
+    xTicksToWait: TickType_t,
+) -> BaseType_t {
+    xQueueGenericSend(xQueue, pvItemToQueue, xTicksToWait, queueSEND_TO_FRONT)
+}
+
+#[inline(always)]
+pub unsafe fn xQueueOverwrite(xQueue: QueueHandle_t, pvItemToQueue: *const c_void) -> BaseType_t {
+    xQueueGenericSend(xQueue, pvItemToQueue, 0, queueOVERWRITE)
+}
+
+#[inline(always)]
+pub unsafe fn xQueueSendFromISR(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const c_void,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xQueueGenericSendFromISR(xQueue, pvItemToQueue, pxHigherPriorityTaskWoken, queueSEND_TO_BACK)
+}
+
+#[inline(always)]
+pub unsafe fn xQueueSendToFrontFromISR(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const c_void,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xQueueGenericSendFromISR(xQueue, pvItemToQueue, pxHigherPriorityTaskWoken, queueSEND_TO_FRONT)
+}
+
+#[inline(always)]
+pub unsafe fn xQueueOverwriteFromISR(
+    xQueue: QueueHandle_t,
+    pvItemToQueue: *const c_void,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xQueueGenericSendFromISR(xQueue, pvItemToQueue, pxHigherPriorityTaskWoken, queueOVERWRITE)
+}
+
+#[inline(always)]
+pub unsafe fn xQueueReset(xQueue: QueueHandle_t) -> BaseType_t {
+    xQueueGenericReset(xQueue, pdFALSE)
+}
+
+// ---------------------------------------------------------------------------
+// semphr.h
+// ---------------------------------------------------------------------------
+
+#[inline(always)]
+pub unsafe fn xSemaphoreCreateBinary() -> SemaphoreHandle_t {
+    xQueueGenericCreate(
+        semBINARY_SEMAPHORE_QUEUE_LENGTH as UBaseType_t,
+        semSEMAPHORE_QUEUE_ITEM_LENGTH as UBaseType_t,
+        queueQUEUE_TYPE_BINARY_SEMAPHORE,
+    )
+}
+
+#[inline(always)]
+pub unsafe fn xSemaphoreCreateBinaryStatic(pxStaticSemaphore: *mut StaticSemaphore_t) -> SemaphoreHandle_t {
+    xQueueGenericCreateStatic(
+        semBINARY_SEMAPHORE_QUEUE_LENGTH as UBaseType_t,
+        semSEMAPHORE_QUEUE_ITEM_LENGTH as UBaseType_t,
+        ptr::null_mut(),
+        pxStaticSemaphore,
+        queueQUEUE_TYPE_BINARY_SEMAPHORE,
+    )
+}
+
+#[inline(always)]
+pub unsafe fn xSemaphoreCreateMutex() -> SemaphoreHandle_t {
+    xQueueCreateMutex(queueQUEUE_TYPE_MUTEX)
+}
+
+#[inline(always)]
+pub unsafe fn xSemaphoreCreateMutexStatic(pxMutexBuffer: *mut StaticSemaphore_t) -> SemaphoreHandle_t {
+    xQueueCreateMutexStatic(queueQUEUE_TYPE_MUTEX, pxMutexBuffer)
+}
+
+#[inline(always)]
+pub unsafe fn xSemaphoreCreateRecursiveMutex() -> SemaphoreHandle_t {
+    xQueueCreateMutex(queueQUEUE_TYPE_RECURSIVE_MUTEX)
+}
+
+#[inline(always)]
+pub unsafe fn xSemaphoreCreateRecursiveMutexStatic(pxMutexBuffer: *mut StaticSemaphore_t) -> SemaphoreHandle_t {
+    xQueueCreateMutexStatic(queueQUEUE_TYPE_RECURSIVE_MUTEX, pxMutexBuffer)
+}
+
+#[inline(always)]
+pub unsafe fn xSemaphoreCreateCounting(uxMaxCount: UBaseType_t, uxInitialCount: UBaseType_t) -> SemaphoreHandle_t {
+    xQueueCreateCountingSemaphore(uxMaxCount, uxInitialCount)
+}
+
+#[inline(always)]
+pub unsafe fn xSemaphoreCreateCountingStatic(
+    uxMaxCount: UBaseType_t,
+    uxInitialCount: UBaseType_t,
+    pxSemaphoreBuffer: *mut StaticSemaphore_t,
+) -> SemaphoreHandle_t {
+    xQueueCreateCountingSemaphoreStatic(uxMaxCount, uxInitialCount, pxSemaphoreBuffer)
+}
+
+#[inline(always)]
+pub unsafe fn vSemaphoreDelete(xSemaphore: SemaphoreHandle_t) {
+    vQueueDelete(xSemaphore)
+}
+
+#[inline(always)]
+pub unsafe fn xSemaphoreTake(xSemaphore: SemaphoreHandle_t, xBlockTime: TickType_t) -> BaseType_t {
+    xQueueSemaphoreTake(xSemaphore, xBlockTime)
+}
+
+#[inline(always)]
+pub unsafe fn xSemaphoreTakeFromISR(
+    xSemaphore: SemaphoreHandle_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xQueueReceiveFromISR(xSemaphore, ptr::null_mut(), pxHigherPriorityTaskWoken)
+}
+
+#[inline(always)]
+pub unsafe fn xSemaphoreGive(xSemaphore: SemaphoreHandle_t) -> BaseType_t {
+    xQueueGenericSend(xSemaphore, ptr::null(), semGIVE_BLOCK_TIME, queueSEND_TO_BACK)
+}
+
+#[inline(always)]
+pub unsafe fn xSemaphoreGiveFromISR(
+    xSemaphore: SemaphoreHandle_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xQueueGiveFromISR(xSemaphore, pxHigherPriorityTaskWoken)
+}
+
+#[inline(always)]
+pub unsafe fn xSemaphoreTakeRecursive(xMutex: SemaphoreHandle_t, xBlockTime: TickType_t) -> BaseType_t {
+    xQueueTakeMutexRecursive(xMutex, xBlockTime)
+}
+
+#[inline(always)]
+pub unsafe fn xSemaphoreGiveRecursive(xMutex: SemaphoreHandle_t) -> BaseType_t {
+    xQueueGiveMutexRecursive(xMutex)
+}
+
+#[inline(always)]
+pub unsafe fn uxSemaphoreGetCount(xSemaphore: SemaphoreHandle_t) -> UBaseType_t {
+    uxQueueMessagesWaiting(xSemaphore)
+}
+
+// ---------------------------------------------------------------------------
+// timers.h
+// ---------------------------------------------------------------------------
+
+/// `xTimerGenericCommand`, which picks the task or ISR entry point by command.
+#[inline(always)]
+pub unsafe fn xTimerGenericCommand(
+    xTimer: TimerHandle_t,
+    xCommandID: BaseType_t,
+    xOptionalValue: TickType_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+    xTicksToWait: TickType_t,
+) -> BaseType_t {
+    if xCommandID < tmrFIRST_FROM_ISR_COMMAND {
+        xTimerGenericCommandFromTask(xTimer, xCommandID, xOptionalValue, pxHigherPriorityTaskWoken, xTicksToWait)
+    } else {
+        xTimerGenericCommandFromISR(xTimer, xCommandID, xOptionalValue, pxHigherPriorityTaskWoken, xTicksToWait)
+    }
+}
+
+#[inline(always)]
+pub unsafe fn xTimerStart(xTimer: TimerHandle_t, xTicksToWait: TickType_t) -> BaseType_t {
+    xTimerGenericCommand(xTimer, tmrCOMMAND_START, xTaskGetTickCount(), ptr::null_mut(), xTicksToWait)
+}
+
+#[inline(always)]
+pub unsafe fn xTimerStop(xTimer: TimerHandle_t, xTicksToWait: TickType_t) -> BaseType_t {
+    xTimerGenericCommand(xTimer, tmrCOMMAND_STOP, 0, ptr::null_mut(), xTicksToWait)
+}
+
+#[inline(always)]
+pub unsafe fn xTimerChangePeriod(
+    xTimer: TimerHandle_t,
+    xNewPeriod: TickType_t,
+    xTicksToWait: TickType_t,
+) -> BaseType_t {
+    xTimerGenericCommand(xTimer, tmrCOMMAND_CHANGE_PERIOD, xNewPeriod, ptr::null_mut(), xTicksToWait)
+}
+
+#[inline(always)]
+pub unsafe fn xTimerDelete(xTimer: TimerHandle_t, xTicksToWait: TickType_t) -> BaseType_t {
+    xTimerGenericCommand(xTimer, tmrCOMMAND_DELETE, 0, ptr::null_mut(), xTicksToWait)
+}
+
+#[inline(always)]
+pub unsafe fn xTimerReset(xTimer: TimerHandle_t, xTicksToWait: TickType_t) -> BaseType_t {
+    xTimerGenericCommand(xTimer, tmrCOMMAND_RESET, xTaskGetTickCount(), ptr::null_mut(), xTicksToWait)
+}
+
+#[inline(always)]
+pub unsafe fn xTimerStartFromISR(xTimer: TimerHandle_t, pxHigherPriorityTaskWoken: *mut BaseType_t) -> BaseType_t {
+    xTimerGenericCommand(
+        xTimer,
+        tmrCOMMAND_START_FROM_ISR,
+        xTaskGetTickCountFromISR(),
+        pxHigherPriorityTaskWoken,
+        0,
+    )
+}
+
+#[inline(always)]
+pub unsafe fn xTimerStopFromISR(xTimer: TimerHandle_t, pxHigherPriorityTaskWoken: *mut BaseType_t) -> BaseType_t {
+    xTimerGenericCommand(xTimer, tmrCOMMAND_STOP_FROM_ISR, 0, pxHigherPriorityTaskWoken, 0)
+}
+
+#[inline(always)]
+pub unsafe fn xTimerChangePeriodFromISR(
+    xTimer: TimerHandle_t,
+    xNewPeriod: TickType_t,
+    pxHigherPriorityTaskWoken: *mut BaseType_t,
+) -> BaseType_t {
+    xTimerGenericCommand(xTimer, tmrCOMMAND_CHANGE_PERIOD_FROM_ISR, xNewPeriod, pxHigherPriorityTaskWoken, 0)
+}
+
+#[inline(always)]
+pub unsafe fn xTimerResetFromISR(xTimer: TimerHandle_t, pxHigherPriorityTaskWoken: *mut BaseType_t) -> BaseType_t {
+    xTimerGenericCommand(
+        xTimer,
+        tmrCOMMAND_RESET_FROM_ISR,
+        xTaskGetTickCountFromISR(),
+        pxHigherPriorityTaskWoken,
+        0,
+    )
+}
+
+// ---------------------------------------------------------------------------
+// stream_buffer.h / message_buffer.h
+// ---------------------------------------------------------------------------
+
+#[inline(always)]
+pub unsafe fn xStreamBufferCreate(xBufferSizeBytes: usize, xTriggerLevelBytes: usize) -> StreamBufferHandle_t {
+    xStreamBufferGenericCreate(xBufferSizeBytes, xTriggerLevelBytes, sbTYPE_STREAM_BUFFER, None, None)
+}
+
+#[inline(always)]
+pub unsafe fn xStreamBufferCreateStatic(
+    xBufferSizeBytes: usize,
+    xTriggerLevelBytes: usize,
+    pucStreamBufferStorageArea: *mut u8,
+    pxStaticStreamBuffer: *mut StaticStreamBuffer_t,
+) -> StreamBufferHandle_t {
+    xStreamBufferGenericCreateStatic(
+        xBufferSizeBytes,
+        xTriggerLevelBytes,
+        sbTYPE_STREAM_BUFFER,
+        pucStreamBufferStorageArea,
+        pxStaticStreamBuffer,
+        None,
+        None,
+    )
+}
+
+#[inline(always)]
+pub unsafe fn xMessageBufferCreate(xBufferSizeBytes: usize) -> StreamBufferHandle_t {
+    xStreamBufferGenericCreate(xBufferSizeBytes, 0, sbTYPE_MESSAGE_BUFFER, None, None)
+}
@@ -0,0 +1,31 @@
+//! Raw FFI bindings to the PowerBlocks SDK.
+//!
+//! Everything declared by the headers in `wrapper.h` is exposed here as-is:
+//! the core system, IOS, graphics, bluetooth and utility modules, the
+//! input and filesystem libraries, and the FreeRTOS kernel the SDK is built
+//! against. Names, types and layouts follow the C side exactly.
+//!
+//! C macros that stand in for functions (semaphore takes, queue sends,
+//! timer commands, address conversion and so on) cannot be bound directly
+//! and are reimplemented as inline functions in [`freertos`] and [`system`].
+//! Both are re-exported at the crate root so they sit next to the functions
+//! they wrap.
+//!
+//! Nothing here is safe to call. The `powerblocks` crate builds the safe
+//! layer on top.
+
+#![no_std]
+#![allow(non_camel_case_types, non_upper_case_globals, non_snake_case)]
+#![allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+
+#[cfg(feature = "generate")]
+include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+
+#[cfg(not(feature = "generate"))]
+include!("bindings.rs");
+
+pub mod freertos;
+pub mod system;
+
+pub use freertos::*;
+pub use system::*;
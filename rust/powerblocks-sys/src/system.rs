@@ -0,0 +1,42 @@
+//! Function-like macros from `powerblocks/core/system/system.h`.
+//!
+//! The MSR/decrementer accessors and `SYSTEM_DISABLE_ISR` are inline
+//! assembly on the C side and are left to the safe crate.
+
+use crate::*;
+
+/// `SYSTEM_US_TO_TICKS`
+#[inline(always)]
+pub const fn SYSTEM_US_TO_TICKS(us: u64) -> u64 {
+    SYSTEM_TB_CLOCK_HZ as u64 / 1000000 * us
+}
+
+/// `SYSTEM_MS_TO_TICKS`
+#[inline(always)]
+pub const fn SYSTEM_MS_TO_TICKS(ms: u64) -> u64 {
+    SYSTEM_TB_CLOCK_HZ as u64 / 1000 * ms
+}
+
+/// `SYSTEM_S_TO_TICKS`
+#[inline(always)]
+pub const fn SYSTEM_S_TO_TICKS(s: u64) -> u64 {
+    SYSTEM_TB_CLOCK_HZ as u64 * s
+}
+
+/// `SYSTEM_MEM_UNCACHED`
+#[inline(always)]
+pub const fn SYSTEM_MEM_UNCACHED(address: u32) -> u32 {
+    (address & 0x1FFFFFFF) | 0xC0000000
+}
+
+/// `SYSTEM_MEM_CACHED`
+#[inline(always)]
+pub const fn SYSTEM_MEM_CACHED(address: u32) -> u32 {
+    (address & 0x1FFFFFFF) | 0x80000000
+}
+
+/// `SYSTEM_MEM_PHYSICAL`
+#[inline(always)]
+pub const fn SYSTEM_MEM_PHYSICAL(address: u32) -> u32 {
+    address & 0x1FFFFFFF
+}
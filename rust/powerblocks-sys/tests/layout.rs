@@ -0,0 +1,227 @@
+//! Checks that the Rust bindings lay structs out the same way the C compiler
+//! does.
+//!
+//! `tests/layout/probe.c` is compiled against the SDK headers with the host C
+//! compiler (`$CC`, or `cc`) and prints the size, alignment and field offsets
+//! of every bound struct. The same values are taken from the Rust side and
+//! compared. Both sides run on the host, so this catches missing, reordered
+//! or mistyped fields rather than target specific ABI details.
+
+use std::collections::HashMap;
+use std::env;
+use std::mem::{align_of, offset_of, size_of};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+
+use powerblocks_sys::*;
+
+macro_rules! layout {
+    ($out:ident, $type:ident { $($field:ident),* $(,)? }) => {
+        $out.push((concat!(stringify!($type), " size"), size_of::<$type>()));
+        $out.push((concat!(stringify!($type), " align"), align_of::<$type>()));
+        $(
+            $out.push((concat!(stringify!($type), ".", stringify!($field)), offset_of!($type, $field)));
+        )*
+    };
+}
+
+fn rust_layout() -> Vec<(&'static str, usize)> {
+    let mut out = Vec::new();
+
+    // FreeRTOS
+    layout!(out, StaticListItem_t { xDummy2, pvDummy3 });
+    layout!(out, StaticMiniListItem_t { xDummy2, pvDummy3 });
+    layout!(out, StaticList_t { uxDummy2, pvDummy3, xDummy4 });
+    layout!(out, StaticTask_t {
+        pxDummy1, xDummy3, uxDummy5, pxDummy6, ucDummy7, uxDummy12, ulDummy18, ucDummy19, uxDummy20,
+    });
+    layout!(out, StaticQueue_t { pvDummy1, u, xDummy3, uxDummy4, ucDummy5, ucDummy6 });
+    layout!(out, StaticTimer_t { pvDummy1, xDummy2, xDummy3, pvDummy5, pvDummy6, ucDummy8 });
+    layout!(out, StaticStreamBuffer_t { uxDummy1, pvDummy2, ucDummy3, uxDummy6 });
+
+    // System
+    layout!(out, system_argv_t { magic, command_line, command_line_length, argc, argv, end_argv });
+    layout!(out, exception_context_t {
+        stack_frame, r0, r2, r31, sprg3, cr, lr, ctr, xer, dar, srr1, srr0, gqr0, gqr7, f, ffs,
+    });
+    layout!(out, ipc_message {
+        command, returned, file_handle, __bindgen_anon_1, magic, response_handler, params,
+    });
+
+    // IOS
+    layout!(out, ios_ioctlv_t { data, size });
+    layout!(out, usb_device_entry_t { device_id, vendor_id, product_id });
+    layout!(out, isfs_attributes_t {
+        owner_id, group_id, path, owner_permissions, group_permissions, other_permissions, attributes,
+    });
+    layout!(out, isfs_stats_t { cluster_size, free_clusters, used_inodes });
+    layout!(out, isfs_file_stats_t { length, position });
+
+    // Math
+    layout!(out, vec2i {});
+    layout!(out, vec2s16 {});
+    layout!(out, vec2 {});
+    layout!(out, vec3 { z });
+
+    // Graphics
+    layout!(out, framebuffer_t {});
+    layout!(out, framebuffer_font_t { font_data, character_size });
+    layout!(out, video_profile_t { width, efb_height, xfb_height, copy_pattern, copy_filer });
+    layout!(out, gx_fifo_t { base_address, breakpoint });
+    layout!(out, gx_texture_t { mode0, mode1, image0, image1, image2, image3, lut });
+    layout!(out, gx_tev_stage_t { alpha_control });
+    layout!(out, gx_light_t { color, cos_attenuation, distance_attenuation, position, direction });
+
+    // Bluetooth
+    layout!(out, hci_discovered_device_info_t {
+        address, link_type, page_scan_repetition_mode, class_of_device, clock_offset, connection_request,
+    });
+    layout!(out, hci_buffer_sizes_t {
+        acl_data_packet_length,
+        synchronous_data_packet_length,
+        total_num_acl_data_packets,
+        total_num_synchronous_data_packets,
+    });
+    layout!(out, hci_acl_packet_t { handle, size, data });
+    layout!(out, l2cap_signal_t { code, id, length, data });
+    layout!(out, l2cap_channel_t {
+        device,
+        sid,
+        did,
+        protocol_id,
+        status_flags,
+        status_error,
+        on_complete_packet,
+        on_status_change,
+        status_lock,
+        buffer,
+        buffer_length,
+        fifo_write_head,
+        fifo_write_head_packet,
+        fifo_read_head,
+        event_packet_available,
+        semaphore_data,
+    });
+    layout!(out, l2cap_device_t {
+        lock,
+        handle,
+        open_channels,
+        reading_channel,
+        reading_remaining,
+        mac_address,
+        disconnect_handler,
+        disconnect_handler_data,
+        semaphore_data,
+    });
+    layout!(out, bluetooth_driver_t {
+        driver_id, filter_device, filter_paired_device, initialize_new_device, initialize_paired_device,
+    });
+    layout!(out, bluetooth_driver_instance_t { instance });
+
+    // Input
+    layout!(out, wiimote_buttons { up });
+    layout!(out, wiimote_extension_data_t { type_, __bindgen_anon_1 });
+    layout!(out, wiimote_extension_mapper_t { phrase_data });
+    layout!(out, wiimote_t { driver, present, buttons, accelerometer, ir_tracking, cursor, extensions });
+    layout!(out, wiimote_raw_t { core_state, calibration, ext_mapper, report_type, data_report });
+    layout!(out, wiimote_hid_t {
+        slot,
+        device,
+        channels,
+        wiimote_signal_channel_buffer,
+        wiimote_control_channel_buffer,
+        wiimote_interrupt_channel_buffer,
+        set_report_mode,
+        set_ir_mode,
+        internal_state_lock,
+        internal_state,
+        semaphore_data,
+    });
+    layout!(out, wiimote_sys_config_t {
+        sensor_bar_position, motor_enabled, ir_sensitivity, speaker_volume, guest_wiimotes, wiimotes,
+    });
+
+    // FatFs
+    layout!(out, FATFS { fs_type, id, csize, lfnbuf, last_clst, fsize, winsect, database, win });
+    layout!(out, FFOBJID { fs, id, attr, stat, sclust, objsize });
+    layout!(out, FIL { obj, flag, err, fptr, clust, sect, dir_sect, dir_ptr, buf });
+    layout!(out, DIR { obj, dptr, clust, sect, dir, fn_, blk_ofs });
+    layout!(out, FILINFO { fsize, fdate, ftime, fattrib, altname, fname });
+
+    out
+}
+
+fn c_layout() -> &'static HashMap<String, usize> {
+    static LAYOUT: OnceLock<HashMap<String, usize>> = OnceLock::new();
+    LAYOUT.get_or_init(compile_and_run_probe)
+}
+
+fn compile_and_run_probe() -> HashMap<String, usize> {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let sdk_dir = crate_dir.join("../..");
+    let probe = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("layout_probe");
+
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".into());
+    let status = Command::new(&cc)
+        .arg("-std=gnu11")
+        .arg("-w")
+        .arg("-I").arg(crate_dir)
+        .arg("-I").arg(&sdk_dir)
+        .arg("-I").arg(sdk_dir.join("powerblocks/core/freertos_port"))
+        .arg("-I").arg(sdk_dir.join("third_party/freertos/include"))
+        .arg("-I").arg(sdk_dir.join("powerblocks/filesystem/fatfs_port"))
+        .arg("-I").arg(sdk_dir.join("third_party/fatfs"))
+        .arg(crate_dir.join("tests/layout/probe.c"))
+        .arg("-o").arg(&probe)
+        .status()
+        .unwrap_or_else(|e| panic!("failed to run C compiler `{cc}`: {e}"));
+    assert!(status.success(), "failed to compile layout probe");
+
+    let output = Command::new(&probe).output().expect("failed to run layout probe");
+    assert!(output.status.success(), "layout probe exited with {}", output.status);
+
+    String::from_utf8(output.stdout)
+        .unwrap()
+        .lines()
+        .map(|line| {
+            let (key, value) = line.rsplit_once(' ').unwrap();
+            (key.to_string(), value.parse().unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn struct_layout_matches_c() {
+    let c = c_layout();
+    let rust = rust_layout();
+
+    let mut mismatches = Vec::new();
+    for (key, rust_value) in &rust {
+        match c.get(*key) {
+            Some(c_value) if c_value == rust_value => {}
+            Some(c_value) => mismatches.push(format!("{key}: C {c_value}, Rust {rust_value}")),
+            None => mismatches.push(format!("{key}: missing from probe.c")),
+        }
+    }
+    for key in c.keys() {
+        if !rust.iter().any(|(k, _)| k == key) {
+            mismatches.push(format!("{key}: missing from layout.rs"));
+        }
+    }
+
+    assert!(mismatches.is_empty(), "layout mismatches:\n{}", mismatches.join("\n"));
+}
+
+#[test]
+fn reference_layouts() {
+    let c = c_layout();
+
+    for (key, value) in [
+        ("exception_context_t size", size_of::<exception_context_t>()),
+        ("wiimote_t size", size_of::<wiimote_t>()),
+        ("gx_texture_t size", size_of::<gx_texture_t>()),
+    ] {
+        assert_eq!(c[key], value, "{key}");
+    }
+}
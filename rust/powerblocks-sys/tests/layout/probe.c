@@ -0,0 +1,314 @@
+/**
+ * @file probe.c
+ * @brief Prints the C layout of every struct bound by powerblocks-sys.
+ *
+ * Built and run on the host by tests/layout.rs. Each line is
+ * "<key> <value>", where key is "<type> size", "<type> align"
+ * or "<type>.<field>" for a field offset. Fields are named as
+ * they are on the Rust side, so anonymous unions become
+ * __bindgen_anon_1 and keywords get a trailing underscore.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include <stdio.h>
+#include <stddef.h>
+
+#include "wrapper.h"
+
+#define SIZE(type) \
+    printf(#type " size %zu\n", sizeof(type)); \
+    printf(#type " align %zu\n", _Alignof(type));
+
+#define FIELD(type, field) \
+    printf(#type "." #field " %zu\n", offsetof(type, field));
+
+#define FIELD_AS(type, field, name) \
+    printf(#type "." name " %zu\n", offsetof(type, field));
+
+int main() {
+    // FreeRTOS
+    SIZE(StaticListItem_t)
+    FIELD(StaticListItem_t, xDummy2)
+    FIELD(StaticListItem_t, pvDummy3)
+    SIZE(StaticMiniListItem_t)
+    FIELD(StaticMiniListItem_t, xDummy2)
+    FIELD(StaticMiniListItem_t, pvDummy3)
+    SIZE(StaticList_t)
+    FIELD(StaticList_t, uxDummy2)
+    FIELD(StaticList_t, pvDummy3)
+    FIELD(StaticList_t, xDummy4)
+    SIZE(StaticTask_t)
+    FIELD(StaticTask_t, pxDummy1)
+    FIELD(StaticTask_t, xDummy3)
+    FIELD(StaticTask_t, uxDummy5)
+    FIELD(StaticTask_t, pxDummy6)
+    FIELD(StaticTask_t, ucDummy7)
+    FIELD(StaticTask_t, uxDummy12)
+    FIELD(StaticTask_t, ulDummy18)
+    FIELD(StaticTask_t, ucDummy19)
+    FIELD(StaticTask_t, uxDummy20)
+    SIZE(StaticQueue_t)
+    FIELD(StaticQueue_t, pvDummy1)
+    FIELD(StaticQueue_t, u)
+    FIELD(StaticQueue_t, xDummy3)
+    FIELD(StaticQueue_t, uxDummy4)
+    FIELD(StaticQueue_t, ucDummy5)
+    FIELD(StaticQueue_t, ucDummy6)
+    SIZE(StaticTimer_t)
+    FIELD(StaticTimer_t, pvDummy1)
+    FIELD(StaticTimer_t, xDummy2)
+    FIELD(StaticTimer_t, xDummy3)
+    FIELD(StaticTimer_t, pvDummy5)
+    FIELD(StaticTimer_t, pvDummy6)
+    FIELD(StaticTimer_t, ucDummy8)
+    SIZE(StaticStreamBuffer_t)
+    FIELD(StaticStreamBuffer_t, uxDummy1)
+    FIELD(StaticStreamBuffer_t, pvDummy2)
+    FIELD(StaticStreamBuffer_t, ucDummy3)
+    FIELD(StaticStreamBuffer_t, uxDummy6)
+
+    // System
+    SIZE(system_argv_t)
+    FIELD(system_argv_t, magic)
+    FIELD(system_argv_t, command_line)
+    FIELD(system_argv_t, command_line_length)
+    FIELD(system_argv_t, argc)
+    FIELD(system_argv_t, argv)
+    FIELD(system_argv_t, end_argv)
+    SIZE(exception_context_t)
+    FIELD(exception_context_t, stack_frame)
+    FIELD(exception_context_t, r0)
+    FIELD(exception_context_t, r2)
+    FIELD(exception_context_t, r31)
+    FIELD(exception_context_t, sprg3)
+    FIELD(exception_context_t, cr)
+    FIELD(exception_context_t, lr)
+    FIELD(exception_context_t, ctr)
+    FIELD(exception_context_t, xer)
+    FIELD(exception_context_t, dar)
+    FIELD(exception_context_t, srr1)
+    FIELD(exception_context_t, srr0)
+    FIELD(exception_context_t, gqr0)
+    FIELD(exception_context_t, gqr7)
+    FIELD(exception_context_t, f)
+    FIELD(exception_context_t, ffs)
+    SIZE(ipc_message)
+    FIELD(ipc_message, command)
+    FIELD(ipc_message, returned)
+    FIELD(ipc_message, file_handle)
+    FIELD_AS(ipc_message, args, "__bindgen_anon_1")
+    FIELD(ipc_message, magic)
+    FIELD(ipc_message, response_handler)
+    FIELD(ipc_message, params)
+
+    // IOS
+    SIZE(ios_ioctlv_t)
+    FIELD(ios_ioctlv_t, data)
+    FIELD(ios_ioctlv_t, size)
+    SIZE(usb_device_entry_t)
+    FIELD(usb_device_entry_t, device_id)
+    FIELD(usb_device_entry_t, vendor_id)
+    FIELD(usb_device_entry_t, product_id)
+    SIZE(isfs_attributes_t)
+    FIELD(isfs_attributes_t, owner_id)
+    FIELD(isfs_attributes_t, group_id)
+    FIELD(isfs_attributes_t, path)
+    FIELD(isfs_attributes_t, owner_permissions)
+    FIELD(isfs_attributes_t, group_permissions)
+    FIELD(isfs_attributes_t, other_permissions)
+    FIELD(isfs_attributes_t, attributes)
+    SIZE(isfs_stats_t)
+    FIELD(isfs_stats_t, cluster_size)
+    FIELD(isfs_stats_t, free_clusters)
+    FIELD(isfs_stats_t, used_inodes)
+    SIZE(isfs_file_stats_t)
+    FIELD(isfs_file_stats_t, length)
+    FIELD(isfs_file_stats_t, position)
+
+    // Math
+    SIZE(vec2i)
+    SIZE(vec2s16)
+    SIZE(vec2)
+    SIZE(vec3)
+    FIELD(vec3, z)
+
+    // Graphics
+    SIZE(framebuffer_t)
+    SIZE(framebuffer_font_t)
+    FIELD(framebuffer_font_t, font_data)
+    FIELD(framebuffer_font_t, character_size)
+    SIZE(video_profile_t)
+    FIELD(video_profile_t, width)
+    FIELD(video_profile_t, efb_height)
+    FIELD(video_profile_t, xfb_height)
+    FIELD(video_profile_t, copy_pattern)
+    FIELD(video_profile_t, copy_filer)
+    SIZE(gx_fifo_t)
+    FIELD(gx_fifo_t, base_address)
+    FIELD(gx_fifo_t, breakpoint)
+    SIZE(gx_texture_t)
+    FIELD(gx_texture_t, mode0)
+    FIELD(gx_texture_t, mode1)
+    FIELD(gx_texture_t, image0)
+    FIELD(gx_texture_t, image1)
+    FIELD(gx_texture_t, image2)
+    FIELD(gx_texture_t, image3)
+    FIELD(gx_texture_t, lut)
+    SIZE(gx_tev_stage_t)
+    FIELD(gx_tev_stage_t, alpha_control)
+    SIZE(gx_light_t)
+    FIELD(gx_light_t, color)
+    FIELD(gx_light_t, cos_attenuation)
+    FIELD(gx_light_t, distance_attenuation)
+    FIELD(gx_light_t, position)
+    FIELD(gx_light_t, direction)
+
+    // Bluetooth
+    SIZE(hci_discovered_device_info_t)
+    FIELD(hci_discovered_device_info_t, address)
+    FIELD(hci_discovered_device_info_t, link_type)
+    FIELD(hci_discovered_device_info_t, page_scan_repetition_mode)
+    FIELD(hci_discovered_device_info_t, class_of_device)
+    FIELD(hci_discovered_device_info_t, clock_offset)
+    FIELD(hci_discovered_device_info_t, connection_request)
+    SIZE(hci_buffer_sizes_t)
+    FIELD(hci_buffer_sizes_t, acl_data_packet_length)
+    FIELD(hci_buffer_sizes_t, synchronous_data_packet_length)
+    FIELD(hci_buffer_sizes_t, total_num_acl_data_packets)
+    FIELD(hci_buffer_sizes_t, total_num_synchronous_data_packets)
+    SIZE(hci_acl_packet_t)
+    FIELD(hci_acl_packet_t, handle)
+    FIELD(hci_acl_packet_t, size)
+    FIELD(hci_acl_packet_t, data)
+    SIZE(l2cap_signal_t)
+    FIELD(l2cap_signal_t, code)
+    FIELD(l2cap_signal_t, id)
+    FIELD(l2cap_signal_t, length)
+    FIELD(l2cap_signal_t, data)
+    SIZE(l2cap_channel_t)
+    FIELD(l2cap_channel_t, device)
+    FIELD(l2cap_channel_t, sid)
+    FIELD(l2cap_channel_t, did)
+    FIELD(l2cap_channel_t, protocol_id)
+    FIELD(l2cap_channel_t, status_flags)
+    FIELD(l2cap_channel_t, status_error)
+    FIELD(l2cap_channel_t, on_complete_packet)
+    FIELD(l2cap_channel_t, on_status_change)
+    FIELD(l2cap_channel_t, status_lock)
+    FIELD(l2cap_channel_t, buffer)
+    FIELD(l2cap_channel_t, buffer_length)
+    FIELD(l2cap_channel_t, fifo_write_head)
+    FIELD(l2cap_channel_t, fifo_write_head_packet)
+    FIELD(l2cap_channel_t, fifo_read_head)
+    FIELD(l2cap_channel_t, event_packet_available)
+    FIELD(l2cap_channel_t, semaphore_data)
+    SIZE(l2cap_device_t)
+    FIELD(l2cap_device_t, lock)
+    FIELD(l2cap_device_t, handle)
+    FIELD(l2cap_device_t, open_channels)
+    FIELD(l2cap_device_t, reading_channel)
+    FIELD(l2cap_device_t, reading_remaining)
+    FIELD(l2cap_device_t, mac_address)
+    FIELD(l2cap_device_t, disconnect_handler)
+    FIELD(l2cap_device_t, disconnect_handler_data)
+    FIELD(l2cap_device_t, semaphore_data)
+    SIZE(bluetooth_driver_t)
+    FIELD(bluetooth_driver_t, driver_id)
+    FIELD(bluetooth_driver_t, filter_device)
+    FIELD(bluetooth_driver_t, filter_paired_device)
+    FIELD(bluetooth_driver_t, initialize_new_device)
+    FIELD(bluetooth_driver_t, initialize_paired_device)
+    SIZE(bluetooth_driver_instance_t)
+    FIELD(bluetooth_driver_instance_t, instance)
+
+    // Input
+    SIZE(wiimote_buttons)
+    FIELD(wiimote_buttons, up)
+    SIZE(wiimote_extension_data_t)
+    FIELD_AS(wiimote_extension_data_t, type, "type_")
+    FIELD_AS(wiimote_extension_data_t, nunchuck, "__bindgen_anon_1")
+    SIZE(wiimote_extension_mapper_t)
+    FIELD(wiimote_extension_mapper_t, phrase_data)
+    SIZE(wiimote_t)
+    FIELD(wiimote_t, driver)
+    FIELD(wiimote_t, present)
+    FIELD(wiimote_t, buttons)
+    FIELD(wiimote_t, accelerometer)
+    FIELD(wiimote_t, ir_tracking)
+    FIELD(wiimote_t, cursor)
+    FIELD(wiimote_t, extensions)
+    SIZE(wiimote_raw_t)
+    FIELD(wiimote_raw_t, core_state)
+    FIELD(wiimote_raw_t, calibration)
+    FIELD(wiimote_raw_t, ext_mapper)
+    FIELD(wiimote_raw_t, report_type)
+    FIELD(wiimote_raw_t, data_report)
+    SIZE(wiimote_hid_t)
+    FIELD(wiimote_hid_t, slot)
+    FIELD(wiimote_hid_t, device)
+    FIELD(wiimote_hid_t, channels)
+    FIELD(wiimote_hid_t, wiimote_signal_channel_buffer)
+    FIELD(wiimote_hid_t, wiimote_control_channel_buffer)
+    FIELD(wiimote_hid_t, wiimote_interrupt_channel_buffer)
+    FIELD(wiimote_hid_t, set_report_mode)
+    FIELD(wiimote_hid_t, set_ir_mode)
+    FIELD(wiimote_hid_t, internal_state_lock)
+    FIELD(wiimote_hid_t, internal_state)
+    FIELD(wiimote_hid_t, semaphore_data)
+    SIZE(wiimote_sys_config_t)
+    FIELD(wiimote_sys_config_t, sensor_bar_position)
+    FIELD(wiimote_sys_config_t, motor_enabled)
+    FIELD(wiimote_sys_config_t, ir_sensitivity)
+    FIELD(wiimote_sys_config_t, speaker_volume)
+    FIELD(wiimote_sys_config_t, guest_wiimotes)
+    FIELD(wiimote_sys_config_t, wiimotes)
+
+    // FatFs
+    SIZE(FATFS)
+    FIELD(FATFS, fs_type)
+    FIELD(FATFS, id)
+    FIELD(FATFS, csize)
+    FIELD(FATFS, lfnbuf)
+    FIELD(FATFS, last_clst)
+    FIELD(FATFS, fsize)
+    FIELD(FATFS, winsect)
+    FIELD(FATFS, database)
+    FIELD(FATFS, win)
+    SIZE(FFOBJID)
+    FIELD(FFOBJID, fs)
+    FIELD(FFOBJID, id)
+    FIELD(FFOBJID, attr)
+    FIELD(FFOBJID, stat)
+    FIELD(FFOBJID, sclust)
+    FIELD(FFOBJID, objsize)
+    SIZE(FIL)
+    FIELD(FIL, obj)
+    FIELD(FIL, flag)
+    FIELD(FIL, err)
+    FIELD(FIL, fptr)
+    FIELD(FIL, clust)
+    FIELD(FIL, sect)
+    FIELD(FIL, dir_sect)
+    FIELD(FIL, dir_ptr)
+    FIELD(FIL, buf)
+    SIZE(DIR)
+    FIELD(DIR, obj)
+    FIELD(DIR, dptr)
+    FIELD(DIR, clust)
+    FIELD(DIR, sect)
+    FIELD(DIR, dir)
+    FIELD_AS(DIR, fn, "fn_")
+    FIELD(DIR, blk_ofs)
+    SIZE(FILINFO)
+    FIELD(FILINFO, fsize)
+    FIELD(FILINFO, fdate)
+    FIELD(FILINFO, ftime)
+    FIELD(FILINFO, fattrib)
+    FIELD(FILINFO, altname)
+    FIELD(FILINFO, fname)
+
+    return 0;
+}
@@ -0,0 +1,74 @@
+/**
+ * @file wrapper.h
+ * @brief Header set exposed through powerblocks-sys.
+ *
+ * Every public header of the SDK along with the FreeRTOS kernel
+ * and FatFs headers it is built against. This is what bindgen reads
+ * when regenerating src/bindings.rs, and what the host layout
+ * probe compiles against.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#pragma once
+
+// FreeRTOS
+#include "FreeRTOS.h"
+#include "task.h"
+#include "queue.h"
+#include "semphr.h"
+#include "timers.h"
+#include "stream_buffer.h"
+
+// Core
+#include "powerblocks/core/system/system.h"
+#include "powerblocks/core/system/cpu.h"
+#include "powerblocks/core/system/exceptions.h"
+#include "powerblocks/core/system/syscall.h"
+#include "powerblocks/core/system/ipc.h"
+#include "powerblocks/core/system/gpio.h"
+
+#include "powerblocks/core/ios/ios.h"
+#include "powerblocks/core/ios/ios_settings.h"
+#include "powerblocks/core/ios/sdio.h"
+#include "powerblocks/core/ios/usb.h"
+#include "powerblocks/core/ios/isfs.h"
+
+#include "powerblocks/core/graphics/framebuffer.h"
+#include "powerblocks/core/graphics/video.h"
+#include "powerblocks/core/graphics/gx.h"
+
+#include "powerblocks/core/bluetooth/blerror.h"
+#include "powerblocks/core/bluetooth/hci.h"
+#include "powerblocks/core/bluetooth/l2cap.h"
+#include "powerblocks/core/bluetooth/bltootls.h"
+
+#include "powerblocks/core/utils/math/vec2.h"
+#include "powerblocks/core/utils/math/vec3.h"
+#include "powerblocks/core/utils/math/matrix3.h"
+#include "powerblocks/core/utils/math/matrix34.h"
+#include "powerblocks/core/utils/math/matrix4.h"
+#include "powerblocks/core/utils/console.h"
+#include "powerblocks/core/utils/crash_handler.h"
+#include "powerblocks/core/utils/fonts.h"
+#include "powerblocks/core/utils/log.h"
+
+// Input
+#include "powerblocks/input/wiimote/wiimote.h"
+#include "powerblocks/input/wiimote/wiimote_extension.h"
+#include "powerblocks/input/wiimote/wiimote_hid.h"
+#include "powerblocks/input/wiimote/wiimote_sys.h"
+
+// File System
+#include "ff.h"
+#include "diskio.h"
+#include "powerblocks/filesystem/sd.h"
+#include "powerblocks/filesystem/fatfs_port/sd_disk.h"
+#include "powerblocks/filesystem/usb_storage.h"
+#include "powerblocks/filesystem/fatfs_port/usb_storage_disk.h"
+#include "powerblocks/filesystem/vfs_fatfs.h"
+
+// Debugger
+#include "powerblocks/debugger/debugger.h"
@@ -0,0 +1,103 @@
+//! Drivers loaded by bltools, like `bluetooth_driver_t`.
+
+use core::ffi::{c_char, c_void, CStr};
+use core::ptr;
+
+use super::DeviceInfo;
+use crate::sys;
+
+/// The driver ID of the SDK's Wii Remote driver.
+pub const WIIMOTE_DRIVER_ID: u16 = sys::BLUETOOTH_DRIVER_ID_WIIMOTE as u16;
+
+/// A Bluetooth peripheral driver, registered with [`register`].
+///
+/// bltools asks each registered driver in turn whether it handles a device,
+/// and hands the device to the first that does. Everything here is called
+/// from the bltools task, so connecting and waiting on L2CAP is fine.
+///
+/// ```ignore
+/// struct Keyboard;
+///
+/// impl Driver for Keyboard {
+///     const ID: u16 = 0x100;
+///     type Instance = KeyboardSlot;
+///
+///     fn filter_device(_: &DeviceInfo, name: Option<&CStr>) -> bool {
+///         name == Some(c"Keyboard")
+///     }
+///     // ...
+/// }
+///
+/// bluetooth::register::<Keyboard>();
+/// ```
+pub trait Driver {
+    /// Unique to the driver. Zero is reserved, and [`WIIMOTE_DRIVER_ID`] is
+    /// taken by the SDK.
+    const ID: u16;
+
+    /// The state of a connected device.
+    type Instance: 'static;
+
+    /// Whether a device found by an inquiry is one this driver handles.
+    /// `name` is the device's remote name, when it was asked for.
+    fn filter_device(device: &DeviceInfo, name: Option<&CStr>) -> bool;
+
+    /// Whether a device asking to reconnect is one paired with this driver
+    /// before.
+    fn filter_paired_device(device: &DeviceInfo) -> bool;
+
+    /// Connects to a device found by an inquiry that passed
+    /// [`filter_device`](Self::filter_device), usually with
+    /// [`DeviceInfo::connect`]. Returns `None` if that fails.
+    fn initialize_new_device(device: &DeviceInfo) -> Option<&'static Self::Instance>;
+
+    /// Accepts a reconnecting device that passed
+    /// [`filter_paired_device`](Self::filter_paired_device), usually with
+    /// [`DeviceInfo::accept`]. Returns `None` if that fails, after
+    /// rejecting the request.
+    fn initialize_paired_device(device: &DeviceInfo) -> Option<&'static Self::Instance>;
+}
+
+/// Registers `D` with bltools, like `bltools_register_driver`.
+///
+/// Must come after [`initialize`](super::initialize). bltools logs an error
+/// and ignores the driver if one with the same ID is already registered.
+pub fn register<D: Driver>() {
+    const { assert!(D::ID != sys::BLUETOOTH_DRIVER_ID_INVALID as u16, "driver ID 0 is reserved") };
+
+    unsafe {
+        sys::bltools_register_driver(sys::bluetooth_driver_t {
+            driver_id: D::ID,
+            filter_device: Some(filter_device::<D>),
+            filter_paired_device: Some(filter_paired_device::<D>),
+            initialize_new_device: Some(initialize_new_device::<D>),
+            initialize_paired_device: Some(initialize_paired_device::<D>),
+        });
+    }
+}
+
+unsafe extern "C" fn filter_device<D: Driver>(device: *const sys::hci_discovered_device_info_t, name: *const c_char) -> bool {
+    let name = (!name.is_null()).then(|| CStr::from_ptr(name));
+    D::filter_device(DeviceInfo::from_ptr(device), name)
+}
+
+unsafe extern "C" fn filter_paired_device<D: Driver>(device: *const sys::hci_discovered_device_info_t) -> bool {
+    D::filter_paired_device(DeviceInfo::from_ptr(device))
+}
+
+unsafe extern "C" fn initialize_new_device<D: Driver>(device: *const sys::hci_discovered_device_info_t) -> *mut c_void {
+    instance_ptr(D::initialize_new_device(DeviceInfo::from_ptr(device)))
+}
+
+unsafe extern "C" fn initialize_paired_device<D: Driver>(device: *const sys::hci_discovered_device_info_t) -> *mut c_void {
+    instance_ptr(D::initialize_paired_device(DeviceInfo::from_ptr(device)))
+}
+
+/// bltools only stores the instance and checks it for null. References are
+/// never null, zero sized ones included, so every `Some` reads as success.
+fn instance_ptr<T>(instance: Option<&'static T>) -> *mut c_void {
+    match instance {
+        Some(instance) => ptr::from_ref(instance).cast_mut().cast(),
+        None => ptr::null_mut(),
+    }
+}
@@ -0,0 +1,71 @@
+//! Bluetooth error codes, from `blerror.h`.
+
+use crate::error::error_codes;
+use crate::sys;
+
+error_codes! {
+    /// An error returned by the HCI, L2CAP and bltools drivers.
+    ///
+    /// Codes not listed here are kept in [`Other`](Self::Other).
+    pub enum Error(i32) {
+        /// `BLERROR_RUNTIME`, the driver got into an unexpected state.
+        Runtime = sys::BLERROR_RUNTIME => "unexpected driver state",
+        /// `BLERROR_IOS_EXCEPTION`, IOS returned an error.
+        Ios = sys::BLERROR_IOS_EXCEPTION => "IOS error",
+        /// `BLERROR_FREERTOS`, a FreeRTOS call failed, likely from running out
+        /// of memory.
+        FreeRtos = sys::BLERROR_FREERTOS => "FreeRTOS error",
+        /// `BLERROR_ARGUMENT`, an argument was invalid.
+        InvalidArgument = sys::BLERROR_ARGUMENT => "invalid argument",
+        /// `BLERROR_HCI_REQUEST_ERR`, the controller rejected a request.
+        HciRequestFailed = sys::BLERROR_HCI_REQUEST_ERR => "HCI request failed",
+        /// `BLERROR_HCI_CONNECT_FAILED`, the device could not be connected to.
+        ConnectFailed = sys::BLERROR_HCI_CONNECT_FAILED => "connection failed",
+        /// `BLERROR_OUT_OF_MEMORY`.
+        OutOfMemory = sys::BLERROR_OUT_OF_MEMORY => "out of memory",
+        /// `BLERROR_TIMEOUT`, the device did not answer in time.
+        Timeout = sys::BLERROR_TIMEOUT => "timed out",
+        /// `BLERROR_L2CAP_SIGNAL_FAILED`, the device refused an L2CAP request.
+        SignalFailed = sys::BLERROR_L2CAP_SIGNAL_FAILED => "L2CAP request refused",
+        /// `BLERROR_L2CAP_ALREADY_OPEN`, the device or channel is already open.
+        AlreadyOpen = sys::BLERROR_L2CAP_ALREADY_OPEN => "already open",
+        /// `BLERROR_DRIVER_INITIALIZE_FAIL`, the driver did not take the device.
+        DriverInitializeFailed = sys::BLERROR_DRIVER_INITIALIZE_FAIL => "driver failed to initialize",
+        /// `BLERROR_NO_DRIVER_FOUND`, no registered driver accepts the device.
+        NoDriverFound = sys::BLERROR_NO_DRIVER_FOUND => "no driver found",
+    }
+    /// Any other negative value.
+    Other => "bluetooth error",
+}
+
+impl Error {
+    /// Splits a return value into a result.
+    pub const fn check(result: i32) -> Result<u32, Self> {
+        if result < 0 {
+            Err(Self::from_code(result))
+        } else {
+            Ok(result as u32)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn driver_codes_skip_the_unused_gap() {
+        assert_eq!(Error::from_code(-10), Error::AlreadyOpen);
+        assert_eq!(Error::from_code(-11), Error::Other(-11));
+        assert_eq!(Error::from_code(-12), Error::Other(-12));
+        assert_eq!(Error::from_code(-13), Error::DriverInitializeFailed);
+        assert_eq!(Error::from_code(-14), Error::NoDriverFound);
+    }
+
+    #[test]
+    fn check_reports_timeouts() {
+        assert_eq!(Error::check(0), Ok(0));
+        assert_eq!(Error::check(-8), Err(Error::Timeout));
+        assert_eq!(Error::Timeout.to_string(), "timed out (-8)");
+    }
+}
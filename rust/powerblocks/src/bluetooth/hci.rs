@@ -0,0 +1,96 @@
+//! Connecting to devices through the HCI driver.
+
+use super::Error;
+use crate::sys;
+
+/// A device found by an inquiry, or asking to connect, like
+/// `hci_discovered_device_info_t`.
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct DeviceInfo(sys::hci_discovered_device_info_t);
+
+impl DeviceInfo {
+    pub const fn from_raw(info: sys::hci_discovered_device_info_t) -> Self {
+        Self(info)
+    }
+
+    /// # Safety
+    /// `info` must be valid for `'a`.
+    pub(super) unsafe fn from_ptr<'a>(info: *const sys::hci_discovered_device_info_t) -> &'a Self {
+        &*info.cast()
+    }
+
+    pub const fn as_raw(&self) -> &sys::hci_discovered_device_info_t {
+        &self.0
+    }
+
+    /// The device's MAC address, as HCI reports it.
+    pub const fn address(&self) -> [u8; 6] {
+        self.0.address
+    }
+
+    pub const fn class_of_device(&self) -> u32 {
+        self.0.class_of_device
+    }
+
+    /// Whether a paired device is asking to reconnect, rather than having
+    /// been found by an inquiry.
+    pub const fn is_connection_request(&self) -> bool {
+        self.0.connection_request
+    }
+
+    /// Connects to a discovered device, like `hci_create_connection`.
+    pub fn connect(&self) -> Result<Handle, Error> {
+        let mut handle = 0;
+        Error::check(unsafe { sys::hci_create_connection(&self.0, &mut handle) })?;
+        Ok(Handle(handle))
+    }
+
+    /// Accepts a connection request, like `hci_accept_connection`.
+    ///
+    /// Reconnecting devices such as Wii Remotes expect us to become the
+    /// host, which `role_switch` asks for.
+    pub fn accept(&self, role_switch: bool) -> Result<Handle, Error> {
+        let mut handle = 0;
+        Error::check(unsafe { sys::hci_accept_connection(&self.0, role_switch, &mut handle) })?;
+        Ok(Handle(handle))
+    }
+
+    /// Turns a connection request down, like `hci_reject_connection`.
+    pub fn reject(&self, reason: RejectReason) -> Result<(), Error> {
+        Error::check(unsafe { sys::hci_reject_connection(&self.0, reason as sys::hci_reject_reason_t) })?;
+        Ok(())
+    }
+}
+
+/// Why a connection request is rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum RejectReason {
+    LimitedResources = sys::HCI_REJECT_REASON_LIMITED_RESOURCES,
+    SecurityReasons = sys::HCI_REJECT_REASON_SECURITY_REASONS,
+    UnacceptableAddress = sys::HCI_REJECT_REASON_UNACCEPTABLE_ADDRESS,
+}
+
+/// An HCI connection handle.
+///
+/// Connections are not closed on drop, as L2CAP keeps using the handle
+/// until the device disconnects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Handle(u16);
+
+impl Handle {
+    pub const fn from_raw(handle: u16) -> Self {
+        Self(handle)
+    }
+
+    pub const fn as_raw(self) -> u16 {
+        self.0
+    }
+
+    /// Disconnects from the device, like `hci_disconnect`.
+    pub fn disconnect(self) -> Result<(), Error> {
+        Error::check(unsafe { sys::hci_disconnect(self.0) })?;
+        Ok(())
+    }
+}
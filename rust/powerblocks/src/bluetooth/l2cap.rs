@@ -0,0 +1,310 @@
+//! L2CAP devices and channels.
+//!
+//! L2CAP multiplexes channels, which work a lot like sockets with a protocol
+//! each, over an HCI connection. The L2CAP driver keeps pointers to a
+//! device's state and channel buffers while it is open, so [`Device`] holds
+//! them inline and is used through `&'static` references, like
+//! [`Timer`](crate::rtos::Timer). Keep devices in a `static` or leak them.
+
+use core::cell::UnsafeCell;
+use core::ffi::{c_int, c_void};
+use core::mem::MaybeUninit;
+use core::ptr;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use super::{DeviceInfo, Error, Handle};
+use crate::sys;
+
+/// Size of the buffer every device has for its signal channel.
+pub const SIGNAL_BUFFER_SIZE: usize = sys::L2CAP_SIGNAL_CHANNEL_BUFFER_SIZE as usize;
+
+/// The channel L2CAP itself talks over, which is always open.
+const SIGNAL_CHANNEL: u16 = sys::L2CAP_CHANNEL_SIGNALS as u16;
+
+/// A channel a [`Device`] can open, or have opened by the device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelConfig {
+    /// The ID the device uses to talk to us on this channel.
+    pub sid: u16,
+    /// The protocol (PSM) the channel carries, such as `0x11` for HID
+    /// control.
+    pub protocol_id: u16,
+}
+
+impl ChannelConfig {
+    pub const fn new(sid: u16, protocol_id: u16) -> Self {
+        Self { sid, protocol_id }
+    }
+}
+
+/// An L2CAP channel, like `l2cap_channel_t`. Borrowed from its [`Device`].
+#[repr(transparent)]
+pub struct Channel(UnsafeCell<MaybeUninit<sys::l2cap_channel_t>>);
+
+// The L2CAP driver locks the channel's state itself.
+unsafe impl Sync for Channel {}
+
+impl Channel {
+    const fn new() -> Self {
+        Self(UnsafeCell::new(MaybeUninit::uninit()))
+    }
+
+    pub fn as_raw(&self) -> *mut sys::l2cap_channel_t {
+        self.0.get().cast()
+    }
+
+    /// Asks the device to open this channel, like `l2cap_open_channel`, and
+    /// waits until it is open and configured.
+    pub fn open(&self) -> Result<(), Error> {
+        let channel = self.as_raw();
+        Error::check(unsafe { sys::l2cap_open_channel((*channel).device.cast(), channel) })?;
+        Ok(())
+    }
+
+    /// Waits for the device to open this channel and for it to be
+    /// configured, as reconnecting devices open their channels themselves.
+    pub fn wait_open(&self) -> Result<(), Error> {
+        let flags = (sys::L2CAP_CHANNEL_STATUS_OPEN | sys::L2CAP_CHANNEL_STATUS_LOCAL_CONFIGURED) as u16;
+        Error::check(unsafe { sys::l2cap_wait_channel_status(self.as_raw(), flags, ptr::null_mut()) })?;
+        Ok(())
+    }
+
+    /// Sends one packet, like `l2cap_send_channel`.
+    ///
+    /// # Panics
+    /// If `data` is longer than 65535 bytes.
+    pub fn send(&self, data: &[u8]) -> Result<(), Error> {
+        let size = u16::try_from(data.len()).expect("L2CAP packets are at most 65535 bytes");
+        Error::check(unsafe { sys::l2cap_send_channel(self.as_raw(), data.as_ptr().cast(), size) })?;
+        Ok(())
+    }
+
+    /// Blocks until a packet arrives and copies it into `buffer`, like
+    /// `l2cap_receive_channel`.
+    ///
+    /// Returns the length of the packet. Packets longer than `buffer` are
+    /// cut short, so that length may be larger than `buffer`.
+    pub fn receive(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        let size = buffer.len().min(u16::MAX.into()) as u16;
+        let length = Error::check(unsafe { sys::l2cap_receive_channel(self.as_raw(), buffer.as_mut_ptr().cast(), size) })?;
+        Ok(length as usize)
+    }
+
+    /// The ID the device uses for this channel.
+    pub fn sid(&self) -> u16 {
+        unsafe { (*self.as_raw()).sid }
+    }
+}
+
+/// The signal channel followed by the others, in the one array
+/// `l2cap_open_device` wants.
+#[repr(C)]
+struct Channels<const N: usize> {
+    signal: Channel,
+    data: [Channel; N],
+}
+
+/// A connected device with `N` channels besides the signal channel, each
+/// buffering up to `B` bytes of received packets, like `l2cap_device_t`.
+///
+/// Handlers run on the L2CAP task. They must not block for long, and must
+/// not open or wait for channels, as the L2CAP task is what answers those.
+///
+/// ```ignore
+/// static DEVICE: Device<2> = Device::new([ChannelConfig::new(0x40, 0x11), ChannelConfig::new(0x41, 0x13)])
+///     .on_receive(report)
+///     .on_disconnect(|_, reason| log::info!("disconnected ({reason:#x})"));
+///
+/// fn report(device: &'static Device<2>, channel: usize) {
+///     let mut packet = [0; 32];
+///     if let Ok(length) = device.channel(channel).receive(&mut packet) {
+///         // ...
+///     }
+/// }
+///
+/// let handle = info.connect()?;
+/// DEVICE.open(handle, info)?;
+/// DEVICE.channel(0).open()?;
+/// DEVICE.channel(1).open()?;
+/// ```
+pub struct Device<const N: usize, const B: usize = 256> {
+    raw: UnsafeCell<MaybeUninit<sys::l2cap_device_t>>,
+    channels: Channels<N>,
+    signal_buffer: UnsafeCell<[u8; SIGNAL_BUFFER_SIZE]>,
+    buffers: UnsafeCell<[[u8; B]; N]>,
+    configs: [ChannelConfig; N],
+    on_receive: Option<fn(&'static Self, usize)>,
+    on_disconnect: Option<fn(&'static Self, u8)>,
+    initialized: AtomicBool,
+    open: AtomicBool,
+}
+
+unsafe impl<const N: usize, const B: usize> Send for Device<N, B> {}
+unsafe impl<const N: usize, const B: usize> Sync for Device<N, B> {}
+
+impl<const N: usize, const B: usize> Device<N, B> {
+    /// Creates a device with the given channels, which it opens, or which
+    /// the device opens, after [`open`](Self::open).
+    ///
+    /// # Panics
+    /// If `B` is not a power of two up to 65536, or a channel uses the
+    /// signal channel's ID.
+    pub const fn new(configs: [ChannelConfig; N]) -> Self {
+        assert!(B.is_power_of_two() && B <= 1 << 16, "L2CAP buffers must be a power of two up to 65536 bytes");
+        let mut i = 0;
+        while i < N {
+            assert!(configs[i].sid != SIGNAL_CHANNEL, "channel ID 1 is the signal channel");
+            i += 1;
+        }
+
+        Self {
+            raw: UnsafeCell::new(MaybeUninit::uninit()),
+            channels: Channels { signal: Channel::new(), data: [const { Channel::new() }; N] },
+            signal_buffer: UnsafeCell::new([0; SIGNAL_BUFFER_SIZE]),
+            buffers: UnsafeCell::new([[0; B]; N]),
+            configs,
+            on_receive: None,
+            on_disconnect: None,
+            initialized: AtomicBool::new(false),
+            open: AtomicBool::new(false),
+        }
+    }
+
+    /// Calls `handler` with the index of a channel when a packet arrives on
+    /// it, like `l2cap_set_channel_receive_event`.
+    pub const fn on_receive(mut self, handler: fn(&'static Self, usize)) -> Self {
+        self.on_receive = Some(handler);
+        self
+    }
+
+    /// Calls `handler` with the reason once the device has disconnected and
+    /// L2CAP has closed it, like `l2cap_set_disconnect_handler`.
+    pub const fn on_disconnect(mut self, handler: fn(&'static Self, u8)) -> Self {
+        self.on_disconnect = Some(handler);
+        self
+    }
+
+    pub fn as_raw(&self) -> *mut sys::l2cap_device_t {
+        self.raw.get().cast()
+    }
+
+    /// Sets up the channels and starts talking L2CAP to the device over
+    /// `handle`, like `l2cap_open_device`. Only the signal channel is open
+    /// to begin with.
+    ///
+    /// Anything still using the channels from an earlier connection must be
+    /// done with them first.
+    ///
+    /// # Panics
+    /// If the device is already open.
+    pub fn open(&'static self, handle: Handle, info: &DeviceInfo) -> Result<(), Error> {
+        assert!(!self.open.swap(true, Ordering::AcqRel), "L2CAP device is already open");
+
+        let device = self.as_raw();
+        let this = ptr::from_ref(self).cast_mut().cast();
+        unsafe {
+            sys::l2cap_initialize_channel(
+                device,
+                self.channels.signal.as_raw(),
+                SIGNAL_CHANNEL,
+                SIGNAL_CHANNEL,
+                0,
+                self.signal_buffer.get().cast(),
+                SIGNAL_BUFFER_SIZE as c_int,
+            );
+
+            let buffers = self.buffers.get().cast::<[u8; B]>();
+            for (i, (channel, config)) in self.channels.data.iter().zip(&self.configs).enumerate() {
+                let buffer = buffers.add(i).cast();
+                sys::l2cap_initialize_channel(device, channel.as_raw(), config.sid, 0, config.protocol_id, buffer, B as c_int);
+                sys::l2cap_set_channel_receive_event(channel.as_raw(), Some(Self::received), this);
+            }
+        }
+        self.initialized.store(true, Ordering::Release);
+
+        let address = info.address();
+        let channels = ptr::from_ref(&self.channels).cast_mut().cast();
+        let opened = unsafe { sys::l2cap_open_device(device, handle.as_raw(), address.as_ptr(), channels, N + 1) };
+        if let Err(error) = Error::check(opened) {
+            self.open.store(false, Ordering::Release);
+            return Err(error);
+        }
+
+        unsafe { sys::l2cap_set_disconnect_handler(device, Some(Self::disconnected), this) };
+        Ok(())
+    }
+
+    /// Stops talking L2CAP to the device, like `l2cap_close_device`. The HCI
+    /// connection stays up; disconnect it with [`Handle::disconnect`].
+    pub fn close(&'static self) {
+        if self.open.swap(false, Ordering::AcqRel) {
+            unsafe { sys::l2cap_close_device(self.as_raw()) };
+        }
+    }
+
+    /// Whether the device is open and has not disconnected.
+    pub fn is_open(&self) -> bool {
+        self.open.load(Ordering::Acquire)
+    }
+
+    /// The HCI connection of the last [`open`](Self::open).
+    ///
+    /// # Panics
+    /// If the device was never opened.
+    pub fn handle(&self) -> Handle {
+        assert!(self.initialized.load(Ordering::Acquire), "L2CAP device was never opened");
+        Handle::from_raw(unsafe { (*self.as_raw()).handle })
+    }
+
+    /// The channel created from `configs[index]`.
+    ///
+    /// # Panics
+    /// If the device was never opened, or `index` is out of bounds.
+    pub fn channel(&self, index: usize) -> &Channel {
+        assert!(self.initialized.load(Ordering::Acquire), "L2CAP device was never opened");
+        &self.channels.data[index]
+    }
+
+    unsafe extern "C" fn received(channel: *mut c_void, device: *mut c_void) {
+        let this = &*device.cast::<Self>();
+        let Some(handler) = this.on_receive else {
+            return;
+        };
+        if let Some(index) = this.channels.data.iter().position(|data| data.as_raw().cast() == channel) {
+            handler(this, index);
+        }
+    }
+
+    unsafe extern "C" fn disconnected(device: *mut c_void, reason: u8) {
+        let this = &*device.cast::<Self>();
+        this.open.store(false, Ordering::Release);
+        if let Some(handler) = this.on_disconnect {
+            handler(this, reason);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem;
+
+    use super::*;
+
+    #[test]
+    fn channels_are_one_array() {
+        let channels = Channels::<3> { signal: Channel::new(), data: [const { Channel::new() }; 3] };
+        assert_eq!(mem::size_of_val(&channels), mem::size_of::<[sys::l2cap_channel_t; 4]>());
+        let base = channels.signal.as_raw();
+        for (i, channel) in channels.data.iter().enumerate() {
+            assert_eq!(channel.as_raw(), base.wrapping_add(i + 1));
+        }
+    }
+
+    #[test]
+    fn builds_in_const() {
+        static DEVICE: Device<2, 64> =
+            Device::new([ChannelConfig::new(0x40, 0x11), ChannelConfig::new(0x41, 0x13)]).on_disconnect(|_, _| {});
+        assert!(!DEVICE.is_open());
+        assert!(DEVICE.on_disconnect.is_some() && DEVICE.on_receive.is_none());
+    }
+}
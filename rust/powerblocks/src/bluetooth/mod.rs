@@ -0,0 +1,52 @@
+//! Bluetooth peripheral drivers, on top of the SDK's HCI, L2CAP and bltools
+//! drivers.
+//!
+//! A [`Driver`] is registered with bltools, which offers it every device
+//! that is discovered or asks to reconnect. The driver connects over HCI,
+//! then talks to the device through an [`l2cap::Device`] and its channels.
+//!
+//! ```ignore
+//! bluetooth::initialize()?;
+//! bluetooth::register::<Keyboard>();
+//! bluetooth::begin_discovery(Duration::from_secs(10), 0)?;
+//! ```
+
+use core::time::Duration;
+
+use crate::rtos::ticks;
+use crate::sys;
+
+mod driver;
+mod error;
+mod hci;
+pub mod l2cap;
+
+pub use driver::{register, Driver, WIIMOTE_DRIVER_ID};
+pub use error::Error;
+pub use hci::{DeviceInfo, Handle, RejectReason};
+
+/// Starts HCI, L2CAP and bltools on the Wii's internal Bluetooth module,
+/// like `bltools_initialize`.
+pub fn initialize() -> Result<(), Error> {
+    Error::check(unsafe { sys::bltools_initialize() })?;
+    Ok(())
+}
+
+/// Looks for discoverable devices for `duration` and hands them to the
+/// registered drivers, like `bltools_begin_discovery`.
+///
+/// `duration` is clamped to 1.28 to 61.44 seconds. Discovery stops early
+/// after `responses` devices, unless that is zero.
+pub fn begin_discovery(duration: Duration, responses: u8) -> Result<(), Error> {
+    let lap = sys::HCI_INQUIRY_MODE_GENERAL_ACCESS;
+    Error::check(unsafe { sys::bltools_begin_discovery(lap, ticks(duration), responses) })?;
+    Ok(())
+}
+
+/// Whether devices may find us, and paired devices reconnect, like
+/// `hci_write_scan_enable`. Drivers for devices that reconnect on their own,
+/// like the Wii Remote, need `page_scan` on.
+pub fn set_scan(inquiry_scan: bool, page_scan: bool) -> Result<(), Error> {
+    Error::check(unsafe { sys::hci_write_scan_enable(inquiry_scan, page_scan) })?;
+    Ok(())
+}
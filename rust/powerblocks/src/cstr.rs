@@ -0,0 +1,75 @@
+//! Fixed size, NUL-terminated buffers for passing formatted text to C.
+
+use core::ffi::CStr;
+use core::fmt::{self, Write};
+
+/// Formats into a buffer of `N` bytes, dropping whatever does not fit.
+///
+/// Always leaves room for the terminator and cuts at character boundaries.
+/// Embedded NULs are replaced with spaces, as they would cut the string
+/// short on the C side.
+#[derive(Clone)]
+pub(crate) struct CStrBuffer<const N: usize> {
+    buffer: [u8; N],
+    length: usize,
+}
+
+impl<const N: usize> CStrBuffer<N> {
+    pub(crate) const fn new() -> Self {
+        Self { buffer: [0; N], length: 0 }
+    }
+
+    /// Formats `args` into a new buffer.
+    pub(crate) fn format(args: fmt::Arguments) -> Self {
+        let mut buffer = Self::new();
+        let _ = buffer.write_fmt(args);
+        buffer
+    }
+
+    pub(crate) fn as_c_str(&self) -> &CStr {
+        CStr::from_bytes_until_nul(&self.buffer).unwrap()
+    }
+}
+
+impl<const N: usize> fmt::Debug for CStrBuffer<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_c_str(), f)
+    }
+}
+
+impl<const N: usize> Write for CStrBuffer<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            if self.length + c.len_utf8() >= N {
+                break;
+            }
+            let c = if c == '\0' { ' ' } else { c };
+            c.encode_utf8(&mut self.buffer[self.length..]);
+            self.length += c.len_utf8();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats() {
+        let buffer = CStrBuffer::<32>::format(format_args!("{} + {} = {}", 1, 2, 3));
+        assert_eq!(buffer.as_c_str(), c"1 + 2 = 3");
+    }
+
+    #[test]
+    fn truncates_at_character_boundaries() {
+        let buffer = CStrBuffer::<8>::format(format_args!("{}", "é".repeat(10)));
+        assert_eq!(buffer.as_c_str(), c"ééé");
+    }
+
+    #[test]
+    fn replaces_terminators() {
+        let buffer = CStrBuffer::<8>::format(format_args!("a\0b"));
+        assert_eq!(buffer.as_c_str(), c"a b");
+    }
+}
@@ -0,0 +1,95 @@
+//! Shared shape of the error code enums.
+
+/// Declares an error enum over the integer codes of a C driver.
+///
+/// Each listed variant maps to one code and a description. Codes not
+/// listed are kept in the `Other` variant. The enum gets `from_code`,
+/// `code`, `Display` as "description (code)" and `core::error::Error`.
+macro_rules! error_codes {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident($code:ty) {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident = $value:expr => $description:literal,
+            )*
+        }
+        $(#[$other_meta:meta])*
+        Other => $other_description:literal $(,)?
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            $(#[$other_meta])*
+            Other($code),
+        }
+
+        impl $name {
+            /// The error for a code, [`Other`](Self::Other) if it is not
+            /// listed.
+            pub const fn from_code(code: $code) -> Self {
+                $(
+                    if code == $value {
+                        return Self::$variant;
+                    }
+                )*
+                Self::Other(code)
+            }
+
+            /// The code for this error.
+            pub const fn code(self) -> $code {
+                match self {
+                    $(Self::$variant => $value,)*
+                    Self::Other(code) => code,
+                }
+            }
+
+            const fn description(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $description,)*
+                    Self::Other(_) => $other_description,
+                }
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{} ({})", self.description(), self.code())
+            }
+        }
+
+        impl core::error::Error for $name {}
+    };
+}
+
+pub(crate) use error_codes;
+
+#[cfg(test)]
+mod tests {
+    error_codes! {
+        pub enum Error(i32) {
+            First = -1 => "first",
+            Second = -2 => "second",
+        }
+        Other => "other",
+    }
+
+    #[test]
+    fn codes_round_trip() {
+        for code in -4..0 {
+            assert_eq!(Error::from_code(code).code(), code);
+        }
+        assert_eq!(Error::from_code(-2), Error::Second);
+        assert_eq!(Error::from_code(-3), Error::Other(-3));
+    }
+
+    #[test]
+    fn display_includes_code() {
+        assert_eq!(Error::First.to_string(), "first (-1)");
+        assert_eq!(Error::Other(-3).to_string(), "other (-3)");
+    }
+}
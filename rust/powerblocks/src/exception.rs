@@ -0,0 +1,454 @@
+//! Handling interrupts and faults, like `exceptions_install_irq` and
+//! `exceptions_install_fault_handler`.
+//!
+//! Handlers are kept in `&'static` references, so they can be closures
+//! kept in a `static` or leaked. They run in the exception with external
+//! interrupts disabled, so they must not block, and use the `_from_isr`
+//! calls of [`rtos`](crate::rtos) to wake tasks.
+//!
+//! Logging takes a lock, so record what happened and report it from a task:
+//!
+//! ```ignore
+//! static LAST_MISALIGNED: AtomicU32 = AtomicU32::new(0);
+//!
+//! fn skip_misaligned(_: Fault, context: &mut Context) -> FaultAction {
+//!     LAST_MISALIGNED.store(context.dar(), Ordering::Relaxed);
+//!     context.skip_instruction();
+//!     FaultAction::Resume
+//! }
+//!
+//! exception::set_fault_handler(Fault::Alignment, &skip_misaligned);
+//! ```
+
+use core::cell::UnsafeCell;
+use core::ffi::c_int;
+use core::fmt;
+use core::ptr;
+
+use crate::interrupt;
+use crate::sys;
+
+/// Handlers indexed by IRQ or fault type. Only touched with interrupts
+/// disabled, which they always are in exceptions.
+struct Handlers<T: ?Sized + 'static, const N: usize>([UnsafeCell<Option<&'static T>>; N]);
+
+unsafe impl<T: ?Sized + Sync, const N: usize> Sync for Handlers<T, N> {}
+
+impl<T: ?Sized, const N: usize> Handlers<T, N> {
+    const fn new() -> Self {
+        Self([const { UnsafeCell::new(None) }; N])
+    }
+
+    /// Swaps the handler at `index`, with interrupts disabled around it and
+    /// `install`.
+    fn set(&self, index: usize, handler: Option<&'static T>, install: impl FnOnce()) {
+        interrupt::free(|| {
+            unsafe { *self.0[index].get() = handler };
+            install();
+        });
+    }
+
+    /// # Safety
+    /// Interrupts must be disabled.
+    unsafe fn get(&self, index: usize) -> Option<&'static T> {
+        *self.0.get(index)?.get()
+    }
+}
+
+/// The interrupt sources of the processor interface, like
+/// `exception_irq_type_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum Irq {
+    GpRuntime = sys::EXCEPTION_IRQ_TYPE_GP_RUNTIME,
+    ResetSwitch = sys::EXCEPTION_IRQ_TYPE_RESET_SWITCH,
+    Dvd = sys::EXCEPTION_IRQ_TYPE_DVD,
+    Serial = sys::EXCEPTION_IRQ_TYPE_SERIAL,
+    Exi = sys::EXCEPTION_IRQ_TYPE_EXI,
+    Streaming = sys::EXCEPTION_IRQ_TYPE_STREAMING,
+    Dsp = sys::EXCEPTION_IRQ_TYPE_DSP,
+    Memory = sys::EXCEPTION_IRQ_TYPE_MEMORY,
+    Video = sys::EXCEPTION_IRQ_TYPE_VIDEO,
+    PeToken = sys::EXCEPTION_IRQ_TYPE_PE_TOKEN,
+    PeFinish = sys::EXCEPTION_IRQ_TYPE_PE_FINISH,
+    Fifo = sys::EXCEPTION_IRQ_TYPE_FIFO,
+    Debugger = sys::EXCEPTION_IRQ_TYPE_DEBUGGER,
+    Hsp = sys::EXCEPTION_IRQ_TYPE_HSP,
+    Ipc = sys::EXCEPTION_IRQ_TYPE_IPC,
+}
+
+impl Irq {
+    const ALL: [Self; sys::EXCEPTION_IRQ_COUNT as usize] = [
+        Self::GpRuntime,
+        Self::ResetSwitch,
+        Self::Dvd,
+        Self::Serial,
+        Self::Exi,
+        Self::Streaming,
+        Self::Dsp,
+        Self::Memory,
+        Self::Video,
+        Self::PeToken,
+        Self::PeFinish,
+        Self::Fifo,
+        Self::Debugger,
+        Self::Hsp,
+        Self::Ipc,
+    ];
+
+    pub const fn from_raw(raw: sys::exception_irq_type_t) -> Option<Self> {
+        if raw < sys::EXCEPTION_IRQ_COUNT {
+            Some(Self::ALL[raw as usize])
+        } else {
+            None
+        }
+    }
+
+    pub const fn as_raw(self) -> sys::exception_irq_type_t {
+        self as sys::exception_irq_type_t
+    }
+}
+
+type IrqHandler = dyn Fn(Irq) + Sync;
+
+static IRQ_HANDLERS: Handlers<IrqHandler, { sys::EXCEPTION_IRQ_COUNT as usize }> = Handlers::new();
+
+unsafe extern "C" fn dispatch_irq(raw: sys::exception_irq_type_t) {
+    if let (Some(irq), Some(handler)) = (Irq::from_raw(raw), IRQ_HANDLERS.get(raw as usize)) {
+        handler(irq);
+    }
+}
+
+/// Calls `handler` whenever `irq` is raised, and enables it, like
+/// `exceptions_install_irq`.
+///
+/// This replaces the handler for `irq`, including one an SDK driver
+/// installed, such as the video driver's for [`Irq::Video`]. The handler
+/// must acknowledge the interrupt at its source, or it is raised again as
+/// soon as it returns.
+pub fn set_irq_handler(irq: Irq, handler: &'static IrqHandler) {
+    IRQ_HANDLERS.set(irq as usize, Some(handler), || unsafe {
+        sys::exceptions_install_irq(Some(dispatch_irq), irq.as_raw());
+    });
+}
+
+/// Removes the handler for `irq` and disables it.
+pub fn remove_irq_handler(irq: Irq) {
+    IRQ_HANDLERS.set(irq as usize, None, || unsafe {
+        sys::exceptions_install_irq(None, irq.as_raw());
+    });
+}
+
+/// Exceptions raised by the running code that can be hooked, like
+/// `exception_fault_type_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum Fault {
+    /// A load or store to an address that is not mapped. The address is in
+    /// [`Context::dar`].
+    Dsi = sys::EXCEPTION_FAULT_TYPE_DSI,
+    /// A jump to an address that is not mapped.
+    Isi = sys::EXCEPTION_FAULT_TYPE_ISI,
+    /// A misaligned access the CPU cannot do, such as a floating point or
+    /// `lmw`/`stmw` one. The address is in [`Context::dar`].
+    Alignment = sys::EXCEPTION_FAULT_TYPE_ALIGNMENT,
+    /// An illegal or privileged instruction, or a trap.
+    Program = sys::EXCEPTION_FAULT_TYPE_PROGRAM,
+    /// A floating point instruction with the FPU turned off.
+    FpuUnavailable = sys::EXCEPTION_FAULT_TYPE_FPU_UNAVAILABLE,
+}
+
+impl Fault {
+    const ALL: [Self; sys::EXCEPTION_FAULT_COUNT as usize] =
+        [Self::Dsi, Self::Isi, Self::Alignment, Self::Program, Self::FpuUnavailable];
+
+    pub const fn from_raw(raw: sys::exception_fault_type_t) -> Option<Self> {
+        if raw < sys::EXCEPTION_FAULT_COUNT {
+            Some(Self::ALL[raw as usize])
+        } else {
+            None
+        }
+    }
+
+    pub const fn as_raw(self) -> sys::exception_fault_type_t {
+        self as sys::exception_fault_type_t
+    }
+}
+
+/// What to do once a fault handler returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FaultAction {
+    /// Return from the exception with the registers in the [`Context`],
+    /// which retries the faulting instruction unless `srr0` was moved.
+    Resume,
+    /// Report the fault through `crash_handler_bug_check`, as if there was
+    /// no handler.
+    Crash,
+}
+
+type FaultHandler = dyn Fn(Fault, &mut Context) -> FaultAction + Sync;
+
+static FAULT_HANDLERS: Handlers<FaultHandler, { sys::EXCEPTION_FAULT_COUNT as usize }> = Handlers::new();
+
+unsafe extern "C" fn dispatch_fault(raw: sys::exception_fault_type_t, context: *mut sys::exception_context_t) -> c_int {
+    match (Fault::from_raw(raw), FAULT_HANDLERS.get(raw as usize)) {
+        (Some(fault), Some(handler)) => (handler(fault, Context::from_raw(context)) == FaultAction::Resume) as c_int,
+        _ => 0,
+    }
+}
+
+/// Calls `handler` on `fault` before the crash handler, like
+/// `exceptions_install_fault_handler`.
+///
+/// The handler gets the registers of the code that faulted, and can change
+/// them to recover. A fault in the handler itself calls it again.
+pub fn set_fault_handler(fault: Fault, handler: &'static FaultHandler) {
+    FAULT_HANDLERS.set(fault as usize, Some(handler), || unsafe {
+        sys::exceptions_install_fault_handler(Some(dispatch_fault), fault.as_raw());
+    });
+}
+
+/// Removes the handler for `fault`, so it goes straight to the crash
+/// handler.
+pub fn remove_fault_handler(fault: Fault) {
+    FAULT_HANDLERS.set(fault as usize, None, || unsafe {
+        sys::exceptions_install_fault_handler(None, fault.as_raw());
+    });
+}
+
+/// The registers saved when an exception was taken, like
+/// `exception_context_t`.
+///
+/// They are restored when the exception returns, apart from `r1`.
+#[repr(transparent)]
+pub struct Context(sys::exception_context_t);
+
+impl Context {
+    /// # Safety
+    /// `raw` must point to a saved context that is not otherwise used for
+    /// `'a`.
+    pub unsafe fn from_raw<'a>(raw: *mut sys::exception_context_t) -> &'a mut Self {
+        &mut *raw.cast()
+    }
+
+    pub fn as_raw(&mut self) -> *mut sys::exception_context_t {
+        &mut self.0
+    }
+
+    /// The saved words from `stack_frame` to `r31`: `r1`, `r0`, then `r2`
+    /// to `r31`.
+    fn words(&self) -> &[u32; 32] {
+        // `exception_context_t` starts with these 32 words, without
+        // padding.
+        unsafe { &*ptr::addr_of!(self.0.stack_frame).cast() }
+    }
+
+    fn words_mut(&mut self) -> &mut [u32; 32] {
+        unsafe { &mut *ptr::addr_of_mut!(self.0.stack_frame).cast() }
+    }
+
+    /// Where general purpose register `n` is in [`words`](Self::words).
+    const fn word(n: usize) -> usize {
+        match n {
+            0 => 1,
+            1 => 0,
+            n => n,
+        }
+    }
+
+    /// General purpose register `n`. `r1`, the stack pointer, is the
+    /// physical address the exception saved it as.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is not below 32.
+    pub fn gpr(&self, n: usize) -> u32 {
+        assert!(n < 32, "there are only 32 general purpose registers");
+        self.words()[Self::word(n)]
+    }
+
+    /// Sets general purpose register `n`.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is not below 32, or is 1, as `r1` is not restored.
+    pub fn set_gpr(&mut self, n: usize, value: u32) {
+        assert!(n < 32, "there are only 32 general purpose registers");
+        assert!(n != 1, "r1 is not restored from the context");
+        self.words_mut()[Self::word(n)] = value;
+    }
+
+    /// Floating point register `n`, as a double.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is not below 32.
+    pub fn fpr(&self, n: usize) -> f64 {
+        self.0.f[n]
+    }
+
+    /// # Panics
+    ///
+    /// If `n` is not below 32.
+    pub fn set_fpr(&mut self, n: usize, value: f64) {
+        self.0.f[n] = value;
+    }
+
+    /// `FPSCR`, the floating point status and control register.
+    pub fn fpscr(&self) -> u32 {
+        self.0.ffs as u32
+    }
+
+    pub fn set_fpscr(&mut self, value: u32) {
+        self.0.ffs = value.into();
+    }
+
+    /// Graphics quantization register `n`, used by the paired single loads
+    /// and stores.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is not below 8.
+    pub fn gqr(&self, n: usize) -> u32 {
+        self.gqrs()[n]
+    }
+
+    /// # Panics
+    ///
+    /// If `n` is not below 8.
+    pub fn set_gqr(&mut self, n: usize, value: u32) {
+        self.gqrs_mut()[n] = value;
+    }
+
+    fn gqrs(&self) -> &[u32; 8] {
+        // `gqr0` to `gqr7` are next to each other.
+        unsafe { &*ptr::addr_of!(self.0.gqr0).cast() }
+    }
+
+    fn gqrs_mut(&mut self) -> &mut [u32; 8] {
+        unsafe { &mut *ptr::addr_of_mut!(self.0.gqr0).cast() }
+    }
+
+    /// `SRR0`, the address execution resumes at. For faults, the
+    /// instruction that faulted.
+    pub fn srr0(&self) -> u32 {
+        self.0.srr0
+    }
+
+    pub fn set_srr0(&mut self, value: u32) {
+        self.0.srr0 = value;
+    }
+
+    /// `SRR1`, the `MSR` restored when the exception returns, with some
+    /// bits describing the fault.
+    pub fn srr1(&self) -> u32 {
+        self.0.srr1
+    }
+
+    pub fn set_srr1(&mut self, value: u32) {
+        self.0.srr1 = value;
+    }
+
+    /// `DAR`, the address a DSI or alignment fault accessed.
+    pub fn dar(&self) -> u32 {
+        self.0.dar
+    }
+
+    pub fn cr(&self) -> u32 {
+        self.0.cr
+    }
+
+    pub fn set_cr(&mut self, value: u32) {
+        self.0.cr = value;
+    }
+
+    pub fn lr(&self) -> u32 {
+        self.0.lr
+    }
+
+    pub fn set_lr(&mut self, value: u32) {
+        self.0.lr = value;
+    }
+
+    pub fn ctr(&self) -> u32 {
+        self.0.ctr
+    }
+
+    pub fn set_ctr(&mut self, value: u32) {
+        self.0.ctr = value;
+    }
+
+    pub fn xer(&self) -> u32 {
+        self.0.xer
+    }
+
+    pub fn set_xer(&mut self, value: u32) {
+        self.0.xer = value;
+    }
+
+    /// Resumes after the instruction at `srr0` instead of retrying it.
+    pub fn skip_instruction(&mut self) {
+        self.0.srr0 = self.0.srr0.wrapping_add(4);
+    }
+}
+
+/// A register, printed in hex.
+struct Hex(u32);
+
+impl fmt::Debug for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#010x}", self.0)
+    }
+}
+
+impl fmt::Debug for Context {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        struct Gprs<'a>(&'a Context);
+
+        impl fmt::Debug for Gprs<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries((0..32).map(|n| Hex(self.0.gpr(n)))).finish()
+            }
+        }
+
+        f.debug_struct("Context")
+            .field("gpr", &Gprs(self))
+            .field("srr0", &Hex(self.srr0()))
+            .field("srr1", &Hex(self.srr1()))
+            .field("dar", &Hex(self.dar()))
+            .field("lr", &Hex(self.lr()))
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gprs_skip_the_stack_frame() {
+        let mut raw: sys::exception_context_t = unsafe { core::mem::zeroed() };
+        raw.stack_frame = 0x0100_0000;
+        raw.r0 = 7;
+        raw.r2 = 2;
+        raw.r31 = 31;
+
+        let context = unsafe { Context::from_raw(&mut raw) };
+        assert_eq!([context.gpr(0), context.gpr(1), context.gpr(2), context.gpr(31)], [7, 0x0100_0000, 2, 31]);
+        context.set_gpr(3, 0xdead);
+        context.set_gqr(7, 5);
+        context.skip_instruction();
+        assert_eq!((raw.r3, raw.gqr7, raw.srr0), (0xdead, 5, 4));
+    }
+
+    #[test]
+    fn types_round_trip() {
+        for raw in 0..sys::EXCEPTION_IRQ_COUNT {
+            assert_eq!(Irq::from_raw(raw).map(Irq::as_raw), Some(raw));
+        }
+        for raw in 0..sys::EXCEPTION_FAULT_COUNT {
+            assert_eq!(Fault::from_raw(raw).map(Fault::as_raw), Some(raw));
+        }
+        assert_eq!(Irq::from_raw(sys::EXCEPTION_IRQ_COUNT), None);
+    }
+}
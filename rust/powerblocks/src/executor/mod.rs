@@ -0,0 +1,217 @@
+//! A small executor for running futures on a FreeRTOS task.
+//!
+//! The executor polls futures from the task that calls
+//! [`Executor::block_on`] and sleeps on a task notification while none of
+//! them can make progress. Wakers may be used from other tasks, timer
+//! callbacks and interrupt handlers, which is how IOS completions
+//! ([`crate::ios`]), [`sleep`] and [`vsync`] resume their futures.
+//!
+//! ```ignore
+//! static EXECUTOR: Executor = Executor::new();
+//!
+//! EXECUTOR.spawn(async {
+//!     loop {
+//!         executor::vsync().await;
+//!         draw();
+//!     }
+//! });
+//! EXECUTOR.block_on(async {
+//!     let device = IosDevice::open_async(c"/dev/stm/immediate", Mode::None).await?;
+//!     executor::sleep(Duration::from_secs(1)).await;
+//! });
+//! ```
+
+use core::future::Future;
+use core::pin::pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+use crate::interrupt;
+use crate::rtos::Notification;
+
+mod sleep;
+mod vsync;
+mod wait_list;
+
+pub use sleep::{sleep, Sleep};
+pub use vsync::{vsync, VSync};
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, sync::Arc, task::Wake, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::cell::UnsafeCell;
+#[cfg(feature = "alloc")]
+use core::pin::Pin;
+
+#[cfg(feature = "alloc")]
+use crate::rtos::Mutex;
+
+/// Runs futures on the task that calls [`block_on`](Self::block_on).
+///
+/// Executors are used through `&'static` references, as their wakers point
+/// back at them. Keep them in a `static`.
+pub struct Executor {
+    notification: Notification,
+    running: AtomicBool,
+    main_woken: AtomicBool,
+    #[cfg(feature = "alloc")]
+    spawned: Mutex<Vec<Arc<Spawned>>>,
+}
+
+impl Executor {
+    pub const fn new() -> Self {
+        Self {
+            notification: Notification::new(),
+            running: AtomicBool::new(false),
+            main_woken: AtomicBool::new(false),
+            #[cfg(feature = "alloc")]
+            spawned: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Wakes the task running the executor. Interrupts are assumed to be
+    /// masked only in interrupt handlers and critical sections, where the
+    /// `_from_isr` variant is safe to use.
+    fn notify(&self) {
+        if interrupt::are_enabled() {
+            self.notification.notify();
+        } else {
+            self.notification.notify_from_isr();
+        }
+    }
+
+    /// Polls `future` until it completes, together with any spawned
+    /// futures, and returns its output. Spawned futures that are still
+    /// pending stay queued for the next call.
+    ///
+    /// # Panics
+    /// If the executor is already running, including when called from one
+    /// of its own futures.
+    pub fn block_on<F: Future>(&'static self, future: F) -> F::Output {
+        assert!(!self.running.swap(true, Ordering::Acquire), "Executor is already running");
+
+        let mut future = pin!(future);
+        let waker = main_waker(self);
+        let mut cx = Context::from_waker(&waker);
+        #[cfg(feature = "alloc")]
+        let mut tasks = Vec::new();
+
+        self.main_woken.store(true, Ordering::Relaxed);
+        let output = loop {
+            if self.main_woken.swap(false, Ordering::AcqRel) {
+                if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                    break output;
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            self.poll_spawned(&mut tasks);
+
+            // Wakes that came in while polling left the notification set,
+            // so this returns straight away for them.
+            self.notification.wait();
+        };
+
+        #[cfg(feature = "alloc")]
+        self.spawned.lock().append(&mut tasks);
+        self.running.store(false, Ordering::Release);
+        output
+    }
+
+    /// Runs spawned futures forever.
+    pub fn run(&'static self) -> ! {
+        self.block_on(core::future::pending())
+    }
+
+    /// Queues `future` to run alongside the one passed to
+    /// [`block_on`](Self::block_on). Can be called from any task, or from
+    /// the executor's own futures.
+    #[cfg(feature = "alloc")]
+    pub fn spawn(&'static self, future: impl Future<Output = ()> + Send + 'static) {
+        let task = Arc::new(Spawned {
+            executor: self,
+            woken: AtomicBool::new(true),
+            future: UnsafeCell::new(Some(Box::pin(future))),
+        });
+        self.spawned.lock().push(task);
+        self.notify();
+    }
+
+    /// Picks up newly spawned futures and polls the woken ones, dropping
+    /// those that complete.
+    #[cfg(feature = "alloc")]
+    fn poll_spawned(&self, tasks: &mut Vec<Arc<Spawned>>) {
+        tasks.append(&mut self.spawned.lock());
+        tasks.retain(|task| !task.woken.swap(false, Ordering::AcqRel) || unsafe { task.poll() }.is_pending());
+    }
+}
+
+impl Default for Executor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The waker for the future passed to `block_on`. It only sets a flag, so
+/// it needs no reference count.
+fn main_waker(executor: &'static Executor) -> Waker {
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(
+        |data| RawWaker::new(data, &VTABLE),
+        wake,
+        wake,
+        |_| {},
+    );
+
+    unsafe fn wake(data: *const ()) {
+        let executor = &*data.cast::<Executor>();
+        executor.main_woken.store(true, Ordering::Release);
+        executor.notify();
+    }
+
+    unsafe { Waker::from_raw(RawWaker::new(core::ptr::from_ref(executor).cast(), &VTABLE)) }
+}
+
+/// A future queued with [`Executor::spawn`], shared with its wakers.
+#[cfg(feature = "alloc")]
+struct Spawned {
+    executor: &'static Executor,
+    woken: AtomicBool,
+    future: UnsafeCell<Option<Pin<Box<dyn Future<Output = ()> + Send>>>>,
+}
+
+// The future is only touched by the task running the executor.
+#[cfg(feature = "alloc")]
+unsafe impl Sync for Spawned {}
+
+#[cfg(feature = "alloc")]
+impl Spawned {
+    /// Polls the future, dropping it once it completes.
+    ///
+    /// # Safety
+    /// Only the task running the executor may call this.
+    unsafe fn poll(self: &Arc<Self>) -> Poll<()> {
+        let future = &mut *self.future.get();
+        let Some(pinned) = future.as_mut() else {
+            return Poll::Ready(());
+        };
+
+        let waker = Waker::from(self.clone());
+        let poll = pinned.as_mut().poll(&mut Context::from_waker(&waker));
+        if poll.is_ready() {
+            *future = None;
+        }
+        poll
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Wake for Spawned {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::Release);
+        self.executor.notify();
+    }
+}
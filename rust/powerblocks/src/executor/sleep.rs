@@ -0,0 +1,67 @@
+//! Waiting for a duration without blocking the executor's task.
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+use super::wait_list::{WaitList, Waiter};
+use crate::rtos::{ticks, Timer};
+use crate::sys;
+
+/// Sleeping futures, keyed by the tick they wake at.
+static SLEEPERS: WaitList = WaitList::new();
+
+/// One-shot timer that checks [`SLEEPERS`] on the next tick. It restarts
+/// itself while anything is still sleeping, and is started again by the
+/// next sleeper once it has stopped.
+static TICKER: Timer<fn()> = Timer::new(c"sleep", Duration::from_millis(1), false, tick);
+
+fn tick() {
+    let now = unsafe { sys::xTaskGetTickCount() };
+    SLEEPERS.wake(|deadline| deadline <= now);
+    if !SLEEPERS.is_empty() {
+        // Queued from the timer task itself, so this cannot block.
+        TICKER.start(Duration::ZERO);
+    }
+}
+
+/// Completes once `duration` has passed, rounded up to whole ticks.
+///
+/// The ticks are counted from when `sleep` is called, not from the first
+/// poll.
+pub fn sleep(duration: Duration) -> Sleep {
+    let now = unsafe { sys::xTaskGetTickCount() };
+    let deadline = now.saturating_add(ticks(duration));
+    Sleep { deadline, waiter: Waiter::new(deadline) }
+}
+
+/// Future returned by [`sleep`].
+pub struct Sleep {
+    deadline: sys::TickType_t,
+    waiter: Waiter,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let deadline = self.deadline;
+        if unsafe { sys::xTaskGetTickCount() } >= deadline {
+            SLEEPERS.remove(&self.waiter);
+            return Poll::Ready(());
+        }
+
+        let waiter = unsafe { self.as_ref().map_unchecked(|sleep| &sleep.waiter) };
+        if SLEEPERS.register(waiter, cx.waker()) && !TICKER.is_active() {
+            TICKER.start(Duration::MAX);
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Sleep {
+    fn drop(&mut self) {
+        SLEEPERS.remove(&self.waiter);
+    }
+}
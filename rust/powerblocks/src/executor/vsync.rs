@@ -0,0 +1,107 @@
+//! Waiting for the vertical retrace without blocking the executor's task.
+
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::task::{Context, Poll};
+
+use super::wait_list::{WaitList, Waiter};
+use crate::interrupt;
+use crate::sys;
+
+/// Retraces seen since the hook was installed.
+static FRAMES: AtomicU32 = AtomicU32::new(0);
+
+/// Futures waiting for a retrace, keyed by the frame they wait for.
+static WAITERS: WaitList = WaitList::new();
+
+/// The retrace callback that was installed before [`retrace`], still called
+/// after it. Only touched with interrupts disabled.
+static mut PREVIOUS: sys::video_retrace_callback_t = None;
+
+unsafe extern "C" fn retrace() {
+    let frame = FRAMES.fetch_add(1, Ordering::AcqRel).wrapping_add(1);
+    WAITERS.wake(|target| reached(frame, target as u32));
+
+    if let Some(previous) = PREVIOUS {
+        previous();
+    }
+}
+
+/// Whether `frame` is at or after `target`, allowing for the counter
+/// wrapping.
+fn reached(frame: u32, target: u32) -> bool {
+    frame.wrapping_sub(target) as i32 >= 0
+}
+
+/// Installs [`retrace`] as the retrace callback, keeping whatever was
+/// there before. Done again if something else replaced it since.
+fn install() {
+    interrupt::free(|| unsafe {
+        let current = sys::video_get_retrace_callback();
+        if current.map(|f| f as *const ()) != Some(retrace as *const ()) {
+            PREVIOUS = current;
+            sys::video_set_retrace_callback(Some(retrace));
+        }
+    });
+}
+
+/// Completes at the next vertical retrace, like `video_wait_vsync`.
+///
+/// The first poll installs a retrace callback through
+/// `video_set_retrace_callback`, which calls any callback set before it.
+pub fn vsync() -> VSync {
+    VSync { target: None, waiter: Waiter::new(0) }
+}
+
+/// Future returned by [`vsync`].
+pub struct VSync {
+    target: Option<u32>,
+    waiter: Waiter,
+}
+
+impl Future for VSync {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = unsafe { self.get_unchecked_mut() };
+        let target = match this.target {
+            Some(target) => target,
+            None => {
+                install();
+                let target = FRAMES.load(Ordering::Acquire).wrapping_add(1);
+                this.target = Some(target);
+                this.waiter = Waiter::new(target.into());
+                target
+            }
+        };
+
+        // Registering before checking means a retrace in between cannot be
+        // missed.
+        WAITERS.register(unsafe { Pin::new_unchecked(&this.waiter) }, cx.waker());
+        if reached(FRAMES.load(Ordering::Acquire), target) {
+            WAITERS.remove(&this.waiter);
+            return Poll::Ready(());
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for VSync {
+    fn drop(&mut self) {
+        WAITERS.remove(&self.waiter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reached_wraps() {
+        assert!(reached(5, 5));
+        assert!(!reached(4, 5));
+        assert!(reached(1, u32::MAX));
+        assert!(!reached(u32::MAX, 1));
+    }
+}
@@ -0,0 +1,155 @@
+//! Intrusive list of futures waiting on an interrupt or the timer task.
+
+use core::cell::{Cell, UnsafeCell};
+use core::marker::PhantomPinned;
+use core::pin::Pin;
+use core::ptr;
+use core::task::Waker;
+
+use crate::interrupt;
+
+/// Entry in a [`WaitList`], kept inside a pinned future.
+pub(crate) struct Waiter {
+    inner: UnsafeCell<WaiterInner>,
+    _pin: PhantomPinned,
+}
+
+struct WaiterInner {
+    waker: Option<Waker>,
+    key: u64,
+    next: *mut Waiter,
+    linked: bool,
+}
+
+impl Waiter {
+    pub(crate) const fn new(key: u64) -> Self {
+        Self {
+            inner: UnsafeCell::new(WaiterInner { waker: None, key, next: ptr::null_mut(), linked: false }),
+            _pin: PhantomPinned,
+        }
+    }
+}
+
+/// List of [`Waiter`]s. Only touched with interrupts disabled.
+pub(crate) struct WaitList {
+    head: Cell<*mut Waiter>,
+}
+
+unsafe impl Sync for WaitList {}
+
+impl WaitList {
+    pub(crate) const fn new() -> Self {
+        Self { head: Cell::new(ptr::null_mut()) }
+    }
+
+    /// Adds `waiter` if it is not in the list yet, and stores `waker` to be
+    /// woken. Returns whether it was added.
+    ///
+    /// The waiter's owner must call [`remove`](Self::remove) before dropping it.
+    pub(crate) fn register(&self, waiter: Pin<&Waiter>, waker: &Waker) -> bool {
+        let waiter = ptr::from_ref(waiter.get_ref()).cast_mut();
+        // A replaced waker is dropped outside the critical section, as
+        // dropping it may run arbitrary code.
+        let (added, _replaced) = interrupt::free(|| unsafe {
+            let inner = &mut *(*waiter).inner.get();
+            let replaced = match &inner.waker {
+                Some(current) if current.will_wake(waker) => None,
+                _ => inner.waker.replace(waker.clone()),
+            };
+
+            if inner.linked {
+                return (false, replaced);
+            }
+            inner.next = self.head.get();
+            inner.linked = true;
+            self.head.set(waiter);
+            (true, replaced)
+        });
+        added
+    }
+
+    /// Takes `waiter` out of the list if it is in it.
+    pub(crate) fn remove(&self, waiter: &Waiter) {
+        let waiter = ptr::from_ref(waiter).cast_mut();
+        let _waker = interrupt::free(|| unsafe {
+            let inner = &mut *(*waiter).inner.get();
+            if !inner.linked {
+                return None;
+            }
+
+            let mut link = self.head.as_ptr();
+            while *link != waiter {
+                link = ptr::addr_of_mut!((*(**link).inner.get()).next);
+            }
+            *link = inner.next;
+            inner.linked = false;
+            inner.waker.take()
+        });
+    }
+
+    /// Takes the first waiter whose key matches `expired` out of the list and
+    /// returns its waker, to be woken outside the critical section.
+    pub(crate) fn pop(&self, expired: impl Fn(u64) -> bool) -> Option<Waker> {
+        interrupt::free(|| unsafe {
+            let mut link = self.head.as_ptr();
+            while !(*link).is_null() {
+                let inner = (**link).inner.get();
+                if expired((*inner).key) {
+                    *link = (*inner).next;
+                    (*inner).linked = false;
+                    return (*inner).waker.take();
+                }
+                link = ptr::addr_of_mut!((*inner).next);
+            }
+            None
+        })
+    }
+
+    /// Wakes every waiter whose key matches `expired`.
+    pub(crate) fn wake(&self, expired: impl Fn(u64) -> bool) {
+        while let Some(waker) = self.pop(&expired) {
+            waker.wake();
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        interrupt::free(|| self.head.get().is_null())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::pin::pin;
+
+    #[test]
+    fn wakes_expired_waiters() {
+        let list = WaitList::new();
+        let early = pin!(Waiter::new(1));
+        let late = pin!(Waiter::new(5));
+        assert!(list.register(early.as_ref(), Waker::noop()));
+        assert!(list.register(late.as_ref(), Waker::noop()));
+        assert!(!list.register(late.as_ref(), Waker::noop()));
+
+        assert!(list.pop(|key| key <= 3).is_some());
+        assert!(list.pop(|key| key <= 3).is_none());
+        assert!(!list.is_empty());
+        list.wake(|key| key <= 5);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn removes_waiters() {
+        let list = WaitList::new();
+        let first = pin!(Waiter::new(0));
+        let second = pin!(Waiter::new(0));
+        list.register(first.as_ref(), Waker::noop());
+        list.register(second.as_ref(), Waker::noop());
+
+        list.remove(&first);
+        list.remove(&first);
+        assert!(!list.is_empty());
+        list.remove(&second);
+        assert!(list.is_empty());
+    }
+}
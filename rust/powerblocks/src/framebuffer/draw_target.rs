@@ -0,0 +1,124 @@
+//! `embedded-graphics` support.
+
+use core::convert::Infallible;
+
+use embedded_graphics_core::draw_target::DrawTarget;
+use embedded_graphics_core::geometry::{OriginDimensions, Size};
+use embedded_graphics_core::pixelcolor::raw::{RawData, RawU32};
+use embedded_graphics_core::pixelcolor::{PixelColor, Rgb888, RgbColor};
+use embedded_graphics_core::primitives::Rectangle;
+use embedded_graphics_core::Pixel;
+
+use super::{Framebuffer, Rgba8888, HEIGHT, WIDTH};
+
+impl PixelColor for Rgba8888 {
+    type Raw = RawU32;
+}
+
+impl From<RawU32> for Rgba8888 {
+    fn from(raw: RawU32) -> Self {
+        Self::from_raw(raw.into_inner())
+    }
+}
+
+impl From<Rgba8888> for RawU32 {
+    fn from(color: Rgba8888) -> Self {
+        RawU32::new(color.into_raw())
+    }
+}
+
+impl From<Rgb888> for Rgba8888 {
+    fn from(color: Rgb888) -> Self {
+        Self::new(color.r(), color.g(), color.b(), 255)
+    }
+}
+
+impl Framebuffer {
+    /// A draw target that blends [`Rgba8888`] colors by their alpha.
+    pub fn blend(&mut self) -> Blend<'_> {
+        Blend(self)
+    }
+
+    /// Draws pixels, blending neighbours that share their chroma together
+    /// when they come one after the other, as they do in filled areas.
+    fn draw_pixels(&mut self, pixels: impl IntoIterator<Item = (i32, i32, Rgba8888)>) {
+        let mut pending = None;
+        for (x, y, color) in pixels {
+            if !(0..WIDTH as i32).contains(&x) || !(0..HEIGHT as i32).contains(&y) {
+                continue;
+            }
+
+            match pending.take() {
+                Some((even, row, first)) if row == y && even + 1 == x => {
+                    self.blend_pair(row as usize, even as usize, [Some(first), Some(color)]);
+                    continue;
+                }
+                Some((even, row, first)) => self.blend_pixel(even, row, first),
+                None => {}
+            }
+
+            if x % 2 == 0 {
+                pending = Some((x, y, color));
+            } else {
+                self.blend_pixel(x, y, color);
+            }
+        }
+
+        if let Some((x, y, color)) = pending {
+            self.blend_pixel(x, y, color);
+        }
+    }
+}
+
+impl OriginDimensions for Framebuffer {
+    fn size(&self) -> Size {
+        Size::new(WIDTH as u32, HEIGHT as u32)
+    }
+}
+
+/// Draws opaque colors.
+impl DrawTarget for Framebuffer {
+    type Color = Rgb888;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Infallible>
+    where
+        I: IntoIterator<Item = Pixel<Rgb888>>,
+    {
+        self.draw_pixels(pixels.into_iter().map(|Pixel(point, color)| (point.x, point.y, color.into())));
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Rgb888) -> Result<(), Infallible> {
+        self.fill(area.top_left.x, area.top_left.y, area.size.width, area.size.height, color.into());
+        Ok(())
+    }
+}
+
+/// A [`Framebuffer`] that blends [`Rgba8888`] colors, from
+/// [`Framebuffer::blend`].
+pub struct Blend<'a>(&'a mut Framebuffer);
+
+impl OriginDimensions for Blend<'_> {
+    fn size(&self) -> Size {
+        self.0.size()
+    }
+}
+
+impl DrawTarget for Blend<'_> {
+    type Color = Rgba8888;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Infallible>
+    where
+        I: IntoIterator<Item = Pixel<Rgba8888>>,
+    {
+        self.0.draw_pixels(pixels.into_iter().map(|Pixel(point, color)| (point.x, point.y, color)));
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Rgba8888) -> Result<(), Infallible> {
+        self.0.fill(area.top_left.x, area.top_left.y, area.size.width, area.size.height, color);
+        Ok(())
+    }
+}
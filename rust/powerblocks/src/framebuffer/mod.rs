@@ -0,0 +1,219 @@
+//! Drawing into the 640x480 YUY2 framebuffers the video interface shows.
+//!
+//! Two horizontally adjacent pixels, starting at an even column, share their
+//! chroma: the even pixel holds its luma and U, the odd one its luma and V.
+//! Colors are blended the way `framebuffer_fill_rgba` does it, by converting
+//! the background to RGB, blending, and converting back. Pairs drawn together
+//! get the average of their chroma. A single pixel keeps its neighbour's
+//! chroma and only sets its own half.
+//!
+//! With the `embedded-graphics` feature, [`Framebuffer`] is a `DrawTarget`
+//! for opaque `Rgb888` colors, and [`Framebuffer::blend`] one for
+//! [`Rgba8888`].
+
+use crate::sys;
+
+#[cfg(feature = "embedded-graphics")]
+mod draw_target;
+
+#[cfg(feature = "embedded-graphics")]
+pub use draw_target::Blend;
+
+pub const WIDTH: usize = sys::VIDEO_WIDTH as usize;
+pub const HEIGHT: usize = sys::VIDEO_HEIGHT as usize;
+
+/// A color with alpha, laid out `0xRRGGBBAA` like the SDK's `uint32_t`
+/// colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgba8888(u32);
+
+impl Rgba8888 {
+    pub const TRANSPARENT: Self = Self::new(0, 0, 0, 0);
+    pub const BLACK: Self = Self::new(0, 0, 0, 255);
+    pub const WHITE: Self = Self::new(255, 255, 255, 255);
+
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self(u32::from_be_bytes([r, g, b, a]))
+    }
+
+    pub const fn from_raw(rgba: u32) -> Self {
+        Self(rgba)
+    }
+
+    pub const fn into_raw(self) -> u32 {
+        self.0
+    }
+
+    pub const fn r(self) -> u8 {
+        self.0.to_be_bytes()[0]
+    }
+
+    pub const fn g(self) -> u8 {
+        self.0.to_be_bytes()[1]
+    }
+
+    pub const fn b(self) -> u8 {
+        self.0.to_be_bytes()[2]
+    }
+
+    pub const fn a(self) -> u8 {
+        self.0.to_be_bytes()[3]
+    }
+}
+
+/// A `framebuffer_t`, one 16-bit YUY2 value per pixel.
+///
+/// ```ignore
+/// let framebuffer = unsafe { Framebuffer::from_ptr(sys::video_get_framebuffer()) };
+/// framebuffer.fill(0, 0, 640, 480, Rgba8888::BLACK);
+/// ```
+#[repr(transparent)]
+pub struct Framebuffer(sys::framebuffer_t);
+
+impl Framebuffer {
+    /// Wraps a framebuffer from C.
+    pub fn from_raw(framebuffer: &mut sys::framebuffer_t) -> &mut Self {
+        unsafe { &mut *core::ptr::from_mut(framebuffer).cast() }
+    }
+
+    /// Wraps a framebuffer pointer from C, such as the one from
+    /// `video_get_framebuffer`.
+    ///
+    /// # Safety
+    /// `framebuffer` must be valid, and nothing else may access it for `'a`.
+    /// The video interface reading it while it is drawn into is fine.
+    pub unsafe fn from_ptr<'a>(framebuffer: *mut sys::framebuffer_t) -> &'a mut Self {
+        &mut *framebuffer.cast()
+    }
+
+    pub fn as_raw(&mut self) -> *mut sys::framebuffer_t {
+        &mut self.0
+    }
+
+    pub fn pixels(&self) -> &[[u16; WIDTH]; HEIGHT] {
+        &self.0.pixels
+    }
+
+    pub fn pixels_mut(&mut self) -> &mut [[u16; WIDTH]; HEIGHT] {
+        &mut self.0.pixels
+    }
+
+    /// Blends `color` into one pixel. Pixels outside the framebuffer are
+    /// ignored.
+    pub fn blend_pixel(&mut self, x: i32, y: i32, color: Rgba8888) {
+        if let (Ok(x @ 0..WIDTH), Ok(y @ 0..HEIGHT)) = (usize::try_from(x), usize::try_from(y)) {
+            let pair = if x % 2 == 0 { [Some(color), None] } else { [None, Some(color)] };
+            self.blend_pair(y, x & !1, pair);
+        }
+    }
+
+    /// Blends `color` into a rectangle, clipped to the framebuffer, like
+    /// `framebuffer_fill_rgba`.
+    pub fn fill(&mut self, x: i32, y: i32, width: u32, height: u32, color: Rgba8888) {
+        let (x0, x1) = clip(x, width, WIDTH);
+        let (y0, y1) = clip(y, height, HEIGHT);
+        for row in y0..y1 {
+            self.fill_row(row, x0, x1, color);
+        }
+    }
+
+    /// Fills the whole framebuffer with `color`.
+    pub fn clear(&mut self, color: Rgba8888) {
+        self.fill(0, 0, WIDTH as u32, HEIGHT as u32, color);
+    }
+
+    fn fill_row(&mut self, row: usize, x0: usize, x1: usize, color: Rgba8888) {
+        let mut x = x0;
+        if x % 2 == 1 && x < x1 {
+            self.blend_pair(row, x - 1, [None, Some(color)]);
+            x += 1;
+        }
+        while x + 1 < x1 {
+            self.blend_pair(row, x, [Some(color), Some(color)]);
+            x += 2;
+        }
+        if x < x1 {
+            self.blend_pair(row, x, [Some(color), None]);
+        }
+    }
+
+    /// Blends colors into the pair of pixels starting at the even column
+    /// `x`. Pixels given `None` are left alone.
+    fn blend_pair(&mut self, row: usize, x: usize, colors: [Option<Rgba8888>; 2]) {
+        let pixels = &mut self.0.pixels[row][x..x + 2];
+        let [even, odd] = [pixels[0], pixels[1]];
+        let (u, v) = (even as u8, odd as u8);
+        let luma = [(even >> 8) as u8, (odd >> 8) as u8];
+
+        let blended = [0, 1].map(|i| colors[i].map(|color| rgb_to_yuv(blend(color, yuv_to_rgb(luma[i], u, v)))));
+        match blended {
+            [Some((y0, u0, v0)), Some((y1, u1, v1))] => {
+                let u = ((u16::from(u0) + u16::from(u1)) / 2) as u8;
+                let v = ((u16::from(v0) + u16::from(v1)) / 2) as u8;
+                pixels[0] = u16::from_be_bytes([y0, u]);
+                pixels[1] = u16::from_be_bytes([y1, v]);
+            }
+            [Some((y0, u0, _)), None] => pixels[0] = u16::from_be_bytes([y0, u0]),
+            [None, Some((y1, _, v1))] => pixels[1] = u16::from_be_bytes([y1, v1]),
+            [None, None] => {}
+        }
+    }
+}
+
+/// Clips `length` pixels from `start` to `0..limit`.
+fn clip(start: i32, length: u32, limit: usize) -> (usize, usize) {
+    let end = i64::from(start) + i64::from(length);
+    let clamp = |value: i64| value.clamp(0, limit as i64) as usize;
+    (clamp(start.into()), clamp(end))
+}
+
+// The conversions below match framebuffer.c exactly, including where it
+// wraps rather than clamps.
+
+fn yuv_to_rgb(y: u8, u: u8, v: u8) -> (u8, u8, u8) {
+    let clamp = |value: i32| value.clamp(0, 255) as u8;
+    let c = i32::from(y) - 16;
+    let d = i32::from(u) - 128;
+    let e = i32::from(v) - 128;
+    (
+        clamp((298 * c + 409 * e + 128) >> 8),
+        clamp((298 * c - 100 * d - 208 * e + 128) >> 8),
+        clamp((298 * c + 516 * d + 128) >> 8),
+    )
+}
+
+fn rgb_to_yuv((r, g, b): (u8, u8, u8)) -> (u8, u8, u8) {
+    let (r, g, b) = (i32::from(r), i32::from(g), i32::from(b));
+    (
+        (((66 * r + 129 * g + 25 * b + 128) >> 8) as u8).wrapping_add(16),
+        (((-38 * r - 74 * g + 112 * b + 128) >> 8) as u8).wrapping_add(128),
+        (((112 * r - 94 * g - 18 * b + 128) >> 8) as u8).wrapping_add(128),
+    )
+}
+
+fn blend(color: Rgba8888, (r, g, b): (u8, u8, u8)) -> (u8, u8, u8) {
+    let alpha = u32::from(color.a());
+    let mix = |source: u8, background: u8| {
+        ((u32::from(source) * alpha + u32::from(background) * (255 - alpha)) / 255) as u8
+    };
+    (mix(color.r(), r), mix(color.g(), g), mix(color.b(), b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rgba_channels() {
+        let color = Rgba8888::new(1, 2, 3, 4);
+        assert_eq!(color.into_raw(), 0x01020304);
+        assert_eq!((color.r(), color.g(), color.b(), color.a()), (1, 2, 3, 4));
+    }
+
+    #[test]
+    fn clips_to_limits() {
+        assert_eq!(clip(-5, 10, 640), (0, 5));
+        assert_eq!(clip(630, 20, 640), (630, 640));
+        assert_eq!(clip(700, 20, 640), (640, 640));
+    }
+}
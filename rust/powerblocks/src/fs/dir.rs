@@ -0,0 +1,100 @@
+//! Reading directories, like FatFs's `DIR`.
+
+use core::ffi::CStr;
+use core::fmt;
+use core::mem::MaybeUninit;
+
+use super::{Error, Metadata, PathBuffer};
+use crate::sys;
+
+/// The entries of a directory, from [`read_dir`]. Closed when dropped, like
+/// `f_closedir`.
+///
+/// `.` and `..` are left out.
+pub struct ReadDir {
+    raw: sys::DIR,
+    done: bool,
+}
+
+// FatFs locks the volume itself, and a `DIR` only points into its volume.
+unsafe impl Send for ReadDir {}
+
+/// Opens the directory at `path` to list its entries, like `f_opendir`.
+///
+/// ```ignore
+/// for entry in fs::read_dir("/apps")? {
+///     let entry = entry?;
+///     println!("{:?} {}", entry.file_name(), entry.metadata().len());
+/// }
+/// ```
+pub fn read_dir(path: &str) -> Result<ReadDir, Error> {
+    let path = PathBuffer::new(path)?;
+    let mut raw = MaybeUninit::<sys::DIR>::uninit();
+    Error::check(unsafe { sys::f_opendir(raw.as_mut_ptr(), path.as_c_str().as_ptr()) })?;
+    Ok(ReadDir { raw: unsafe { raw.assume_init() }, done: false })
+}
+
+impl Iterator for ReadDir {
+    type Item = Result<DirEntry, Error>;
+
+    /// The next entry, like `f_readdir`. Stops after the first error.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut info = MaybeUninit::<sys::FILINFO>::uninit();
+        let result = Error::check(unsafe { sys::f_readdir(&mut self.raw, info.as_mut_ptr()) });
+        let entry = result.map(|()| DirEntry { info: unsafe { info.assume_init() } });
+        match entry {
+            Ok(entry) if entry.info.fname[0] == 0 => {
+                self.done = true;
+                None
+            }
+            Err(error) => {
+                self.done = true;
+                Some(Err(error))
+            }
+            entry => Some(entry),
+        }
+    }
+}
+
+impl fmt::Debug for ReadDir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadDir").field("done", &self.done).finish_non_exhaustive()
+    }
+}
+
+impl Drop for ReadDir {
+    fn drop(&mut self) {
+        unsafe { sys::f_closedir(&mut self.raw) };
+    }
+}
+
+/// An entry of a directory, like `FILINFO`.
+#[derive(Clone)]
+pub struct DirEntry {
+    info: sys::FILINFO,
+}
+
+impl DirEntry {
+    /// The entry's long file name.
+    ///
+    /// Names are in code page 437, not UTF-8, so names with characters
+    /// outside of ASCII may not convert to `str`.
+    pub fn file_name(&self) -> &CStr {
+        // FatFs always terminates the name within `fname`.
+        unsafe { CStr::from_ptr(self.info.fname.as_ptr()) }
+    }
+
+    pub fn metadata(&self) -> Metadata {
+        Metadata::from_info(&self.info)
+    }
+}
+
+impl fmt::Debug for DirEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DirEntry").field("file_name", &self.file_name()).field("metadata", &self.metadata()).finish()
+    }
+}
@@ -0,0 +1,105 @@
+//! FatFs result codes, from `ff.h`.
+
+use embedded_io::ErrorKind;
+
+use crate::error::error_codes;
+use crate::sys;
+
+error_codes! {
+    /// An error returned by FatFs, a `FRESULT` other than `FR_OK`.
+    ///
+    /// Codes not listed here are kept in [`Other`](Self::Other).
+    pub enum Error(sys::FRESULT) {
+        /// `FR_DISK_ERR`, the disk driver failed.
+        Disk = sys::FR_DISK_ERR => "disk error",
+        /// `FR_INT_ERR`, FatFs got into an unexpected state.
+        Internal = sys::FR_INT_ERR => "internal FatFs error",
+        /// `FR_NOT_READY`, the drive is not ready, such as when there is no
+        /// card.
+        NotReady = sys::FR_NOT_READY => "drive not ready",
+        /// `FR_NO_FILE`, the file does not exist.
+        NotFound = sys::FR_NO_FILE => "file not found",
+        /// `FR_NO_PATH`, a directory on the path does not exist.
+        PathNotFound = sys::FR_NO_PATH => "path not found",
+        /// `FR_INVALID_NAME`, the path is not valid.
+        InvalidName = sys::FR_INVALID_NAME => "invalid path",
+        /// `FR_DENIED`, the file is read only, the directory not empty, or the
+        /// disk full.
+        Denied = sys::FR_DENIED => "access denied",
+        /// `FR_EXIST`, the file already exists.
+        AlreadyExists = sys::FR_EXIST => "already exists",
+        /// `FR_INVALID_OBJECT`, the file or directory is not open.
+        InvalidObject = sys::FR_INVALID_OBJECT => "invalid file or directory",
+        /// `FR_WRITE_PROTECTED`, the disk is write protected.
+        WriteProtected = sys::FR_WRITE_PROTECTED => "write protected",
+        /// `FR_INVALID_DRIVE`, the drive in the path does not exist.
+        InvalidDrive = sys::FR_INVALID_DRIVE => "invalid drive",
+        /// `FR_NOT_ENABLED`, the volume is not mounted.
+        NotEnabled = sys::FR_NOT_ENABLED => "volume not mounted",
+        /// `FR_NO_FILESYSTEM`, there is no FAT filesystem on the disk.
+        NoFilesystem = sys::FR_NO_FILESYSTEM => "no FAT filesystem",
+        /// `FR_MKFS_ABORTED`.
+        MkfsAborted = sys::FR_MKFS_ABORTED => "formatting aborted",
+        /// `FR_TIMEOUT`, the volume stayed locked by another task for too long.
+        Timeout = sys::FR_TIMEOUT => "timed out",
+        /// `FR_LOCKED`, the file is open elsewhere.
+        Locked = sys::FR_LOCKED => "file locked",
+        /// `FR_NOT_ENOUGH_CORE`, a working buffer could not be allocated.
+        OutOfMemory = sys::FR_NOT_ENOUGH_CORE => "out of memory",
+        /// `FR_TOO_MANY_OPEN_FILES`.
+        TooManyOpenFiles = sys::FR_TOO_MANY_OPEN_FILES => "too many open files",
+        /// `FR_INVALID_PARAMETER`, an argument was invalid.
+        InvalidParameter = sys::FR_INVALID_PARAMETER => "invalid argument",
+    }
+    /// Any other result.
+    Other => "filesystem error",
+}
+
+impl Error {
+    /// Splits a result into `Ok` for `FR_OK` and the error otherwise.
+    pub const fn check(result: sys::FRESULT) -> Result<(), Self> {
+        if result == sys::FR_OK {
+            Ok(())
+        } else {
+            Err(Self::from_code(result))
+        }
+    }
+}
+
+impl embedded_io::Error for Error {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::NotFound | Self::PathNotFound => ErrorKind::NotFound,
+            Self::Denied | Self::WriteProtected | Self::Locked => ErrorKind::PermissionDenied,
+            Self::AlreadyExists => ErrorKind::AlreadyExists,
+            Self::InvalidName | Self::InvalidObject | Self::InvalidDrive | Self::InvalidParameter => ErrorKind::InvalidInput,
+            Self::NoFilesystem => ErrorKind::InvalidData,
+            Self::Timeout => ErrorKind::TimedOut,
+            Self::OutOfMemory => ErrorKind::OutOfMemory,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_io::Error as _;
+
+    #[test]
+    fn check_treats_only_fr_ok_as_success() {
+        assert_eq!(Error::check(sys::FR_OK), Ok(()));
+        assert_eq!(Error::check(sys::FR_NO_FILE), Err(Error::NotFound));
+        assert_eq!(Error::check(sys::FR_NO_PATH), Err(Error::PathNotFound));
+    }
+
+    #[test]
+    fn kinds_match_std_io() {
+        assert_eq!(Error::NotFound.kind(), ErrorKind::NotFound);
+        assert_eq!(Error::PathNotFound.kind(), ErrorKind::NotFound);
+        assert_eq!(Error::WriteProtected.kind(), ErrorKind::PermissionDenied);
+        assert_eq!(Error::Locked.kind(), ErrorKind::PermissionDenied);
+        assert_eq!(Error::NoFilesystem.kind(), ErrorKind::InvalidData);
+        assert_eq!(Error::Disk.kind(), ErrorKind::Other);
+    }
+}
@@ -0,0 +1,254 @@
+//! Open files, like FatFs's `FIL`.
+
+use core::fmt;
+use core::mem::MaybeUninit;
+
+use embedded_io::{ErrorType, Read, Seek, SeekFrom, Write};
+
+use super::{Error, PathBuffer};
+use crate::sys;
+
+/// An open file. Closed when dropped, like `f_close`.
+///
+/// Reading, writing and seeking go through the [`embedded_io`] traits.
+/// FatFs keeps a sector of the file buffered, so small reads and writes are
+/// cheap, but writes only reach the card on [`sync`](Self::sync) or when
+/// the file is closed.
+///
+/// ```ignore
+/// use embedded_io::{Read, Write};
+///
+/// let mut file = File::create(&fs::app_path("save.dat")?)?;
+/// file.write_all(&save)?;
+/// ```
+pub struct File {
+    raw: sys::FIL,
+}
+
+// FatFs locks the volume itself, and a `FIL` only points into its volume.
+unsafe impl Send for File {}
+
+impl File {
+    /// Opens an existing file for reading.
+    pub fn open(path: &str) -> Result<Self, Error> {
+        OpenOptions::new().read(true).open(path)
+    }
+
+    /// Opens a file for writing, creating it or cutting it to nothing.
+    pub fn create(path: &str) -> Result<Self, Error> {
+        OpenOptions::new().write(true).create(true).truncate(true).open(path)
+    }
+
+    pub fn as_raw(&mut self) -> *mut sys::FIL {
+        &mut self.raw
+    }
+
+    /// The size of the file in bytes, like `f_size`.
+    pub fn len(&self) -> u64 {
+        self.raw.obj.objsize.into()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The position reads and writes happen at, like `f_tell`.
+    pub fn position(&self) -> u64 {
+        self.raw.fptr.into()
+    }
+
+    /// Writes buffered data to the card, like `f_sync`.
+    pub fn sync(&mut self) -> Result<(), Error> {
+        Error::check(unsafe { sys::f_sync(&mut self.raw) })
+    }
+
+    /// Grows or shrinks the file to `size` bytes, like `f_lseek` and
+    /// `f_truncate`. The position stays where it was, or moves to the new
+    /// end if that was past it.
+    pub fn set_len(&mut self, size: u64) -> Result<(), Error> {
+        let position = self.position().min(size);
+        self.seek(SeekFrom::Start(size))?;
+        Error::check(unsafe { sys::f_truncate(&mut self.raw) })?;
+        self.seek(SeekFrom::Start(position))?;
+        Ok(())
+    }
+}
+
+impl fmt::Debug for File {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("File").field("len", &self.len()).field("position", &self.position()).finish()
+    }
+}
+
+impl Drop for File {
+    fn drop(&mut self) {
+        unsafe { sys::f_close(&mut self.raw) };
+    }
+}
+
+impl ErrorType for File {
+    type Error = Error;
+}
+
+impl Read for File {
+    /// Reads up to `buf.len()` bytes, like `f_read`. Returns 0 at the end of
+    /// the file.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        let mut read = 0;
+        let size = buf.len().min(sys::UINT::MAX as usize) as sys::UINT;
+        Error::check(unsafe { sys::f_read(&mut self.raw, buf.as_mut_ptr().cast(), size, &mut read) })?;
+        Ok(read as usize)
+    }
+}
+
+impl Write for File {
+    /// Writes up to `buf.len()` bytes, like `f_write`. Writes less once the
+    /// disk is full.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Error> {
+        let mut written = 0;
+        let size = buf.len().min(sys::UINT::MAX as usize) as sys::UINT;
+        Error::check(unsafe { sys::f_write(&mut self.raw, buf.as_ptr().cast(), size, &mut written) })?;
+        Ok(written as usize)
+    }
+
+    fn flush(&mut self) -> Result<(), Error> {
+        self.sync()
+    }
+}
+
+impl Seek for File {
+    /// Moves the position, like `f_lseek`. Seeking past the end of a file
+    /// open for writing grows it.
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64, Error> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::Current(offset) => self.position().checked_add_signed(offset),
+            SeekFrom::End(offset) => self.len().checked_add_signed(offset),
+        };
+        let position = position.and_then(|position| sys::FSIZE_t::try_from(position).ok()).ok_or(Error::InvalidParameter)?;
+        Error::check(unsafe { sys::f_lseek(&mut self.raw, position) })?;
+        Ok(position.into())
+    }
+}
+
+/// How to open a [`File`], like `std::fs::OpenOptions`.
+///
+/// ```ignore
+/// let log = OpenOptions::new().append(true).create(true).open("log.txt")?;
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OpenOptions {
+    read: bool,
+    write: bool,
+    append: bool,
+    truncate: bool,
+    create: bool,
+    create_new: bool,
+}
+
+impl OpenOptions {
+    /// Options with everything off. Set at least one of `read`, `write` or
+    /// `append`.
+    pub const fn new() -> Self {
+        Self { read: false, write: false, append: false, truncate: false, create: false, create_new: false }
+    }
+
+    pub const fn read(mut self, read: bool) -> Self {
+        self.read = read;
+        self
+    }
+
+    pub const fn write(mut self, write: bool) -> Self {
+        self.write = write;
+        self
+    }
+
+    /// Opens for writing, starting at the end of the file.
+    pub const fn append(mut self, append: bool) -> Self {
+        self.append = append;
+        self
+    }
+
+    /// Cuts an existing file to nothing. Needs `write`.
+    pub const fn truncate(mut self, truncate: bool) -> Self {
+        self.truncate = truncate;
+        self
+    }
+
+    /// Creates the file if it does not exist. Needs `write` or `append`.
+    pub const fn create(mut self, create: bool) -> Self {
+        self.create = create;
+        self
+    }
+
+    /// Creates the file, failing with [`Error::AlreadyExists`] if it
+    /// exists. Needs `write` or `append`.
+    pub const fn create_new(mut self, create_new: bool) -> Self {
+        self.create_new = create_new;
+        self
+    }
+
+    /// The `FA_*` mode `f_open` gets.
+    fn mode(&self) -> Result<u8, Error> {
+        let write = self.write || self.append;
+        if !self.read && !write {
+            return Err(Error::InvalidParameter);
+        }
+        if (self.truncate || self.create || self.create_new) && !write {
+            return Err(Error::InvalidParameter);
+        }
+
+        let access = if self.read { sys::FA_READ } else { 0 } | if write { sys::FA_WRITE } else { 0 };
+        let disposition = if self.create_new {
+            sys::FA_CREATE_NEW
+        } else if self.create && self.truncate {
+            sys::FA_CREATE_ALWAYS
+        } else if self.create {
+            sys::FA_OPEN_ALWAYS
+        } else {
+            sys::FA_OPEN_EXISTING
+        };
+        Ok((access | disposition) as u8)
+    }
+
+    /// Opens the file at `path`, like `f_open`.
+    pub fn open(&self, path: &str) -> Result<File, Error> {
+        let mode = self.mode()?;
+        let path = PathBuffer::new(path)?;
+
+        let mut raw = MaybeUninit::<sys::FIL>::uninit();
+        Error::check(unsafe { sys::f_open(raw.as_mut_ptr(), path.as_c_str().as_ptr(), mode) })?;
+        let mut file = File { raw: unsafe { raw.assume_init() } };
+
+        // FatFs can only truncate when creating, and appending implies
+        // creating.
+        if self.truncate && !self.create && !self.create_new {
+            Error::check(unsafe { sys::f_truncate(&mut file.raw) })?;
+        }
+        if self.append {
+            file.seek(SeekFrom::End(0))?;
+        }
+        Ok(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_pick_fatfs_modes() {
+        let mode = |options: OpenOptions| options.mode().map(u32::from);
+        assert_eq!(mode(OpenOptions::new().read(true)), Ok(sys::FA_READ));
+        assert_eq!(mode(OpenOptions::new().write(true).create(true).truncate(true)), Ok(sys::FA_WRITE | sys::FA_CREATE_ALWAYS));
+        assert_eq!(mode(OpenOptions::new().append(true).create(true)), Ok(sys::FA_WRITE | sys::FA_OPEN_ALWAYS));
+        assert_eq!(mode(OpenOptions::new().read(true).write(true).create_new(true)), Ok(sys::FA_READ | sys::FA_WRITE | sys::FA_CREATE_NEW));
+        assert_eq!(mode(OpenOptions::new().write(true).truncate(true)), Ok(sys::FA_WRITE | sys::FA_OPEN_EXISTING));
+    }
+
+    #[test]
+    fn options_need_access() {
+        assert_eq!(OpenOptions::new().mode(), Err(Error::InvalidParameter));
+        assert_eq!(OpenOptions::new().read(true).create(true).mode(), Err(Error::InvalidParameter));
+    }
+}
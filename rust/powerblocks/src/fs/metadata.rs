@@ -0,0 +1,99 @@
+//! What FatFs knows about a file, from `FILINFO`.
+
+use crate::sys;
+
+/// The size, attributes and modification time of a file or directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metadata {
+    len: u32,
+    attributes: u8,
+    date: u16,
+    time: u16,
+}
+
+impl Metadata {
+    pub(super) const fn from_info(info: &sys::FILINFO) -> Self {
+        Self { len: info.fsize, attributes: info.fattrib, date: info.fdate, time: info.ftime }
+    }
+
+    /// The root directory, which has no `FILINFO` of its own.
+    pub(super) const fn root() -> Self {
+        Self { len: 0, attributes: sys::AM_DIR as u8, date: 0, time: 0 }
+    }
+
+    /// The size of the file in bytes. Zero for directories.
+    pub const fn len(&self) -> u64 {
+        self.len as u64
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn is_dir(&self) -> bool {
+        self.attributes & sys::AM_DIR as u8 != 0
+    }
+
+    pub const fn is_file(&self) -> bool {
+        !self.is_dir()
+    }
+
+    pub const fn is_read_only(&self) -> bool {
+        self.attributes & sys::AM_RDO as u8 != 0
+    }
+
+    pub const fn is_hidden(&self) -> bool {
+        self.attributes & sys::AM_HID as u8 != 0
+    }
+
+    /// The raw `AM_*` attribute bits.
+    pub const fn attributes(&self) -> u8 {
+        self.attributes
+    }
+
+    /// When the file was last modified, in the local time `get_fattime`
+    /// reported then. The root directory has none, so it gets the zero FAT
+    /// timestamp.
+    pub const fn modified(&self) -> Timestamp {
+        Timestamp::from_fat(self.date, self.time)
+    }
+}
+
+/// A FAT timestamp. FAT keeps seconds in steps of two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl Timestamp {
+    /// Unpacks FAT's date and time words.
+    pub const fn from_fat(date: u16, time: u16) -> Self {
+        Self {
+            year: 1980 + (date >> 9),
+            month: ((date >> 5) & 0xF) as u8,
+            day: (date & 0x1F) as u8,
+            hour: (time >> 11) as u8,
+            minute: ((time >> 5) & 0x3F) as u8,
+            second: (time & 0x1F) as u8 * 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unpacks_fat_timestamps() {
+        // 2025-07-14 13:37:42
+        let date = (45 << 9) | (7 << 5) | 14;
+        let time = (13 << 11) | (37 << 5) | 21;
+        let expected = Timestamp { year: 2025, month: 7, day: 14, hour: 13, minute: 37, second: 42 };
+        assert_eq!(Timestamp::from_fat(date, time), expected);
+    }
+}
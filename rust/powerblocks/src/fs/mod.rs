@@ -0,0 +1,133 @@
+//! Files and directories on the SD card, through FatFs.
+//!
+//! This works like a small `std::fs`. Paths are `str`s, absolute from the
+//! root of the card or relative to the current directory, and are handed to
+//! FatFs as they are. FatFs uses code page 437 for names, so stick to ASCII.
+//!
+//! Apps are usually launched from their own directory, which
+//! [`app_path`] resolves paths against:
+//!
+//! ```ignore
+//! fs::mount_sd()?;
+//! let mut file = File::open(&fs::app_path("level1.bin")?)?;
+//! ```
+
+use core::mem::MaybeUninit;
+
+use crate::rtos::Mutex;
+use crate::sys;
+
+mod dir;
+mod error;
+mod file;
+mod metadata;
+mod path;
+
+pub use dir::{read_dir, DirEntry, ReadDir};
+pub use error::Error;
+pub use file::{File, OpenOptions};
+pub use metadata::{Metadata, Timestamp};
+pub use path::{app_dir, app_path, PathBuffer, MAX_PATH};
+
+struct Sd {
+    initialized: bool,
+    mounted: bool,
+    // FatFs keeps a pointer to the volume while it is mounted.
+    volume: MaybeUninit<sys::FATFS>,
+}
+
+// Only FatFs touches the volume, under its own lock.
+unsafe impl Send for Sd {}
+
+static SD: Mutex<Sd> = Mutex::new(Sd { initialized: false, mounted: false, volume: MaybeUninit::uninit() });
+
+/// Starts the SD card driver and mounts the card, like `sd_initialize` and
+/// `f_mount`. Does nothing if the card is already mounted.
+///
+/// Fails with [`Error::NotReady`] if the driver could not start, or there
+/// is no card, and with [`Error::Denied`] if the card is already mounted
+/// through the C VFS, as `"sd:"`. FatFs has one volume per drive, so the
+/// two can not share the card.
+pub fn mount_sd() -> Result<(), Error> {
+    let mut sd = SD.lock();
+    if sd.mounted {
+        return Ok(());
+    }
+
+    // The driver may only be started once, card or not.
+    if !sd.initialized {
+        if unsafe { sys::sd_initialize() } < 0 {
+            return Err(Error::NotReady);
+        }
+        sd.initialized = true;
+    }
+
+    let drive = sys::DISK_DEV_SD as u8;
+    if unsafe { sys::vfs_fatfs_claim_drive(drive) } < 0 {
+        return Err(Error::Denied);
+    }
+
+    let result = Error::check(unsafe { sys::f_mount(sd.volume.as_mut_ptr(), c"0:".as_ptr(), 1) });
+    if result.is_err() {
+        // FatFs keeps the volume registered even when mounting fails.
+        unsafe {
+            sys::f_mount(core::ptr::null_mut(), c"0:".as_ptr(), 0);
+            sys::vfs_fatfs_release_drive(drive);
+        }
+    }
+
+    result?;
+    sd.mounted = true;
+    Ok(())
+}
+
+/// Creates a directory, like `f_mkdir`. Its parent must exist.
+pub fn create_dir(path: &str) -> Result<(), Error> {
+    let path = PathBuffer::new(path)?;
+    Error::check(unsafe { sys::f_mkdir(path.as_c_str().as_ptr()) })
+}
+
+/// Removes a file or an empty directory, like `f_unlink`.
+pub fn remove(path: &str) -> Result<(), Error> {
+    let path = PathBuffer::new(path)?;
+    Error::check(unsafe { sys::f_unlink(path.as_c_str().as_ptr()) })
+}
+
+/// Renames or moves a file or directory, like `f_rename`. Fails with
+/// [`Error::AlreadyExists`] if `to` exists.
+pub fn rename(from: &str, to: &str) -> Result<(), Error> {
+    let from = PathBuffer::new(from)?;
+    let to = PathBuffer::new(to)?;
+    Error::check(unsafe { sys::f_rename(from.as_c_str().as_ptr(), to.as_c_str().as_ptr()) })
+}
+
+/// The size, attributes and modification time of a file or directory,
+/// like `f_stat`.
+pub fn metadata(path: &str) -> Result<Metadata, Error> {
+    let path = PathBuffer::new(path)?;
+    if path::is_root(&path) {
+        // FatFs cannot stat the root, but opening it still checks the
+        // card is mounted.
+        drop(read_dir(&path)?);
+        return Ok(Metadata::root());
+    }
+
+    let mut info = MaybeUninit::<sys::FILINFO>::uninit();
+    Error::check(unsafe { sys::f_stat(path.as_c_str().as_ptr(), info.as_mut_ptr()) })?;
+    Ok(Metadata::from_info(unsafe { info.assume_init_ref() }))
+}
+
+/// Whether anything exists at `path`.
+pub fn exists(path: &str) -> Result<bool, Error> {
+    match metadata(path) {
+        Ok(_) => Ok(true),
+        Err(Error::NotFound | Error::PathNotFound) => Ok(false),
+        Err(error) => Err(error),
+    }
+}
+
+/// Changes the directory relative paths start from, like `f_chdir`.
+pub fn set_current_dir(path: &str) -> Result<(), Error> {
+    let path = PathBuffer::new(path)?;
+    Error::check(unsafe { sys::f_chdir(path.as_c_str().as_ptr()) })
+}
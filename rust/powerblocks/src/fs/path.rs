@@ -0,0 +1,171 @@
+//! Paths, and resolving them against the directory the app was launched
+//! from.
+
+use core::ffi::CStr;
+use core::fmt;
+use core::ops::Deref;
+
+use super::Error;
+use crate::sys;
+
+/// The longest path, in bytes, that fits a [`PathBuffer`].
+pub const MAX_PATH: usize = 255;
+
+/// A path in a fixed size, NUL-terminated buffer, ready to hand to FatFs.
+///
+/// Dereferences to `str`, so it can be passed to any function here that
+/// takes a path.
+#[derive(Clone)]
+pub struct PathBuffer {
+    buffer: [u8; MAX_PATH + 1],
+    length: usize,
+}
+
+impl PathBuffer {
+    /// Copies `path` into a buffer.
+    ///
+    /// Fails with [`Error::InvalidName`] if it is longer than [`MAX_PATH`]
+    /// or contains a NUL, rather than cutting it short and naming a
+    /// different file.
+    pub fn new(path: &str) -> Result<Self, Error> {
+        let mut buffer = Self { buffer: [0; MAX_PATH + 1], length: 0 };
+        buffer.push(path)?;
+        Ok(buffer)
+    }
+
+    fn push(&mut self, s: &str) -> Result<(), Error> {
+        let end = self.length + s.len();
+        if end > MAX_PATH || s.contains('\0') {
+            return Err(Error::InvalidName);
+        }
+        self.buffer[self.length..end].copy_from_slice(s.as_bytes());
+        self.length = end;
+        Ok(())
+    }
+
+    /// Resolves `path` against this directory. Absolute paths, starting
+    /// with `/` or a drive like `0:`, are returned as they are.
+    pub fn join(&self, path: &str) -> Result<Self, Error> {
+        if is_absolute(path) {
+            return Self::new(path);
+        }
+
+        let mut joined = self.clone();
+        if !joined.ends_with('/') {
+            joined.push("/")?;
+        }
+        joined.push(path)?;
+        Ok(joined)
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Only ever filled from `str`s.
+        unsafe { core::str::from_utf8_unchecked(&self.buffer[..self.length]) }
+    }
+
+    pub fn as_c_str(&self) -> &CStr {
+        // The byte after the path is always NUL, and `push` keeps NULs out.
+        unsafe { CStr::from_bytes_with_nul_unchecked(&self.buffer[..=self.length]) }
+    }
+}
+
+impl Deref for PathBuffer {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for PathBuffer {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for PathBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for PathBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+fn is_absolute(path: &str) -> bool {
+    let drive = path.find(':').is_some_and(|colon| !path[..colon].contains('/'));
+    path.starts_with('/') || drive
+}
+
+/// Whether `path` is the root directory of a drive, like `/` or `0:/`.
+pub(super) fn is_root(path: &str) -> bool {
+    let path = match path.find(':') {
+        Some(colon) if !path[..colon].contains('/') => &path[colon + 1..],
+        _ => path,
+    };
+    !path.is_empty() && path.bytes().all(|byte| byte == b'/')
+}
+
+/// The directory the app was launched from on the SD card, like
+/// `system_get_boot_path("sd", ...)`. This is `/` if it was launched from
+/// somewhere else.
+pub fn app_dir() -> PathBuffer {
+    let mut buffer = [0u8; MAX_PATH + 1];
+    unsafe { sys::system_get_boot_path(c"sd".as_ptr(), buffer.as_mut_ptr().cast(), buffer.len()) };
+    let path = CStr::from_bytes_until_nul(&buffer).ok().and_then(|path| path.to_str().ok());
+    PathBuffer::new(path.unwrap_or("/")).unwrap()
+}
+
+/// Resolves `path` against [`app_dir`], so apps find their files wherever
+/// they were installed.
+///
+/// ```ignore
+/// let config = File::open(&fs::app_path("config.ini")?)?;
+/// ```
+pub fn app_path(path: &str) -> Result<PathBuffer, Error> {
+    app_dir().join(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn joins_relative_paths() {
+        let app = PathBuffer::new("/apps/game/").unwrap();
+        assert_eq!(&*app.join("data/level1.bin").unwrap(), "/apps/game/data/level1.bin");
+        let app = PathBuffer::new("/apps/game").unwrap();
+        assert_eq!(&*app.join("save.dat").unwrap(), "/apps/game/save.dat");
+        assert_eq!(app.join("save.dat").unwrap().as_c_str(), c"/apps/game/save.dat");
+    }
+
+    #[test]
+    fn keeps_absolute_paths() {
+        let app = PathBuffer::new("/apps/game/").unwrap();
+        assert_eq!(&*app.join("/boot.dol").unwrap(), "/boot.dol");
+        assert_eq!(&*app.join("0:/boot.dol").unwrap(), "0:/boot.dol");
+        assert_eq!(&*app.join("0:boot.dol").unwrap(), "0:boot.dol");
+    }
+
+    #[test]
+    fn finds_the_root() {
+        assert!(is_root("/"));
+        assert!(is_root("0:/"));
+        assert!(is_root("//"));
+        assert!(!is_root(""));
+        assert!(!is_root("0:"));
+        assert!(!is_root("/apps"));
+    }
+
+    #[test]
+    fn rejects_paths_that_do_not_fit() {
+        assert!(PathBuffer::new(&"a".repeat(MAX_PATH)).is_ok());
+        assert_eq!(PathBuffer::new(&"a".repeat(MAX_PATH + 1)).unwrap_err(), Error::InvalidName);
+        assert_eq!(PathBuffer::new("a\0b").unwrap_err(), Error::InvalidName);
+        let app = PathBuffer::new(&"a".repeat(MAX_PATH - 1)).unwrap();
+        assert_eq!(app.join("b").unwrap_err(), Error::InvalidName);
+    }
+}
@@ -0,0 +1,232 @@
+//! Drawing with the GPU through GX.
+//!
+//! Vertex data goes straight into the write gather pipe, and the GPU reads
+//! it according to the vertex descriptor and attribute format set up before
+//! `gx_begin`. Any mismatch between the two hangs or corrupts the GPU, so
+//! here both come from one type: a [`vertex!`](crate::vertex) struct knows
+//! its format, and [`begin`] sets it up and returns a [`Draw`] that takes
+//! exactly as many of those vertices as it was told to expect.
+//!
+//! ```ignore
+//! powerblocks::vertex! {
+//!     struct Colored {
+//!         #[position] position: [f32; 3],
+//!         #[color0] color: Rgba8888,
+//!     }
+//! }
+//!
+//! let mut draw = gx::begin::<Colored>(Primitive::Triangles, 3);
+//! draw.vertex(Colored { position: [0.0, 1.0, 0.0], color: Rgba8888::WHITE });
+//! // ...two more...
+//! draw.end();
+//! ```
+//!
+//! TEV stages are built with [`tev::Stage`], and matrices, color channels
+//! and lights are loaded through [`xf`].
+
+use core::marker::PhantomData;
+use core::ptr;
+
+use crate::sys;
+
+pub mod tev;
+pub mod vertex;
+pub mod xf;
+
+pub use vertex::Vertex;
+
+/// The vertex attribute table [`begin`] sets up and draws with.
+const VERTEX_TABLE: u8 = 0;
+
+/// What the vertices of a [`Draw`] make up, like `gx_primitive_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Primitive {
+    Quads = sys::GX_QUADS,
+    Triangles = sys::GX_TRIANGLES,
+    TriangleStrip = sys::GX_TRIANGLE_STRIP,
+    TriangleFan = sys::GX_TRIANGLE_FAN,
+    Lines = sys::GX_LINES,
+    LineStrip = sys::GX_LINESTRIP,
+    Points = sys::GX_POINTS,
+}
+
+/// Somewhere vertex data is written to, in the order the GPU reads it.
+///
+/// [`Wpar`] is the real thing. Anything else is for checking what a
+/// [`Vertex`] writes.
+pub trait WriteGather {
+    fn write_u8(&mut self, value: u8);
+    fn write_u16(&mut self, value: u16);
+    fn write_u32(&mut self, value: u32);
+
+    fn write_f32(&mut self, value: f32) {
+        self.write_u32(value.to_bits());
+    }
+}
+
+/// The write gather pipe, which the `GX_WPAR_*` macros write to.
+///
+/// Only handed out by [`begin`], as writing to it outside of a draw puts
+/// the GPU out of step with its FIFO.
+#[derive(Debug)]
+pub struct Wpar {
+    // GX state is not locked, so draw from one task only.
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl Wpar {
+    const ADDRESS: usize = sys::GX_WPAR_ADDRESS as usize;
+
+    fn write<T>(&mut self, value: T) {
+        unsafe { ptr::write_volatile(Self::ADDRESS as *mut T, value) };
+    }
+}
+
+impl WriteGather for Wpar {
+    fn write_u8(&mut self, value: u8) {
+        self.write(value);
+    }
+
+    fn write_u16(&mut self, value: u16) {
+        self.write(value);
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write(value);
+    }
+
+    fn write_f32(&mut self, value: f32) {
+        self.write(value);
+    }
+}
+
+/// Sets up the vertex format of `V` and starts drawing `count` of them,
+/// like `gx_begin`.
+///
+/// GX state set with `gx_set_*` functions is flushed here, so set it first.
+pub fn begin<V: Vertex>(primitive: Primitive, count: u16) -> Draw<V> {
+    V::FORMAT.apply(VERTEX_TABLE);
+    unsafe { sys::gx_begin(primitive as sys::gx_primitive_t, VERTEX_TABLE, count) };
+    Draw::new(Wpar { _not_send: PhantomData }, count)
+}
+
+/// A primitive being drawn, from [`begin`]. Takes exactly the number of
+/// vertices it was started with.
+///
+/// # Panics
+/// When dropped before all vertices were written, as the GPU would take
+/// whatever is written next as the rest.
+#[must_use = "the GPU waits for every vertex of a draw"]
+pub struct Draw<V, W: WriteGather = Wpar> {
+    fifo: W,
+    remaining: u16,
+    _vertex: PhantomData<fn(V)>,
+}
+
+impl<V: Vertex, W: WriteGather> Draw<V, W> {
+    fn new(fifo: W, count: u16) -> Self {
+        Self { fifo, remaining: count, _vertex: PhantomData }
+    }
+
+    /// Writes the next vertex.
+    ///
+    /// # Panics
+    /// If all vertices were already written.
+    pub fn vertex(&mut self, vertex: V) -> &mut Self {
+        assert!(self.remaining > 0, "draw already has all its vertices");
+        self.remaining -= 1;
+        vertex.write(&mut self.fifo);
+        self
+    }
+
+    /// Writes the next `vertices.len()` vertices.
+    ///
+    /// # Panics
+    /// If that is more than are left.
+    pub fn vertices(&mut self, vertices: &[V]) -> &mut Self {
+        assert!(vertices.len() <= self.remaining.into(), "draw has fewer vertices left than given");
+        self.remaining -= vertices.len() as u16;
+        for vertex in vertices {
+            vertex.write(&mut self.fifo);
+        }
+        self
+    }
+
+    /// How many vertices are still to be written.
+    pub fn remaining(&self) -> u16 {
+        self.remaining
+    }
+
+    /// Finishes the draw, like `gx_end`.
+    ///
+    /// # Panics
+    /// If not all vertices were written.
+    pub fn end(self) {}
+}
+
+impl<V, W: WriteGather> Drop for Draw<V, W> {
+    fn drop(&mut self) {
+        assert!(self.remaining == 0, "draw ended {} vertices short", self.remaining);
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::framebuffer::Rgba8888;
+
+    /// Records writes as big endian bytes, the way the GPU sees them.
+    #[derive(Default)]
+    pub(crate) struct Recorder(pub Vec<u8>);
+
+    impl WriteGather for Recorder {
+        fn write_u8(&mut self, value: u8) {
+            self.0.push(value);
+        }
+
+        fn write_u16(&mut self, value: u16) {
+            self.0.extend(value.to_be_bytes());
+        }
+
+        fn write_u32(&mut self, value: u32) {
+            self.0.extend(value.to_be_bytes());
+        }
+    }
+
+    crate::vertex! {
+        #[derive(Clone, Copy)]
+        struct Colored {
+            #[position] position: [i16; 2],
+            #[color0] color: Rgba8888,
+        }
+    }
+
+    const VERTEX: Colored = Colored { position: [1, -2], color: Rgba8888::new(1, 2, 3, 4) };
+
+    #[test]
+    fn draw_takes_count_vertices() {
+        let mut draw = Draw::<Colored, _>::new(Recorder::default(), 3);
+        draw.vertex(VERTEX).vertices(&[VERTEX, VERTEX]);
+        assert_eq!(draw.remaining(), 0);
+        assert_eq!(draw.fifo.0.len(), 3 * 8);
+        assert_eq!(draw.fifo.0[..8], [0, 1, 0xFF, 0xFE, 1, 2, 3, 4]);
+        draw.end();
+    }
+
+    #[test]
+    #[should_panic = "draw already has all its vertices"]
+    fn draw_rejects_extra_vertices() {
+        let mut draw = Draw::<Colored, _>::new(Recorder::default(), 1);
+        draw.vertex(VERTEX);
+        draw.vertex(VERTEX);
+    }
+
+    #[test]
+    #[should_panic = "draw ended 1 vertices short"]
+    fn draw_rejects_missing_vertices() {
+        let mut draw = Draw::<Colored, _>::new(Recorder::default(), 2);
+        draw.vertex(VERTEX);
+        draw.end();
+    }
+}
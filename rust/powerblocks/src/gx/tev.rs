@@ -0,0 +1,229 @@
+//! TEV stages, which combine colors, textures and registers into the
+//! color of each pixel.
+//!
+//! Each stage mixes its inputs as `d + mix(a, b, c)`, then biases and
+//! scales the result, or compares `a` with `b` to choose between `c` and
+//! zero. The output of the last stage, normally [`Output::Previous`], is the
+//! pixel's color.
+//!
+//! ```ignore
+//! // Texture color times vertex color.
+//! tev::Stage::new()
+//!     .color_input(ColorInput::Zero, ColorInput::Texture, ColorInput::Rasterizer, ColorInput::Zero)
+//!     .alpha_input(AlphaInput::Zero, AlphaInput::Texture, AlphaInput::Rasterizer, AlphaInput::Zero)
+//!     .flash(0);
+//! tev::set_stages(1);
+//! ```
+
+use crate::sys;
+
+/// A color input of a stage, like `gx_tev_io_t`. The `*Alpha` inputs
+/// repeat the alpha of their source in all three channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum ColorInput {
+    Previous = sys::GX_TEV_IO_PREVIOUS,
+    PreviousAlpha = sys::GX_TEV_IO_PREVIOUS | sys::GX_TEV_IO_ALPHA,
+    Register0 = sys::GX_TEV_IO_REGISTER_0,
+    Register0Alpha = sys::GX_TEV_IO_REGISTER_0 | sys::GX_TEV_IO_ALPHA,
+    Register1 = sys::GX_TEV_IO_REGISTER_1,
+    Register1Alpha = sys::GX_TEV_IO_REGISTER_1 | sys::GX_TEV_IO_ALPHA,
+    Register2 = sys::GX_TEV_IO_REGISTER_2,
+    Register2Alpha = sys::GX_TEV_IO_REGISTER_2 | sys::GX_TEV_IO_ALPHA,
+    Texture = sys::GX_TEV_IO_TEXTURE,
+    TextureAlpha = sys::GX_TEV_IO_TEXTURE | sys::GX_TEV_IO_ALPHA,
+    Rasterizer = sys::GX_TEV_IO_RASTERIZER,
+    RasterizerAlpha = sys::GX_TEV_IO_RASTERIZER | sys::GX_TEV_IO_ALPHA,
+    One = sys::GX_TEV_IO_ONE,
+    Half = sys::GX_TEV_IO_HALF,
+    Constant = sys::GX_TEV_IO_CONSTANT,
+    Zero = sys::GX_TEV_IO_ZERO,
+}
+
+/// An alpha input of a stage, like `gx_tev_io_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum AlphaInput {
+    Previous = sys::GX_TEV_IO_PREVIOUS,
+    Register0 = sys::GX_TEV_IO_REGISTER_0,
+    Register1 = sys::GX_TEV_IO_REGISTER_1,
+    Register2 = sys::GX_TEV_IO_REGISTER_2,
+    Texture = sys::GX_TEV_IO_TEXTURE,
+    Rasterizer = sys::GX_TEV_IO_RASTERIZER,
+    Constant = sys::GX_TEV_IO_CONSTANT,
+    Zero = sys::GX_TEV_IO_ZERO,
+}
+
+/// Where a stage writes its result, like `gx_tev_io_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Output {
+    Previous = sys::GX_TEV_IO_PREVIOUS,
+    Register0 = sys::GX_TEV_IO_REGISTER_0,
+    Register1 = sys::GX_TEV_IO_REGISTER_1,
+    Register2 = sys::GX_TEV_IO_REGISTER_2,
+}
+
+/// A TEV register that can be loaded with [`flash_register`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Register {
+    Register0 = sys::GX_TEV_IO_REGISTER_0,
+    Register1 = sys::GX_TEV_IO_REGISTER_1,
+    Register2 = sys::GX_TEV_IO_REGISTER_2,
+}
+
+/// Added to a stage's result before scaling, like `gx_tev_bias_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u32)]
+pub enum Bias {
+    #[default]
+    Zero = sys::GX_TEV_BIAS_0,
+    AddHalf = sys::GX_TEV_BIAS_ADD_HALF,
+    SubtractHalf = sys::GX_TEV_BIAS_SUB_HALF,
+}
+
+/// What a stage's result is multiplied by, like `gx_tev_scale_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u32)]
+pub enum Scale {
+    #[default]
+    One = sys::GX_TEV_SCALE_1,
+    Two = sys::GX_TEV_SCALE_2,
+    Four = sys::GX_TEV_SCALE_4,
+    Half = sys::GX_TEV_SCALE_HALF,
+}
+
+/// How a stage in compare mode compares `a` with `b`, like
+/// `gx_tev_compare_t`.
+///
+/// The wider comparisons treat the red, green and blue of `a` and `b` as
+/// one number. The `Channel` ones compare each channel on its own, or the
+/// alpha for an alpha comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Compare {
+    R8Greater = sys::GX_TEV_COMPARE_R8_GREATER,
+    R8Equal = sys::GX_TEV_COMPARE_R8_EQUAL,
+    Gr16Greater = sys::GX_TEV_COMPARE_GR16_GREATER,
+    Gr16Equal = sys::GX_TEV_COMPARE_GR16_EQUAL,
+    Bgr24Greater = sys::GX_TEV_COMPARE_BGR24_GREATER,
+    Bgr24Equal = sys::GX_TEV_COMPARE_BGR24_EQUAL,
+    ChannelGreater = sys::GX_TEV_COMPARE_RGB8_GT,
+    ChannelEqual = sys::GX_TEV_COMPARE_RGB8_EQ,
+}
+
+/// A TEV stage, like `gx_tev_stage_t`. Nothing changes on the GPU until it
+/// is [flashed](Self::flash).
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct Stage(sys::gx_tev_stage_t);
+
+impl Stage {
+    /// A stage that passes the rasterized color and alpha through, like
+    /// `gx_initialize_tev_stage`.
+    pub fn new() -> Self {
+        let mut stage = sys::gx_tev_stage_t { color_control: 0, alpha_control: 0 };
+        unsafe { sys::gx_initialize_tev_stage(&mut stage) };
+        Self(stage)
+    }
+
+    pub const fn from_raw(stage: sys::gx_tev_stage_t) -> Self {
+        Self(stage)
+    }
+
+    pub const fn as_raw(&self) -> &sys::gx_tev_stage_t {
+        &self.0
+    }
+
+    /// Sets the color inputs, like `gx_set_tev_stage_color_input`.
+    pub fn color_input(mut self, a: ColorInput, b: ColorInput, c: ColorInput, d: ColorInput) -> Self {
+        unsafe { sys::gx_set_tev_stage_color_input(&mut self.0, a as u32, b as u32, c as u32, d as u32) };
+        self
+    }
+
+    /// Sets the alpha inputs, like `gx_set_tev_stage_alpha_input`.
+    pub fn alpha_input(mut self, a: AlphaInput, b: AlphaInput, c: AlphaInput, d: AlphaInput) -> Self {
+        unsafe { sys::gx_set_tev_stage_alpha_input(&mut self.0, a as u32, b as u32, c as u32, d as u32) };
+        self
+    }
+
+    /// Sets where the color goes, and whether it is clamped to 0-255
+    /// rather than -1024-1023, like `gx_set_tev_stage_color_output`.
+    pub fn color_output(mut self, output: Output, clamp: bool) -> Self {
+        unsafe { sys::gx_set_tev_stage_color_output(&mut self.0, output as u32, clamp) };
+        self
+    }
+
+    /// Sets where the alpha goes, and whether it is clamped to 0-255
+    /// rather than -1024-1023, like `gx_set_tev_stage_alpha_output`.
+    pub fn alpha_output(mut self, output: Output, clamp: bool) -> Self {
+        unsafe { sys::gx_set_tev_stage_alpha_output(&mut self.0, output as u32, clamp) };
+        self
+    }
+
+    /// Computes the color as `(mix(a, b, c) ± d + bias) * scale`, like
+    /// `gx_set_tev_stage_color_biasing`. `subtract` subtracts `d`.
+    pub fn color_bias(mut self, subtract: bool, bias: Bias, scale: Scale) -> Self {
+        unsafe { sys::gx_set_tev_stage_color_biasing(&mut self.0, subtract, bias as u32, scale as u32) };
+        self
+    }
+
+    /// Computes the color as `d + (compare(a, b) ? c : 0)`, like
+    /// `gx_set_tev_stage_color_comparison`.
+    pub fn color_compare(mut self, compare: Compare) -> Self {
+        unsafe { sys::gx_set_tev_stage_color_comparison(&mut self.0, compare as u32) };
+        self
+    }
+
+    /// Computes the alpha as `(mix(a, b, c) ± d + bias) * scale`, like
+    /// `gx_set_tev_stage_alpha_biasing`. `subtract` subtracts `d`.
+    pub fn alpha_bias(mut self, subtract: bool, bias: Bias, scale: Scale) -> Self {
+        unsafe { sys::gx_set_tev_stage_alpha_biasing(&mut self.0, subtract, bias as u32, scale as u32) };
+        self
+    }
+
+    /// Computes the alpha as `d + (compare(a, b) ? c : 0)`, like
+    /// `gx_set_tev_stage_alpha_comparison`.
+    pub fn alpha_compare(mut self, compare: Compare) -> Self {
+        unsafe { sys::gx_set_tev_stage_alpha_comparison(&mut self.0, compare as u32) };
+        self
+    }
+
+    /// Loads this stage as stage `id`, like `gx_flash_tev_stage`.
+    ///
+    /// # Panics
+    /// If `id` is 16 or more.
+    pub fn flash(&self, id: u8) {
+        assert!(id < 16, "there are 16 TEV stages");
+        unsafe { sys::gx_flash_tev_stage(id.into(), &self.0) };
+    }
+}
+
+impl Default for Stage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sets how many stages run, starting from stage 0, like
+/// `gx_set_tev_stages`.
+///
+/// # Panics
+/// If `count` is not 1 to 16.
+pub fn set_stages(count: u8) {
+    assert!((1..=16).contains(&count), "1 to 16 TEV stages can run");
+    unsafe { sys::gx_set_tev_stages(count.into()) };
+}
+
+/// Loads a register with an RGBA color, like `gx_flash_tev_register_color`.
+/// Values are 0-255, or signed 11 bit values for unclamped math.
+///
+/// # Panics
+/// If a value is outside -1024-1023.
+pub fn flash_register(register: Register, [r, g, b, a]: [i16; 4]) {
+    for value in [r, g, b, a] {
+        assert!((-1024..=1023).contains(&value), "TEV registers hold signed 11 bit values");
+    }
+    unsafe { sys::gx_flash_tev_register_color(register as u32, r.into(), g.into(), b.into(), a.into()) };
+}
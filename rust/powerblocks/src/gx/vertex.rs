@@ -0,0 +1,510 @@
+//! Vertex formats, declared with [`vertex!`](crate::vertex).
+//!
+//! Each field of a vertex is one [`Attribute`], and its type decides the
+//! attribute's format through [`Data`]:
+//!
+//! | Attribute            | Types                                                  |
+//! |----------------------|--------------------------------------------------------|
+//! | `matrix`             | [`MatrixId`]                                           |
+//! | `position`           | `[T; 2]`, `[T; 3]`                                     |
+//! | `normal`             | `[f32; 3]`, `[i16; 3]`, `[i8; 3]`                      |
+//! | `color0`, `color1`   | [`Rgba8888`], `[u8; 4]`, `[u8; 3]`                     |
+//! | `texcoord0`..`7`     | `T`, `[T; 2]`                                          |
+//!
+//! where `T` is one of `u8`, `i8`, `u16`, `i16` or `f32`. Integer positions
+//! and texture coordinates can be given fraction bits with [`Fixed`].
+//!
+//! Only direct data is supported, not indices into arrays.
+
+use super::xf::MatrixId;
+use super::WriteGather;
+use crate::framebuffer::Rgba8888;
+use crate::sys;
+
+/// A vertex type, which knows its format and how to write itself.
+///
+/// Implemented by [`vertex!`](crate::vertex).
+///
+/// # Safety
+/// `write` must write exactly the attributes in `FORMAT`, in their order
+/// and laid out as described.
+pub unsafe trait Vertex {
+    const FORMAT: Format;
+
+    fn write<W: WriteGather>(&self, fifo: &mut W);
+}
+
+/// Declares a struct that is a [`Vertex`](crate::gx::Vertex).
+///
+/// Every field starts with the [`Attribute`](crate::gx::vertex::Attribute)
+/// it is, as a lowercase attribute: `#[matrix]`, `#[position]`,
+/// `#[normal]`, `#[color0]`, `#[color1]` or `#[texcoord0]` to
+/// `#[texcoord7]`. Fields must come in that order, which is the order the
+/// GPU reads them in, and their types must suit their attribute.
+///
+/// ```ignore
+/// powerblocks::vertex! {
+///     #[derive(Debug, Clone, Copy)]
+///     pub struct Textured {
+///         #[position] pub position: [f32; 3],
+///         #[texcoord0] pub uv: Fixed<[u16; 2], 8>,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! vertex {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                #[$slot:ident]
+                $(#[$field_meta:meta])*
+                $field_vis:vis $field:ident: $ty:ty
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $($(#[$field_meta])* $field_vis $field: $ty,)*
+        }
+
+        unsafe impl $crate::gx::Vertex for $name {
+            const FORMAT: $crate::gx::vertex::Format = $crate::gx::vertex::Format::new()
+                $(.with::<$crate::gx::vertex::slot::$slot, $ty>())*;
+
+            fn write<W: $crate::gx::WriteGather>(&self, fifo: &mut W) {
+                $(
+                    <$ty as $crate::gx::vertex::Data<<$crate::gx::vertex::slot::$slot as $crate::gx::vertex::Slot>::Kind>>::write(
+                        &self.$field,
+                        fifo,
+                    );
+                )*
+            }
+        }
+    };
+}
+
+/// A vertex attribute, like `gx_vtxdesc_t`. Attributes are written in this
+/// order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u32)]
+pub enum Attribute {
+    /// The position and normal matrix to transform the vertex with,
+    /// instead of the one set with
+    /// [`set_current_matrix`](super::xf::set_current_matrix).
+    Matrix = sys::GX_VTXDESC_POSNORM_INDEX,
+    Position = sys::GX_VTXDESC_POSITION,
+    Normal = sys::GX_VTXDESC_NORMAL,
+    Color0 = sys::GX_VTXDESC_COLOR0,
+    Color1 = sys::GX_VTXDESC_COLOR1,
+    TexCoord0 = sys::GX_VTXDESC_TEXCOORD0,
+    TexCoord1 = sys::GX_VTXDESC_TEXCOORD1,
+    TexCoord2 = sys::GX_VTXDESC_TEXCOORD2,
+    TexCoord3 = sys::GX_VTXDESC_TEXCOORD3,
+    TexCoord4 = sys::GX_VTXDESC_TEXCOORD4,
+    TexCoord5 = sys::GX_VTXDESC_TEXCOORD5,
+    TexCoord6 = sys::GX_VTXDESC_TEXCOORD6,
+    TexCoord7 = sys::GX_VTXDESC_TEXCOORD7,
+}
+
+impl Attribute {
+    pub const ALL: [Self; 13] = [
+        Self::Matrix,
+        Self::Position,
+        Self::Normal,
+        Self::Color0,
+        Self::Color1,
+        Self::TexCoord0,
+        Self::TexCoord1,
+        Self::TexCoord2,
+        Self::TexCoord3,
+        Self::TexCoord4,
+        Self::TexCoord5,
+        Self::TexCoord6,
+        Self::TexCoord7,
+    ];
+
+    const fn index(self) -> usize {
+        match self {
+            Self::Matrix => 0,
+            Self::Position => 1,
+            Self::Normal => 2,
+            Self::Color0 => 3,
+            Self::Color1 => 4,
+            texcoord => texcoord as usize - Self::TexCoord0 as usize + 5,
+        }
+    }
+}
+
+/// How one attribute is laid out, like the arguments of
+/// `gx_vtxfmtattr_set`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttributeFormat {
+    /// A `gx_vtxattr_component_t`.
+    pub components: sys::gx_vtxattr_component_t,
+    /// A `gx_vtxattr_component_format_t`.
+    pub format: sys::gx_vtxattr_component_format_t,
+    /// Fraction bits of integer positions and texture coordinates.
+    pub fraction: u8,
+}
+
+/// The attributes of a [`Vertex`] and their layout, which make up its
+/// vertex descriptor and attribute format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Format {
+    attributes: [Option<AttributeFormat>; Attribute::ALL.len()],
+    next: usize,
+}
+
+impl Format {
+    /// A format without any attributes.
+    pub const fn new() -> Self {
+        Self { attributes: [None; Attribute::ALL.len()], next: 0 }
+    }
+
+    /// Adds an attribute.
+    ///
+    /// # Panics
+    /// If `attribute` does not come after the ones already added.
+    pub const fn with_attribute(mut self, attribute: Attribute, format: AttributeFormat) -> Self {
+        let index = attribute.index();
+        assert!(index >= self.next, "vertex attributes must be declared in the order GX reads them");
+        self.attributes[index] = Some(format);
+        self.next = index + 1;
+        self
+    }
+
+    /// Adds the attribute `S` with data of type `D`.
+    ///
+    /// # Panics
+    /// If `S` does not come after the attributes already added.
+    pub const fn with<S: Slot, D: Data<S::Kind>>(self) -> Self {
+        let format = AttributeFormat { components: D::COMPONENTS, format: D::FORMAT, fraction: D::FRACTION };
+        self.with_attribute(S::ATTRIBUTE, format)
+    }
+
+    pub const fn get(&self, attribute: Attribute) -> Option<AttributeFormat> {
+        self.attributes[attribute.index()]
+    }
+
+    /// Sets the vertex descriptor, and this format in vertex attribute
+    /// table `table`, like `gx_vtxdesc_set` and `gx_vtxfmtattr_set`. Takes
+    /// effect on the next `gx_begin`.
+    pub fn apply(&self, table: u8) {
+        assert!(table < 8, "there are 8 vertex attribute tables");
+        unsafe {
+            for desc in sys::GX_VTXDESC_TEXCOORDMTX0..=sys::GX_VTXDESC_TEXCOORDMTX7 {
+                sys::gx_vtxdesc_set(desc, sys::GX_VTXATTR_DATA_DISABLED);
+            }
+
+            for attribute in Attribute::ALL {
+                let desc = attribute as sys::gx_vtxdesc_t;
+                let Some(format) = self.get(attribute) else {
+                    sys::gx_vtxdesc_set(desc, sys::GX_VTXATTR_DATA_DISABLED);
+                    continue;
+                };
+
+                sys::gx_vtxdesc_set(desc, sys::GX_VTXATTR_DATA_DIRECT);
+                // Matrix indices are always one byte and have no format.
+                if attribute != Attribute::Matrix {
+                    sys::gx_vtxfmtattr_set(table, desc, format.components, format.format, format.fraction);
+                }
+            }
+        }
+    }
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The kinds of data attributes hold, which [`Data`] is implemented for.
+pub mod kind {
+    pub enum Matrix {}
+    pub enum Position {}
+    pub enum Normal {}
+    pub enum Color {}
+    pub enum TexCoord {}
+}
+
+/// Data for a kind of attribute, such as [`kind::Position`].
+///
+/// # Safety
+/// `write` must write exactly what `COMPONENTS`, `FORMAT` and `FRACTION`
+/// describe.
+pub unsafe trait Data<K> {
+    /// A `gx_vtxattr_component_t`.
+    const COMPONENTS: sys::gx_vtxattr_component_t;
+    /// A `gx_vtxattr_component_format_t`.
+    const FORMAT: sys::gx_vtxattr_component_format_t;
+    const FRACTION: u8 = 0;
+
+    fn write<W: WriteGather>(&self, fifo: &mut W);
+}
+
+/// An attribute as named in [`vertex!`](crate::vertex), in [`slot`].
+pub trait Slot {
+    const ATTRIBUTE: Attribute;
+    type Kind;
+}
+
+/// The attribute names [`vertex!`](crate::vertex) takes.
+#[allow(non_camel_case_types)]
+pub mod slot {
+    use super::{kind, Attribute, Slot};
+
+    macro_rules! slots {
+        ($($slot:ident => $attribute:ident, $kind:ident;)*) => {
+            $(
+                pub enum $slot {}
+
+                impl Slot for $slot {
+                    const ATTRIBUTE: Attribute = Attribute::$attribute;
+                    type Kind = kind::$kind;
+                }
+            )*
+        };
+    }
+
+    slots! {
+        matrix => Matrix, Matrix;
+        position => Position, Position;
+        normal => Normal, Normal;
+        color0 => Color0, Color;
+        color1 => Color1, Color;
+        texcoord0 => TexCoord0, TexCoord;
+        texcoord1 => TexCoord1, TexCoord;
+        texcoord2 => TexCoord2, TexCoord;
+        texcoord3 => TexCoord3, TexCoord;
+        texcoord4 => TexCoord4, TexCoord;
+        texcoord5 => TexCoord5, TexCoord;
+        texcoord6 => TexCoord6, TexCoord;
+        texcoord7 => TexCoord7, TexCoord;
+    }
+}
+
+/// Integer positions or texture coordinates with `F` fraction bits, so
+/// that `Fixed::<[i16; 2], 8>([256, 128])` is `[1.0, 0.5]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[repr(transparent)]
+pub struct Fixed<T, const F: u8>(pub T);
+
+/// A component of positions and texture coordinates, one of `u8`, `i8`,
+/// `u16`, `i16` or `f32`.
+///
+/// # Safety
+/// `write` must write one value in `FORMAT`.
+pub unsafe trait Component: Copy {
+    const FORMAT: sys::gx_vtxattr_component_format_t;
+
+    fn write<W: WriteGather>(self, fifo: &mut W);
+}
+
+macro_rules! components {
+    ($($ty:ty => $format:ident, |$value:ident, $fifo:ident| $write:expr;)*) => {
+        $(
+            unsafe impl Component for $ty {
+                const FORMAT: sys::gx_vtxattr_component_format_t = sys::$format;
+
+                fn write<W: WriteGather>(self, $fifo: &mut W) {
+                    let $value = self;
+                    $write
+                }
+            }
+        )*
+    };
+}
+
+components! {
+    u8 => GX_VTXATTR_U8, |value, fifo| fifo.write_u8(value);
+    i8 => GX_VTXATTR_S8, |value, fifo| fifo.write_u8(value as u8);
+    u16 => GX_VTXATTR_U16, |value, fifo| fifo.write_u16(value);
+    i16 => GX_VTXATTR_S16, |value, fifo| fifo.write_u16(value as u16);
+    f32 => GX_VTXATTR_F32, |value, fifo| fifo.write_f32(value);
+}
+
+fn write_all<T: Component, W: WriteGather>(components: &[T], fifo: &mut W) {
+    for &component in components {
+        component.write(fifo);
+    }
+}
+
+unsafe impl Data<kind::Matrix> for MatrixId {
+    const COMPONENTS: sys::gx_vtxattr_component_t = 0;
+    const FORMAT: sys::gx_vtxattr_component_format_t = 0;
+
+    fn write<W: WriteGather>(&self, fifo: &mut W) {
+        fifo.write_u8(self.as_raw() as u8);
+    }
+}
+
+unsafe impl<T: Component> Data<kind::Position> for [T; 2] {
+    const COMPONENTS: sys::gx_vtxattr_component_t = sys::GX_VTXATTR_POS_XY;
+    const FORMAT: sys::gx_vtxattr_component_format_t = T::FORMAT;
+
+    fn write<W: WriteGather>(&self, fifo: &mut W) {
+        write_all(self, fifo);
+    }
+}
+
+unsafe impl<T: Component> Data<kind::Position> for [T; 3] {
+    const COMPONENTS: sys::gx_vtxattr_component_t = sys::GX_VTXATTR_POS_XYZ;
+    const FORMAT: sys::gx_vtxattr_component_format_t = T::FORMAT;
+
+    fn write<W: WriteGather>(&self, fifo: &mut W) {
+        write_all(self, fifo);
+    }
+}
+
+macro_rules! normals {
+    ($($ty:ty),*) => {
+        $(
+            unsafe impl Data<kind::Normal> for [$ty; 3] {
+                const COMPONENTS: sys::gx_vtxattr_component_t = sys::GX_VTXATTR_NRM_XYZ;
+                const FORMAT: sys::gx_vtxattr_component_format_t = <$ty as Component>::FORMAT;
+
+                fn write<W: WriteGather>(&self, fifo: &mut W) {
+                    write_all(self, fifo);
+                }
+            }
+        )*
+    };
+}
+
+// Integer normals have a fixed 6 or 14 fraction bits.
+normals!(i8, i16, f32);
+
+unsafe impl Data<kind::Color> for Rgba8888 {
+    const COMPONENTS: sys::gx_vtxattr_component_t = sys::GX_VTXATTR_RGBA;
+    const FORMAT: sys::gx_vtxattr_component_format_t = sys::GX_VTXATTR_RGBA8;
+
+    fn write<W: WriteGather>(&self, fifo: &mut W) {
+        fifo.write_u32(self.into_raw());
+    }
+}
+
+unsafe impl Data<kind::Color> for [u8; 4] {
+    const COMPONENTS: sys::gx_vtxattr_component_t = sys::GX_VTXATTR_RGBA;
+    const FORMAT: sys::gx_vtxattr_component_format_t = sys::GX_VTXATTR_RGBA8;
+
+    fn write<W: WriteGather>(&self, fifo: &mut W) {
+        write_all(self, fifo);
+    }
+}
+
+unsafe impl Data<kind::Color> for [u8; 3] {
+    const COMPONENTS: sys::gx_vtxattr_component_t = sys::GX_VTXATTR_RGB;
+    const FORMAT: sys::gx_vtxattr_component_format_t = sys::GX_VTXATTR_RGB8;
+
+    fn write<W: WriteGather>(&self, fifo: &mut W) {
+        write_all(self, fifo);
+    }
+}
+
+unsafe impl<T: Component> Data<kind::TexCoord> for T {
+    const COMPONENTS: sys::gx_vtxattr_component_t = sys::GX_VTXATTR_TEX_S;
+    const FORMAT: sys::gx_vtxattr_component_format_t = T::FORMAT;
+
+    fn write<W: WriteGather>(&self, fifo: &mut W) {
+        Component::write(*self, fifo);
+    }
+}
+
+unsafe impl<T: Component> Data<kind::TexCoord> for [T; 2] {
+    const COMPONENTS: sys::gx_vtxattr_component_t = sys::GX_VTXATTR_TEX_ST;
+    const FORMAT: sys::gx_vtxattr_component_format_t = T::FORMAT;
+
+    fn write<W: WriteGather>(&self, fifo: &mut W) {
+        write_all(self, fifo);
+    }
+}
+
+macro_rules! fixed {
+    ($($kind:ident),*) => {
+        $(
+            unsafe impl<D: Data<kind::$kind>, const F: u8> Data<kind::$kind> for Fixed<D, F> {
+                const COMPONENTS: sys::gx_vtxattr_component_t = D::COMPONENTS;
+                const FORMAT: sys::gx_vtxattr_component_format_t = D::FORMAT;
+                const FRACTION: u8 = {
+                    assert!(F < 32, "fixed point data has at most 31 fraction bits");
+                    F
+                };
+
+                fn write<W: WriteGather>(&self, fifo: &mut W) {
+                    self.0.write(fifo);
+                }
+            }
+        )*
+    };
+}
+
+fixed!(Position, TexCoord);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gx::tests::Recorder;
+
+    crate::vertex! {
+        struct Lit {
+            #[matrix] matrix: MatrixId,
+            #[position] position: Fixed<[i16; 3], 4>,
+            #[normal] normal: [i8; 3],
+            #[color0] color: [u8; 3],
+            #[texcoord0] s: f32,
+            #[texcoord3] uv: [u8; 2],
+        }
+    }
+
+    #[test]
+    fn fields_make_the_format() {
+        let format = Lit::FORMAT;
+        let position = AttributeFormat { components: sys::GX_VTXATTR_POS_XYZ, format: sys::GX_VTXATTR_S16, fraction: 4 };
+        assert_eq!(format.get(Attribute::Position), Some(position));
+        let normal = AttributeFormat { components: sys::GX_VTXATTR_NRM_XYZ, format: sys::GX_VTXATTR_S8, fraction: 0 };
+        assert_eq!(format.get(Attribute::Normal), Some(normal));
+        let uv = AttributeFormat { components: sys::GX_VTXATTR_TEX_ST, format: sys::GX_VTXATTR_U8, fraction: 0 };
+        assert_eq!(format.get(Attribute::TexCoord3), Some(uv));
+        assert!(format.get(Attribute::Matrix).is_some());
+        assert!(format.get(Attribute::Color1).is_none() && format.get(Attribute::TexCoord1).is_none());
+    }
+
+    #[test]
+    fn fields_are_written_in_order() {
+        let vertex = Lit {
+            matrix: MatrixId::new(2),
+            position: Fixed([16, -16, 1]),
+            normal: [0, 0, -64],
+            color: [0xAA, 0xBB, 0xCC],
+            s: 1.0,
+            uv: [7, 8],
+        };
+        let mut fifo = Recorder::default();
+        vertex.write(&mut fifo);
+        #[rustfmt::skip]
+        let expected = [
+            6,
+            0, 16, 0xFF, 0xF0, 0, 1,
+            0, 0, 0xC0,
+            0xAA, 0xBB, 0xCC,
+            0x3F, 0x80, 0, 0,
+            7, 8,
+        ];
+        assert_eq!(fifo.0, expected);
+    }
+
+    #[test]
+    #[should_panic = "vertex attributes must be declared in the order GX reads them"]
+    fn attributes_must_be_in_order() {
+        let _ = Format::new().with::<slot::color0, [u8; 4]>().with::<slot::position, [f32; 3]>();
+    }
+
+    #[test]
+    fn attribute_indices_are_dense() {
+        for (i, attribute) in Attribute::ALL.into_iter().enumerate() {
+            assert_eq!(attribute.index(), i);
+        }
+    }
+}
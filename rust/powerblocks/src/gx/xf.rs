@@ -0,0 +1,314 @@
+//! The transform unit: viewport, matrices, color channels and lights.
+//!
+//! `flash_*` functions write to the GPU right away, while `set_*` and
+//! `configure_*` ones only take effect on the next [`begin`](super::begin),
+//! like their C counterparts.
+
+use crate::framebuffer::Rgba8888;
+use crate::sys;
+
+/// A slot in the matrix memory, like `gx_mtx_id_t`.
+///
+/// Position and texture matrices share slots 0 to 20, while normal matrices
+/// have their own slots 0 to 9.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MatrixId(u8);
+
+impl MatrixId {
+    /// Slot 20, which `gx_initialize` loads with an identity matrix and
+    /// makes current.
+    pub const IDENTITY: Self = Self(20);
+
+    /// # Panics
+    /// If `index` is over 20.
+    pub const fn new(index: u8) -> Self {
+        assert!(index <= 20, "there are 21 matrix slots");
+        Self(index)
+    }
+
+    pub const fn index(self) -> u8 {
+        self.0
+    }
+
+    pub const fn as_raw(self) -> sys::gx_mtx_id_t {
+        self.0 as sys::gx_mtx_id_t * 3
+    }
+}
+
+/// Sets the viewport geometry is scaled to, like `gx_flash_viewport`.
+///
+/// `jitter` moves pixels up slightly, which reduces flicker when
+/// interlaced.
+pub fn flash_viewport(x: f32, y: f32, width: f32, height: f32, near: f32, far: f32, jitter: bool) {
+    unsafe { sys::gx_flash_viewport(x, y, width, height, near, far, jitter) };
+}
+
+/// The kind of a projection matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Projection {
+    Perspective,
+    Orthographic,
+}
+
+/// Loads the projection matrix, like `gx_flash_projection`.
+pub fn flash_projection(matrix: &[[f32; 4]; 4], projection: Projection) {
+    unsafe { sys::gx_flash_projection(matrix.as_ptr(), projection == Projection::Perspective) };
+}
+
+/// Loads a 3x4 position or texture matrix, like `gx_flash_matrix`.
+pub fn flash_matrix(matrix: &[[f32; 4]; 3], id: MatrixId) {
+    unsafe { sys::gx_flash_matrix(matrix.as_ptr(), id.as_raw(), true) };
+}
+
+/// Loads a normal matrix, like `gx_flash_nrm_matrix`.
+///
+/// # Panics
+/// If `id` is over 9.
+pub fn flash_normal_matrix(matrix: &[[f32; 3]; 3], id: MatrixId) {
+    assert!(id.index() < 10, "there are 10 normal matrix slots");
+    unsafe { sys::gx_flash_nrm_matrix(matrix.as_ptr(), id.as_raw()) };
+}
+
+/// Transforms positions and normals with the matrices in `id`, unless the
+/// vertices give their own, like `gx_set_current_psn_matrix`.
+pub fn set_current_matrix(id: MatrixId) {
+    unsafe { sys::gx_set_current_psn_matrix(id.as_raw()) };
+}
+
+/// One of the eight hardware lights, like `gx_light_id_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LightId(u8);
+
+impl LightId {
+    /// # Panics
+    /// If `index` is 8 or more.
+    pub const fn new(index: u8) -> Self {
+        assert!(index < 8, "there are 8 lights");
+        Self(index)
+    }
+
+    pub const fn index(self) -> u8 {
+        self.0
+    }
+
+    /// The light's `gx_light_bit_t`.
+    const fn bit(self) -> sys::gx_light_bit_t {
+        if self.0 < 4 {
+            sys::GX_LIGHT_BIT_0 << self.0
+        } else {
+            sys::GX_LIGHT_BIT_4 << (self.0 - 4)
+        }
+    }
+}
+
+/// A light, like `gx_light_t`. Nothing changes on the GPU until it is
+/// [flashed](Self::flash).
+///
+/// Brightness falls off as `a0 + a1 * cos + a2 * cos²` of the angle from
+/// the light's direction, and as `1 / (k0 + k1 * d + k2 * d²)` of the
+/// distance `d`. A new light is not attenuated at all.
+#[derive(Debug, Clone, Copy)]
+#[repr(transparent)]
+pub struct Light(sys::gx_light_t);
+
+impl Light {
+    const fn vec3([x, y, z]: [f32; 3]) -> sys::vec3 {
+        sys::vec3 { x, y, z }
+    }
+
+    pub const fn new(color: Rgba8888) -> Self {
+        Self(sys::gx_light_t {
+            color: color.into_raw(),
+            cos_attenuation: Self::vec3([1.0, 0.0, 0.0]),
+            distance_attenuation: Self::vec3([1.0, 0.0, 0.0]),
+            position: Self::vec3([0.0; 3]),
+            direction: Self::vec3([0.0; 3]),
+        })
+    }
+
+    pub const fn as_raw(&self) -> &sys::gx_light_t {
+        &self.0
+    }
+
+    /// The light's position, in view space.
+    pub const fn position(mut self, position: [f32; 3]) -> Self {
+        self.0.position = Self::vec3(position);
+        self
+    }
+
+    /// The direction the light shines in, in view space.
+    pub const fn direction(mut self, [x, y, z]: [f32; 3]) -> Self {
+        // The hardware wants it pointing back at the light.
+        self.0.direction = Self::vec3([-x, -y, -z]);
+        self
+    }
+
+    /// `[a0, a1, a2]` of the falloff by angle.
+    pub const fn angle_attenuation(mut self, attenuation: [f32; 3]) -> Self {
+        self.0.cos_attenuation = Self::vec3(attenuation);
+        self
+    }
+
+    /// `[k0, k1, k2]` of the falloff by distance.
+    pub const fn distance_attenuation(mut self, attenuation: [f32; 3]) -> Self {
+        self.0.distance_attenuation = Self::vec3(attenuation);
+        self
+    }
+
+    /// Loads the light into `id`, like `gx_flash_light`.
+    pub fn flash(&self, id: LightId) {
+        unsafe { sys::gx_flash_light(id.index().into(), &self.0) };
+    }
+}
+
+/// A color channel, like `gx_color_channel_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum Channel {
+    Color0 = sys::GX_COLOR_CHANNEL_COLOR0,
+    Color1 = sys::GX_COLOR_CHANNEL_COLOR1,
+    Alpha0 = sys::GX_COLOR_CHANNEL_ALPHA0,
+    Alpha1 = sys::GX_COLOR_CHANNEL_ALPHA1,
+}
+
+/// How lights affect a channel, like `gx_diffuse_mode_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u32)]
+pub enum Diffuse {
+    #[default]
+    None = sys::GX_DIFFUSE_MODE_NONE,
+    Signed = sys::GX_DIFFUSE_SIGNED,
+    Clamped = sys::GX_DIFFUSE_CLAMPED,
+}
+
+/// Which attenuation a channel's lights use, like
+/// `gx_attenuation_mode_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u32)]
+pub enum Attenuation {
+    #[default]
+    None = sys::GX_ATTENUATION_MODE_NONE,
+    Specular = sys::GX_ATTENUATION_MODE_SPECULAR,
+    Spotlight = sys::GX_ATTENUATION_MODE_SPOTLIGHT,
+}
+
+/// How a color channel is lit, for [`configure_channel`].
+///
+/// The default passes the vertex color through unlit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelConfig {
+    lights: sys::gx_light_bit_t,
+    lighting: bool,
+    ambient_from_vertex: bool,
+    material_from_vertex: bool,
+    diffuse: Diffuse,
+    attenuation: Attenuation,
+}
+
+impl ChannelConfig {
+    pub const fn new() -> Self {
+        Self {
+            lights: sys::GX_LIGHT_BIT_NONE,
+            lighting: false,
+            ambient_from_vertex: false,
+            material_from_vertex: true,
+            diffuse: Diffuse::None,
+            attenuation: Attenuation::None,
+        }
+    }
+
+    /// Lights the channel with `lights`, which may be none for a dark scene.
+    pub const fn lit(mut self, lights: &[LightId], diffuse: Diffuse, attenuation: Attenuation) -> Self {
+        self.lights = sys::GX_LIGHT_BIT_NONE;
+        let mut i = 0;
+        while i < lights.len() {
+            self.lights |= lights[i].bit();
+            i += 1;
+        }
+        self.lighting = true;
+        self.diffuse = diffuse;
+        self.attenuation = attenuation;
+        self
+    }
+
+    /// Takes the ambient color from the vertex rather than from
+    /// [`flash_channel_color`].
+    pub const fn ambient_from_vertex(mut self, from_vertex: bool) -> Self {
+        self.ambient_from_vertex = from_vertex;
+        self
+    }
+
+    /// Takes the material color from the vertex rather than from
+    /// [`flash_channel_color`].
+    pub const fn material_from_vertex(mut self, from_vertex: bool) -> Self {
+        self.material_from_vertex = from_vertex;
+        self
+    }
+}
+
+impl Default for ChannelConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sets how many color channels reach the TEV, like
+/// `gx_set_color_channels`.
+///
+/// # Panics
+/// If `count` is over 2.
+pub fn set_channels(count: u8) {
+    assert!(count <= 2, "there are 2 color channels");
+    unsafe { sys::gx_set_color_channels(count.into()) };
+}
+
+/// Configures how `channel` is lit, like `gx_configure_color_channel`.
+pub fn configure_channel(channel: Channel, config: ChannelConfig) {
+    unsafe {
+        sys::gx_configure_color_channel(
+            channel as u32,
+            config.lights,
+            config.lighting,
+            config.ambient_from_vertex,
+            config.material_from_vertex,
+            config.diffuse as u32,
+            config.attenuation as u32,
+        );
+    }
+}
+
+/// A color register of the color channels, like `gx_xf_color_t`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum ChannelColor {
+    Ambient0 = sys::GX_XF_COLOR_AMBIENT_0,
+    Ambient1 = sys::GX_XF_COLOR_AMBIENT_1,
+    Material0 = sys::GX_XF_COLOR_MATERIAL_0,
+    Material1 = sys::GX_XF_COLOR_MATERIAL_1,
+}
+
+/// Loads a channel's ambient or material color, like `gx_flash_xf_color`.
+pub fn flash_channel_color(register: ChannelColor, color: Rgba8888) {
+    unsafe { sys::gx_flash_xf_color(register as u32, color.r(), color.g(), color.b(), color.a()) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_match_the_sdk() {
+        assert_eq!(MatrixId::new(7).as_raw(), sys::GX_MTX_ID_7);
+        assert_eq!(MatrixId::IDENTITY.as_raw(), sys::GX_MTX_ID_IDENTITY);
+        let ids = [0, 3, 4, 7].map(LightId::new);
+        let lights = ChannelConfig::new().lit(&ids, Diffuse::Clamped, Attenuation::None);
+        assert_eq!(lights.lights, sys::GX_LIGHT_BIT_0 | sys::GX_LIGHT_BIT_3 | sys::GX_LIGHT_BIT_4 | sys::GX_LIGHT_BIT_7);
+    }
+
+    #[test]
+    fn light_direction_is_flipped() {
+        let light = Light::new(Rgba8888::WHITE).direction([0.0, -1.0, 0.5]);
+        let direction = light.as_raw().direction;
+        assert_eq!([direction.x, direction.y, direction.z], [-0.0, 1.0, -0.5]);
+    }
+}
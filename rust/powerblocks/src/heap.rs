@@ -0,0 +1,183 @@
+//! Heap allocators for `alloc`.
+//!
+//! [`SystemHeap`] allocates from the main heap in MEM1 through
+//! `system_aligned_malloc`, the same heap FreeRTOS uses through heap_3. Enable
+//! the `global-allocator` feature to install it as the `#[global_allocator]`.
+//!
+//! With the `mem2-heap` feature, [`Mem2Heap`] allocates from a [`Mem2Arena`]
+//! placed in the `.mem2` section, falling back to the system heap once the
+//! arena is full:
+//!
+//! ```ignore
+//! use powerblocks::heap::{Mem2Arena, Mem2Heap};
+//!
+//! #[link_section = ".mem2"]
+//! static ARENA: Mem2Arena<{ 16 * 1024 * 1024 }> = Mem2Arena::new();
+//!
+//! #[global_allocator]
+//! static HEAP: Mem2Heap = unsafe { Mem2Heap::new(&ARENA) };
+//! ```
+//!
+//! Like `pvPortMalloc`, these suspend the scheduler while they run and must
+//! not be used from interrupts.
+
+use core::alloc::{GlobalAlloc, Layout};
+use core::ptr;
+
+use crate::sys;
+
+/// Runs `f` with the scheduler suspended, the same way heap_3 guards `malloc`.
+fn with_scheduler_suspended<R>(f: impl FnOnce() -> R) -> R {
+    unsafe { sys::vTaskSuspendAll() };
+    let result = f();
+    unsafe { sys::xTaskResumeAll() };
+    result
+}
+
+/// Allocator over `system_aligned_malloc` and `system_aligned_free`.
+pub struct SystemHeap;
+
+unsafe impl GlobalAlloc for SystemHeap {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let (Ok(size), Ok(align)) = (u32::try_from(layout.size()), u32::try_from(layout.align())) else {
+            return ptr::null_mut();
+        };
+
+        with_scheduler_suspended(|| sys::system_aligned_malloc(size, align).cast())
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, _layout: Layout) {
+        with_scheduler_suspended(|| sys::system_aligned_free(ptr.cast()));
+    }
+}
+
+#[cfg(all(feature = "global-allocator", not(test)))]
+#[global_allocator]
+static GLOBAL: SystemHeap = SystemHeap;
+
+/// Reports allocation failures the same way `ASSERT_OUT_OF_MEMORY` does.
+#[cfg(all(feature = "alloc-error-handler", not(test)))]
+#[alloc_error_handler]
+fn alloc_error(_layout: Layout) -> ! {
+    crate::syscall::assert_failed(c"OUT OF MEMORY", file!(), line!())
+}
+
+#[cfg(feature = "mem2-heap")]
+pub use mem2::{Mem2Arena, Mem2Heap};
+
+#[cfg(feature = "mem2-heap")]
+mod mem2 {
+    use core::alloc::{GlobalAlloc, Layout};
+    use core::cell::UnsafeCell;
+    use core::mem::MaybeUninit;
+    use core::ptr::{self, NonNull};
+
+    use linked_list_allocator::Heap;
+
+    use super::{with_scheduler_suspended, SystemHeap};
+
+    /// Backing memory for a [`Mem2Heap`].
+    ///
+    /// Place it in MEM2 with `#[link_section = ".mem2"]`. That section is not
+    /// loaded, so the arena starts out uninitialized and costs nothing in the
+    /// executable.
+    #[repr(C, align(32))]
+    pub struct Mem2Arena<const N: usize>(UnsafeCell<MaybeUninit<[u8; N]>>);
+
+    unsafe impl<const N: usize> Sync for Mem2Arena<N> {}
+
+    impl<const N: usize> Mem2Arena<N> {
+        pub const fn new() -> Self {
+            Self(UnsafeCell::new(MaybeUninit::uninit()))
+        }
+    }
+
+    impl<const N: usize> Default for Mem2Arena<N> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    struct State {
+        heap: Heap,
+        initialized: bool,
+    }
+
+    /// Allocator over a [`Mem2Arena`].
+    ///
+    /// The arena is set up on the first allocation. Requests that do not fit
+    /// in it are passed on to [`SystemHeap`].
+    pub struct Mem2Heap {
+        arena: *mut u8,
+        size: usize,
+        state: UnsafeCell<State>,
+    }
+
+    unsafe impl Sync for Mem2Heap {}
+
+    impl Mem2Heap {
+        /// Creates an allocator over `arena`.
+        ///
+        /// # Safety
+        /// `arena` must not be used by anything else, including another
+        /// `Mem2Heap`.
+        pub const unsafe fn new<const N: usize>(arena: &'static Mem2Arena<N>) -> Self {
+            Self {
+                arena: arena.0.get().cast(),
+                size: N,
+                state: UnsafeCell::new(State { heap: Heap::empty(), initialized: false }),
+            }
+        }
+
+        /// Bytes in use in the arena.
+        pub fn used(&self) -> usize {
+            with_scheduler_suspended(|| unsafe { (*self.state.get()).heap.used() })
+        }
+
+        /// Bytes free in the arena.
+        pub fn free(&self) -> usize {
+            with_scheduler_suspended(|| unsafe {
+                let state = &*self.state.get();
+                if state.initialized {
+                    state.heap.free()
+                } else {
+                    self.size
+                }
+            })
+        }
+
+        fn contains(&self, ptr: *mut u8) -> bool {
+            let start = self.arena as usize;
+            (start..start + self.size).contains(&(ptr as usize))
+        }
+    }
+
+    unsafe impl GlobalAlloc for Mem2Heap {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = with_scheduler_suspended(|| {
+                let state = &mut *self.state.get();
+                if !state.initialized {
+                    state.heap.init(self.arena, self.size);
+                    state.initialized = true;
+                }
+                state.heap.allocate_first_fit(layout).map_or(ptr::null_mut(), NonNull::as_ptr)
+            });
+
+            if ptr.is_null() {
+                SystemHeap.alloc(layout)
+            } else {
+                ptr
+            }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            if self.contains(ptr) {
+                with_scheduler_suspended(|| {
+                    (*self.state.get()).heap.deallocate(NonNull::new_unchecked(ptr), layout)
+                });
+            } else {
+                SystemHeap.dealloc(ptr, layout);
+            }
+        }
+    }
+}
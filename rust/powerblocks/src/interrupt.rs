@@ -0,0 +1,93 @@
+//! Masking external interrupts, like `SYSTEM_DISABLE_ISR` and
+//! `SYSTEM_ENABLE_ISR`.
+//!
+//! These save the previous state in a local rather than FreeRTOS's
+//! `freertos_isr_enabled`, so they nest, and can be used from both tasks and
+//! interrupt handlers.
+//!
+//! With the `critical-section` feature, the `critical-section` crate is
+//! implemented the same way, so crates built on it like `heapless`,
+//! `embassy-sync` and `portable-atomic` work from tasks and from handlers
+//! installed with `exceptions_install_irq`. Handlers run with interrupts
+//! masked already, so a critical section in one leaves them masked.
+
+/// `MSR[EE]`, external interrupts enabled.
+#[cfg(target_arch = "powerpc")]
+const MSR_EE: u32 = 0x8000;
+
+/// Whether external interrupts are enabled. They are not in interrupt
+/// handlers or while they are masked.
+#[inline(always)]
+pub fn are_enabled() -> bool {
+    #[cfg(target_arch = "powerpc")]
+    unsafe {
+        let msr: u32;
+        core::arch::asm!("mfmsr {0}", out(reg) msr, options(nomem, nostack, preserves_flags));
+        msr & MSR_EE != 0
+    }
+
+    #[cfg(not(target_arch = "powerpc"))]
+    true
+}
+
+/// Disables external interrupts and returns whether they were enabled.
+#[inline(always)]
+pub fn disable() -> bool {
+    #[cfg(target_arch = "powerpc")]
+    unsafe {
+        let msr: u32;
+        core::arch::asm!("mfmsr {0}", out(reg) msr, options(nomem, nostack, preserves_flags));
+        core::arch::asm!("mtmsr {0}", in(reg) msr & !MSR_EE, options(nostack, preserves_flags));
+        msr & MSR_EE != 0
+    }
+
+    #[cfg(not(target_arch = "powerpc"))]
+    false
+}
+
+/// Re-enables external interrupts if `enabled` is true.
+///
+/// # Safety
+/// `enabled` must come from the matching [`disable`], and calls must nest.
+#[inline(always)]
+pub unsafe fn restore(enabled: bool) {
+    #[cfg(target_arch = "powerpc")]
+    if enabled {
+        let msr: u32;
+        core::arch::asm!("mfmsr {0}", out(reg) msr, options(nomem, nostack, preserves_flags));
+        core::arch::asm!("mtmsr {0}", in(reg) msr | MSR_EE, options(nostack, preserves_flags));
+    }
+
+    #[cfg(not(target_arch = "powerpc"))]
+    let _ = enabled;
+}
+
+/// Runs `f` with external interrupts disabled.
+///
+/// This only keeps interrupts and task switches out. `f` must not block.
+#[inline]
+pub fn free<R>(f: impl FnOnce() -> R) -> R {
+    let enabled = disable();
+    let result = f();
+    unsafe { restore(enabled) };
+    result
+}
+
+/// The `critical-section` implementation, masking external interrupts like
+/// [`disable`] and [`restore`].
+#[cfg(feature = "critical-section")]
+struct CriticalSection;
+
+#[cfg(feature = "critical-section")]
+critical_section::set_impl!(CriticalSection);
+
+#[cfg(feature = "critical-section")]
+unsafe impl critical_section::Impl for CriticalSection {
+    unsafe fn acquire() -> critical_section::RawRestoreState {
+        disable()
+    }
+
+    unsafe fn release(enabled: critical_section::RawRestoreState) {
+        restore(enabled);
+    }
+}
@@ -0,0 +1,72 @@
+//! Standard output.
+//!
+//! Writes go through libc's `stdout`, the same path as `printf` in C, which
+//! `libcio.c` sends to the console. There is no separate `stderr`, so
+//! [`eprint!`] and [`eprintln!`] write there too.
+//!
+//! Like `printf`, output from several tasks is not serialized. Use the
+//! [`logger`](crate::logger) for that.
+
+use core::ffi::c_void;
+use core::fmt;
+
+extern "C" {
+    static stdout: *mut c_void;
+
+    fn fwrite(ptr: *const c_void, size: usize, count: usize, stream: *mut c_void) -> usize;
+}
+
+/// Handle to libc's `stdout`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Stdout;
+
+impl fmt::Write for Stdout {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let written = unsafe { fwrite(s.as_ptr().cast(), 1, s.len(), stdout) };
+        if written == s.len() {
+            Ok(())
+        } else {
+            Err(fmt::Error)
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn _print(args: fmt::Arguments) {
+    let _ = fmt::Write::write_fmt(&mut Stdout, args);
+}
+
+/// Prints to `stdout`.
+#[macro_export]
+macro_rules! print {
+    ($($arg:tt)*) => {
+        $crate::io::_print(format_args!($($arg)*))
+    };
+}
+
+/// Prints to `stdout`, with a newline.
+#[macro_export]
+macro_rules! println {
+    () => {
+        $crate::print!("\n")
+    };
+    ($($arg:tt)*) => {
+        $crate::io::_print(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+/// Prints to `stdout`. There is no separate `stderr`.
+#[macro_export]
+macro_rules! eprint {
+    ($($arg:tt)*) => {
+        $crate::print!($($arg)*)
+    };
+}
+
+/// Prints to `stdout`, with a newline. There is no separate `stderr`.
+#[macro_export]
+macro_rules! eprintln {
+    ($($arg:tt)*) => {
+        $crate::println!($($arg)*)
+    };
+}
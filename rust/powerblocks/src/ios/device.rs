@@ -0,0 +1,182 @@
+//! File handles that close themselves.
+
+use core::ffi::{c_int, CStr};
+use core::mem;
+
+use super::request::{self, size};
+use super::{DmaBuffer, Error, Mode, SeekFrom, MAX_VECTORS};
+use crate::sys;
+
+/// An open IOS file or device, closed when dropped.
+///
+/// The blocking methods must be called from a task, and so must `drop`.
+/// The `_async` methods are for an [`Executor`](crate::executor::Executor)
+/// and behave the same. Buffers are [`DmaBuffer`]s, which are aligned the
+/// way IOS needs.
+#[derive(Debug)]
+pub struct IosDevice {
+    fd: c_int,
+}
+
+impl IosDevice {
+    /// Opens `path`, like `ios_open`.
+    ///
+    /// # Panics
+    /// If `path` is `IOS_MAX_PATH` bytes or longer, including the terminator.
+    pub fn open(path: &CStr, mode: Mode) -> Result<Self, Error> {
+        // ios_open hands IOS the first IOS_MAX_PATH bytes of the path.
+        let path = request::copy_path(path);
+        let fd = Error::check(unsafe { sys::ios_open(path.as_ptr().cast(), mode as c_int) })?;
+        Ok(Self { fd: fd as c_int })
+    }
+
+    /// [`open`](Self::open) without blocking the task.
+    pub async fn open_async(path: &CStr, mode: Mode) -> Result<Self, Error> {
+        let fd = Error::check(request::open(path, mode).await)?;
+        Ok(Self { fd: fd as c_int })
+    }
+
+    /// Takes ownership of a file handle from C.
+    ///
+    /// # Safety
+    /// `fd` must be open, and must not be closed by anything else.
+    pub const unsafe fn from_raw(fd: i32) -> Self {
+        Self { fd }
+    }
+
+    pub const fn as_raw(&self) -> i32 {
+        self.fd
+    }
+
+    /// Gives up ownership of the file handle without closing it.
+    pub fn into_raw(self) -> i32 {
+        let fd = self.fd;
+        mem::forget(self);
+        fd
+    }
+
+    /// Closes the handle and reports any error, which dropping ignores.
+    pub fn close(self) -> Result<(), Error> {
+        let fd = self.into_raw();
+        Error::check(unsafe { sys::ios_close(fd) }).map(drop)
+    }
+
+    /// [`close`](Self::close) without blocking the task.
+    pub async fn close_async(self) -> Result<(), Error> {
+        let fd = self.into_raw();
+        Error::check(request::close(fd).await).map(drop)
+    }
+
+    /// Reads into `buffer`, returning the number of bytes read.
+    pub fn read<B: DmaBuffer + ?Sized>(&self, buffer: &mut B) -> Result<usize, Error> {
+        let buffer = buffer.as_dma_mut();
+        let read = unsafe { sys::ios_read(self.fd, buffer.as_mut_ptr().cast(), size(buffer.len())) };
+        Error::check(read).map(|read| read as usize)
+    }
+
+    /// [`read`](Self::read) without blocking the task.
+    pub async fn read_async<B: DmaBuffer + ?Sized>(&self, buffer: &mut B) -> Result<usize, Error> {
+        Error::check(request::read(self.fd, buffer.as_dma_mut()).await).map(|read| read as usize)
+    }
+
+    /// Writes `buffer`, returning the number of bytes written.
+    pub fn write<B: DmaBuffer + ?Sized>(&self, buffer: &B) -> Result<usize, Error> {
+        let buffer = buffer.as_dma();
+        let written = unsafe { sys::ios_write(self.fd, buffer.as_ptr().cast_mut().cast(), size(buffer.len())) };
+        Error::check(written).map(|written| written as usize)
+    }
+
+    /// [`write`](Self::write) without blocking the task.
+    pub async fn write_async<B: DmaBuffer + ?Sized>(&self, buffer: &B) -> Result<usize, Error> {
+        Error::check(request::write(self.fd, buffer.as_dma()).await).map(|written| written as usize)
+    }
+
+    /// Moves the file position, returning the new one.
+    pub fn seek(&self, position: SeekFrom) -> Result<u32, Error> {
+        let (offset, whence) = position.into_raw();
+        Error::check(unsafe { sys::ios_seek(self.fd, offset, whence) })
+    }
+
+    /// [`seek`](Self::seek) without blocking the task.
+    pub async fn seek_async(&self, position: SeekFrom) -> Result<u32, Error> {
+        let (offset, whence) = position.into_raw();
+        Error::check(request::seek(self.fd, offset, whence).await)
+    }
+
+    /// Sends a device command with one input and one output buffer.
+    pub fn ioctl<I, O>(&self, ioctl: u32, input: &I, output: &mut O) -> Result<u32, Error>
+    where
+        I: DmaBuffer + ?Sized,
+        O: DmaBuffer + ?Sized,
+    {
+        let (input, output) = (input.as_dma(), output.as_dma_mut());
+        let result = unsafe {
+            sys::ios_ioctl(
+                self.fd,
+                ioctl as c_int,
+                input.as_ptr().cast_mut().cast(),
+                size(input.len()),
+                output.as_mut_ptr().cast(),
+                size(output.len()),
+            )
+        };
+        Error::check(result)
+    }
+
+    /// [`ioctl`](Self::ioctl) without blocking the task.
+    pub async fn ioctl_async<I, O>(&self, ioctl: u32, input: &I, output: &mut O) -> Result<u32, Error>
+    where
+        I: DmaBuffer + ?Sized,
+        O: DmaBuffer + ?Sized,
+    {
+        Error::check(request::ioctl(self.fd, ioctl as c_int, input.as_dma(), output.as_dma_mut()).await)
+    }
+
+    /// Sends a device command with several input and output buffers.
+    ///
+    /// At most [`MAX_VECTORS`] buffers can be passed, which is checked when
+    /// compiling.
+    pub fn ioctlv<const I: usize, const O: usize>(
+        &self,
+        ioctl: u32,
+        inputs: [&dyn DmaBuffer; I],
+        outputs: [&mut dyn DmaBuffer; O],
+    ) -> Result<u32, Error> {
+        const { assert!(I + O <= MAX_VECTORS, "too many ioctlv vectors") };
+
+        let mut vectors = [sys::ios_ioctlv_t { data: core::ptr::null_mut(), size: 0 }; MAX_VECTORS];
+        let buffers = inputs
+            .into_iter()
+            .map(|input| (input.as_dma().as_ptr().cast_mut(), input.as_dma().len()))
+            .chain(outputs.into_iter().map(|output| {
+                let output = output.as_dma_mut();
+                (output.as_mut_ptr(), output.len())
+            }));
+        for (vector, (data, length)) in vectors.iter_mut().zip(buffers) {
+            *vector = sys::ios_ioctlv_t { data: data.cast(), size: length as u32 };
+        }
+
+        let result = unsafe { sys::ios_ioctlv(self.fd, ioctl as c_int, I as c_int, O as c_int, vectors.as_mut_ptr()) };
+        Error::check(result)
+    }
+
+    /// [`ioctlv`](Self::ioctlv) without blocking the task.
+    pub async fn ioctlv_async<const I: usize, const O: usize>(
+        &self,
+        ioctl: u32,
+        inputs: [&dyn DmaBuffer; I],
+        outputs: [&mut dyn DmaBuffer; O],
+    ) -> Result<u32, Error> {
+        const { assert!(I + O <= MAX_VECTORS, "too many ioctlv vectors") };
+
+        let inputs = inputs.map(|input| input.as_dma());
+        let mut outputs = outputs.map(|output| output.as_dma_mut());
+        Error::check(request::ioctlv(self.fd, ioctl as c_int, &inputs, &mut outputs).await)
+    }
+}
+
+impl Drop for IosDevice {
+    fn drop(&mut self) {
+        unsafe { sys::ios_close(self.fd) };
+    }
+}
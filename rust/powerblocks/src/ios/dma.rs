@@ -0,0 +1,160 @@
+//! Buffers laid out for IOS to reach by DMA.
+
+use core::ops::{Deref, DerefMut};
+use core::slice;
+
+/// Plain data, for which any bytes IOS writes are a valid value.
+///
+/// # Safety
+/// The type must have no padding, and every bit pattern must be a valid
+/// value of it.
+pub unsafe trait Plain: Copy + 'static {}
+
+unsafe impl Plain for u8 {}
+unsafe impl Plain for u16 {}
+unsafe impl Plain for u32 {}
+unsafe impl Plain for u64 {}
+unsafe impl Plain for i8 {}
+unsafe impl Plain for i16 {}
+unsafe impl Plain for i32 {}
+unsafe impl Plain for i64 {}
+unsafe impl<T: Plain, const N: usize> Plain for [T; N] {}
+
+/// A value aligned to 32 bytes.
+///
+/// Alignment also rounds the size up to 32 bytes, so a `Dma` owns all of
+/// its cache lines and can be handed to IOS as it is.
+///
+/// ```ignore
+/// #[repr(C)]
+/// #[derive(Clone, Copy)]
+/// struct Request { command: u32, length: u32 }
+///
+/// unsafe impl Plain for Request {}
+///
+/// device.ioctl(REQUEST, &Dma(Request { command: 1, length: 16 }), &mut Dma([0u8; 16]))?;
+/// ```
+#[repr(C, align(32))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Dma<T>(pub T);
+
+impl<T> Deref for Dma<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Dma<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// Memory IOS may read from, and write to, by DMA.
+///
+/// # Safety
+/// Both slices must start on a 32-byte boundary and cover the same memory.
+/// The memory from their end up to the next 32-byte boundary must belong to
+/// the buffer as well. Any bytes written through
+/// [`as_dma_mut`](Self::as_dma_mut) must leave the buffer valid.
+pub unsafe trait DmaBuffer {
+    fn as_dma(&self) -> &[u8];
+    fn as_dma_mut(&mut self) -> &mut [u8];
+}
+
+unsafe impl<T: Plain> DmaBuffer for Dma<T> {
+    fn as_dma(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(core::ptr::from_ref(&self.0).cast(), size_of::<T>()) }
+    }
+
+    fn as_dma_mut(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(core::ptr::from_mut(&mut self.0).cast(), size_of::<T>()) }
+    }
+}
+
+/// Bytes on the heap, for buffers whose size is only known at run time.
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct DmaBox {
+    blocks: alloc::boxed::Box<[Dma<[u8; 32]>]>,
+    len: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl DmaBox {
+    /// Allocates `len` zeroed bytes.
+    pub fn new(len: usize) -> Self {
+        let blocks = alloc::vec![Dma([0; 32]); len.div_ceil(32)].into_boxed_slice();
+        Self { blocks, len }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Deref for DmaBox {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.blocks.as_ptr().cast(), self.len) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl DerefMut for DmaBox {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self.blocks.as_mut_ptr().cast(), self.len) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Debug for DmaBox {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DmaBox").field("len", &self.len).finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe impl DmaBuffer for DmaBox {
+    fn as_dma(&self) -> &[u8] {
+        self
+    }
+
+    fn as_dma_mut(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dma_owns_whole_cache_lines() {
+        assert_eq!(align_of::<Dma<u8>>(), 32);
+        assert_eq!(size_of::<Dma<u8>>(), 32);
+        assert_eq!(size_of::<Dma<[u32; 9]>>(), 64);
+
+        let buffer = Dma([0u32; 9]);
+        assert_eq!(buffer.as_dma().len(), 36);
+        assert_eq!(buffer.as_dma().as_ptr() as usize % 32, 0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn dma_box_is_aligned() {
+        let mut buffer = DmaBox::new(40);
+        buffer[39] = 1;
+        assert_eq!(buffer.len(), 40);
+        assert_eq!(buffer.as_dma().as_ptr() as usize % 32, 0);
+        assert_eq!(buffer.as_dma()[39], 1);
+    }
+}
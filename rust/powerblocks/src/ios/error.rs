@@ -0,0 +1,69 @@
+//! IOS error codes.
+
+use crate::error::error_codes;
+
+error_codes! {
+    /// An error returned by IOS, from its negative return values.
+    ///
+    /// The kernel and the file system module use separate ranges; codes not
+    /// listed here are kept in [`Other`](Self::Other).
+    pub enum Error(i32) {
+        /// -1, the process may not use the resource.
+        PermissionDenied = -1 => "permission denied",
+        /// -2, the resource already exists.
+        AlreadyExists = -2 => "already exists",
+        /// -4, an argument was invalid, such as an unknown ioctl or a buffer of
+        /// the wrong size.
+        InvalidArgument = -4 => "invalid argument",
+        /// -6, no such device or file.
+        NotFound = -6 => "not found",
+        /// -8, the request queue of the device is full.
+        QueueFull = -8 => "request queue full",
+        /// -22, IOS ran out of memory.
+        OutOfMemory = -22 => "out of memory",
+        /// -101, the file system was given an invalid argument.
+        FsInvalidArgument = -101 => "invalid argument",
+        /// -102, the file system denied access.
+        FsPermissionDenied = -102 => "permission denied",
+        /// -103, the NAND is corrupted.
+        FsCorrupted = -103 => "file system corrupted",
+        /// -105, the file already exists.
+        FsAlreadyExists = -105 => "already exists",
+        /// -106, no such file.
+        FsNotFound = -106 => "not found",
+    }
+    /// Any other negative value.
+    Other => "IOS error",
+}
+
+impl Error {
+    /// Splits an IOS return value into a result.
+    pub const fn check(result: i32) -> Result<u32, Self> {
+        if result < 0 {
+            Err(Self::from_code(result))
+        } else {
+            Ok(result as u32)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kernel_and_fs_ranges_stay_apart() {
+        assert_eq!(Error::from_code(-6), Error::NotFound);
+        assert_eq!(Error::from_code(-106), Error::FsNotFound);
+        assert_eq!(Error::from_code(-1), Error::PermissionDenied);
+        assert_eq!(Error::from_code(-102), Error::FsPermissionDenied);
+        assert_eq!(Error::FsNotFound.to_string(), "not found (-106)");
+    }
+
+    #[test]
+    fn check_keeps_descriptors() {
+        assert_eq!(Error::check(3), Ok(3));
+        assert_eq!(Error::check(-8), Err(Error::QueueFull));
+        assert_eq!(Error::check(-104), Err(Error::Other(-104)));
+    }
+}
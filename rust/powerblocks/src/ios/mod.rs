@@ -0,0 +1,62 @@
+//! The Input Output System, Starlet's device and file interface.
+//!
+//! [`IosDevice`] is a file handle that closes itself, with blocking and
+//! `async` methods that take [`DmaBuffer`]s and return [`Error`]s. The free
+//! functions, such as [`open`] and [`ioctl`], are the lower level
+//! [`Request`] futures over the `ios_*_async` calls, which take plain
+//! slices and resolve to the raw IOS return value.
+//!
+//! IOS reaches buffers by DMA, so they must start on a 32-byte boundary and
+//! own the whole of their last cache line: sending one flushes or
+//! invalidates it by cache line, which would also hit any data sharing
+//! those lines. [`Dma`] lays any value out that way.
+//!
+//! ```ignore
+//! let device = IosDevice::open(c"/dev/stm/immediate", Mode::None)?;
+//! let input = Dma([0u32; 8]);
+//! let mut output = Dma([0u32; 8]);
+//! device.ioctl(0x5001, &input, &mut output)?;
+//! ```
+
+use crate::sys;
+
+mod device;
+mod dma;
+mod error;
+mod request;
+
+pub use device::IosDevice;
+pub use dma::{Dma, DmaBuffer, Plain};
+#[cfg(feature = "alloc")]
+pub use dma::DmaBox;
+pub use error::Error;
+pub use request::{close, ioctl, ioctlv, open, read, seek, write, Request, MAX_VECTORS};
+
+/// Access requested when opening a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// No file access, as most devices under `/dev` are opened.
+    None = 0,
+    Read = sys::IOS_MODE_READ as isize,
+    Write = sys::IOS_MODE_WRITE as isize,
+    ReadWrite = (sys::IOS_MODE_READ | sys::IOS_MODE_WRITE) as isize,
+}
+
+/// Where [`IosDevice::seek`] counts from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeekFrom {
+    Start(i32),
+    Current(i32),
+    End(i32),
+}
+
+impl SeekFrom {
+    /// The `where` and `whence` arguments of `ios_seek`.
+    fn into_raw(self) -> (i32, i32) {
+        match self {
+            Self::Start(offset) => (offset, 0),
+            Self::Current(offset) => (offset, 1),
+            Self::End(offset) => (offset, 2),
+        }
+    }
+}
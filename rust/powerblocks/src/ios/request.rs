@@ -0,0 +1,274 @@
+//! Raw asynchronous IOS requests.
+//!
+//! Each function here returns a [`Request`] future wrapping the matching
+//! `ios_*_async` call. The `ipc_message` lives inside the future, the
+//! request is sent on the first poll, and the IPC completion handler wakes
+//! the future from the interrupt handler. Results are the raw IOS return
+//! values, negative on error; see [`Error::check`](super::Error::check).
+//!
+//! Requests must be polled from a task, as sending one waits for IOS to
+//! acknowledge it. The buffers passed in should follow the alignment rules
+//! in the [module docs](super).
+//!
+//! ```ignore
+//! let fd = ios::open(c"/dev/stm/immediate", Mode::None).await;
+//! let result = ios::ioctl(fd, 0x5001, &input, &mut output).await;
+//! ios::close(fd).await;
+//! ```
+
+use core::cell::UnsafeCell;
+use core::ffi::{c_int, c_void, CStr};
+use core::future::Future;
+use core::marker::{PhantomData, PhantomPinned};
+use core::mem;
+use core::pin::Pin;
+use core::ptr;
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+
+use super::{Dma, Mode};
+use crate::interrupt;
+use crate::rtos::task;
+use crate::sys;
+
+/// Most vectors, inputs and outputs together, that [`ioctlv`] accepts.
+pub const MAX_VECTORS: usize = 8;
+
+const PATH_LENGTH: usize = sys::IOS_MAX_PATH as usize;
+
+enum Operation {
+    Open { mode: c_int },
+    Close,
+    Read { buffer: *mut u8, size: c_int },
+    Write { buffer: *const u8, size: c_int },
+    Seek { offset: c_int, whence: c_int },
+    Ioctl { ioctl: c_int, input: *const u8, input_size: c_int, output: *mut u8, output_size: c_int },
+    Ioctlv { ioctl: c_int, inputs: c_int, outputs: c_int },
+}
+
+enum State {
+    Unsent(Operation),
+    Sent,
+    Done,
+}
+
+/// Written by [`complete`] from the interrupt handler, and read with
+/// interrupts disabled.
+struct Completion {
+    result: Option<c_int>,
+    waker: Option<Waker>,
+}
+
+/// A pending IOS request, returned by the functions in this module.
+///
+/// The request stays in flight until IOS answers, even if the future is
+/// dropped. Dropping a sent request therefore blocks the task until it
+/// completes, so that IOS does not write into freed memory. Leaking one
+/// with [`mem::forget`] while it is in flight is undefined behavior.
+#[must_use = "requests do nothing unless awaited"]
+pub struct Request<'a> {
+    message: Dma<sys::ipc_message>,
+    state: State,
+    completion: UnsafeCell<Completion>,
+    path: Dma<[u8; PATH_LENGTH]>,
+    vectors: Dma<[sys::ios_ioctlv_t; MAX_VECTORS]>,
+    _buffers: PhantomData<&'a mut [u8]>,
+    _pin: PhantomPinned,
+}
+
+// The raw pointers in `Operation` come from borrows held for `'a`.
+unsafe impl Send for Request<'_> {}
+
+unsafe extern "C" fn complete(params: *mut c_void, result: c_int) {
+    let completion = &mut *params.cast::<Completion>();
+    completion.result = Some(result);
+    // The future keeps the waker, so this never drops the last reference
+    // from the interrupt handler.
+    if let Some(waker) = &completion.waker {
+        waker.wake_by_ref();
+    }
+}
+
+pub(super) fn size(length: usize) -> c_int {
+    c_int::try_from(length).unwrap_or(c_int::MAX)
+}
+
+impl<'a> Request<'a> {
+    fn new(file_handle: c_int, operation: Operation) -> Self {
+        let mut message: sys::ipc_message = unsafe { mem::zeroed() };
+        message.file_handle = file_handle;
+        Self {
+            message: Dma(message),
+            state: State::Unsent(operation),
+            completion: UnsafeCell::new(Completion { result: None, waker: None }),
+            path: Dma([0; PATH_LENGTH]),
+            vectors: Dma([sys::ios_ioctlv_t { data: ptr::null_mut(), size: 0 }; MAX_VECTORS]),
+            _buffers: PhantomData,
+            _pin: PhantomPinned,
+        }
+    }
+
+    /// Hands the request to IOS. Returns the error if it could not be sent.
+    unsafe fn send(&mut self, operation: Operation) -> Option<c_int> {
+        let fd = self.message.0.file_handle;
+        let message = ptr::addr_of_mut!(self.message.0);
+        let handler = Some(complete as unsafe extern "C" fn(*mut c_void, c_int));
+        let params = self.completion.get().cast();
+
+        let sent = match operation {
+            Operation::Open { mode } => {
+                sys::ios_open_async(self.path.0.as_ptr().cast(), mode, message, handler, params)
+            }
+            Operation::Close => sys::ios_close_async(fd, message, handler, params),
+            Operation::Read { buffer, size } => {
+                sys::ios_read_async(fd, buffer.cast(), size, message, handler, params)
+            }
+            Operation::Write { buffer, size } => {
+                sys::ios_write_async(fd, buffer.cast_mut().cast(), size, message, handler, params)
+            }
+            Operation::Seek { offset, whence } => sys::ios_seek_async(fd, offset, whence, message, handler, params),
+            Operation::Ioctl { ioctl, input, input_size, output, output_size } => sys::ios_ioctl_async(
+                fd,
+                ioctl,
+                input.cast_mut().cast(),
+                input_size,
+                output.cast(),
+                output_size,
+                message,
+                handler,
+                params,
+            ),
+            Operation::Ioctlv { ioctl, inputs, outputs } => {
+                // The vectors are converted to physical addresses in place.
+                let vectors = self.vectors.0.as_mut_ptr();
+                sys::ios_ioctlv_async(fd, ioctl, inputs, outputs, vectors, message, vectors, handler, params)
+            }
+        };
+        (sent < 0).then_some(sent)
+    }
+
+    fn is_in_flight(&self) -> bool {
+        matches!(self.state, State::Sent) && interrupt::free(|| unsafe { (*self.completion.get()).result.is_none() })
+    }
+}
+
+impl Future for Request<'_> {
+    type Output = i32;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<i32> {
+        let this = unsafe { self.get_unchecked_mut() };
+
+        // Any waker replaced here is dropped once interrupts are back on.
+        let _replaced = interrupt::free(|| unsafe {
+            let completion = &mut *this.completion.get();
+            match &completion.waker {
+                Some(waker) if waker.will_wake(cx.waker()) => None,
+                _ => completion.waker.replace(cx.waker().clone()),
+            }
+        });
+
+        match mem::replace(&mut this.state, State::Sent) {
+            State::Unsent(operation) => {
+                if let Some(error) = unsafe { this.send(operation) } {
+                    this.state = State::Done;
+                    return Poll::Ready(error);
+                }
+            }
+            State::Sent => {}
+            State::Done => panic!("IOS request polled after completion"),
+        }
+
+        match interrupt::free(|| unsafe { (*this.completion.get()).result }) {
+            Some(result) => {
+                this.state = State::Done;
+                Poll::Ready(result)
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl Drop for Request<'_> {
+    fn drop(&mut self) {
+        while self.is_in_flight() {
+            task::delay(Duration::from_millis(1));
+        }
+    }
+}
+
+/// Copies `path` into a buffer of `IOS_MAX_PATH` bytes, which is how much
+/// `ios_open_async` hands to IOS.
+pub(super) fn copy_path(path: &CStr) -> Dma<[u8; PATH_LENGTH]> {
+    let bytes = path.to_bytes_with_nul();
+    assert!(bytes.len() <= PATH_LENGTH, "IOS path too long");
+
+    let mut buffer = Dma([0; PATH_LENGTH]);
+    buffer.0[..bytes.len()].copy_from_slice(bytes);
+    buffer
+}
+
+/// Opens `path`, like `ios_open`. Resolves to the file handle.
+///
+/// # Panics
+/// If `path` is `IOS_MAX_PATH` bytes or longer, including the terminator.
+pub fn open(path: &CStr, mode: Mode) -> Request<'static> {
+    let mut request = Request::new(0, Operation::Open { mode: mode as c_int });
+    request.path = copy_path(path);
+    request
+}
+
+/// Closes a file handle, like `ios_close`.
+pub fn close(fd: i32) -> Request<'static> {
+    Request::new(fd, Operation::Close)
+}
+
+/// Reads into `buffer`, like `ios_read`. Resolves to the number of bytes
+/// read.
+pub fn read(fd: i32, buffer: &mut [u8]) -> Request<'_> {
+    Request::new(fd, Operation::Read { buffer: buffer.as_mut_ptr(), size: size(buffer.len()) })
+}
+
+/// Writes `buffer`, like `ios_write`. Resolves to the number of bytes
+/// written.
+pub fn write(fd: i32, buffer: &[u8]) -> Request<'_> {
+    Request::new(fd, Operation::Write { buffer: buffer.as_ptr(), size: size(buffer.len()) })
+}
+
+/// Moves the file position, like `ios_seek`. `whence` is 0, 1 or 2 for the
+/// start, the current position or the end.
+pub fn seek(fd: i32, offset: i32, whence: i32) -> Request<'static> {
+    Request::new(fd, Operation::Seek { offset, whence })
+}
+
+/// Sends a device command, like `ios_ioctl`.
+pub fn ioctl<'a>(fd: i32, ioctl: i32, input: &'a [u8], output: &'a mut [u8]) -> Request<'a> {
+    Request::new(
+        fd,
+        Operation::Ioctl {
+            ioctl,
+            input: input.as_ptr(),
+            input_size: size(input.len()),
+            output: output.as_mut_ptr(),
+            output_size: size(output.len()),
+        },
+    )
+}
+
+/// Sends a device command with several buffers, like `ios_ioctlv`.
+///
+/// # Panics
+/// If there are more than [`MAX_VECTORS`] buffers.
+pub fn ioctlv<'a>(fd: i32, ioctl: i32, inputs: &[&'a [u8]], outputs: &mut [&'a mut [u8]]) -> Request<'a> {
+    assert!(inputs.len() + outputs.len() <= MAX_VECTORS, "too many ioctlv vectors");
+
+    let operation = Operation::Ioctlv { ioctl, inputs: size(inputs.len()), outputs: size(outputs.len()) };
+    let mut request = Request::new(fd, operation);
+    let buffers = inputs
+        .iter()
+        .map(|input| (input.as_ptr().cast_mut(), input.len()))
+        .chain(outputs.iter_mut().map(|output| (output.as_mut_ptr(), output.len())));
+    for (vector, (data, length)) in request.vectors.0.iter_mut().zip(buffers) {
+        *vector = sys::ios_ioctlv_t { data: data.cast(), size: length as u32 };
+    }
+    request
+}
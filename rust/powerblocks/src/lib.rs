@@ -0,0 +1,34 @@
+//! Safe Rust interface to the PowerBlocks SDK.
+//!
+//! Builds on the raw bindings in [`powerblocks_sys`], which are re-exported
+//! as [`sys`] for anything not covered here yet.
+
+#![cfg_attr(not(test), no_std)]
+#![cfg_attr(target_arch = "powerpc", feature(asm_experimental_arch))]
+#![cfg_attr(all(feature = "alloc-error-handler", not(test)), feature(alloc_error_handler))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub use powerblocks_sys as sys;
+
+mod cstr;
+mod error;
+
+pub mod bluetooth;
+pub mod exception;
+pub mod executor;
+pub mod framebuffer;
+pub mod fs;
+pub mod gx;
+pub mod heap;
+pub mod interrupt;
+pub mod io;
+pub mod ios;
+#[cfg(feature = "log")]
+pub mod logger;
+pub mod panic;
+pub mod rtos;
+pub mod syscall;
+pub mod time;
+pub mod wiimote;
@@ -0,0 +1,61 @@
+//! [`log`] backend on top of `log_message`.
+//!
+//! Records go through the same mutex and `printf` as `LOG_INFO` and friends
+//! in C, so C and Rust logs come out as one stream:
+//!
+//! ```text
+//! [INFO] (my_game::audio) mixer started
+//! ```
+//!
+//! The record's target, which defaults to the module path, is the tag.
+//! `log_initialize` must have been called first, and like `log_message`,
+//! logging blocks and must not be used from interrupts.
+
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+use crate::cstr::CStrBuffer;
+use crate::sys;
+
+/// Longest message passed to `log_message`, including the terminator.
+const MESSAGE_LENGTH: usize = 256;
+
+/// Longest tag passed to `log_message`, including the terminator.
+const TAG_LENGTH: usize = 64;
+
+/// Forwards [`log`] records to `log_message`.
+#[derive(Debug)]
+pub struct Logger;
+
+static LOGGER: Logger = Logger;
+
+/// Installs [`Logger`] as the [`log`] logger and sets the maximum level.
+pub fn init(level: LevelFilter) -> Result<(), SetLoggerError> {
+    log::set_logger(&LOGGER)?;
+    log::set_max_level(level);
+    Ok(())
+}
+
+impl Log for Logger {
+    // Filtering is left to `log::set_max_level`.
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        let level = match record.level() {
+            Level::Error => c"ERROR",
+            Level::Warn => c"WARN",
+            Level::Info => c"INFO",
+            Level::Debug => c"DEBUG",
+            Level::Trace => c"TRACE",
+        };
+        let tag = CStrBuffer::<TAG_LENGTH>::format(format_args!("{}", record.target()));
+        let message = CStrBuffer::<MESSAGE_LENGTH>::format(*record.args());
+
+        unsafe {
+            sys::log_message(level.as_ptr(), tag.as_c_str().as_ptr(), c"%s".as_ptr(), message.as_c_str().as_ptr());
+        }
+    }
+
+    fn flush(&self) {}
+}
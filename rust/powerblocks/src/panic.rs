@@ -0,0 +1,60 @@
+//! Reports Rust panics through the SDK crash handler.
+//!
+//! A panic becomes a call to `crash_handler_bug_check` with the cause
+//! `PANIC: <message> (<file>:<line>)` and the registers at the point of the
+//! panic, so it shows on the crash screen or reaches whatever handler was
+//! installed with `crash_handler_set`, such as the debugger's.
+//!
+//! Enable the `panic-handler` feature to use [`bug_check_panic`] as the
+//! `#[panic_handler]`, or call it from your own.
+
+use core::fmt;
+use core::panic::{Location, PanicInfo};
+
+use crate::cstr::CStrBuffer;
+use crate::syscall;
+
+/// Longest cause passed to the crash handler, including the terminator.
+const CAUSE_LENGTH: usize = 128;
+
+fn format_cause(message: impl fmt::Display, location: Option<&Location>) -> CStrBuffer<CAUSE_LENGTH> {
+    match location {
+        Some(location) => {
+            CStrBuffer::format(format_args!("PANIC: {message} ({}:{})", location.file(), location.line()))
+        }
+        None => CStrBuffer::format(format_args!("PANIC: {message}")),
+    }
+}
+
+/// Reports `info` through `crash_handler_bug_check` and halts.
+///
+/// The crash handler sees the context saved at the point of the call. If an
+/// installed handler returns, this spins forever.
+pub fn bug_check_panic(info: &PanicInfo) -> ! {
+    let cause = format_cause(info.message(), info.location());
+    syscall::bug_check(cause.as_c_str());
+
+    loop {
+        core::hint::spin_loop();
+    }
+}
+
+#[cfg(all(feature = "panic-handler", not(test)))]
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    bug_check_panic(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cause_includes_location() {
+        let location = Location::caller();
+        let cause = format_cause(format_args!("index {} out of range", 3), Some(location));
+
+        let expected = format!("PANIC: index 3 out of range ({}:{})", location.file(), location.line());
+        assert_eq!(cause.as_c_str().to_str().unwrap(), expected);
+    }
+}
@@ -0,0 +1,134 @@
+//! Safe wrappers over the FreeRTOS kernel.
+//!
+//! Everything here uses static allocation, the same way the SDK's drivers
+//! do. Kernel objects keep their `Static*_t` storage inline and are created in
+//! place the first time they are used, so they can be built in a `const`
+//! context and live in a `static`. After that first use they must stay where
+//! they are. Keep them in a `static`, a `Box` or an `Arc`; using one after it
+//! has been moved panics.
+//!
+//! Blocking calls must only be made from tasks. The `_from_isr` variants are
+//! for interrupt handlers, and set `exception_isr_context_switch_needed` when
+//! they wake a task of higher priority so the switch happens as the interrupt
+//! returns. Interrupts cannot create objects, so a task must use an object
+//! before an interrupt handler does.
+
+use core::cell::UnsafeCell;
+use core::ffi::c_void;
+use core::mem::MaybeUninit;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use core::time::Duration;
+
+use crate::interrupt;
+use crate::sys;
+
+mod mutex;
+mod notification;
+mod queue;
+mod rwlock;
+pub mod task;
+mod timer;
+
+pub use mutex::{Mutex, MutexGuard, ReentrantMutex, ReentrantMutexGuard};
+pub use notification::Notification;
+pub use queue::Queue;
+pub use rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+pub use task::Task;
+pub use timer::Timer;
+
+/// Converts `duration` to kernel ticks, rounding up.
+///
+/// [`Duration::MAX`] waits forever.
+pub const fn ticks(duration: Duration) -> sys::TickType_t {
+    if duration.as_secs() == u64::MAX {
+        return sys::portMAX_DELAY;
+    }
+
+    let tick_ns = 1_000_000_000 / sys::configTICK_RATE_HZ as u128;
+    let ticks = duration.as_nanos().div_ceil(tick_ns);
+    if ticks >= sys::portMAX_DELAY as u128 {
+        sys::portMAX_DELAY - 1
+    } else {
+        ticks as sys::TickType_t
+    }
+}
+
+/// Where the `_from_isr` calls record that a task switch is needed.
+pub(crate) fn higher_priority_task_woken() -> *mut sys::BaseType_t {
+    ptr::addr_of_mut!(sys::exception_isr_context_switch_needed)
+}
+
+/// Storage for a statically allocated kernel object, created on first use.
+pub(crate) struct Static<S> {
+    storage: UnsafeCell<MaybeUninit<S>>,
+    handle: AtomicPtr<c_void>,
+}
+
+unsafe impl<S> Send for Static<S> {}
+unsafe impl<S> Sync for Static<S> {}
+
+impl<S> Static<S> {
+    pub(crate) const fn new() -> Self {
+        Self { storage: UnsafeCell::new(MaybeUninit::uninit()), handle: AtomicPtr::new(ptr::null_mut()) }
+    }
+
+    fn storage(&self) -> *mut S {
+        self.storage.get().cast()
+    }
+
+    /// Returns the handle, calling `create` with the storage first if the
+    /// object has not been created yet.
+    ///
+    /// Static creation returns the storage itself as the handle, which is
+    /// how a moved object is caught.
+    pub(crate) fn get_or_create<H>(&self, create: impl FnOnce(*mut S) -> *mut H) -> *mut H {
+        let storage = self.storage();
+        let handle = self.handle.load(Ordering::Acquire);
+        if handle == storage.cast() {
+            return handle.cast();
+        }
+        assert!(handle.is_null(), "FreeRTOS object moved after first use");
+
+        interrupt::free(|| {
+            if self.handle.load(Ordering::Relaxed).is_null() {
+                let handle = create(storage);
+                assert!(handle.cast() == storage, "failed to create FreeRTOS object");
+                self.handle.store(handle.cast(), Ordering::Release);
+            }
+        });
+        storage.cast()
+    }
+
+    /// The handle, if the object was created and has not moved since.
+    pub(crate) fn get(&self) -> Option<*mut c_void> {
+        let handle = self.handle.load(Ordering::Acquire);
+        (handle == self.storage().cast()).then_some(handle)
+    }
+
+    /// The handle, for interrupt handlers, which must not create objects.
+    ///
+    /// Panics if no task has used the object yet.
+    pub(crate) fn created<H>(&self) -> *mut H {
+        self.get().expect("FreeRTOS object used from an interrupt before any task used it").cast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticks_round_up() {
+        assert_eq!(ticks(Duration::ZERO), 0);
+        assert_eq!(ticks(Duration::from_micros(1)), 1);
+        assert_eq!(ticks(Duration::from_millis(25)), 25);
+        assert_eq!(ticks(Duration::from_secs(2)), 2000);
+    }
+
+    #[test]
+    fn ticks_saturate() {
+        assert_eq!(ticks(Duration::MAX), sys::portMAX_DELAY);
+        assert_eq!(ticks(Duration::from_secs(u64::MAX - 1)), sys::portMAX_DELAY - 1);
+    }
+}
@@ -0,0 +1,230 @@
+//! Mutexes over FreeRTOS mutex semaphores, with priority inheritance.
+
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::time::Duration;
+
+use super::{ticks, Static};
+use crate::sys;
+
+/// Mutual exclusion over a value, like `xSemaphoreCreateMutexStatic`.
+///
+/// Only for tasks. FreeRTOS mutexes cannot be taken from interrupts.
+pub struct Mutex<T: ?Sized> {
+    semaphore: Static<sys::StaticSemaphore_t>,
+    value: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
+
+/// Access to a [`Mutex`]'s value. Releases the mutex when dropped.
+#[must_use = "the mutex is released as soon as the guard is dropped"]
+pub struct MutexGuard<'a, T: ?Sized> {
+    mutex: &'a Mutex<T>,
+    // The mutex must be given back by the task that took it.
+    _not_send: PhantomData<*const ()>,
+}
+
+unsafe impl<T: ?Sized + Sync> Sync for MutexGuard<'_, T> {}
+
+impl<T> Mutex<T> {
+    pub const fn new(value: T) -> Self {
+        Self { semaphore: Static::new(), value: UnsafeCell::new(value) }
+    }
+
+    pub fn into_inner(self) -> T {
+        let mut mutex = ManuallyDrop::new(self);
+        mutex.delete();
+        // The semaphore's storage needs no dropping once deleted.
+        unsafe { ptr::read(&mutex.value) }.into_inner()
+    }
+}
+
+impl<T: ?Sized> Mutex<T> {
+    fn handle(&self) -> sys::SemaphoreHandle_t {
+        self.semaphore.get_or_create(|storage| unsafe { sys::xSemaphoreCreateMutexStatic(storage) })
+    }
+
+    fn delete(&mut self) {
+        if let Some(handle) = self.semaphore.get() {
+            unsafe { sys::vSemaphoreDelete(handle.cast()) };
+        }
+    }
+
+    fn take(&self, ticks: sys::TickType_t) -> Option<MutexGuard<'_, T>> {
+        let taken = unsafe { sys::xSemaphoreTake(self.handle(), ticks) } == sys::pdTRUE;
+        taken.then_some(MutexGuard { mutex: self, _not_send: PhantomData })
+    }
+
+    /// Blocks until the mutex is free.
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        self.take(sys::portMAX_DELAY).unwrap()
+    }
+
+    /// Waits up to `timeout` for the mutex.
+    pub fn lock_timeout(&self, timeout: Duration) -> Option<MutexGuard<'_, T>> {
+        self.take(ticks(timeout))
+    }
+
+    /// Takes the mutex if it is free.
+    pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        self.take(0)
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+}
+
+impl<T: Default> Default for Mutex<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: ?Sized> Drop for Mutex<T> {
+    fn drop(&mut self) {
+        // A mutex moved after its first use is left registered, as FreeRTOS
+        // still points at the old storage.
+        self.delete();
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Mutex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("Mutex");
+        match self.try_lock() {
+            Some(guard) => d.field("value", &&*guard),
+            None => d.field("value", &format_args!("<locked>")),
+        };
+        d.finish_non_exhaustive()
+    }
+}
+
+impl<T: ?Sized> Deref for MutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for MutexGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe { sys::xSemaphoreGive(self.mutex.handle()) };
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for MutexGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// A mutex the owning task can take again while holding it, like
+/// `xSemaphoreCreateRecursiveMutexStatic`.
+///
+/// As the value may be borrowed several times at once, guards only give
+/// shared access. Use a `Cell` or `RefCell` inside for mutation.
+pub struct ReentrantMutex<T: ?Sized> {
+    semaphore: Static<sys::StaticSemaphore_t>,
+    value: T,
+}
+
+unsafe impl<T: ?Sized + Send> Send for ReentrantMutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for ReentrantMutex<T> {}
+
+/// Shared access to a [`ReentrantMutex`]'s value. Gives the mutex back once
+/// when dropped.
+#[must_use = "the mutex is released as soon as the guard is dropped"]
+pub struct ReentrantMutexGuard<'a, T: ?Sized> {
+    mutex: &'a ReentrantMutex<T>,
+    _not_send: PhantomData<*const ()>,
+}
+
+unsafe impl<T: ?Sized + Sync> Sync for ReentrantMutexGuard<'_, T> {}
+
+impl<T> ReentrantMutex<T> {
+    pub const fn new(value: T) -> Self {
+        Self { semaphore: Static::new(), value }
+    }
+
+    pub fn into_inner(self) -> T {
+        let mut mutex = ManuallyDrop::new(self);
+        mutex.delete();
+        unsafe { ptr::read(&mutex.value) }
+    }
+}
+
+impl<T: ?Sized> ReentrantMutex<T> {
+    fn handle(&self) -> sys::SemaphoreHandle_t {
+        self.semaphore.get_or_create(|storage| unsafe { sys::xSemaphoreCreateRecursiveMutexStatic(storage) })
+    }
+
+    fn delete(&mut self) {
+        if let Some(handle) = self.semaphore.get() {
+            unsafe { sys::vSemaphoreDelete(handle.cast()) };
+        }
+    }
+
+    fn take(&self, ticks: sys::TickType_t) -> Option<ReentrantMutexGuard<'_, T>> {
+        let taken = unsafe { sys::xSemaphoreTakeRecursive(self.handle(), ticks) } == sys::pdTRUE;
+        taken.then_some(ReentrantMutexGuard { mutex: self, _not_send: PhantomData })
+    }
+
+    /// Blocks until the mutex is free or already held by this task.
+    pub fn lock(&self) -> ReentrantMutexGuard<'_, T> {
+        self.take(sys::portMAX_DELAY).unwrap()
+    }
+
+    /// Waits up to `timeout` for the mutex.
+    pub fn lock_timeout(&self, timeout: Duration) -> Option<ReentrantMutexGuard<'_, T>> {
+        self.take(ticks(timeout))
+    }
+
+    /// Takes the mutex if it is free or already held by this task.
+    pub fn try_lock(&self) -> Option<ReentrantMutexGuard<'_, T>> {
+        self.take(0)
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: Default> Default for ReentrantMutex<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: ?Sized> Drop for ReentrantMutex<T> {
+    fn drop(&mut self) {
+        self.delete();
+    }
+}
+
+impl<T: ?Sized> Deref for ReentrantMutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.mutex.value
+    }
+}
+
+impl<T: ?Sized> Drop for ReentrantMutexGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe { sys::xSemaphoreGiveRecursive(self.mutex.handle()) };
+    }
+}
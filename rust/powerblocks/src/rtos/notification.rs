@@ -0,0 +1,96 @@
+//! Signalling a waiting task with task notifications.
+
+use core::ptr;
+use core::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+use core::time::Duration;
+
+use super::{higher_priority_task_woken, ticks};
+use crate::sys;
+
+/// A flag one task waits on and anything, including interrupt handlers,
+/// can set.
+///
+/// Waking uses the waiting task's notification, which is lighter than a
+/// semaphore and needs no kernel object. Notifying before anyone waits is
+/// remembered, so the next wait returns straight away. Only one task may
+/// wait at a time.
+#[derive(Debug)]
+pub struct Notification {
+    waiter: AtomicPtr<sys::tskTaskControlBlock>,
+    notified: AtomicBool,
+}
+
+impl Notification {
+    pub const fn new() -> Self {
+        Self { waiter: AtomicPtr::new(ptr::null_mut()), notified: AtomicBool::new(false) }
+    }
+
+    /// Sets the flag and wakes the waiting task, if any.
+    pub fn notify(&self) {
+        self.notified.store(true, Ordering::Release);
+        let waiter = self.waiter.load(Ordering::Acquire);
+        if !waiter.is_null() {
+            unsafe { sys::xTaskNotifyGive(waiter) };
+        }
+    }
+
+    /// Sets the flag and wakes the waiting task from an interrupt handler.
+    pub fn notify_from_isr(&self) {
+        self.notified.store(true, Ordering::Release);
+        let waiter = self.waiter.load(Ordering::Acquire);
+        if !waiter.is_null() {
+            unsafe { sys::vTaskNotifyGiveFromISR(waiter, higher_priority_task_woken()) };
+        }
+    }
+
+    /// Blocks until the flag is set, then clears it.
+    pub fn wait(&self) {
+        self.wait_ticks(sys::portMAX_DELAY);
+    }
+
+    /// Waits up to `timeout` for the flag. Returns whether it was set, and
+    /// clears it.
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        self.wait_ticks(ticks(timeout))
+    }
+
+    /// Clears the flag and returns whether it was set, without blocking.
+    pub fn take(&self) -> bool {
+        self.notified.swap(false, Ordering::Acquire)
+    }
+
+    fn wait_ticks(&self, timeout: sys::TickType_t) -> bool {
+        let current = unsafe { sys::xTaskGetCurrentTaskHandle() };
+        let previous = self.waiter.swap(current, Ordering::AcqRel);
+        assert!(previous.is_null() || previous == current, "Notification waited on by two tasks");
+
+        // Other notifications to this task can wake it too, so check the flag
+        // each time round and only give up once the timeout has passed.
+        let start = unsafe { sys::xTaskGetTickCount() };
+        let notified = loop {
+            if self.take() {
+                break true;
+            }
+
+            let remaining = if timeout == sys::portMAX_DELAY {
+                timeout
+            } else {
+                let elapsed = unsafe { sys::xTaskGetTickCount() } - start;
+                match timeout.checked_sub(elapsed) {
+                    Some(remaining) if remaining > 0 => remaining,
+                    _ => break self.take(),
+                }
+            };
+            unsafe { sys::ulTaskNotifyTake(sys::pdTRUE, remaining) };
+        };
+
+        self.waiter.store(ptr::null_mut(), Ordering::Release);
+        notified
+    }
+}
+
+impl Default for Notification {
+    fn default() -> Self {
+        Self::new()
+    }
+}
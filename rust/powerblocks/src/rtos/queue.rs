@@ -0,0 +1,149 @@
+//! Bounded queues over FreeRTOS queues.
+
+use core::cell::UnsafeCell;
+use core::ffi::c_void;
+use core::mem::{self, ManuallyDrop, MaybeUninit};
+use core::ptr;
+use core::time::Duration;
+
+use super::{higher_priority_task_woken, ticks, Static};
+use crate::sys;
+
+/// A queue of up to `N` values, like `xQueueCreateStatic`.
+///
+/// Values are moved in and out by copying their bytes, the way FreeRTOS
+/// queues work. Sends give the value back when the queue is full.
+pub struct Queue<T, const N: usize> {
+    queue: Static<sys::StaticQueue_t>,
+    buffer: UnsafeCell<MaybeUninit<[T; N]>>,
+}
+
+unsafe impl<T: Send, const N: usize> Send for Queue<T, N> {}
+unsafe impl<T: Send, const N: usize> Sync for Queue<T, N> {}
+
+impl<T, const N: usize> Queue<T, N> {
+    pub const fn new() -> Self {
+        assert!(N > 0, "queue length must not be zero");
+        Self { queue: Static::new(), buffer: UnsafeCell::new(MaybeUninit::uninit()) }
+    }
+
+    fn handle(&self) -> sys::QueueHandle_t {
+        self.queue.get_or_create(|storage| unsafe {
+            // FreeRTOS wants no storage at all for zero sized items.
+            let buffer = if mem::size_of::<T>() == 0 { ptr::null_mut() } else { self.buffer.get().cast() };
+            sys::xQueueCreateStatic(N as sys::UBaseType_t, mem::size_of::<T>() as sys::UBaseType_t, buffer, storage)
+        })
+    }
+
+    fn send_ticks(&self, value: T, ticks: sys::TickType_t, position: sys::BaseType_t) -> Result<(), T> {
+        let value = ManuallyDrop::new(value);
+        let item: *const T = &*value;
+        let sent = unsafe { sys::xQueueGenericSend(self.handle(), item.cast(), ticks, position) };
+        if sent == sys::pdTRUE {
+            Ok(())
+        } else {
+            Err(ManuallyDrop::into_inner(value))
+        }
+    }
+
+    fn receive_ticks(&self, ticks: sys::TickType_t) -> Option<T> {
+        let mut value = MaybeUninit::<T>::uninit();
+        let received = unsafe { sys::xQueueReceive(self.handle(), value.as_mut_ptr().cast(), ticks) };
+        (received == sys::pdTRUE).then(|| unsafe { value.assume_init() })
+    }
+
+    /// Blocks until there is room for `value`.
+    pub fn send(&self, value: T) {
+        if self.send_ticks(value, sys::portMAX_DELAY, sys::queueSEND_TO_BACK).is_err() {
+            unreachable!();
+        }
+    }
+
+    /// Waits up to `timeout` for room, giving `value` back if there is none.
+    pub fn send_timeout(&self, value: T, timeout: Duration) -> Result<(), T> {
+        self.send_ticks(value, ticks(timeout), sys::queueSEND_TO_BACK)
+    }
+
+    /// Sends `value` if there is room.
+    pub fn try_send(&self, value: T) -> Result<(), T> {
+        self.send_ticks(value, 0, sys::queueSEND_TO_BACK)
+    }
+
+    /// Sends `value` to the front of the queue if there is room, so it is
+    /// received next.
+    pub fn try_send_to_front(&self, value: T) -> Result<(), T> {
+        self.send_ticks(value, 0, sys::queueSEND_TO_FRONT)
+    }
+
+    /// Sends `value` from an interrupt handler if there is room.
+    ///
+    /// Interrupts cannot create the queue, so a task must have used it
+    /// first. Panics if none has.
+    pub fn send_from_isr(&self, value: T) -> Result<(), T> {
+        let handle = self.queue.created();
+        let value = ManuallyDrop::new(value);
+        let item: *const T = &*value;
+        let sent = unsafe { sys::xQueueSendFromISR(handle, item.cast(), higher_priority_task_woken()) };
+        if sent == sys::pdTRUE {
+            Ok(())
+        } else {
+            Err(ManuallyDrop::into_inner(value))
+        }
+    }
+
+    /// Blocks until there is a value.
+    pub fn receive(&self) -> T {
+        self.receive_ticks(sys::portMAX_DELAY).unwrap()
+    }
+
+    /// Waits up to `timeout` for a value.
+    pub fn receive_timeout(&self, timeout: Duration) -> Option<T> {
+        self.receive_ticks(ticks(timeout))
+    }
+
+    /// Receives a value if there is one.
+    pub fn try_receive(&self) -> Option<T> {
+        self.receive_ticks(0)
+    }
+
+    /// Receives a value from an interrupt handler if there is one.
+    pub fn receive_from_isr(&self) -> Option<T> {
+        // Nothing was ever sent to a queue that was never created.
+        let handle = self.queue.get()?;
+        let mut value = MaybeUninit::<T>::uninit();
+        let received = unsafe {
+            sys::xQueueReceiveFromISR(handle.cast(), value.as_mut_ptr().cast::<c_void>(), higher_priority_task_woken())
+        };
+        (received == sys::pdTRUE).then(|| unsafe { value.assume_init() })
+    }
+
+    /// Number of values waiting.
+    pub fn len(&self) -> usize {
+        unsafe { sys::uxQueueMessagesWaiting(self.handle()) as usize }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<T, const N: usize> Default for Queue<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for Queue<T, N> {
+    fn drop(&mut self) {
+        // Values in a queue that was moved after its first use are leaked, as
+        // FreeRTOS still points at the old buffer.
+        if self.queue.get().is_some() {
+            while self.try_receive().is_some() {}
+            unsafe { sys::vQueueDelete(self.handle()) };
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! Reader-writer lock built from FreeRTOS semaphores.
+
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+
+use super::Static;
+use crate::sys;
+
+/// Lets any number of readers or one writer at a time at a value.
+///
+/// FreeRTOS has no reader-writer lock, so this is the classic pair of
+/// semaphores: a mutex around the reader count, and a binary semaphore that
+/// the first reader or a writer takes. Readers are preferred, so a steady
+/// stream of them can starve writers. Only for tasks.
+pub struct RwLock<T: ?Sized> {
+    readers_lock: Static<sys::StaticSemaphore_t>,
+    writer_lock: Static<sys::StaticSemaphore_t>,
+    readers: UnsafeCell<usize>,
+    value: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for RwLock<T> {}
+unsafe impl<T: ?Sized + Send + Sync> Sync for RwLock<T> {}
+
+/// Shared access to an [`RwLock`]'s value.
+#[must_use = "the lock is released as soon as the guard is dropped"]
+pub struct RwLockReadGuard<'a, T: ?Sized> {
+    lock: &'a RwLock<T>,
+    _not_send: PhantomData<*const ()>,
+}
+
+unsafe impl<T: ?Sized + Sync> Sync for RwLockReadGuard<'_, T> {}
+
+/// Exclusive access to an [`RwLock`]'s value.
+#[must_use = "the lock is released as soon as the guard is dropped"]
+pub struct RwLockWriteGuard<'a, T: ?Sized> {
+    lock: &'a RwLock<T>,
+    _not_send: PhantomData<*const ()>,
+}
+
+unsafe impl<T: ?Sized + Sync> Sync for RwLockWriteGuard<'_, T> {}
+
+impl<T> RwLock<T> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            readers_lock: Static::new(),
+            writer_lock: Static::new(),
+            readers: UnsafeCell::new(0),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        let mut lock = ManuallyDrop::new(self);
+        lock.delete();
+        unsafe { ptr::read(&lock.value) }.into_inner()
+    }
+}
+
+impl<T: ?Sized> RwLock<T> {
+    fn readers_lock(&self) -> sys::SemaphoreHandle_t {
+        self.readers_lock.get_or_create(|storage| unsafe { sys::xSemaphoreCreateMutexStatic(storage) })
+    }
+
+    fn writer_lock(&self) -> sys::SemaphoreHandle_t {
+        self.writer_lock.get_or_create(|storage| unsafe { sys::xSemaphoreCreateCountingStatic(1, 1, storage) })
+    }
+
+    fn delete(&mut self) {
+        for semaphore in [&self.readers_lock, &self.writer_lock] {
+            if let Some(handle) = semaphore.get() {
+                unsafe { sys::vSemaphoreDelete(handle.cast()) };
+            }
+        }
+    }
+
+    /// Blocks until there is no writer.
+    pub fn read(&self) -> RwLockReadGuard<'_, T> {
+        let readers_lock = self.readers_lock();
+        unsafe {
+            sys::xSemaphoreTake(readers_lock, sys::portMAX_DELAY);
+            *self.readers.get() += 1;
+            if *self.readers.get() == 1 {
+                sys::xSemaphoreTake(self.writer_lock(), sys::portMAX_DELAY);
+            }
+            sys::xSemaphoreGive(readers_lock);
+        }
+        RwLockReadGuard { lock: self, _not_send: PhantomData }
+    }
+
+    /// Blocks until there are no readers or writer.
+    pub fn write(&self) -> RwLockWriteGuard<'_, T> {
+        unsafe { sys::xSemaphoreTake(self.writer_lock(), sys::portMAX_DELAY) };
+        RwLockWriteGuard { lock: self, _not_send: PhantomData }
+    }
+
+    /// Takes the lock for writing if it is free.
+    pub fn try_write(&self) -> Option<RwLockWriteGuard<'_, T>> {
+        let taken = unsafe { sys::xSemaphoreTake(self.writer_lock(), 0) } == sys::pdTRUE;
+        taken.then_some(RwLockWriteGuard { lock: self, _not_send: PhantomData })
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+}
+
+impl<T: Default> Default for RwLock<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: ?Sized> Drop for RwLock<T> {
+    fn drop(&mut self) {
+        self.delete();
+    }
+}
+
+impl<T: ?Sized> Deref for RwLockReadGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockReadGuard<'_, T> {
+    fn drop(&mut self) {
+        let readers_lock = self.lock.readers_lock();
+        unsafe {
+            sys::xSemaphoreTake(readers_lock, sys::portMAX_DELAY);
+            *self.lock.readers.get() -= 1;
+            if *self.lock.readers.get() == 0 {
+                sys::xSemaphoreGive(self.lock.writer_lock());
+            }
+            sys::xSemaphoreGive(readers_lock);
+        }
+    }
+}
+
+impl<T: ?Sized> Deref for RwLockWriteGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for RwLockWriteGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for RwLockWriteGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe { sys::xSemaphoreGive(self.lock.writer_lock()) };
+    }
+}
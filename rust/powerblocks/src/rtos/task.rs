@@ -0,0 +1,273 @@
+//! Tasks and task notifications.
+
+use core::ffi::c_void;
+use core::fmt;
+use core::ptr;
+use core::time::Duration;
+
+use super::{higher_priority_task_woken, ticks};
+use crate::cstr::CStrBuffer;
+use crate::sys;
+
+/// Longest task name, including the terminator.
+const NAME_LENGTH: usize = sys::configMAX_TASK_NAME_LEN as usize;
+
+/// Handle to a FreeRTOS task.
+///
+/// A handle must not be used after its task is deleted. Tasks started with
+/// [`Builder`] delete themselves when their function returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Task(sys::TaskHandle_t);
+
+unsafe impl Send for Task {}
+unsafe impl Sync for Task {}
+
+/// How [`Task::notify`] changes the task's notification value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotifyAction {
+    /// Leave the value alone and only wake the task.
+    None,
+    /// OR these bits into the value.
+    SetBits(u32),
+    /// Add one to the value, like `xTaskNotifyGive`.
+    Increment,
+    /// Replace the value.
+    SetValue(u32),
+    /// Replace the value unless the task has not received the last one.
+    SetValueWithoutOverwrite(u32),
+}
+
+impl NotifyAction {
+    fn into_raw(self) -> (u32, sys::eNotifyAction) {
+        match self {
+            Self::None => (0, sys::eNoAction),
+            Self::SetBits(bits) => (bits, sys::eSetBits),
+            Self::Increment => (0, sys::eIncrement),
+            Self::SetValue(value) => (value, sys::eSetValueWithOverwrite),
+            Self::SetValueWithoutOverwrite(value) => (value, sys::eSetValueWithoutOverwrite),
+        }
+    }
+}
+
+impl Task {
+    /// The calling task.
+    pub fn current() -> Self {
+        Self(unsafe { sys::xTaskGetCurrentTaskHandle() })
+    }
+
+    /// Wraps a handle from C.
+    ///
+    /// # Safety
+    /// `handle` must be a live task.
+    pub const unsafe fn from_raw(handle: sys::TaskHandle_t) -> Self {
+        Self(handle)
+    }
+
+    pub const fn as_raw(self) -> sys::TaskHandle_t {
+        self.0
+    }
+
+    pub fn priority(self) -> u32 {
+        unsafe { sys::uxTaskPriorityGet(self.0) }
+    }
+
+    pub fn set_priority(self, priority: u32) {
+        unsafe { sys::vTaskPrioritySet(self.0, priority.min(sys::configMAX_PRIORITIES - 1)) };
+    }
+
+    /// Stops the task from running until [`resume`](Self::resume) is called.
+    pub fn suspend(self) {
+        unsafe { sys::vTaskSuspend(self.0) };
+    }
+
+    pub fn resume(self) {
+        unsafe { sys::vTaskResume(self.0) };
+    }
+
+    pub fn resume_from_isr(self) {
+        if unsafe { sys::xTaskResumeFromISR(self.0) } != sys::pdFALSE {
+            unsafe { *higher_priority_task_woken() = sys::pdTRUE };
+        }
+    }
+
+    /// Updates the task's notification value and wakes it if it is waiting
+    /// in [`notify_wait`] or [`notify_take`].
+    ///
+    /// Returns false only for [`NotifyAction::SetValueWithoutOverwrite`] when
+    /// the previous value was still pending.
+    pub fn notify(self, action: NotifyAction) -> bool {
+        let (value, action) = action.into_raw();
+        unsafe { sys::xTaskNotify(self.0, value, action) == sys::pdPASS }
+    }
+
+    /// [`notify`](Self::notify) from an interrupt handler.
+    pub fn notify_from_isr(self, action: NotifyAction) -> bool {
+        let (value, action) = action.into_raw();
+        unsafe { sys::xTaskNotifyFromISR(self.0, value, action, higher_priority_task_woken()) == sys::pdPASS }
+    }
+}
+
+/// Blocks until the calling task is notified, like `xTaskNotifyWait`.
+///
+/// Clears `clear_on_entry` bits first, and `clear_on_exit` bits once a
+/// notification arrives. Returns the value from before the exit clear, or
+/// `None` on timeout.
+pub fn notify_wait(clear_on_entry: u32, clear_on_exit: u32, timeout: Duration) -> Option<u32> {
+    let mut value = 0;
+    let notified = unsafe { sys::xTaskNotifyWait(clear_on_entry, clear_on_exit, &mut value, ticks(timeout)) };
+    (notified == sys::pdTRUE).then_some(value)
+}
+
+/// Waits for the calling task's notification value to be non-zero, like
+/// `ulTaskNotifyTake`, then decrements it or clears it if `clear` is set.
+///
+/// Returns the value from before that, which is zero on timeout.
+pub fn notify_take(clear: bool, timeout: Duration) -> u32 {
+    unsafe { sys::ulTaskNotifyTake(clear as sys::BaseType_t, ticks(timeout)) }
+}
+
+/// Blocks the calling task for at least `duration`.
+pub fn delay(duration: Duration) {
+    unsafe { sys::vTaskDelay(ticks(duration)) };
+}
+
+/// Lets other tasks of the same priority run.
+pub fn yield_now() {
+    unsafe { sys::taskYIELD() };
+}
+
+/// The task could not be created, as there was not enough memory for its
+/// stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpawnError;
+
+impl fmt::Display for SpawnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("not enough memory to create task")
+    }
+}
+
+impl core::error::Error for SpawnError {}
+
+/// Stack and control block for a task started with
+/// [`Builder::spawn_static`], like the buffers passed to
+/// `xTaskCreateStatic`. `WORDS` is the stack size in 32-bit words.
+pub struct TaskStorage<const WORDS: usize> {
+    task: sys::StaticTask_t,
+    stack: [sys::StackType_t; WORDS],
+}
+
+impl<const WORDS: usize> TaskStorage<WORDS> {
+    pub const fn new() -> Self {
+        // Both are plain data that FreeRTOS initializes itself.
+        unsafe { core::mem::zeroed() }
+    }
+}
+
+impl<const WORDS: usize> Default for TaskStorage<WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Options for starting a task.
+///
+/// ```ignore
+/// let task = Builder::new("worker").priority(4).stack_size(32 * 1024).spawn(move || work(data))?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Builder {
+    name: CStrBuffer<NAME_LENGTH>,
+    priority: u32,
+    stack_size: usize,
+}
+
+impl Builder {
+    /// Stack size used unless [`stack_size`](Self::stack_size) is called.
+    pub const DEFAULT_STACK_SIZE: usize = sys::configMINIMAL_STACK_SIZE as usize * 16;
+
+    /// Priority used unless [`priority`](Self::priority) is called. The same
+    /// as the main task.
+    pub const DEFAULT_PRIORITY: u32 = sys::configMAX_PRIORITIES / 2;
+
+    /// Starts options for a task called `name`. Names longer than
+    /// `configMAX_TASK_NAME_LEN` are cut short.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: CStrBuffer::format(format_args!("{name}")),
+            priority: Self::DEFAULT_PRIORITY,
+            stack_size: Self::DEFAULT_STACK_SIZE,
+        }
+    }
+
+    /// Priority from `tskIDLE_PRIORITY` (0) to `configMAX_PRIORITIES - 1`.
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.priority = priority.min(sys::configMAX_PRIORITIES - 1);
+        self
+    }
+
+    /// Stack size in bytes, for [`spawn`](Self::spawn).
+    pub fn stack_size(mut self, bytes: usize) -> Self {
+        self.stack_size = bytes;
+        self
+    }
+
+    /// Starts a task running `f`, with its stack on the heap.
+    #[cfg(feature = "alloc")]
+    pub fn spawn<F>(self, f: F) -> Result<Task, SpawnError>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        use alloc::boxed::Box;
+
+        unsafe extern "C" fn run<F: FnOnce()>(f: *mut c_void) {
+            let f = Box::from_raw(f.cast::<F>());
+            f();
+            sys::vTaskDelete(ptr::null_mut());
+        }
+
+        let f = Box::into_raw(Box::new(f));
+        let words = self.stack_size.div_ceil(size_of::<sys::StackType_t>());
+        let mut handle = ptr::null_mut();
+        let created = unsafe {
+            sys::xTaskCreate(
+                Some(run::<F>),
+                self.name.as_c_str().as_ptr(),
+                words as sys::StackType_t,
+                f.cast(),
+                self.priority,
+                &mut handle,
+            )
+        };
+
+        if created == sys::pdPASS {
+            Ok(Task(handle))
+        } else {
+            drop(unsafe { Box::from_raw(f) });
+            Err(SpawnError)
+        }
+    }
+
+    /// Starts a task running `f` in `storage`, without touching the heap.
+    /// The stack size is set by `storage`.
+    pub fn spawn_static<const WORDS: usize>(self, storage: &'static mut TaskStorage<WORDS>, f: fn()) -> Task {
+        unsafe extern "C" fn run(f: *mut c_void) {
+            let f: fn() = core::mem::transmute(f);
+            f();
+            sys::vTaskDelete(ptr::null_mut());
+        }
+
+        let handle = unsafe {
+            sys::xTaskCreateStatic(
+                Some(run),
+                self.name.as_c_str().as_ptr(),
+                WORDS as sys::StackType_t,
+                f as *mut c_void,
+                self.priority,
+                storage.stack.as_mut_ptr(),
+                &mut storage.task,
+            )
+        };
+        Task(handle)
+    }
+}
@@ -0,0 +1,107 @@
+//! Software timers run by the FreeRTOS timer task.
+
+use core::ffi::CStr;
+use core::ptr;
+use core::time::Duration;
+
+use super::{higher_priority_task_woken, ticks, Static};
+use crate::sys;
+
+/// A timer that calls `callback` from the timer task, like
+/// `xTimerCreateStatic`.
+///
+/// Commands are queued to the timer task, which runs at
+/// `configTIMER_TASK_PRIORITY`, and fail if its queue stays full for the
+/// given timeout. Callbacks must not block. As the timer task may still
+/// call the callback after a timer is stopped, timers are only used through
+/// `&'static` references; keep them in a `static` or leak them.
+///
+/// ```ignore
+/// static BLINK: Timer<fn()> = Timer::new(c"blink", Duration::from_millis(500), true, toggle_led);
+///
+/// BLINK.start(Duration::MAX);
+/// ```
+pub struct Timer<F> {
+    timer: Static<sys::StaticTimer_t>,
+    name: &'static CStr,
+    period: sys::TickType_t,
+    auto_reload: bool,
+    callback: F,
+}
+
+unsafe impl<F: Send> Send for Timer<F> {}
+unsafe impl<F: Sync> Sync for Timer<F> {}
+
+impl<F: Fn() + Sync + 'static> Timer<F> {
+    /// Creates a timer that fires `period` after being started, and again
+    /// every `period` if `auto_reload` is set.
+    ///
+    /// `period` must be at least one tick.
+    pub const fn new(name: &'static CStr, period: Duration, auto_reload: bool, callback: F) -> Self {
+        let period = ticks(period);
+        assert!(period > 0, "timer period must be at least one tick");
+        Self { timer: Static::new(), name, period, auto_reload, callback }
+    }
+
+    unsafe extern "C" fn expired(timer: sys::TimerHandle_t) {
+        let this = sys::pvTimerGetTimerID(timer) as *const Self;
+        ((*this).callback)();
+    }
+
+    fn handle(&'static self) -> sys::TimerHandle_t {
+        self.timer.get_or_create(|storage| unsafe {
+            sys::xTimerCreateStatic(
+                self.name.as_ptr(),
+                self.period,
+                self.auto_reload as sys::BaseType_t,
+                ptr::from_ref(self).cast_mut().cast(),
+                Some(Self::expired),
+                storage,
+            )
+        })
+    }
+
+    /// Starts the timer, or restarts it if it is running.
+    pub fn start(&'static self, timeout: Duration) -> bool {
+        unsafe { sys::xTimerStart(self.handle(), ticks(timeout)) == sys::pdPASS }
+    }
+
+    /// Stops the timer.
+    pub fn stop(&'static self, timeout: Duration) -> bool {
+        unsafe { sys::xTimerStop(self.handle(), ticks(timeout)) == sys::pdPASS }
+    }
+
+    /// Restarts the timer from now.
+    pub fn reset(&'static self, timeout: Duration) -> bool {
+        unsafe { sys::xTimerReset(self.handle(), ticks(timeout)) == sys::pdPASS }
+    }
+
+    /// Changes the period and starts the timer.
+    pub fn set_period(&'static self, period: Duration, timeout: Duration) -> bool {
+        let period = ticks(period).max(1);
+        unsafe { sys::xTimerChangePeriod(self.handle(), period, ticks(timeout)) == sys::pdPASS }
+    }
+
+    /// Starts the timer from an interrupt handler.
+    ///
+    /// Interrupts cannot create the timer, so a task must have used it
+    /// first. Panics if none has, as do the other `_from_isr` calls.
+    pub fn start_from_isr(&'static self) -> bool {
+        unsafe { sys::xTimerStartFromISR(self.timer.created(), higher_priority_task_woken()) == sys::pdPASS }
+    }
+
+    /// Stops the timer from an interrupt handler.
+    pub fn stop_from_isr(&'static self) -> bool {
+        unsafe { sys::xTimerStopFromISR(self.timer.created(), higher_priority_task_woken()) == sys::pdPASS }
+    }
+
+    /// Restarts the timer from now, from an interrupt handler.
+    pub fn reset_from_isr(&'static self) -> bool {
+        unsafe { sys::xTimerResetFromISR(self.timer.created(), higher_priority_task_woken()) == sys::pdPASS }
+    }
+
+    /// Whether the timer is running.
+    pub fn is_active(&'static self) -> bool {
+        unsafe { sys::xTimerIsTimerActive(self.handle()) != sys::pdFALSE }
+    }
+}
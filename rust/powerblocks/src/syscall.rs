@@ -0,0 +1,72 @@
+//! Syscalls from `powerblocks/core/system/syscall.h`.
+
+use core::ffi::CStr;
+
+/// Issues syscall `id` with up to three arguments and returns the result,
+/// like the `SYSCALL` macro.
+///
+/// # Safety
+/// The arguments must be valid for the syscall handler registered for `id`.
+#[cfg(target_arch = "powerpc")]
+#[inline(always)]
+pub unsafe fn syscall(id: u32, arg1: u32, arg2: u32, arg3: u32) -> u32 {
+    let mut r3 = arg1;
+    core::arch::asm!(
+        "sc",
+        inout("r3") r3,
+        in("r0") id,
+        in("r4") arg2,
+        in("r5") arg3,
+        out("cr0") _,
+        options(nostack),
+    );
+    r3
+}
+
+/// Reports a failed check through the crash handler, like `SYSCALL_ASSERT`.
+///
+/// The crash screen shows `error_name: file:line`. `file` is truncated to
+/// fit the handler's message buffer.
+pub fn assert_failed(error_name: &CStr, file: &str, line: u32) -> ! {
+    #[cfg(target_arch = "powerpc")]
+    {
+        let mut path = [0u8; 64];
+        let len = file.len().min(path.len() - 1);
+        path[..len].copy_from_slice(&file.as_bytes()[..len]);
+
+        unsafe {
+            syscall(
+                crate::sys::SYSCALL_ID_ASSERT,
+                error_name.as_ptr() as u32,
+                line,
+                path.as_ptr() as u32,
+            );
+        }
+
+        // The crash handler does not return.
+        loop {
+            core::hint::spin_loop();
+        }
+    }
+
+    #[cfg(not(target_arch = "powerpc"))]
+    {
+        panic!("{}: {file}:{line}", error_name.to_str().unwrap_or("?"));
+    }
+}
+
+/// Calls `crash_handler_bug_check` with the context saved by the syscall, like
+/// `SYSCALL_BUG_CHECK`, so the crash screen shows the caller's registers.
+///
+/// Returns if a handler installed with `crash_handler_set` returns.
+pub fn bug_check(cause: &CStr) {
+    #[cfg(target_arch = "powerpc")]
+    unsafe {
+        syscall(crate::sys::SYSCALL_ID_BUG_CHECK, cause.as_ptr() as u32, 0, 0);
+    }
+
+    #[cfg(not(target_arch = "powerpc"))]
+    unsafe {
+        crate::sys::crash_handler_bug_check(cause.as_ptr(), core::ptr::null_mut());
+    }
+}
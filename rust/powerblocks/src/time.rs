@@ -0,0 +1,296 @@
+//! Measuring time with the Broadway's time base, and sleeping.
+//!
+//! The time base counts up at [`TB_HZ`] from when the console starts, and is
+//! the finest clock there is. [`Instant`] reads it, and [`to_ticks`] and
+//! [`from_ticks`] convert between its ticks and [`Duration`]s, agreeing with
+//! `SYSTEM_MS_TO_TICKS` and `SYSTEM_S_TO_TICKS`.
+//!
+//! Sleeping goes through the kernel instead, so it only has the precision
+//! of the kernel's tick:
+//!
+//! ```ignore
+//! let mut pacer = FramePacer::new();
+//! loop {
+//!     let delta = pacer.wait();
+//!     update(delta);
+//!     draw();
+//! }
+//! ```
+
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::time::Duration;
+
+use crate::rtos::{self, task};
+use crate::sys;
+
+/// How fast the time base counts, like `SYSTEM_TB_CLOCK_HZ`.
+pub const TB_HZ: u64 = sys::SYSTEM_TB_CLOCK_HZ as u64;
+
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+/// Converts `duration` to time base ticks, rounding down and saturating.
+///
+/// Whole milliseconds and seconds give the same as `SYSTEM_MS_TO_TICKS` and
+/// `SYSTEM_S_TO_TICKS`. `SYSTEM_US_TO_TICKS` rounds its 60.75 ticks per
+/// microsecond down to 60, this does not.
+pub const fn to_ticks(duration: Duration) -> u64 {
+    let ticks = duration.as_nanos() * TB_HZ as u128 / NANOS_PER_SEC as u128;
+    if ticks > u64::MAX as u128 {
+        u64::MAX
+    } else {
+        ticks as u64
+    }
+}
+
+/// Converts time base ticks to a [`Duration`], rounding down to whole
+/// nanoseconds.
+pub const fn from_ticks(ticks: u64) -> Duration {
+    let nanos = (ticks % TB_HZ) * NANOS_PER_SEC / TB_HZ;
+    Duration::new(ticks / TB_HZ, nanos as u32)
+}
+
+/// A point in time, read from the time base.
+///
+/// The time base never goes backwards, and only wraps after thousands of
+/// years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant(u64);
+
+impl Instant {
+    /// The current time, like `system_get_time_base_int`.
+    pub fn now() -> Self {
+        Self(unsafe { sys::system_get_time_base_int() })
+    }
+
+    /// The instant the time base read `ticks`.
+    pub const fn from_ticks(ticks: u64) -> Self {
+        Self(ticks)
+    }
+
+    /// The time base ticks since the console started.
+    pub const fn ticks(self) -> u64 {
+        self.0
+    }
+
+    /// The time from `earlier` to `self`, or zero if `earlier` is later.
+    pub const fn duration_since(self, earlier: Self) -> Duration {
+        from_ticks(self.0.saturating_sub(earlier.0))
+    }
+
+    /// The time from `earlier` to `self`, or `None` if `earlier` is later.
+    pub const fn checked_duration_since(self, earlier: Self) -> Option<Duration> {
+        match self.0.checked_sub(earlier.0) {
+            Some(ticks) => Some(from_ticks(ticks)),
+            None => None,
+        }
+    }
+
+    /// The time that has passed since `self`.
+    pub fn elapsed(self) -> Duration {
+        Self::now().duration_since(self)
+    }
+
+    pub const fn checked_add(self, duration: Duration) -> Option<Self> {
+        match self.0.checked_add(to_ticks(duration)) {
+            Some(ticks) => Some(Self(ticks)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_sub(self, duration: Duration) -> Option<Self> {
+        match self.0.checked_sub(to_ticks(duration)) {
+            Some(ticks) => Some(Self(ticks)),
+            None => None,
+        }
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If the result does not fit in the time base.
+    fn add(self, duration: Duration) -> Self {
+        self.checked_add(duration).expect("overflow when adding duration to instant")
+    }
+}
+
+impl AddAssign<Duration> for Instant {
+    fn add_assign(&mut self, duration: Duration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<Duration> for Instant {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// If the result is before the time base started.
+    fn sub(self, duration: Duration) -> Self {
+        self.checked_sub(duration).expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl SubAssign<Duration> for Instant {
+    fn sub_assign(&mut self, duration: Duration) {
+        *self = *self - duration;
+    }
+}
+
+impl Sub for Instant {
+    type Output = Duration;
+
+    /// Like [`duration_since`](Self::duration_since), zero if `earlier` is
+    /// later.
+    fn sub(self, earlier: Self) -> Duration {
+        self.duration_since(earlier)
+    }
+}
+
+/// Blocks the calling task for at least `duration`, like `vTaskDelay`.
+pub fn sleep(duration: Duration) {
+    task::delay(duration);
+}
+
+/// Blocks the calling task until at least `deadline`, like `vTaskDelay`.
+/// Returns at once if it has passed.
+pub fn sleep_until(deadline: Instant) {
+    if let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+        task::delay(remaining);
+    }
+}
+
+/// Wakes a task up at a fixed rate, like `xTaskDelayUntil`.
+///
+/// Each period is counted from when the last one should have ended, not
+/// from when the task got to [`wait`](Self::wait), so the rate does not
+/// drift however long the work in between takes.
+#[derive(Debug)]
+pub struct Interval {
+    previous: sys::TickType_t,
+    period: sys::TickType_t,
+}
+
+impl Interval {
+    /// An interval whose first period starts now.
+    ///
+    /// # Panics
+    ///
+    /// If `period` rounds to zero kernel ticks.
+    pub fn new(period: Duration) -> Self {
+        let period = rtos::ticks(period);
+        assert!(period > 0, "interval period must be at least one tick");
+        Self { previous: unsafe { sys::xTaskGetTickCount() }, period }
+    }
+
+    /// Blocks the calling task until the end of the current period.
+    ///
+    /// Returns `false` without blocking if that has already passed. The next
+    /// period then still starts where this one ended, so an interval that
+    /// fell behind catches up.
+    pub fn wait(&mut self) -> bool {
+        unsafe { sys::xTaskDelayUntil(&mut self.previous, self.period) == sys::pdTRUE }
+    }
+}
+
+/// Paces a render loop to the display, one or more retraces per frame.
+///
+/// Measures how long each frame took so the loop can scale its updates by
+/// it, which stays right if a frame is dropped.
+#[derive(Debug)]
+pub struct FramePacer {
+    interval: u32,
+    last: Instant,
+    frame_time: Duration,
+    frames: u64,
+}
+
+impl FramePacer {
+    /// A pacer that shows a frame on every retrace.
+    pub fn new() -> Self {
+        Self::with_interval(1)
+    }
+
+    /// A pacer that shows a frame every `interval` retraces, such as 2 for
+    /// 30 frames per second on a 60 Hz display.
+    ///
+    /// # Panics
+    ///
+    /// If `interval` is zero.
+    pub fn with_interval(interval: u32) -> Self {
+        assert!(interval > 0, "frame interval must be at least one retrace");
+        Self { interval, last: Instant::now(), frame_time: Duration::ZERO, frames: 0 }
+    }
+
+    /// Blocks the calling task until the frame should be shown, like
+    /// `video_wait_vsync`, and returns how long the frame took.
+    ///
+    /// The video must be initialized, or this never returns.
+    pub fn wait(&mut self) -> Duration {
+        for _ in 0..self.interval {
+            unsafe { sys::video_wait_vsync() };
+        }
+
+        let now = Instant::now();
+        self.frame_time = now.duration_since(self.last);
+        self.last = now;
+        self.frames += 1;
+        self.frame_time
+    }
+
+    /// How long the last frame took, from one [`wait`](Self::wait) to the
+    /// next. Zero before the first.
+    pub fn frame_time(&self) -> Duration {
+        self.frame_time
+    }
+
+    /// The frames waited for so far.
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+}
+
+impl Default for FramePacer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticks_match_system_macros() {
+        for ms in [0, 1, 16, 1000, 123_456] {
+            assert_eq!(to_ticks(Duration::from_millis(ms)), sys::SYSTEM_MS_TO_TICKS(ms));
+        }
+        for s in [0, 1, 60, 3600] {
+            assert_eq!(to_ticks(Duration::from_secs(s)), sys::SYSTEM_S_TO_TICKS(s));
+        }
+        assert_eq!(to_ticks(Duration::from_micros(4)), 243);
+        assert_eq!(to_ticks(Duration::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn ticks_round_trip() {
+        assert_eq!(from_ticks(TB_HZ * 3 / 2), Duration::from_millis(1500));
+        for ms in [0, 1, 17, 100_000] {
+            let duration = Duration::from_millis(ms);
+            assert_eq!(from_ticks(to_ticks(duration)), duration);
+        }
+    }
+
+    #[test]
+    fn instants_add_and_subtract() {
+        let start = Instant::from_ticks(1000);
+        let later = start + Duration::from_millis(2);
+        assert_eq!(later.ticks(), 1000 + 2 * 60_750);
+        assert_eq!(later - start, Duration::from_millis(2));
+        assert_eq!(start - later, Duration::ZERO);
+        assert_eq!(start.checked_duration_since(later), None);
+        assert_eq!(start.checked_sub(Duration::from_secs(1)), None);
+    }
+}
@@ -0,0 +1,225 @@
+//! Buttons on the remote and its extensions, from `wiimote_buttons`.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::sys;
+
+/// A set of buttons that report through one `wiimote_buttons` mask.
+pub trait ButtonSet: Copy + fmt::Debug + 'static {
+    /// Every button in the set, in the order their bits are in.
+    const ALL: &'static [Self];
+
+    /// The button's bit in the mask.
+    fn mask(self) -> u16;
+}
+
+/// Buttons on the remote itself, like `WIIMOTE_BUTTONS_*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum Button {
+    Left = sys::WIIMOTE_BUTTONS_DPAD_LEFT as u16,
+    Right = sys::WIIMOTE_BUTTONS_DPAD_RIGHT as u16,
+    Down = sys::WIIMOTE_BUTTONS_DPAD_DOWN as u16,
+    Up = sys::WIIMOTE_BUTTONS_DPAD_UP as u16,
+    Plus = sys::WIIMOTE_BUTTONS_PLUS as u16,
+    Two = sys::WIIMOTE_BUTTONS_TWO as u16,
+    One = sys::WIIMOTE_BUTTONS_ONE as u16,
+    B = sys::WIIMOTE_BUTTONS_B as u16,
+    A = sys::WIIMOTE_BUTTONS_A as u16,
+    Minus = sys::WIIMOTE_BUTTONS_MINUS as u16,
+    Home = sys::WIIMOTE_BUTTONS_HOME as u16,
+}
+
+impl ButtonSet for Button {
+    const ALL: &'static [Self] = &[
+        Self::Left,
+        Self::Right,
+        Self::Down,
+        Self::Up,
+        Self::Plus,
+        Self::Two,
+        Self::One,
+        Self::B,
+        Self::A,
+        Self::Minus,
+        Self::Home,
+    ];
+
+    fn mask(self) -> u16 {
+        self as u16
+    }
+}
+
+/// Buttons on the Nunchuk, like `WIIMOTE_EXTENSION_NUNCHUCK_BUTTONS_*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum NunchukButton {
+    Z = sys::WIIMOTE_EXTENSION_NUNCHUCK_BUTTONS_Z as u16,
+    C = sys::WIIMOTE_EXTENSION_NUNCHUCK_BUTTONS_C as u16,
+}
+
+impl ButtonSet for NunchukButton {
+    const ALL: &'static [Self] = &[Self::Z, Self::C];
+
+    fn mask(self) -> u16 {
+        self as u16
+    }
+}
+
+/// Buttons on the Classic Controller, like
+/// `WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum ClassicButton {
+    Up = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_DPAD_UP as u16,
+    Left = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_DPAD_LEFT as u16,
+    ZRight = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_Z_RIGHT as u16,
+    X = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_X as u16,
+    A = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_A as u16,
+    Y = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_Y as u16,
+    B = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_B as u16,
+    ZLeft = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_Z_LEFT as u16,
+    /// The click at the bottom of the right trigger.
+    RightTrigger = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_RIGHT_TRIGGER as u16,
+    Plus = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_PLUS as u16,
+    Home = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_HOME as u16,
+    Minus = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_MINUS as u16,
+    /// The click at the bottom of the left trigger.
+    LeftTrigger = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_LEFT_TRIGGER as u16,
+    Down = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_DPAD_DOWN as u16,
+    Right = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_DPAD_RIGHT as u16,
+}
+
+impl ButtonSet for ClassicButton {
+    const ALL: &'static [Self] = &[
+        Self::Up,
+        Self::Left,
+        Self::ZRight,
+        Self::X,
+        Self::A,
+        Self::Y,
+        Self::B,
+        Self::ZLeft,
+        Self::RightTrigger,
+        Self::Plus,
+        Self::Home,
+        Self::Minus,
+        Self::LeftTrigger,
+        Self::Down,
+        Self::Right,
+    ];
+
+    fn mask(self) -> u16 {
+        self as u16
+    }
+}
+
+/// Takes the first button of `B` out of `bits`.
+pub(super) fn take<B: ButtonSet>(bits: &mut u16) -> Option<B> {
+    let button = B::ALL.iter().copied().find(|button| *bits & button.mask() != 0)?;
+    *bits &= !button.mask();
+    Some(button)
+}
+
+/// Which buttons are down, and which changed on the last poll.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ButtonState<B> {
+    held: u16,
+    pressed: u16,
+    released: u16,
+    _buttons: PhantomData<B>,
+}
+
+impl<B: ButtonSet> ButtonState<B> {
+    pub(super) const fn from_raw(raw: &sys::wiimote_buttons) -> Self {
+        Self { held: raw.state, pressed: raw.down, released: raw.up, _buttons: PhantomData }
+    }
+
+    /// Whether `button` went down on the last poll.
+    pub fn is_pressed(&self, button: B) -> bool {
+        self.pressed & button.mask() != 0
+    }
+
+    /// Whether `button` came up on the last poll.
+    pub fn is_released(&self, button: B) -> bool {
+        self.released & button.mask() != 0
+    }
+
+    /// Whether `button` is down, however long it has been.
+    pub fn is_held(&self, button: B) -> bool {
+        self.held & button.mask() != 0
+    }
+
+    /// The buttons that went down on the last poll.
+    pub fn pressed(&self) -> impl Iterator<Item = B> {
+        let mask = self.pressed;
+        B::ALL.iter().copied().filter(move |button| mask & button.mask() != 0)
+    }
+
+    /// The buttons that came up on the last poll.
+    pub fn released(&self) -> impl Iterator<Item = B> {
+        let mask = self.released;
+        B::ALL.iter().copied().filter(move |button| mask & button.mask() != 0)
+    }
+
+    /// The buttons that are down.
+    pub fn held(&self) -> impl Iterator<Item = B> {
+        let mask = self.held;
+        B::ALL.iter().copied().filter(move |button| mask & button.mask() != 0)
+    }
+
+    /// The raw masks of held, pressed and released buttons.
+    pub const fn bits(&self) -> (u16, u16, u16) {
+        (self.held, self.pressed, self.released)
+    }
+}
+
+impl<B: ButtonSet> Default for ButtonState<B> {
+    fn default() -> Self {
+        Self { held: 0, pressed: 0, released: 0, _buttons: PhantomData }
+    }
+}
+
+/// The buttons in a mask, for [`ButtonState`]'s `Debug`.
+struct Mask<B>(u16, PhantomData<B>);
+
+impl<B: ButtonSet> fmt::Debug for Mask<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(B::ALL.iter().filter(|button| self.0 & button.mask() != 0)).finish()
+    }
+}
+
+impl<B: ButtonSet> fmt::Debug for ButtonState<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ButtonState")
+            .field("held", &Mask::<B>(self.held, PhantomData))
+            .field("pressed", &Mask::<B>(self.pressed, PhantomData))
+            .field("released", &Mask::<B>(self.released, PhantomData))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_splits_masks() {
+        let raw = sys::wiimote_buttons { state: Button::A as u16 | Button::B as u16, held: 0, down: Button::A as u16, up: Button::Home as u16 };
+        let buttons = ButtonState::<Button>::from_raw(&raw);
+        assert!(buttons.is_held(Button::A) && buttons.is_held(Button::B));
+        assert!(buttons.is_pressed(Button::A) && !buttons.is_pressed(Button::B));
+        assert!(buttons.is_released(Button::Home));
+        assert!(buttons.held().eq([Button::B, Button::A]));
+    }
+
+    #[test]
+    fn take_clears_known_bits() {
+        let mut bits = NunchukButton::C as u16 | NunchukButton::Z as u16 | 0x100;
+        assert_eq!(take(&mut bits), Some(NunchukButton::Z));
+        assert_eq!(take(&mut bits), Some(NunchukButton::C));
+        assert_eq!(take::<NunchukButton>(&mut bits), None);
+        assert_eq!(bits, 0x100);
+    }
+}
@@ -0,0 +1,188 @@
+//! What changed between two polls.
+
+use super::buttons::take;
+use super::{Button, ClassicButton, Extension, ExtensionType, NunchukButton, State, Wiimote, MAX_REMOTES};
+
+/// A change to a remote, from [`events`](super::events).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// The remote connected, or reconnected.
+    Connected(Wiimote),
+    /// The remote disconnected. No more events come from it until it
+    /// connects again.
+    Disconnected(Wiimote),
+    /// A button on the remote went down.
+    Pressed(Wiimote, Button),
+    /// A button on the remote came up.
+    Released(Wiimote, Button),
+    /// An extension was plugged in or pulled out, `None` if the remote has
+    /// none now.
+    ExtensionChanged(Wiimote, Option<ExtensionType>),
+    /// A button on the extension went down.
+    ExtensionPressed(Wiimote, ExtensionButton),
+    /// A button on the extension came up.
+    ExtensionReleased(Wiimote, ExtensionButton),
+}
+
+/// A button on an extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtensionButton {
+    Nunchuk(NunchukButton),
+    Classic(ClassicButton),
+}
+
+/// The changes to one remote still to be reported.
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct Changes {
+    connected: Option<bool>,
+    extension: Option<Option<ExtensionType>>,
+    pressed: u16,
+    released: u16,
+    extension_kind: Option<ExtensionType>,
+    extension_pressed: u16,
+    extension_released: u16,
+}
+
+impl Changes {
+    /// The changes from `old` to `new`, `None` for a remote that is not
+    /// connected.
+    pub(super) fn between(old: Option<&State>, new: Option<&State>) -> Self {
+        let Some(new) = new else {
+            return Self { connected: old.map(|_| false), ..Self::default() };
+        };
+
+        let kind = new.extension().kind();
+        let old_kind = old.and_then(|old| old.extension().kind());
+        let (_, pressed, released) = new.buttons().bits();
+        let (_, extension_pressed, extension_released) = match new.extension() {
+            Extension::None => (0, 0, 0),
+            Extension::Nunchuk(nunchuk) => nunchuk.buttons.bits(),
+            Extension::ClassicController(classic) => classic.buttons.bits(),
+        };
+        Self {
+            connected: old.is_none().then_some(true),
+            extension: (kind != old_kind).then_some(kind),
+            pressed,
+            released,
+            extension_kind: kind,
+            extension_pressed,
+            extension_released,
+        }
+    }
+
+    fn extension_button(kind: Option<ExtensionType>, bits: &mut u16) -> Option<ExtensionButton> {
+        match kind? {
+            ExtensionType::Nunchuk => take(bits).map(ExtensionButton::Nunchuk),
+            ExtensionType::ClassicController => take(bits).map(ExtensionButton::Classic),
+            _ => None,
+        }
+    }
+
+    fn next(&mut self, wiimote: Wiimote) -> Option<Event> {
+        match self.connected.take() {
+            Some(true) => return Some(Event::Connected(wiimote)),
+            Some(false) => return Some(Event::Disconnected(wiimote)),
+            None => {}
+        }
+        if let Some(kind) = self.extension.take() {
+            return Some(Event::ExtensionChanged(wiimote, kind));
+        }
+        if let Some(button) = take(&mut self.pressed) {
+            return Some(Event::Pressed(wiimote, button));
+        }
+        if let Some(button) = take(&mut self.released) {
+            return Some(Event::Released(wiimote, button));
+        }
+        if let Some(button) = Self::extension_button(self.extension_kind, &mut self.extension_pressed) {
+            return Some(Event::ExtensionPressed(wiimote, button));
+        }
+        Self::extension_button(self.extension_kind, &mut self.extension_released).map(|button| Event::ExtensionReleased(wiimote, button))
+    }
+}
+
+/// The changes found by one poll, from [`events`](super::events), remote by
+/// remote.
+#[derive(Debug, Clone)]
+pub struct Events {
+    changes: [Changes; MAX_REMOTES],
+    remote: usize,
+}
+
+impl Events {
+    pub(super) const fn new(changes: [Changes; MAX_REMOTES]) -> Self {
+        Self { changes, remote: 0 }
+    }
+}
+
+impl Iterator for Events {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        while let Some(changes) = self.changes.get_mut(self.remote) {
+            if let Some(event) = changes.next(Wiimote::new(self.remote)) {
+                return Some(event);
+            }
+            self.remote += 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sys;
+
+    fn state(buttons: sys::wiimote_buttons, extension: Option<sys::wiimote_buttons>) -> State {
+        let mut raw: sys::wiimote_t = unsafe { core::mem::zeroed() };
+        raw.buttons = buttons;
+        if let Some(buttons) = extension {
+            raw.extensions.type_ = sys::WIIMOTE_EXTENSION_NUNCHUK;
+            raw.extensions.__bindgen_anon_1.nunchuck.buttons = buttons;
+        }
+        State::from_raw(&raw)
+    }
+
+    fn buttons(down: u16, up: u16) -> sys::wiimote_buttons {
+        sys::wiimote_buttons { state: down, held: 0, down, up }
+    }
+
+    fn events(changes: impl IntoIterator<Item = (usize, Changes)>) -> Vec<Event> {
+        let mut all = [Changes::default(); MAX_REMOTES];
+        for (index, changes) in changes {
+            all[index] = changes;
+        }
+        Events::new(all).collect()
+    }
+
+    #[test]
+    fn connecting_reports_everything_new() {
+        let new = state(buttons(Button::A as u16, 0), Some(buttons(NunchukButton::Z as u16, 0)));
+        let remote = Wiimote::new(1);
+        assert_eq!(
+            events([(1, Changes::between(None, Some(&new)))]),
+            [
+                Event::Connected(remote),
+                Event::ExtensionChanged(remote, Some(ExtensionType::Nunchuk)),
+                Event::Pressed(remote, Button::A),
+                Event::ExtensionPressed(remote, ExtensionButton::Nunchuk(NunchukButton::Z)),
+            ]
+        );
+    }
+
+    #[test]
+    fn polls_report_changes_in_remote_order() {
+        let old = state(buttons(0, 0), Some(buttons(0, 0)));
+        let new = state(buttons(Button::One as u16, Button::Two as u16), None);
+        assert_eq!(
+            events([(0, Changes::between(Some(&old), None)), (3, Changes::between(Some(&old), Some(&new)))]),
+            [
+                Event::Disconnected(Wiimote::new(0)),
+                Event::ExtensionChanged(Wiimote::new(3), None),
+                Event::Pressed(Wiimote::new(3), Button::One),
+                Event::Released(Wiimote::new(3), Button::Two),
+            ]
+        );
+        assert!(events([(2, Changes::between(Some(&old), Some(&old)))]).is_empty());
+    }
+}
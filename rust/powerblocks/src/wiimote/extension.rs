@@ -0,0 +1,156 @@
+//! Controllers plugged into the remote, from `wiimote_extension_data_t`.
+
+use core::ffi::CStr;
+use core::fmt;
+
+use super::{ButtonState, ClassicButton, NunchukButton};
+use crate::sys;
+
+/// The kinds of extension the SDK recognizes, like `wiimote_extension_t`.
+///
+/// Only the Nunchuk and Classic Controller are decoded so far. The others
+/// are detected when plugged in, but read as [`Extension::None`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum ExtensionType {
+    Nunchuk = sys::WIIMOTE_EXTENSION_NUNCHUK,
+    ClassicController = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER,
+    ClassicControllerPro = sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_PRO,
+    DrawsomeTablet = sys::WIIMOTE_EXTENSION_DRAWSOME_GRAPHICS_TABLET,
+    GuitarHeroGuitar = sys::WIIMOTE_EXTENSION_GH_GUITAR,
+    GuitarHeroDrums = sys::WIIMOTE_EXTENSION_GH_DRUMS,
+    DjHeroTurntable = sys::WIIMOTE_EXTENSION_DJ_HERO_TURNTABLE,
+    TaikoDrum = sys::WIIMOTE_EXTENSION_TAIKO_DRUMS,
+    UDrawTablet = sys::WIIMOTE_EXTENSION_UDRAW_GAME_TABLET,
+    ShinkansenController = sys::WIIMOTE_EXTENSION_SHINKANSEN_CONTROLLER,
+    BalanceBoard = sys::WIIMOTE_EXTENSION_BALANCE_BOARD,
+}
+
+impl ExtensionType {
+    /// The type for a `wiimote_extension_t`, or `None` for
+    /// `WIIMOTE_EXTENSION_NONE` and unknown values.
+    pub const fn from_raw(raw: sys::wiimote_extension_t) -> Option<Self> {
+        Some(match raw {
+            sys::WIIMOTE_EXTENSION_NUNCHUK => Self::Nunchuk,
+            sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER => Self::ClassicController,
+            sys::WIIMOTE_EXTENSION_CLASSIC_CONTROLLER_PRO => Self::ClassicControllerPro,
+            sys::WIIMOTE_EXTENSION_DRAWSOME_GRAPHICS_TABLET => Self::DrawsomeTablet,
+            sys::WIIMOTE_EXTENSION_GH_GUITAR => Self::GuitarHeroGuitar,
+            sys::WIIMOTE_EXTENSION_GH_DRUMS => Self::GuitarHeroDrums,
+            sys::WIIMOTE_EXTENSION_DJ_HERO_TURNTABLE => Self::DjHeroTurntable,
+            sys::WIIMOTE_EXTENSION_TAIKO_DRUMS => Self::TaikoDrum,
+            sys::WIIMOTE_EXTENSION_UDRAW_GAME_TABLET => Self::UDrawTablet,
+            sys::WIIMOTE_EXTENSION_SHINKANSEN_CONTROLLER => Self::ShinkansenController,
+            sys::WIIMOTE_EXTENSION_BALANCE_BOARD => Self::BalanceBoard,
+            _ => return None,
+        })
+    }
+
+    pub const fn as_raw(self) -> sys::wiimote_extension_t {
+        self as sys::wiimote_extension_t
+    }
+
+    /// The product's name, like `wiimote_extension_get_name`.
+    pub fn name(self) -> &'static str {
+        // The names are static ASCII strings.
+        let name = unsafe { CStr::from_ptr(sys::wiimote_extension_get_name(self.as_raw())) };
+        name.to_str().unwrap_or("Unknown")
+    }
+}
+
+impl fmt::Display for ExtensionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The state of the extension plugged into a remote, keyed on
+/// `wiimote_extension_data_t`'s `type`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Extension {
+    /// Nothing is plugged in, or the SDK cannot decode what is.
+    #[default]
+    None,
+    Nunchuk(Nunchuk),
+    ClassicController(ClassicController),
+}
+
+impl Extension {
+    /// Reads the member of the union that `type` names.
+    pub fn from_raw(raw: &sys::wiimote_extension_data_t) -> Self {
+        match ExtensionType::from_raw(raw.type_) {
+            Some(ExtensionType::Nunchuk) => {
+                // The decoder wrote the Nunchuk member, and any bits are valid
+                // for its fields.
+                let raw = unsafe { &raw.__bindgen_anon_1.nunchuck };
+                Self::Nunchuk(Nunchuk {
+                    buttons: ButtonState::from_raw(&raw.buttons),
+                    stick: [raw.stick.x, raw.stick.y],
+                    accelerometer: [raw.accelerometer.x, raw.accelerometer.y, raw.accelerometer.z],
+                })
+            }
+            Some(ExtensionType::ClassicController) => {
+                let raw = unsafe { &raw.__bindgen_anon_1.classic_controller };
+                Self::ClassicController(ClassicController {
+                    buttons: ButtonState::from_raw(&raw.buttons),
+                    left_stick: [raw.left_stick.x, raw.left_stick.y],
+                    right_stick: [raw.right_stick.x, raw.right_stick.y],
+                    triggers: [raw.triggers.x, raw.triggers.y],
+                })
+            }
+            _ => Self::None,
+        }
+    }
+
+    /// What is plugged in, `None` if nothing the SDK can decode is.
+    pub const fn kind(&self) -> Option<ExtensionType> {
+        match self {
+            Self::None => None,
+            Self::Nunchuk(_) => Some(ExtensionType::Nunchuk),
+            Self::ClassicController(_) => Some(ExtensionType::ClassicController),
+        }
+    }
+}
+
+/// The Nunchuk's buttons, stick and accelerometer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Nunchuk {
+    pub buttons: ButtonState<NunchukButton>,
+    /// X and Y, each about -1.0 to 1.0.
+    pub stick: [f32; 2],
+    /// X, Y and Z acceleration, each about -1.0 to 1.0.
+    pub accelerometer: [f32; 3],
+}
+
+/// The Classic Controller's buttons, sticks and triggers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClassicController {
+    pub buttons: ButtonState<ClassicButton>,
+    /// X and Y, each -1.0 to 1.0.
+    pub left_stick: [f32; 2],
+    /// X and Y, each -1.0 to 1.0.
+    pub right_stick: [f32; 2],
+    /// How far the left and right triggers are pulled, 0.0 to 1.0.
+    pub triggers: [f32; 2],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_member_for_type() {
+        let mut raw: sys::wiimote_extension_data_t = unsafe { core::mem::zeroed() };
+        assert_eq!(Extension::from_raw(&raw), Extension::None);
+
+        raw.type_ = sys::WIIMOTE_EXTENSION_NUNCHUK;
+        raw.__bindgen_anon_1.nunchuck.buttons.state = NunchukButton::C as u16;
+        raw.__bindgen_anon_1.nunchuck.stick = sys::vec2 { x: 0.5, y: -1.0 };
+        let Extension::Nunchuk(nunchuk) = Extension::from_raw(&raw) else { panic!("expected a Nunchuk") };
+        assert!(nunchuk.buttons.is_held(NunchukButton::C));
+        assert_eq!(nunchuk.stick, [0.5, -1.0]);
+
+        raw.type_ = sys::WIIMOTE_EXTENSION_BALANCE_BOARD;
+        assert_eq!(Extension::from_raw(&raw), Extension::None);
+    }
+}
@@ -0,0 +1,180 @@
+//! Wii Remotes, on top of the SDK's Bluetooth driver for them.
+//!
+//! The SDK keeps the state of up to four remotes in `WIIMOTES`, and updates
+//! it on `wiimote_poll`. Here [`events`] polls and reports what changed,
+//! and [`Wiimote`] reads the state one remote had at that poll:
+//!
+//! ```ignore
+//! bluetooth::initialize()?;
+//! wiimote::initialize();
+//!
+//! let mut pacer = FramePacer::new();
+//! loop {
+//!     for event in wiimote::events() {
+//!         match event {
+//!             Event::Pressed(remote, Button::A) => jump(remote.index()),
+//!             Event::ExtensionChanged(remote, Some(ExtensionType::Nunchuk)) => enable_stick(remote.index()),
+//!             _ => {}
+//!         }
+//!     }
+//!     if Wiimote::new(0).is_held(Button::B) {
+//!         fire();
+//!     }
+//!     pacer.wait();
+//! }
+//! ```
+//!
+//! Only poll from one place, and not also with `wiimote_poll` from C, or
+//! presses will be missed.
+
+use core::ffi::c_int;
+use core::ptr;
+
+use crate::bluetooth;
+use crate::rtos::Mutex;
+use crate::sys;
+
+mod buttons;
+mod event;
+mod extension;
+mod state;
+
+pub use buttons::{Button, ButtonSet, ButtonState, ClassicButton, NunchukButton};
+pub use event::{Event, Events, ExtensionButton};
+pub use extension::{ClassicController, Extension, ExtensionType, Nunchuk};
+pub use state::{Accelerometer, Cursor, IrPoint, State};
+
+use event::Changes;
+
+/// How many remotes can be connected at once, like `WIIMOTE_MAX_REMOTES`.
+pub const MAX_REMOTES: usize = sys::WIIMOTE_MAX_REMOTES as usize;
+
+/// The state each remote had at the last poll, `None` while it is not
+/// connected.
+static STATES: Mutex<[Option<State>; MAX_REMOTES]> = Mutex::new([None; MAX_REMOTES]);
+
+/// Registers the remote driver with bltools and lets remotes connect, like
+/// `wiimotes_initialize`. Bluetooth must be started first with
+/// [`bluetooth::initialize`].
+pub fn initialize() {
+    unsafe { sys::wiimotes_initialize() };
+}
+
+/// Polls all remotes, like `wiimote_poll`, and returns what changed since
+/// the last poll.
+///
+/// Call this once a frame, even if the events are not needed, as it is
+/// also what updates the state [`Wiimote`] reads.
+pub fn events() -> Events {
+    let mut states = STATES.lock();
+    unsafe { sys::wiimote_poll() };
+
+    let mut changes = [Changes::default(); MAX_REMOTES];
+    for (index, (state, changes)) in states.iter_mut().zip(&mut changes).enumerate() {
+        // The Bluetooth task connects and disconnects remotes behind our
+        // back, so read the slot in one go.
+        let raw = unsafe { ptr::read_volatile(ptr::addr_of!(sys::WIIMOTES[index])) };
+        let new = (!raw.driver.is_null()).then(|| State::from_raw(&raw));
+        *changes = Changes::between(state.as_ref(), new.as_ref());
+        *state = new;
+    }
+    Events::new(changes)
+}
+
+/// One of the [`MAX_REMOTES`] remote slots, numbered like the lights on
+/// the remote from zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Wiimote(u8);
+
+impl Wiimote {
+    /// The remote in slot `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is not below [`MAX_REMOTES`].
+    pub const fn new(index: usize) -> Self {
+        assert!(index < MAX_REMOTES, "there are only four remotes");
+        Self(index as u8)
+    }
+
+    /// Every remote slot.
+    pub fn all() -> impl Iterator<Item = Self> {
+        (0..MAX_REMOTES).map(Self::new)
+    }
+
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+
+    /// The remote's state as of the last [`events`], `None` if it was not
+    /// connected.
+    pub fn state(self) -> Option<State> {
+        STATES.lock()[self.index()]
+    }
+
+    pub fn is_connected(self) -> bool {
+        self.state().is_some()
+    }
+
+    /// Whether `button` went down on the last poll.
+    pub fn is_pressed(self, button: Button) -> bool {
+        self.state().is_some_and(|state| state.buttons().is_pressed(button))
+    }
+
+    /// Whether `button` came up on the last poll.
+    pub fn is_released(self, button: Button) -> bool {
+        self.state().is_some_and(|state| state.buttons().is_released(button))
+    }
+
+    /// Whether `button` is down.
+    pub fn is_held(self, button: Button) -> bool {
+        self.state().is_some_and(|state| state.buttons().is_held(button))
+    }
+
+    /// What is plugged into the remote.
+    pub fn extension(self) -> Extension {
+        self.state().map(|state| state.extension()).unwrap_or_default()
+    }
+
+    /// Picks what the remote reports besides its buttons, like
+    /// `wiimote_set_reporting`.
+    ///
+    /// Fails with [`bluetooth::Error::InvalidArgument`] if the remote is not
+    /// connected.
+    pub fn set_reporting(self, reporting: Reporting) -> Result<(), bluetooth::Error> {
+        let _states = STATES.lock();
+        let raw = unsafe { ptr::addr_of_mut!(sys::WIIMOTES[self.index()]) };
+        if unsafe { ptr::read_volatile(ptr::addr_of!((*raw).driver)) }.is_null() {
+            return Err(bluetooth::Error::InvalidArgument);
+        }
+        bluetooth::Error::check(unsafe { sys::wiimote_set_reporting(raw, reporting.present()) })?;
+        Ok(())
+    }
+}
+
+/// What a remote reports besides its buttons, from
+/// [`Wiimote::set_reporting`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Reporting {
+    pub accelerometer: bool,
+    /// The IR camera, and the [`Cursor`] worked out from it.
+    pub ir: bool,
+    pub extension: bool,
+}
+
+impl Reporting {
+    /// The `WIIMOTE_PRESENT_*` flags for `wiimote_set_reporting`.
+    const fn present(self) -> c_int {
+        let mut present = sys::WIIMOTE_PRESENT_BUTTONS;
+        if self.accelerometer {
+            present |= sys::WIIMOTE_PRESENT_ACCELEROMETER;
+        }
+        if self.ir {
+            present |= sys::WIIMOTE_PRESENT_IR;
+        }
+        if self.extension {
+            present |= sys::WIIMOTE_PRESENT_EXTENSION;
+        }
+        present as c_int
+    }
+}
@@ -0,0 +1,125 @@
+//! A remote's state after a poll, from `wiimote_t`.
+
+use super::{Button, ButtonState, Extension};
+use crate::sys;
+
+/// What a remote reported as of the last poll.
+///
+/// Which parts it reports is picked with
+/// [`Wiimote::set_reporting`](super::Wiimote::set_reporting). Parts it does
+/// not report read as `None`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct State {
+    present: u32,
+    buttons: ButtonState<Button>,
+    accelerometer: Accelerometer,
+    ir: [Option<IrPoint>; 4],
+    cursor: Cursor,
+    extension: Extension,
+}
+
+impl State {
+    pub fn from_raw(raw: &sys::wiimote_t) -> Self {
+        let accelerometer = &raw.accelerometer;
+        let cursor = &raw.cursor;
+        Self {
+            present: raw.present,
+            buttons: ButtonState::from_raw(&raw.buttons),
+            accelerometer: Accelerometer {
+                acceleration: [accelerometer.rectangular.x, accelerometer.rectangular.y, accelerometer.rectangular.z],
+                spherical: [accelerometer.spherical.x, accelerometer.spherical.y, accelerometer.spherical.z],
+                orientation: [accelerometer.orientation.x, accelerometer.orientation.y, accelerometer.orientation.z],
+            },
+            ir: raw.ir_tracking.map(|point| {
+                point.visible.then_some(IrPoint {
+                    position: [point.position.x, point.position.y],
+                    size: point.size as u8,
+                    intensity: point.intensity,
+                    right: point.side,
+                })
+            }),
+            cursor: Cursor {
+                position: [cursor.pos.x, cursor.pos.y],
+                distance: cursor.distance,
+                z: cursor.z,
+                yaw: cursor.yaw,
+                known_dots: cursor.known_dots as u8,
+            },
+            extension: Extension::from_raw(&raw.extensions),
+        }
+    }
+
+    fn has(&self, present: u32) -> bool {
+        self.present & present != 0
+    }
+
+    /// The buttons on the remote itself.
+    pub fn buttons(&self) -> ButtonState<Button> {
+        self.buttons
+    }
+
+    pub fn accelerometer(&self) -> Option<Accelerometer> {
+        self.has(sys::WIIMOTE_PRESENT_ACCELEROMETER).then_some(self.accelerometer)
+    }
+
+    /// The up to four points of light the camera sees, usually the two ends
+    /// of the sensor bar.
+    pub fn ir_points(&self) -> [Option<IrPoint>; 4] {
+        if self.has(sys::WIIMOTE_PRESENT_IR) {
+            self.ir
+        } else {
+            [None; 4]
+        }
+    }
+
+    /// Where the remote points on the screen, worked out from the IR points.
+    pub fn cursor(&self) -> Option<Cursor> {
+        self.has(sys::WIIMOTE_PRESENT_IR).then_some(self.cursor)
+    }
+
+    /// What is plugged into the remote. Holds its last state while the
+    /// remote does not report extension data.
+    pub fn extension(&self) -> Extension {
+        self.extension
+    }
+}
+
+/// The remote's accelerometer, in G.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Accelerometer {
+    /// X, Y and Z acceleration.
+    pub acceleration: [f32; 3],
+    /// The acceleration as a magnitude and two angles.
+    pub spherical: [f32; 3],
+    /// Like `spherical`, but only updated while the remote is held still,
+    /// so it is the last orientation that can be trusted.
+    pub orientation: [f32; 3],
+}
+
+/// A point of light seen by the remote's camera.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IrPoint {
+    /// X and Y, 0 to 1023 and 0 to 767.
+    pub position: [i32; 2],
+    /// 0 to 15, in the extended and full IR modes.
+    pub size: u8,
+    /// Only in the full IR mode.
+    pub intensity: u8,
+    /// Whether the point is the right end of the sensor bar.
+    pub right: bool,
+}
+
+/// Where the remote points, from `wiimote_t`'s `cursor`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cursor {
+    /// In pixels. May be off screen.
+    pub position: [i32; 2],
+    /// Between the two ends of the sensor bar, in camera pixels.
+    pub distance: f32,
+    /// 1023 less `distance`, which grows with the distance to the screen.
+    pub z: f32,
+    pub yaw: f32,
+    /// How many points were seen before tracking was lost, zero while
+    /// nothing is seen.
+    pub known_dots: u8,
+}
@@ -0,0 +1,182 @@
+//! Renders into an in-memory framebuffer on the host.
+//!
+//! Fills are compared with `framebuffer_fill_rgba` itself, built from the
+//! SDK sources with the host C compiler (`$CC`, or `cc`) along with
+//! `reference.c`. Drawing with `embedded-graphics` is compared with golden
+//! images in this directory, stored as raw big-endian YUY2. Run with
+//! `UPDATE_GOLDEN=1` to rewrite them after an intended change.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use embedded_graphics::mono_font::ascii::FONT_6X10;
+use embedded_graphics::mono_font::MonoTextStyle;
+use embedded_graphics::pixelcolor::Rgb888;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{Circle, Line, PrimitiveStyle, Rectangle, Triangle};
+use embedded_graphics::text::Text;
+
+use powerblocks::framebuffer::{Framebuffer, Rgba8888, WIDTH};
+use powerblocks::sys;
+
+fn framebuffer() -> Box<sys::framebuffer_t> {
+    let mut framebuffer = unsafe { Box::<sys::framebuffer_t>::new_zeroed().assume_init() };
+    for (y, row) in framebuffer.pixels.iter_mut().enumerate() {
+        for (x, pixel) in row.iter_mut().enumerate() {
+            *pixel = (x * 331 + y * 1031) as u16;
+        }
+    }
+    framebuffer
+}
+
+fn reference_fills(fills: &[(i32, i32, i32, i32, u32)]) -> Vec<u16> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let sdk_dir = dir.join("../..");
+    let program = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("framebuffer_reference");
+
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".into());
+    let status = Command::new(&cc)
+        .arg("-std=gnu11")
+        .arg("-w")
+        .arg("-I").arg(&sdk_dir)
+        .arg(dir.join("tests/framebuffer/reference.c"))
+        .arg(sdk_dir.join("powerblocks/core/graphics/framebuffer.c"))
+        .arg("-o").arg(&program)
+        .status()
+        .unwrap_or_else(|e| panic!("failed to run C compiler `{cc}`: {e}"));
+    assert!(status.success(), "failed to compile reference.c");
+
+    let args = fills.iter().flat_map(|&(ax, ay, bx, by, rgba)| {
+        [ax.to_string(), ay.to_string(), bx.to_string(), by.to_string(), rgba.to_string()]
+    });
+    let output = Command::new(&program).args(args).output().expect("failed to run reference");
+    assert!(output.status.success(), "reference exited with {}", output.status);
+
+    output.stdout.chunks_exact(2).map(|pixel| u16::from_ne_bytes([pixel[0], pixel[1]])).collect()
+}
+
+fn assert_pixels_eq(actual: &[u16], expected: &[u16], width: usize) {
+    assert_eq!(actual.len(), expected.len());
+    let mismatches: Vec<_> = actual
+        .iter()
+        .zip(expected)
+        .enumerate()
+        .filter(|(_, (a, e))| a != e)
+        .map(|(i, (a, e))| format!("({}, {}): {a:#06x}, expected {e:#06x}", i % width, i / width))
+        .collect();
+    let first = &mismatches[..mismatches.len().min(10)];
+    assert!(mismatches.is_empty(), "{} pixels differ, first:\n{}", mismatches.len(), first.join("\n"));
+}
+
+#[test]
+fn fill_matches_framebuffer_fill_rgba() {
+    // framebuffer_fill_rgba pairs pixels from the left edge of the fill and
+    // approximates V with U for a lone last pixel, so translucent fills here
+    // start and end on even columns.
+    let fills = [
+        (0, 0, 640, 480, 0x204060ff),
+        (10, 12, 300, 200, 0xff000080),
+        (100, 50, 401, 61, 0x00ff00ff),
+        (-20, -20, 50, 30, 0x0000ffc0),
+        (600, 400, 700, 500, 0xffffff40),
+        (40, 300, 41, 420, 0xff8000ff),
+        (200, 100, 200, 300, 0xffffffff),
+    ];
+
+    let mut raw = framebuffer();
+    let fb = Framebuffer::from_raw(&mut raw);
+    for (ax, ay, bx, by, rgba) in fills {
+        let (width, height) = ((bx - ax).max(0) as u32, (by - ay).max(0) as u32);
+        fb.fill(ax, ay, width, height, Rgba8888::from_raw(rgba));
+    }
+
+    assert_pixels_eq(fb.pixels().as_flattened(), &reference_fills(&fills), WIDTH);
+}
+
+#[test]
+fn pairs_start_on_even_columns() {
+    let mut raw = framebuffer();
+    let fb = Framebuffer::from_raw(&mut raw);
+    let before = *fb.pixels();
+    fb.fill(3, 0, 1, 1, Rgba8888::WHITE);
+
+    // Only the odd pixel's luma and V change; its even neighbour keeps its
+    // luma and U.
+    assert_eq!(fb.pixels()[0][2], before[0][2]);
+    assert_eq!(fb.pixels()[0][3] >> 8, 235);
+    assert_eq!(fb.pixels()[0][4..], before[0][4..]);
+}
+
+#[test]
+fn draw_target_fills_like_fill() {
+    let mut raw = framebuffer();
+    let mut expected = framebuffer();
+    let fb = Framebuffer::from_raw(&mut raw);
+    Framebuffer::from_raw(&mut expected).fill(5, 7, 31, 9, Rgba8888::new(10, 200, 30, 255));
+
+    // Drawn pixel by pixel, so this goes through draw_iter rather than
+    // fill_solid.
+    let area = Rectangle::new(Point::new(5, 7), Size::new(31, 9));
+    let colors = area.points().map(|_| Rgb888::new(10, 200, 30));
+    fb.fill_contiguous(&area, colors).unwrap();
+
+    assert_pixels_eq(fb.pixels().as_flattened(), expected.pixels.as_flattened(), WIDTH);
+}
+
+/// Crops `width` x `height` pixels from the top left and compares them with
+/// `name` in this directory.
+fn assert_golden(fb: &Framebuffer, name: &str, width: usize, height: usize) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/framebuffer").join(name);
+    let actual: Vec<u16> = fb.pixels()[..height].iter().flat_map(|row| &row[..width]).copied().collect();
+
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, actual.iter().flat_map(|pixel| pixel.to_be_bytes()).collect::<Vec<_>>()).unwrap();
+        return;
+    }
+
+    let golden = fs::read(&path).unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+    let expected: Vec<u16> = golden.chunks_exact(2).map(|pixel| u16::from_be_bytes([pixel[0], pixel[1]])).collect();
+    assert_pixels_eq(&actual, &expected, width);
+}
+
+#[test]
+fn primitives_match_golden() {
+    let mut raw = framebuffer();
+    let fb = Framebuffer::from_raw(&mut raw);
+    fb.clear(Rgba8888::BLACK);
+
+    Line::new(Point::new(2, 3), Point::new(90, 60))
+        .into_styled(PrimitiveStyle::with_stroke(Rgb888::RED, 3))
+        .draw(fb)
+        .unwrap();
+    Circle::new(Point::new(60, 4), 40)
+        .into_styled(PrimitiveStyle::with_fill(Rgb888::new(30, 144, 255)))
+        .draw(fb)
+        .unwrap();
+    Triangle::new(Point::new(10, 60), Point::new(50, 20), Point::new(70, 62))
+        .into_styled(PrimitiveStyle::with_stroke(Rgb888::YELLOW, 1))
+        .draw(fb)
+        .unwrap();
+    Rectangle::new(Point::new(20, 10), Size::new(80, 30))
+        .into_styled(PrimitiveStyle::with_fill(Rgba8888::new(0, 255, 0, 96)))
+        .draw(&mut fb.blend())
+        .unwrap();
+
+    assert_golden(fb, "primitives.yuy2", 128, 64);
+}
+
+#[test]
+fn text_matches_golden() {
+    let mut raw = framebuffer();
+    let fb = Framebuffer::from_raw(&mut raw);
+    fb.clear(Rgba8888::new(0, 0, 128, 255));
+
+    let style = MonoTextStyle::new(&FONT_6X10, Rgb888::WHITE);
+    Text::new("PowerBlocks", Point::new(3, 10), style).draw(fb).unwrap();
+    let shadow = MonoTextStyle::new(&FONT_6X10, Rgba8888::new(255, 255, 0, 128));
+    Text::new("YUY2", Point::new(4, 22), shadow).draw(&mut fb.blend()).unwrap();
+
+    assert_golden(fb, "text.yuy2", 96, 32);
+}
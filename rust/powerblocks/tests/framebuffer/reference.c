@@ -0,0 +1,37 @@
+/**
+ * @file reference.c
+ * @brief Renders fills with framebuffer_fill_rgba for tests/framebuffer.rs.
+ *
+ * Built and run on the host. Starts from the same patterned framebuffer
+ * as the Rust side, applies one framebuffer_fill_rgba per group of five
+ * arguments "<a.x> <a.y> <b.x> <b.y> <rgba>", and writes the pixels to
+ * stdout in host byte order.
+ *
+ * @author Samuel Fitzsimons (rainbain)
+ * @date 2025
+ * @license MIT (see LICENSE file)
+ */
+
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "powerblocks/core/graphics/framebuffer.h"
+
+static framebuffer_t framebuffer;
+
+int main(int argc, char** argv) {
+    for(int y = 0; y < VIDEO_HEIGHT; y++) {
+        for(int x = 0; x < VIDEO_WIDTH; x++)
+            framebuffer.pixels[y][x] = (uint16_t)(x * 331 + y * 1031);
+    }
+
+    for(int i = 1; i + 4 < argc; i += 5) {
+        vec2i a = vec2i_new(atoi(argv[i]), atoi(argv[i + 1]));
+        vec2i b = vec2i_new(atoi(argv[i + 2]), atoi(argv[i + 3]));
+        uint32_t rgba = (uint32_t)strtoul(argv[i + 4], NULL, 0);
+        framebuffer_fill_rgba(&framebuffer, rgba, a, b);
+    }
+
+    fwrite(framebuffer.pixels, sizeof(framebuffer.pixels), 1, stdout);
+    return 0;
+}
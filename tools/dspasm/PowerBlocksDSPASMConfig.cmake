@@ -1,3 +1,3 @@
-include("${CMAKE_CURRENT_LIST_DIR}/PowerBlocksDSPasmMacros.cmake")
+include("${CMAKE_CURRENT_LIST_DIR}/PowerBlocksDSPASMMacros.cmake")
 
 set(PowerBlocksDSPASM_VERSION 1.0.0)
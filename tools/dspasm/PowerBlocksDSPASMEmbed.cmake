@@ -0,0 +1,49 @@
+# Writes assembled microcode out as a C array, run by dsp_embed_microcode.
+#   cmake -DINPUT=<bin> -DSYMBOL=<name> -DOUTPUT_SOURCE=<c> -DOUTPUT_HEADER=<h> -P PowerBlocksDSPASMEmbed.cmake
+
+file(READ ${INPUT} DATA HEX)
+string(LENGTH "${DATA}" DATA_LENGTH)
+
+if(DATA_LENGTH EQUAL 0)
+    message(FATAL_ERROR "DSP microcode ${INPUT} is empty")
+endif()
+
+# The DMA copies whole 32 byte blocks, so pad it out to them.
+math(EXPR PADDING "(64 - ${DATA_LENGTH} % 64) % 64")
+if(PADDING GREATER 0)
+    string(REPEAT "0" ${PADDING} ZEROS)
+    string(APPEND DATA "${ZEROS}")
+    math(EXPR DATA_LENGTH "${DATA_LENGTH} + ${PADDING}")
+endif()
+
+# 16 bytes on each line
+set(BYTES "")
+set(OFFSET 0)
+while(OFFSET LESS DATA_LENGTH)
+    string(SUBSTRING "${DATA}" ${OFFSET} 32 LINE)
+    string(REGEX REPLACE "([0-9a-fA-F][0-9a-fA-F])" "0x\\1, " LINE "${LINE}")
+    string(STRIP "${LINE}" LINE)
+    string(APPEND BYTES "    ${LINE}\n")
+    math(EXPR OFFSET "${OFFSET} + 32")
+endwhile()
+
+file(WRITE ${OUTPUT_HEADER}
+"// Generated by dsp_embed_microcode, do not edit.
+#pragma once
+
+#include <stddef.h>
+#include <stdint.h>
+
+extern const uint8_t ${SYMBOL}[];
+extern const size_t ${SYMBOL}_size;
+")
+
+file(WRITE ${OUTPUT_SOURCE}
+"// Generated by dsp_embed_microcode, do not edit.
+#include \"${SYMBOL}.h\"
+
+const uint8_t ${SYMBOL}[] __attribute__((aligned(32))) = {
+${BYTES}};
+
+const size_t ${SYMBOL}_size = sizeof(${SYMBOL});
+")
@@ -1,6 +1,8 @@
 find_package(Python3 REQUIRED COMPONENTS Interpreter)
 
-set(DSPASM_CLI_PATH "${CMAKE_CURRENT_LIST_DIR}/dspasm_cli.py")
+# Cached so the macros can use them from any directory
+set(DSPASM_CLI_PATH "${CMAKE_CURRENT_LIST_DIR}/dspasm_cli.py" CACHE INTERNAL "DSP microcode assembler")
+set(DSPASM_EMBED_PATH "${CMAKE_CURRENT_LIST_DIR}/PowerBlocksDSPASMEmbed.cmake" CACHE INTERNAL "DSP microcode embedding script")
 
 macro(dsp_assemble target_name)
     set(SOURCE_FILES ${ARGN})
@@ -29,3 +31,36 @@ macro(dsp_assemble target_name)
     # Export path for convenience
     set(${target_name}_BINARY ${ABS_OUTPUT} CACHE INTERNAL "DSP microcode binary for ${target_name}")
 endmacro()
+
+# Assembles microcode and builds it into a target, ready for dsp_load.
+# It is included with #include "<symbol>.h", which declares:
+#   extern const uint8_t <symbol>[];
+#   extern const size_t <symbol>_size;
+# The assembly target is named <target>_<symbol>, so several targets
+# can each embed microcode under the same symbol.
+macro(dsp_embed_microcode target symbol source)
+    set(DSP_EMBED_NAME ${target}_${symbol})
+    dsp_assemble(${DSP_EMBED_NAME} ${source})
+
+    # Kept apart per target, as the header is included by the symbol name
+    set(DSP_EMBED_DIR ${DSP_GENERATED_DIR}/${target})
+    file(MAKE_DIRECTORY ${DSP_EMBED_DIR})
+    set(DSP_EMBED_SOURCE ${DSP_EMBED_DIR}/${symbol}.c)
+    set(DSP_EMBED_HEADER ${DSP_EMBED_DIR}/${symbol}.h)
+
+    add_custom_command(
+        OUTPUT ${DSP_EMBED_SOURCE} ${DSP_EMBED_HEADER}
+        COMMAND ${CMAKE_COMMAND}
+            -DINPUT=${${DSP_EMBED_NAME}_BINARY}
+            -DSYMBOL=${symbol}
+            -DOUTPUT_SOURCE=${DSP_EMBED_SOURCE}
+            -DOUTPUT_HEADER=${DSP_EMBED_HEADER}
+            -P ${DSPASM_EMBED_PATH}
+        DEPENDS ${${DSP_EMBED_NAME}_BINARY} ${DSPASM_EMBED_PATH}
+        COMMENT "Embedding DSP microcode: ${${DSP_EMBED_NAME}_BINARY} -> ${DSP_EMBED_SOURCE}"
+        VERBATIM
+    )
+
+    target_sources(${target} PRIVATE ${DSP_EMBED_SOURCE})
+    target_include_directories(${target} PRIVATE ${DSP_EMBED_DIR})
+endmacro()
@@ -367,7 +367,7 @@ class TokenConsumer:
             if nests > 0:
                 field.append(value)
 
-                if value.type == end_value:
+                if value.type == "RPAREN":
                     nests -= 1
                 
                 continue
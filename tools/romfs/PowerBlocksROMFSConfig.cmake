@@ -0,0 +1,3 @@
+include("${CMAKE_CURRENT_LIST_DIR}/PowerBlocksROMFSMacros.cmake")
+
+set(PowerBlocksROMFS_VERSION 1.0.0)
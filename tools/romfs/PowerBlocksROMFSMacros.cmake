@@ -0,0 +1,34 @@
+find_package(Python3 REQUIRED COMPONENTS Interpreter)
+
+# Cached so the macros can use them from any directory
+set(ROMFS_PACK_PATH "${CMAKE_CURRENT_LIST_DIR}/romfs_pack.py" CACHE INTERNAL "ROM file system packer")
+set(ROMFS_PYTHON_PATH "${Python3_EXECUTABLE}" CACHE INTERNAL "Python running the ROM file system packer")
+
+# Packs a directory into a romfs archive and builds it into a target, ready for vfs_romfs_mount.
+# It is included with #include "<symbol>.h", which declares:
+#   extern const uint8_t <symbol>[];
+#   extern const size_t <symbol>_size;
+macro(romfs_embed target symbol directory)
+    get_filename_component(ROMFS_DIRECTORY ${directory} ABSOLUTE)
+
+    set(ROMFS_GENERATED_DIR ${CMAKE_CURRENT_BINARY_DIR}/romfs)
+    file(MAKE_DIRECTORY ${ROMFS_GENERATED_DIR})
+
+    set(ROMFS_SOURCE ${ROMFS_GENERATED_DIR}/${symbol}.c)
+    set(ROMFS_HEADER ${ROMFS_GENERATED_DIR}/${symbol}.h)
+
+    # Repack when files are added, removed or changed
+    file(GLOB_RECURSE ROMFS_FILES CONFIGURE_DEPENDS ${ROMFS_DIRECTORY}/*)
+
+    add_custom_command(
+        OUTPUT ${ROMFS_SOURCE} ${ROMFS_HEADER}
+        COMMAND ${ROMFS_PYTHON_PATH} ${ROMFS_PACK_PATH} ${ROMFS_DIRECTORY}
+            -s ${symbol} --c-source ${ROMFS_SOURCE} --c-header ${ROMFS_HEADER}
+        DEPENDS ${ROMFS_FILES} ${ROMFS_PACK_PATH}
+        COMMENT "Packing romfs: ${ROMFS_DIRECTORY} -> ${ROMFS_SOURCE}"
+        VERBATIM
+    )
+
+    target_sources(${target} PRIVATE ${ROMFS_SOURCE})
+    target_include_directories(${target} PRIVATE ${ROMFS_GENERATED_DIR})
+endmacro()
@@ -0,0 +1,165 @@
+"""
+ROM File System Packer
+
+Packs a directory into a romfs archive, which is linked
+into the app and read by powerblocks/filesystem/romfs.c.
+
+Archive layout, all numbers big endian:
+    Header, 32 bytes
+        char[4]  magic "PBRF"
+        uint32   version
+        uint32   entry count
+        uint32   archive size
+        uint32   offset of the name table
+        12 bytes zero
+    Entries, 16 bytes each. Entry 0 is the root directory.
+        uint32   name offset
+        uint32   type, 0 for files, 1 for directories
+        uint32   file: data offset, directory: index of first child
+        uint32   file: size, directory: number of children
+    Name table of zero terminated UTF-8 names
+    File data
+
+A directory's children are next to each other, sorted by name.
+File data and the archive size are aligned to 32 bytes.
+
+Author: Samuel Fitzsimons (rainbain)
+File: romfs_pack.py
+Date: 2025
+"""
+#!/usr/bin/env python3
+
+import argparse
+import struct
+import sys
+from pathlib import Path
+
+MAGIC = b"PBRF"
+VERSION = 1
+ALIGNMENT = 32
+
+HEADER_FORMAT = ">4sIIII12x"
+ENTRY_FORMAT = ">IIII"
+
+TYPE_FILE = 0
+TYPE_DIRECTORY = 1
+
+class Node:
+    def __init__(self, name, path):
+        self.name = name
+        self.path = path
+        self.children = []
+        self.index = 0
+
+    def is_directory(self):
+        return self.path.is_dir()
+
+def align(value):
+    return (value + ALIGNMENT - 1) & ~(ALIGNMENT - 1)
+
+def gather(path, name=""):
+    node = Node(name, path)
+
+    if node.is_directory():
+        for child in sorted(path.iterdir(), key=lambda p: p.name.encode("utf-8")):
+            if child.is_dir() or child.is_file():
+                node.children.append(gather(child, child.name))
+
+    return node
+
+def pack(root):
+    # Breadth first, so the children of each directory come out together
+    entries = [root]
+    queue = [root]
+    while queue:
+        node = queue.pop(0)
+        node.index = len(entries) if node.children else 0
+        entries.extend(node.children)
+        queue.extend(child for child in node.children if child.is_directory())
+
+    header_size = struct.calcsize(HEADER_FORMAT)
+    entry_size = struct.calcsize(ENTRY_FORMAT)
+
+    # Names, the root's is the empty one at the start
+    names = bytearray(b"\0")
+    name_offsets = {}
+    names_offset = header_size + entry_size * len(entries)
+    for node in entries[1:]:
+        name_offsets[id(node)] = names_offset + len(names)
+        names += node.name.encode("utf-8") + b"\0"
+    name_offsets[id(root)] = names_offset
+
+    # File data
+    data = bytearray()
+    data_start = align(names_offset + len(names))
+    data_offsets = {}
+    for node in entries:
+        if node.is_directory():
+            continue
+
+        data += bytes(align(len(data)) - len(data))
+        data_offsets[id(node)] = data_start + len(data)
+        data += node.path.read_bytes()
+
+    size = align(data_start + len(data))
+
+    archive = bytearray(struct.pack(HEADER_FORMAT, MAGIC, VERSION, len(entries), size, names_offset))
+    for node in entries:
+        if node.is_directory():
+            archive += struct.pack(ENTRY_FORMAT, name_offsets[id(node)], TYPE_DIRECTORY, node.index, len(node.children))
+        else:
+            archive += struct.pack(ENTRY_FORMAT, name_offsets[id(node)], TYPE_FILE, data_offsets[id(node)], node.path.stat().st_size)
+
+    archive += names
+    archive += bytes(data_start - len(archive))
+    archive += data
+    archive += bytes(size - len(archive))
+
+    return bytes(archive)
+
+def write_c(archive, symbol, source_path, header_path):
+    with open(header_path, "w") as f:
+        f.write("// Generated by romfs_pack.py, do not edit.\n")
+        f.write("#pragma once\n\n")
+        f.write("#include <stddef.h>\n")
+        f.write("#include <stdint.h>\n\n")
+        f.write(f"extern const uint8_t {symbol}[];\n")
+        f.write(f"extern const size_t {symbol}_size;\n")
+
+    with open(source_path, "w") as f:
+        f.write("// Generated by romfs_pack.py, do not edit.\n")
+        f.write(f"#include \"{header_path.name}\"\n\n")
+        f.write(f"const uint8_t {symbol}[] __attribute__((aligned({ALIGNMENT}))) = {{\n")
+        for i in range(0, len(archive), 16):
+            f.write("    " + " ".join(f"0x{b:02x}," for b in archive[i:i + 16]) + "\n")
+        f.write("};\n\n")
+        f.write(f"const size_t {symbol}_size = sizeof({symbol});\n")
+
+def main():
+    parser = argparse.ArgumentParser(description="PowerBlocks SDK ROM File System Packer")
+    parser.add_argument("input", type=Path, help="Directory to pack")
+    parser.add_argument("-o", "--output", type=Path, help="Output archive")
+    parser.add_argument("-s", "--symbol", help="Name of the C array, for --c-source and --c-header", default="romfs")
+    parser.add_argument("--c-source", type=Path, help="Output the archive as a C array")
+    parser.add_argument("--c-header", type=Path, help="Output the header declaring the C array")
+
+    args = parser.parse_args()
+
+    if not args.input.is_dir():
+        print(f"{args.input}: Not a directory")
+        sys.exit(1)
+
+    if (args.c_source is None) != (args.c_header is None):
+        print("--c-source and --c-header must be given together")
+        sys.exit(1)
+
+    archive = pack(gather(args.input))
+
+    if args.output:
+        args.output.write_bytes(archive)
+
+    if args.c_source:
+        write_c(archive, args.symbol, args.c_source, args.c_header)
+
+if __name__ == "__main__":
+    main()